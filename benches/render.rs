@@ -0,0 +1,192 @@
+//! Benchmarks for rebuilding, layout and painting, run with `cargo bench --features bench`.
+
+use std::{
+    any::Any,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use trui::{bench::Harness, *};
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn long_list(c: &mut Criterion) {
+    let list = |selected: &mut usize| {
+        let selected = *selected;
+        v_stack(
+            (0..10_000)
+                .map(|i| {
+                    format!("Item {i}").fg(if i == selected {
+                        Color::Red
+                    } else {
+                        Color::Blue
+                    })
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+    c.bench_function("list 10k build", |b| {
+        b.iter_batched(
+            || Harness::new(0, list, 80, 40),
+            |mut harness| harness.frame(FRAME),
+            BatchSize::LargeInput,
+        )
+    });
+    let mut harness = Harness::new(0, list, 80, 40);
+    harness.frame(FRAME);
+    c.bench_function("list 10k rebuild unchanged", |b| {
+        b.iter(|| harness.frame(FRAME))
+    });
+    c.bench_function("list 10k rebuild one changed", |b| {
+        b.iter(|| {
+            *harness.data_mut() = (*harness.data_mut() + 1) % 10_000;
+            harness.frame(FRAME)
+        })
+    });
+}
+
+fn nested(depth: usize, label: String) -> Box<dyn AnyView<()>> {
+    (0..depth).fold(label.boxed(), |view, i| {
+        if i % 2 == 0 {
+            view.border(BorderKind::Straight).boxed()
+        } else {
+            view.margin(1).boxed()
+        }
+    })
+}
+
+fn deep_nesting(c: &mut Criterion) {
+    c.bench_function("nesting 200 build", |b| {
+        b.iter_batched(
+            || Harness::new((), |_: &mut ()| nested(200, "Deep".into()), 500, 500),
+            |mut harness| harness.frame(FRAME),
+            BatchSize::LargeInput,
+        )
+    });
+    let mut count = 0;
+    let mut harness = Harness::new(
+        (),
+        move |_: &mut ()| {
+            count += 1;
+            nested(200, format!("Frame {count}"))
+        },
+        500,
+        500,
+    );
+    harness.frame(FRAME);
+    c.bench_function("nesting 200 relayout", |b| b.iter(|| harness.frame(FRAME)));
+}
+
+fn animation_storm(c: &mut Criterion) {
+    let storm = |_: &mut ()| {
+        v_stack(
+            (0..1_000)
+                .map(|i| {
+                    format!("Block {i}")
+                        .fg(Color::Rgb(0, (i % 256) as u8, 255))
+                        .fill_max_width(
+                            (Length::Percent(0.0)..Length::Percent(1.0))
+                                .quadratic_in_out_ease()
+                                .play(1.0)
+                                .repeat(Repeat::Forever)
+                                .ping_pong(),
+                        )
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+    let mut harness = Harness::new((), storm, 120, 1_000);
+    harness.frame(FRAME);
+    c.bench_function("animation storm 1k", |b| b.iter(|| harness.frame(FRAME)));
+}
+
+/// Records the id path of its content when it's built, to send messages to it.
+struct Probe {
+    label: String,
+    id_path: Arc<Mutex<Vec<Id>>>,
+}
+
+impl ViewMarker for Probe {}
+
+impl View<()> for Probe {
+    type State = <String as View<()>>::State;
+
+    type Element = <String as View<()>>::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = View::<()>::build(&self.label, cx);
+        let mut id_path = cx.id_path().clone();
+        id_path.push(id);
+        *self.id_path.lock().unwrap() = id_path;
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        View::<()>::rebuild(&self.label, cx, &prev.label, id, state, element)
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        _message: Box<dyn Any>,
+        _app_state: &mut (),
+    ) -> MessageResult<()> {
+        MessageResult::Nop
+    }
+}
+
+fn probes(id_path: &Arc<Mutex<Vec<Id>>>) -> impl Iterator<Item = Probe> + '_ {
+    (0..10_000).map(|i| Probe {
+        label: format!("Item {i}"),
+        id_path: id_path.clone(),
+    })
+}
+
+fn message_routing(c: &mut Criterion) {
+    let id_path = Arc::new(Mutex::new(Vec::new()));
+
+    let shared = id_path.clone();
+    let mut harness = Harness::new(
+        (),
+        move |_: &mut ()| v_stack(probes(&shared).collect::<Vec<_>>()),
+        80,
+        40,
+    );
+    harness.frame(FRAME);
+    // the last item was built last
+    let last = id_path.lock().unwrap().clone();
+    c.bench_function("list 10k message to last", |b| {
+        b.iter(|| harness.message(&last, Box::new(())))
+    });
+
+    let shared = id_path.clone();
+    let mut harness = Harness::new(
+        (),
+        move |_: &mut ()| v_stack(keyed(probes(&shared).enumerate())),
+        80,
+        40,
+    );
+    harness.frame(FRAME);
+    let last = id_path.lock().unwrap().clone();
+    c.bench_function("keyed list 10k message to last", |b| {
+        b.iter(|| harness.message(&last, Box::new(())))
+    });
+}
+
+criterion_group!(
+    benches,
+    long_list,
+    deep_nesting,
+    animation_storm,
+    message_routing
+);
+criterion_main!(benches);
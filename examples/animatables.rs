@@ -3,6 +3,7 @@ use std::{f64::consts::PI, time::Duration};
 use anyhow::Result;
 use ratatui::style::{Color, Style};
 use trui::*;
+use Length::Percent;
 
 pub fn button<T>(
     content: impl View<T>,
@@ -51,20 +52,24 @@ fn main() -> Result<()> {
                 weighted_h_stack((tab(0), tab(1), tab(2), tab(3), tab(4))),
                 "Elastic Title"
                     .fill_max_width(
-                        (0.2..1.0)
+                        (Percent(0.2)..Percent(1.0))
                             .duration(Duration::from_secs(3))
                             .elastic_in_out_ease()
                             .play(play_speed),
                     )
                     .border((Borders::HORIZONTAL, BorderKind::ThickStraight)),
                 "Quadratic Title"
-                    .fill_max_width((0.2..1.0).quadratic_in_out_ease().play(play_speed))
+                    .fill_max_width(
+                        (Percent(0.2)..Percent(1.0))
+                            .quadratic_in_out_ease()
+                            .play(play_speed),
+                    )
                     .border((Borders::HORIZONTAL, BorderKind::ThickStraight)),
                 "This does some weird stuff"
                     .fill_max_width(
                         (
-                            (0.2..1.0).duration(Duration::from_secs(1)),
-                            (1.0..0.5)
+                            (Percent(0.2)..Percent(1.0)).duration(Duration::from_secs(1)),
+                            (Percent(1.0)..Percent(0.5))
                                 .duration(Duration::from_secs(2))
                                 .quadratic_in_out_ease(),
                         )
@@ -79,7 +84,7 @@ fn main() -> Result<()> {
                 "This is oscillating"
                     .fill_max_width(
                         (0.0..(4.0 * PI))
-                            .map(|v| v.cos() * 0.3 + 0.7)
+                            .map(|v: &f64| Percent(v.cos() * 0.3 + 0.7))
                             .duration(Duration::from_secs(4))
                             .play(play_speed),
                     )
@@ -91,14 +96,14 @@ fn main() -> Result<()> {
                     "same, but different"
                         .border(BorderKind::Rounded)
                         .fill_max_height(
-                            (0.1..1.0)
+                            (Percent(0.1)..Percent(1.0))
                                 .quadratic_in_out_ease()
                                 .lerp(low_pass(0.05, if state.maximize { 0.7 } else { 0.1 })),
                         ),
                 )),
                 "Expanding Title 2"
                     .fill_max_width(
-                        (0.2..1.0)
+                        (Percent(0.2)..Percent(1.0))
                             .reverse()
                             .map_ease(|r| 1.0 - r) // cancels out the reverse(). Why? Because it's possible...
                             .duration(Duration::from_millis(500))
@@ -1,18 +1,7 @@
 use anyhow::Result;
-use ratatui::style::{Color, Style};
+use ratatui::style::Color;
 use trui::*;
 
-pub fn button<T>(
-    content: impl View<T>,
-    click_cb: impl EventHandler<T> + Send,
-) -> impl View<T> + Styleable {
-    content
-        .border(BorderKind::ThickStraight)
-        .on_hover_style(Style::default().fg(Color::Green).bg(Color::LightYellow))
-        .on_pressed_fg(Color::Blue)
-        .on_click(click_cb)
-}
-
 fn main() -> Result<()> {
     App::new(0, |count| {
         v_stack((
@@ -22,7 +11,9 @@ fn main() -> Result<()> {
             ),
             button("Click me to decrement".fg(Color::Red), |count: &mut i32| {
                 *count -= 1
-            }),
+            })
+            .disabled(*count <= 0),
+            "Use Tab to focus a button and Enter or Space to press it",
         ))
     })
     .run()
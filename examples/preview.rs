@@ -0,0 +1,26 @@
+//! Renders a responsive layout at several terminal sizes into the directory given as the first argument (`preview` by default),
+//! run with `cargo run --example preview --features preview -- <dir>`.
+
+use anyhow::Result;
+use trui::{preview::Preview, *};
+
+fn main() -> Result<()> {
+    let dir = std::env::args().nth(1).unwrap_or_else(|| "preview".into());
+    let app_logic = |_: &mut ()| {
+        responsive(|size_class| {
+            let sidebar = v_stack(("Inbox", "Sent", "Drafts")).border(BorderKind::Rounded);
+            let content = "The selected message".border(BorderKind::Rounded);
+            match size_class {
+                SizeClass::Compact => OneOf2::A(v_stack((sidebar, content))),
+                SizeClass::Medium | SizeClass::Wide => {
+                    OneOf2::B(weighted_h_stack((sidebar.weight(1.0), content.weight(3.0))))
+                }
+            }
+        })
+    };
+    let paths = Preview::new((), app_logic).save(&dir, "layout", ScreenshotFormat::Plain)?;
+    for path in paths {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//! An animation storm: 1000 blocks, which grow and shrink continuously, all with their own tween.
+
+use anyhow::Result;
+use ratatui::style::Color;
+use trui::*;
+
+fn main() -> Result<()> {
+    App::new((), |_| {
+        v_stack(
+            (0..1_000)
+                .map(|i| {
+                    format!("Block {i}")
+                        .bg(Color::Rgb((i % 256) as u8, 100, 200))
+                        .fill_max_width(
+                            (Length::Percent(0.2)..Length::Percent(1.0))
+                                .quadratic_in_out_ease()
+                                .play(0.5 + (i % 10) as f64 * 0.1)
+                                .repeat(Repeat::Forever)
+                                .ping_pong(),
+                        )
+                })
+                .collect::<Vec<_>>(),
+        )
+    })
+    .run()
+}
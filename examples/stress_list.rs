@@ -0,0 +1,24 @@
+//! A list with 10k items, where hovering highlights an item, for measuring rebuilds of long lists.
+
+use anyhow::Result;
+use ratatui::style::Color;
+use trui::*;
+
+fn main() -> Result<()> {
+    App::new(None, |hovered: &mut Option<usize>| {
+        v_stack(
+            (0..10_000)
+                .map(|i| {
+                    format!("Item {i}")
+                        .fg(if *hovered == Some(i) {
+                            Color::Red
+                        } else {
+                            Color::Blue
+                        })
+                        .on_hover(move |hovered: &mut Option<usize>| *hovered = Some(i))
+                })
+                .collect::<Vec<_>>(),
+        )
+    })
+    .run()
+}
@@ -0,0 +1,22 @@
+//! A deeply nested view tree (200 levels of borders and margins), which changes with every click.
+
+use anyhow::Result;
+use trui::*;
+
+fn main() -> Result<()> {
+    App::new(0, |clicks: &mut u32| {
+        (0..200).fold(
+            format!("Clicked {clicks} times")
+                .on_click(|clicks: &mut u32| *clicks += 1)
+                .boxed(),
+            |view, depth| {
+                if depth % 2 == 0 {
+                    view.border(BorderKind::Straight).boxed()
+                } else {
+                    view.margin(1).boxed()
+                }
+            },
+        )
+    })
+    .run()
+}
@@ -1,16 +1,23 @@
 use anyhow::Result;
-// use ratatui::style::Color;
-// use trui::*;
+use trui::*;
 
-// TODO this currently doesn't work anymore
 fn main() -> Result<()> {
-    // App::new((), |()| {
-    //     h_stack((
-    //         block(("Different ".fg(Color::Red), "Colors that are wrapped: Lorem ipsum dolor sit amet, consetetur sadipscing elitr, sed diam nonumy eirmod tempor invidunt ut labore et dolore magna aliquyam erat, sed diam voluptua.".fg(Color::Blue)).wrapped()),
-    //         // TODO proper wrapping with new lines etc.
-    //         block("This should be wrapped very soon:\n\n Lorem ipsum dolor sit amet, consetetur sadipscing elitr, sed diam nonumy eirmod tempor invidunt ut labore et dolore magna aliquyam erat, sed diam voluptua. At vero eos et accusam et justo duo dolores et ea rebum.".wrapped()),
-    //     ))
-    // })
-    // .run()
-    Ok(())
+    App::new((), |()| {
+        h_stack((
+            ("Different ".fg(Color::Red), "Colors that are wrapped: Lorem ipsum dolor sit amet, consetetur sadipscing elitr, sed diam nonumy eirmod tempor invidunt ut labore et dolore magna aliquyam erat, sed diam voluptua.".fg(Color::Blue))
+                .wrapped()
+                .border(BorderKind::Rounded),
+            v_stack((
+                "This should be wrapped very soon:\n\n Lorem ipsum dolor sit amet, consetetur sadipscing elitr, sed diam nonumy eirmod tempor invidunt ut labore et dolore magna aliquyam erat, sed diam voluptua. At vero eos et accusam et justo duo dolores et ea rebum."
+                    .wrapped()
+                    .max_lines(4)
+                    .border(BorderKind::Rounded),
+                "Long URLs are broken anywhere: https://github.com/Philipp-M/trui/blob/master/examples/wrapped_text.rs"
+                    .wrapped()
+                    .wrap_mode(WrapMode::Anywhere)
+                    .border(BorderKind::Rounded),
+            )),
+        ))
+    })
+    .run()
 }
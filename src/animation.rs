@@ -0,0 +1,99 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, OnceLock,
+};
+
+/// Controls all animations of an app, e.g. to pause them, or to slow them down for debugging.
+///
+/// It's a shared handle, so a clone can be kept in the app state to change the settings while the app is running,
+/// after it was given to the app via [`App::with_animation_control`](crate::App::with_animation_control).
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let animations = AnimationControl::new();
+/// App::new(animations.clone(), |animations: &mut AnimationControl| {
+///     button(
+///         if animations.is_paused() { "Resume" } else { "Pause" },
+///         |animations: &mut AnimationControl| animations.set_paused(!animations.is_paused()),
+///     )
+/// })
+/// .with_animation_control(animations);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnimationControl {
+    inner: Arc<AnimationControlInner>,
+}
+
+#[derive(Debug)]
+struct AnimationControlInner {
+    paused: AtomicBool,
+    /// The bits of the `f64` speed multiplier.
+    speed: AtomicU64,
+    reduce_motion: AtomicBool,
+    /// Requests a render of the app, when the settings changed.
+    render_notifier: OnceLock<Arc<tokio::sync::Notify>>,
+}
+
+impl AnimationControl {
+    pub fn new() -> Self {
+        AnimationControl {
+            inner: Arc::new(AnimationControlInner {
+                paused: AtomicBool::new(false),
+                speed: AtomicU64::new(1.0_f64.to_bits()),
+                reduce_motion: AtomicBool::new(false),
+                render_notifier: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// Pauses or resumes all animations, paused animations keep their current value.
+    pub fn set_paused(&self, paused: bool) {
+        self.inner.paused.store(paused, Ordering::Relaxed);
+        self.request_render();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.inner.paused.load(Ordering::Relaxed)
+    }
+
+    /// Multiplies the speed of all animations, e.g. `0.1` for slow motion, the default is `1.0`.
+    pub fn set_speed(&self, speed: f64) {
+        self.inner
+            .speed
+            .store(speed.max(0.0).to_bits(), Ordering::Relaxed);
+        self.request_render();
+    }
+
+    pub fn speed(&self) -> f64 {
+        f64::from_bits(self.inner.speed.load(Ordering::Relaxed))
+    }
+
+    /// Transitions jump directly to their end, instead of being animated, e.g. for users sensitive to motion.
+    pub fn set_reduce_motion(&self, reduce_motion: bool) {
+        self.inner
+            .reduce_motion
+            .store(reduce_motion, Ordering::Relaxed);
+        self.request_render();
+    }
+
+    pub fn reduce_motion(&self) -> bool {
+        self.inner.reduce_motion.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_render_notifier(&self, notifier: Arc<tokio::sync::Notify>) {
+        let _ = self.inner.render_notifier.set(notifier);
+    }
+
+    fn request_render(&self) {
+        if let Some(notifier) = self.inner.render_notifier.get() {
+            notifier.notify_one();
+        }
+    }
+}
+
+impl Default for AnimationControl {
+    fn default() -> Self {
+        AnimationControl::new()
+    }
+}
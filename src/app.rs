@@ -1,25 +1,31 @@
 use crate::{
-    geometry::{Point, Size},
-    view::{Cx, View},
+    animation::AnimationControl,
+    channel_stats::ChannelStats,
+    geometry::{CellPoint, Point, Size},
+    latency::InputLatency,
+    middleware::{self, AnyMiddleware, Middleware},
+    recorder::Recorder,
+    screenshot::Screenshots,
+    view::{Catalog, Cx, Theme, UnmountCallback, UnmountQueue, View},
     widget::{
-        BoxConstraints, Canvas, CxState, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
-        PaintCx, Pod, PodFlags, ViewContext, WidgetState,
+        fold_mnemonic, BoxConstraints, Canvas, ContrastCheck, CxState, Event, EventCx,
+        FocusDirection, FocusState, LayoutCx, LifeCycle, LifeCycleCx, Message, PaintCx, Pod,
+        PodFlags, TimerToken, ViewContext, WidgetState,
     },
 };
 use anyhow::Result;
 
+#[cfg(not(any(test, doctest, feature = "doctests")))]
+use crate::flush::Flusher;
 #[cfg(not(any(test, doctest, feature = "doctests")))]
 use crossterm::{
     cursor,
     event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
-    execute, queue,
-    terminal::{
-        disable_raw_mode, enable_raw_mode, BeginSynchronizedUpdate, EndSynchronizedUpdate,
-        EnterAlternateScreen, LeaveAlternateScreen,
-    },
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
-use crossterm::event::{poll, read, Event as CxEvent, KeyCode, KeyEvent};
+use crossterm::event::{read, Event as CxEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use directories::ProjectDirs;
 use ratatui::Terminal;
 
@@ -27,10 +33,13 @@ use ratatui::Terminal;
 use std::io::stdout;
 
 use std::{
-    collections::HashSet,
-    sync::Arc,
+    any::Any,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
+use tokio::sync::mpsc::error::TrySendError;
 use tracing_subscriber::{fmt::writer::MakeWriterExt, layer::SubscriberExt, Registry};
 use xilem_core::{AsyncWake, Id, IdPath, MessageResult};
 
@@ -40,7 +49,7 @@ use ratatui::backend::TestBackend;
 #[cfg(not(any(test, doctest, feature = "doctests")))]
 use ratatui::backend::CrosstermBackend;
 #[cfg(not(any(test, doctest, feature = "doctests")))]
-use std::io::{Stdout, Write};
+use std::io::Stdout;
 
 // TODO less hardcoding and cross-platform support
 fn setup_logging(log_level: tracing::Level) -> Result<tracing_appender::non_blocking::WorkerGuard> {
@@ -63,8 +72,6 @@ pub struct App<T: Send + 'static, V: View<T> + 'static> {
     render_response_chan: tokio::sync::mpsc::Receiver<RenderResponse<V, V::State>>,
     return_chan: tokio::sync::mpsc::Sender<(V, V::State, HashSet<Id>)>,
     event_chan: tokio::sync::mpsc::Receiver<Event>,
-
-    #[cfg(any(test, doctest, feature = "doctests"))]
     event_tx: tokio::sync::mpsc::Sender<Event>,
 
     #[cfg(any(test, doctest, feature = "doctests"))]
@@ -72,19 +79,285 @@ pub struct App<T: Send + 'static, V: View<T> + 'static> {
 
     #[cfg(not(any(test, doctest, feature = "doctests")))]
     terminal: Terminal<CrosstermBackend<Stdout>>,
+    /// Writes the frames to the terminal, while the app is running.
+    #[cfg(not(any(test, doctest, feature = "doctests")))]
+    flusher: Option<Flusher>,
     size: Size,
     request_render_notifier: Arc<tokio::sync::Notify>,
-    cursor_pos: Option<Point>,
+    cursor_pos: Option<CellPoint>,
     events: Vec<Message>,
+    focus: FocusState,
+    mouse_captured: bool,
     root_state: WidgetState,
     root_pod: Option<Pod>,
     cx: Cx,
     id: Option<Id>,
+    app_task: Option<tokio::task::JoinHandle<()>>,
+    animation: AnimationControl,
+    screenshots: Screenshots,
+    /// Takes a screenshot to the path when the key is pressed, see [`App::with_screenshot_key`].
+    screenshot_key: Option<(KeyCode, PathBuf)>,
+    recorder: Option<Recorder>,
+    input_latency: InputLatency,
+    /// Whether the input is read on a separate thread, see [`AppConfig::read_input`].
+    read_input: bool,
+    channel_stats: ChannelStats,
+    /// See [`App::with_min_terminal_size`].
+    min_terminal_size: Option<MinTerminalSize>,
+    /// Whether the fallback of [`App::with_min_terminal_size`] was shown in the last frame.
+    showing_fallback: bool,
+    /// See [`App::with_contrast_check`].
+    contrast_check: Option<ContrastCheck>,
+    /// See [`App::with_unfocused_mode`].
+    unfocused_mode: Option<UnfocusedMode>,
+    /// Whether the terminal window is focused, tracked with the `FocusGained` and `FocusLost` events.
+    terminal_focused: bool,
+    /// Whether a render was deferred, because the terminal isn't focused (a wake event is scheduled for it).
+    render_deferred: bool,
+    /// See [`App::on_start`].
+    on_start: Option<UnmountCallback>,
+    /// See [`App::on_terminal_event`].
+    on_terminal_event: Option<TerminalEventHandler>,
+    /// See [`App::with_render_delay`].
+    render_delay: Duration,
+    /// See [`App::with_first_frame`].
+    first_frame: FirstFrame,
+    /// Whether the terminal was set up, see [`App::step`].
+    started: bool,
+    /// Whether the app has quit, after which stepping it does nothing.
+    has_quit: bool,
+    time_of_last_render: Instant,
+    /// The time since the last frame, while an animation requests frames, otherwise zero.
+    time_since_last_render_request: Duration,
+    #[cfg(all(feature = "json", feature = "watch"))]
+    theme_watcher: Option<ThemeWatcher>,
+}
+
+/// Watches a theme file for changes, see [`App::with_theme_file`].
+#[cfg(all(feature = "json", feature = "watch"))]
+struct ThemeWatcher {
+    _debouncer: notify_debouncer_mini::Debouncer<notify_debouncer_mini::notify::RecommendedWatcher>,
+    themes: std::sync::mpsc::Receiver<Theme>,
+}
+
+/// The view shown instead of the app, when the terminal is too small, see [`App::with_min_terminal_size`].
+struct MinTerminalSize {
+    width: u16,
+    height: u16,
+    fallback: Pod,
+    state: WidgetState,
+    /// The view state of the fallback, kept so that it's dropped together with the app.
+    _view_state: Box<dyn Any>,
+}
+
+impl MinTerminalSize {
+    fn is_too_small(&self, width: u16, height: u16) -> bool {
+        width < self.width || height < self.height
+    }
 }
 
-/// The standard delay for waiting for async futures.
+/// What the app does, while the terminal window isn't focused, see [`App::with_unfocused_mode`].
+///
+/// This reduces the CPU usage of long-running apps (like dashboards) in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnfocusedMode {
+    /// Pauses all animations, they continue where they left off, when the terminal is focused again.
+    pub pause_animations: bool,
+    /// The minimum time between two frames, updates in between (e.g. by async tasks) are shown with the next frame.
+    ///
+    /// Events are still handled immediately, and the app is rendered right away, when the terminal is focused again.
+    pub frame_interval: Duration,
+}
+
+impl Default for UnfocusedMode {
+    /// Pauses animations and renders at most once per second.
+    fn default() -> Self {
+        UnfocusedMode {
+            pause_animations: true,
+            frame_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// The standard delay for waiting for async futures, see [`App::with_render_delay`].
 const RENDER_DELAY: Duration = Duration::from_millis(5);
 
+/// How the first frame is painted, while views are still waiting for async results
+/// (e.g. [`defer_view`](crate::view::defer_view)), see [`App::with_first_frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FirstFrame {
+    /// Waits up to the duration for pending async views to resolve, before the first frame is painted,
+    /// so that the app doesn't start with a flash of placeholders.
+    WaitForAsync(Duration),
+    /// Paints the first frame immediately, with the placeholders of pending async views.
+    Immediate,
+}
+
+impl Default for FirstFrame {
+    /// Waits as long as for any other frame, i.e. the default render delay.
+    fn default() -> Self {
+        FirstFrame::WaitForAsync(RENDER_DELAY)
+    }
+}
+
+/// The configuration of the threads of the app and the channels between them, see [`App::new_with_config`].
+///
+/// Wake-ups of async views are coalesced per view and wake events are dropped, when the event queue is full,
+/// so they never block, see [`ChannelStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AppConfig {
+    /// How many messages (e.g. from the widgets to the views) can be queued for the app logic,
+    /// before the sender has to wait.
+    pub message_capacity: usize,
+    /// How many events (e.g. key presses) can be queued for the UI, before the sender has to wait.
+    pub event_capacity: usize,
+    /// Whether the app reads the terminal input on its own thread, once it's started.
+    ///
+    /// This should be disabled, when the app is embedded in an event loop, which reads the input itself
+    /// and passes it to [`App::handle_event`], otherwise both would compete for every input event.
+    pub read_input: bool,
+    /// Whether the default colors of the terminal are queried, when the app is created,
+    /// to adapt the theme to light backgrounds, see [`Cx::terminal_colors`](crate::view::Cx::terminal_colors).
+    ///
+    /// This blocks creating the app until the terminal has answered, or at most 100ms for terminals, which don't answer.
+    pub query_terminal_colors: bool,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        AppConfig {
+            message_capacity: 1000,
+            event_capacity: 1000,
+            read_input: true,
+            query_terminal_colors: false,
+        }
+    }
+}
+
+/// Sends a wake event without waiting, it's dropped, when the event queue is full, as the queued events are rendered anyway.
+///
+/// Returns `false`, when the app has quit.
+fn send_wake(event_tx: &tokio::sync::mpsc::Sender<Event>, stats: &ChannelStats) -> bool {
+    match event_tx.try_send(Event::Wake) {
+        Ok(()) => true,
+        Err(TrySendError::Full(_)) => {
+            stats.wake_event_dropped();
+            true
+        }
+        Err(TrySendError::Closed(_)) => false,
+    }
+}
+
+/// Forwards the wake-ups of async views (from their wakers) to the app task, without ever blocking the waker.
+///
+/// A wake-up of a view, which is still waiting to be forwarded, is coalesced with it.
+#[derive(Clone)]
+pub(crate) struct WakeQueue {
+    pending: Arc<Mutex<Vec<IdPath>>>,
+    /// Notifies the forwarding thread, when the queue becomes non-empty.
+    doorbell: std::sync::mpsc::SyncSender<()>,
+    stats: ChannelStats,
+}
+
+impl WakeQueue {
+    /// Returns the queue and the receiver, which is notified, when wake-ups are pending.
+    pub(crate) fn new(stats: ChannelStats) -> (Self, std::sync::mpsc::Receiver<()>) {
+        let (doorbell, doorbell_rx) = std::sync::mpsc::sync_channel(1);
+        let queue = WakeQueue {
+            pending: Arc::default(),
+            doorbell,
+            stats,
+        };
+        (queue, doorbell_rx)
+    }
+
+    pub(crate) fn wake(&self, id_path: &IdPath) {
+        let mut pending = self.pending.lock().unwrap();
+        if pending.contains(id_path) {
+            self.stats.async_wake_coalesced();
+            return;
+        }
+        pending.push(id_path.clone());
+        // otherwise the forwarding thread is already notified
+        if pending.len() == 1 {
+            let _ = self.doorbell.try_send(());
+        }
+    }
+
+    fn take(&self) -> Vec<IdPath> {
+        std::mem::take(&mut *self.pending.lock().unwrap())
+    }
+}
+
+/// How long to wait for the terminal to answer the query of its default colors.
+#[cfg(not(any(test, doctest, feature = "doctests")))]
+const TERMINAL_COLORS_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The delay after the last state mutation, before the persisted state is saved, see [`App::with_persisted_state`].
+const AUTOSAVE_DELAY: Duration = Duration::from_secs(1);
+
+/// Restores the app state (as `&mut dyn Any`) from the saved string.
+type LoadCallback = Box<dyn FnOnce(&mut dyn Any, &str) + Send>;
+
+/// Serializes the app state (as `&dyn Any`), or returns `None` to skip saving.
+type SaveCallback = Box<dyn Fn(&dyn Any) -> Option<String> + Send>;
+
+/// Decides with the app state (as `&mut dyn Any`), whether the app quits, see [`App::on_quit_request`].
+pub(crate) type QuitHandler = Box<dyn FnMut(&mut dyn Any) -> bool + Send>;
+
+/// Observes the events of the terminal, see [`App::on_terminal_event`].
+type TerminalEventHandler = Box<dyn FnMut(&Event) + Send>;
+
+/// The directory of the persisted state of this application, named after its binary within the data directory of trui.
+fn persisted_state_dir() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let app_name = exe.file_stem()?.to_string_lossy();
+    let proj_dirs = ProjectDirs::from("", "", "trui")?;
+    Some(proj_dirs.data_dir().join(&*app_name))
+}
+
+/// Loads the app state from and saves it to a file, see [`App::with_persisted_state`].
+pub(crate) struct Persistence {
+    path: PathBuf,
+    load: Option<LoadCallback>,
+    save: SaveCallback,
+    /// Set after a state mutation, when the state should be saved.
+    save_deadline: Option<tokio::time::Instant>,
+}
+
+impl Persistence {
+    fn load(&mut self, data: &mut dyn Any) {
+        let Some(load) = self.load.take() else {
+            return;
+        };
+        match std::fs::read_to_string(&self.path) {
+            Ok(saved) => load(data, &saved),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => (),
+            Err(err) => tracing::error!("Loading the persisted state failed: {err}"),
+        }
+    }
+
+    fn save(&mut self, data: &dyn Any) {
+        self.save_deadline = None;
+        let Some(saved) = (self.save)(data) else {
+            return;
+        };
+        let result = self
+            .path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&self.path, saved));
+        if let Err(err) = result {
+            tracing::error!("Saving the persisted state failed: {err}");
+        }
+    }
+
+    /// Debounces saving, after the state may have been mutated.
+    fn mark_dirty(&mut self) {
+        self.save_deadline = Some(tokio::time::Instant::now() + AUTOSAVE_DELAY);
+    }
+}
+
 /// This is the view logic of Xilem.
 ///
 /// It contains no information about how to interact with the User (browser, native, terminal).
@@ -102,6 +375,17 @@ struct AppTask<T, V: View<T>, F: FnMut(&mut T) -> V> {
     state: Option<V::State>,
     pending_async: HashSet<Id>,
     ui_state: UiState,
+    persistence: Option<Persistence>,
+    quit_handler: Option<QuitHandler>,
+    /// Observe the messages sent to the view tree, see [`App::with_middleware`].
+    middlewares: Vec<AnyMiddleware>,
+    channel_stats: ChannelStats,
+    /// Whether the app state may have changed since the last render.
+    data_changed: bool,
+    /// Whether a [`Signal`](crate::Signal) has changed since the last render.
+    signals_changed: bool,
+    /// The unmount handlers queued by the rebuilds of the UI thread, see [`ViewExt::on_unmount`](crate::view::ViewExt::on_unmount).
+    unmounted: UnmountQueue,
 }
 
 // TODO maybe rename this, so that it is clear that these events are sent to the AppTask (AppTask name is also for debate IMO)
@@ -109,12 +393,27 @@ struct AppTask<T, V: View<T>, F: FnMut(&mut T) -> V> {
 pub(crate) enum AppMessage {
     Events(Vec<Message>),
     Wake(IdPath),
-    // Parameter indicates whether it should be delayed for async
-    Render(bool),
+    /// The parameter is how long rendering may be delayed for pending async views.
+    Render(Option<Duration>),
+    /// The app is about to exit, drops the view tree, so that background tasks (like workers) are stopped.
+    Quit,
+    /// Loads the persisted app state and saves it after mutations, see [`App::with_persisted_state`].
+    Persist(Persistence),
+    /// A [`Signal`](crate::Signal) with subscribed views has changed.
+    SignalChanged,
+    /// The first frame was rendered, see [`App::on_start`].
+    Start(UnmountCallback),
+    /// Sets the handler, which decides whether the app quits, see [`App::on_quit_request`].
+    SetQuitHandler(QuitHandler),
+    /// The app should quit, the app task answers whether it really does.
+    QuitRequest(tokio::sync::oneshot::Sender<bool>),
+    /// Adds a middleware for the messages sent to the view tree, see [`App::with_middleware`].
+    AddMiddleware(AnyMiddleware),
 }
 
 /// A message sent from [`AppTask`] to [`App`] in response to a render request.
 struct RenderResponse<V, S> {
+    /// `None` on the first render, or when the app logic wasn't run, as only signals have changed.
     prev: Option<V>,
     view: V,
     state: Option<S>,
@@ -137,6 +436,40 @@ enum UiState {
 
 impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
     pub fn new(data: T, app_logic: impl FnMut(&mut T) -> V + Send + 'static) -> Self {
+        Self::new_with_config(data, app_logic, AppConfig::default())
+    }
+
+    /// Like [`App::new`], with custom capacities of the channels between the threads of the app,
+    /// e.g. larger ones for apps with many async views sending messages at once,
+    /// or without reading the input, when the app is embedded in another event loop (see [`App::step`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let config = AppConfig {
+    ///     message_capacity: 10_000,
+    ///     ..AppConfig::default()
+    /// };
+    /// App::new_with_config((), |_| "Hello", config);
+    /// ```
+    pub fn new_with_config(
+        data: T,
+        app_logic: impl FnMut(&mut T) -> V + Send + 'static,
+        config: AppConfig,
+    ) -> Self {
+        // This has to happen before the io event thread starts reading the input
+        #[cfg(not(any(test, doctest, feature = "doctests")))]
+        let terminal_colors = if config.query_terminal_colors {
+            let _ = enable_raw_mode();
+            let colors = crate::terminal_colors::query(TERMINAL_COLORS_TIMEOUT);
+            let _ = disable_raw_mode();
+            colors
+        } else {
+            crate::TerminalColors::default()
+        };
+        #[cfg(any(test, doctest, feature = "doctests"))]
+        let terminal_colors = crate::TerminalColors::default();
+
         #[cfg(not(any(test, doctest, feature = "doctests")))]
         let backend = CrosstermBackend::new(stdout()); // TODO handle errors...
 
@@ -149,24 +482,30 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
         // the client to do it.
         let rt = Arc::new(tokio::runtime::Runtime::new().unwrap());
 
-        // Note: there is danger of deadlock if exceeded; think this through.
-        const CHANNEL_SIZE: usize = 1000;
-        let (message_tx, message_rx) = tokio::sync::mpsc::channel(CHANNEL_SIZE);
-        let (event_tx, event_rx) = tokio::sync::mpsc::channel(CHANNEL_SIZE);
+        // The app task never waits for the event channel (it only sends wake events, which may be dropped),
+        // so the UI thread can't deadlock with it, when it waits for the message channel.
+        let (message_tx, message_rx) = tokio::sync::mpsc::channel(config.message_capacity.max(1));
+        let (event_tx, event_rx) = tokio::sync::mpsc::channel(config.event_capacity.max(1));
         let (response_tx, response_rx) = tokio::sync::mpsc::channel(1);
         let (return_tx, return_rx) = tokio::sync::mpsc::channel(1);
 
         // We have a separate thread to forward wake requests (mostly generated
         // by the custom waker when we poll) to the async task. Maybe there's a
         // better way, but this is expedient.
-        //
-        // It's a sync_channel because sender needs to be sync to work in an async
-        // context. Consider crossbeam and flume channels as alternatives.
         let message_tx_clone = message_tx.clone();
-        let (wake_tx, wake_rx) = std::sync::mpsc::sync_channel(10);
+        let channel_stats = ChannelStats::default();
+        let (wake_queue, wake_rx) = WakeQueue::new(channel_stats.clone());
+        let wake_queue_clone = wake_queue.clone();
         std::thread::spawn(move || {
-            while let Ok(id_path) = wake_rx.recv() {
-                let _ = message_tx_clone.blocking_send(AppMessage::Wake(id_path));
+            while wake_rx.recv().is_ok() {
+                for id_path in wake_queue_clone.take() {
+                    if message_tx_clone
+                        .blocking_send(AppMessage::Wake(id_path))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
             }
         });
 
@@ -174,8 +513,11 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
 
         let request_render_notifier_clone = Arc::clone(&request_render_notifier);
         let event_tx_clone = event_tx.clone();
+        let channel_stats_clone = channel_stats.clone();
 
-        // Until we have a solid way to sync with the screen refresh rate, do an update every 1/60 secs when it is requested
+        // Until we have a solid way to sync with the screen refresh rate, do an update every 1/60 secs when it is requested.
+        // A render is only requested while animations are running (or for screenshots etc.), otherwise this task sleeps,
+        // pending async futures wake the app themselves.
         rt.spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs_f64(1.0 / 60.0));
             interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -183,48 +525,23 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
             loop {
                 request_render_notifier_clone.notified().await;
                 interval.tick().await;
-                if event_tx_clone.send(Event::Wake).await.is_err() {
+                if !send_wake(&event_tx_clone, &channel_stats_clone) {
                     break;
                 }
             }
         });
 
-        // spawn io event proxy task
-        let event_tx_clone = event_tx.clone();
-        std::thread::spawn(move || {
-            loop {
-                if let Ok(true) = poll(Duration::from_millis(100)) {
-                    let event = match read() {
-                        // TODO quit app at least for now, until proper key handling is implemented, then this thread might need a signal to quit itself
-                        Ok(CxEvent::Key(KeyEvent {
-                            code: KeyCode::Esc, ..
-                        })) => Event::Quit,
-                        Ok(CxEvent::Key(key_event)) => Event::Key(key_event),
-                        Ok(CxEvent::Mouse(mouse_event)) => Event::Mouse(mouse_event.into()),
-                        Ok(CxEvent::FocusGained) => Event::FocusGained,
-                        Ok(CxEvent::FocusLost) => Event::FocusLost,
-                        // CxEvent::Paste(_) => todo!(),
-                        Ok(CxEvent::Resize(width, height)) => Event::Resize { width, height },
-                        _ => continue, // TODO handle other kinds of events and errors
-                    };
-
-                    let quit = matches!(event, Event::Quit);
-
-                    let _ = event_tx_clone.blocking_send(event);
-
-                    if quit {
-                        break;
-                    }
-                }
-            }
-        });
+        let input_latency = InputLatency::default();
 
         // Send this event here, so that the app renders directly when it is run.
         let _ = event_tx.blocking_send(Event::Start);
 
+        let unmounted = UnmountQueue::default();
+        let unmounted_clone = unmounted.clone();
         let event_tx_clone = event_tx.clone();
+        let channel_stats_clone = channel_stats.clone();
         // spawn app task
-        rt.spawn(async move {
+        let app_task = rt.spawn(async move {
             let mut app_task = AppTask {
                 req_chan: message_rx,
                 response_chan: response_tx,
@@ -236,22 +553,34 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
                 state: None,
                 pending_async: HashSet::new(),
                 ui_state: UiState::Start,
+                persistence: None,
+                quit_handler: None,
+                middlewares: Vec::new(),
+                channel_stats: channel_stats_clone,
+                data_changed: true,
+                signals_changed: false,
+                unmounted: unmounted_clone,
             };
             app_task.run().await;
         });
 
-        let cx = Cx::new(&wake_tx, rt);
+        let mut cx = Cx::new(&wake_queue, &message_tx, rt);
+        cx.unmounted = unmounted;
+        cx.terminal_colors = terminal_colors;
+        if let Some(scheme) = terminal_colors.color_scheme() {
+            cx.theme = Arc::new(Theme::for_color_scheme(scheme));
+        }
 
         App {
             req_chan: message_tx,
             render_response_chan: response_rx,
             return_chan: return_tx,
             event_chan: event_rx,
-
-            #[cfg(any(test, doctest, feature = "doctests"))]
             event_tx: event_tx.clone(),
 
             terminal,
+            #[cfg(not(any(test, doctest, feature = "doctests")))]
+            flusher: None,
             size: Size::default(),
             cursor_pos: None,
             root_pod: None,
@@ -259,7 +588,33 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
             id: None,
             root_state: WidgetState::new(),
             events: Vec::new(),
+            focus: FocusState::default(),
+            mouse_captured: false,
+            screenshots: Screenshots::new(Arc::clone(&request_render_notifier)),
+            screenshot_key: None,
+            recorder: None,
+            input_latency,
+            read_input: config.read_input,
+            channel_stats,
+            min_terminal_size: None,
+            showing_fallback: false,
+            contrast_check: None,
+            unfocused_mode: None,
+            terminal_focused: true,
+            render_deferred: false,
+            on_start: None,
+            on_terminal_event: None,
+            render_delay: RENDER_DELAY,
+            first_frame: FirstFrame::default(),
+            started: false,
+            has_quit: false,
+            time_of_last_render: Instant::now(),
+            time_since_last_render_request: Duration::ZERO,
             request_render_notifier,
+            app_task: Some(app_task),
+            animation: AnimationControl::new(),
+            #[cfg(all(feature = "json", feature = "watch"))]
+            theme_watcher: None,
         }
     }
 
@@ -274,13 +629,22 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
     /// Returns whether a rerender should be scheduled
     #[tracing::instrument(skip(self))]
     fn render(&mut self, time_since_last_render: Duration) -> Result<bool> {
-        if self.build_widget_tree(false) {
-            self.build_widget_tree(true);
+        let delay = match self.first_frame {
+            FirstFrame::WaitForAsync(delay) if self.root_pod.is_none() => delay,
+            FirstFrame::Immediate if self.root_pod.is_none() => Duration::ZERO,
+            _ => self.render_delay,
+        };
+        if self.build_widget_tree(None) && !delay.is_zero() {
+            self.build_widget_tree(Some(delay));
         }
-        let root_pod = self.root_pod.as_mut().unwrap();
-        let cx_state = &mut CxState::new(&mut self.events, time_since_last_render);
-
         // TODO via event (Event::Resize)?
+        #[cfg(not(any(test, doctest, feature = "doctests")))]
+        if let Some(flusher) = &self.flusher {
+            // resizing clears the terminal, which must not interleave with writing the previous frame
+            if self.terminal.size()? != self.terminal.get_frame().size() {
+                flusher.wait_idle();
+            }
+        }
         self.terminal.autoresize()?;
 
         let term_rect = self.terminal.size()?;
@@ -290,11 +654,35 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
             height: height as f64,
         };
 
-        if root_pod.state.flags.contains(PodFlags::REQUEST_ANIMATION) {
+        let show_fallback = self
+            .min_terminal_size
+            .as_ref()
+            .is_some_and(|min_size| min_size.is_too_small(width, height));
+        let fallback_changed = show_fallback != self.showing_fallback;
+        self.showing_fallback = show_fallback;
+        let (root_pod, root_state) = match &mut self.min_terminal_size {
+            Some(min_size) if show_fallback => (&mut min_size.fallback, &mut min_size.state),
+            _ => (self.root_pod.as_mut().unwrap(), &mut self.root_state),
+        };
+        let animations_paused = self.animation.is_paused()
+            || (!self.terminal_focused
+                && self
+                    .unfocused_mode
+                    .is_some_and(|mode| mode.pause_animations));
+        let cx_state = &mut CxState::new(
+            &mut self.events,
+            &mut self.focus,
+            &mut self.mouse_captured,
+            time_since_last_render.mul_f64(self.animation.speed()),
+            self.animation.reduce_motion(),
+        );
+
+        // the animation request stays pending while paused, so that animations continue when resumed
+        if !animations_paused && root_pod.state.flags.contains(PodFlags::REQUEST_ANIMATION) {
             root_pod.lifecycle(
                 &mut LifeCycleCx {
                     cx_state,
-                    widget_state: &mut self.root_state,
+                    widget_state: &mut *root_state,
                 },
                 &LifeCycle::Animate,
             );
@@ -304,19 +692,22 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
             .state
             .flags
             .intersects(PodFlags::REQUEST_LAYOUT | PodFlags::TREE_CHANGED)
-            || term_size != self.size;
+            || term_size != self.size
+            || fallback_changed;
 
         if needs_layout_recomputation {
             let _ = tracing::debug_span!("compute layout");
             self.size = term_size;
             let mut layout_cx = LayoutCx {
-                widget_state: &mut self.root_state,
+                widget_state: &mut *root_state,
                 cx_state,
             };
             let bc = BoxConstraints::tight(self.size).loosen();
             root_pod.layout(&mut layout_cx, &bc);
             root_pod.set_origin(&mut layout_cx, Point::ORIGIN);
         }
+
+        // before the focus chain is built, as it contains the window rects of the focusable widgets
         if root_pod
             .state
             .flags
@@ -329,7 +720,7 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
             };
             let mut lifecycle_cx = LifeCycleCx {
                 cx_state,
-                widget_state: &mut self.root_state,
+                widget_state: &mut *root_state,
             };
             root_pod.lifecycle(
                 &mut lifecycle_cx,
@@ -337,10 +728,36 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
             );
         }
 
-        if root_pod.state.flags.intersects(PodFlags::REQUEST_PAINT) || needs_layout_recomputation {
+        // the focus of the app is kept, while the fallback is shown
+        if !show_fallback && (needs_layout_recomputation || cx_state.focus.request.is_some()) {
+            cx_state.focus.clear_focus_chain();
+            let mut lifecycle_cx = LifeCycleCx {
+                cx_state,
+                widget_state: &mut *root_state,
+            };
+            root_pod.lifecycle(&mut lifecycle_cx, &LifeCycle::BuildFocusChain);
+            let removed = cx_state.focus.validate();
+            if cx_state.focus.apply_request() || removed {
+                let mut lifecycle_cx = LifeCycleCx {
+                    cx_state,
+                    widget_state: &mut *root_state,
+                };
+                root_pod.lifecycle(&mut lifecycle_cx, &LifeCycle::FocusChanged);
+            }
+        }
+        let screenshot_requested = self.screenshots.has_requests();
+        if root_pod.state.flags.intersects(PodFlags::REQUEST_PAINT)
+            || needs_layout_recomputation
+            || screenshot_requested
+            || self.recorder.as_ref().is_some_and(Recorder::needs_frame)
+        {
             let _paint_span = tracing::debug_span!("paint");
+            if let Some(check) = &mut self.contrast_check {
+                check.begin_frame();
+                cx_state.contrast_check = Some(check);
+            }
             let mut paint_cx = PaintCx {
-                widget_state: &mut self.root_state,
+                widget_state: &mut *root_state,
                 cx_state,
                 canvas: &mut Canvas::new(self.terminal.current_buffer_mut()),
                 override_style: ratatui::style::Style::default(),
@@ -348,36 +765,51 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
 
             root_pod.paint(&mut paint_cx);
 
+            if screenshot_requested {
+                self.screenshots.save(self.terminal.current_buffer_mut());
+            }
+            if let Some(recorder) = &self.recorder {
+                recorder.record(self.terminal.current_buffer_mut());
+            }
+
+            // the frame is written on another thread, while the events of the next frame are handled
             #[cfg(not(any(test, doctest, feature = "doctests")))]
-            queue!(stdout(), BeginSynchronizedUpdate)?;
+            if let Some(flusher) = &self.flusher {
+                flusher.submit(self.terminal.current_buffer_mut().clone());
+            }
 
+            #[cfg(any(test, doctest, feature = "doctests"))]
             self.terminal.flush()?;
 
-            #[cfg(not(any(test, doctest, feature = "doctests")))]
-            execute!(stdout(), EndSynchronizedUpdate)?;
-
             self.terminal.swap_buffers();
-
-            #[cfg(not(any(test, doctest, feature = "doctests")))]
-            self.terminal.backend_mut().flush()?;
         }
 
-        // currently only an animation update can request a rerender
-        Ok(root_pod.state.flags.contains(PodFlags::REQUEST_ANIMATION))
+        let timers = std::mem::take(&mut cx_state.timers);
+        schedule_timers(&self.cx.rt, &self.event_tx, timers);
+
+        // an animation update, or messages sent while rendering (e.g. by `on_appear`) request a rerender
+        Ok(
+            (!animations_paused && root_pod.state.flags.contains(PodFlags::REQUEST_ANIMATION))
+                || !self.events.is_empty(),
+        )
     }
 
     /// Run one pass of app logic.
+    /// Rendering is delayed by up to `delay` for pending async views.
     ///
     /// Return value is whether there are any pending async futures.
-    fn build_widget_tree(&mut self, delay: bool) -> bool {
+    fn build_widget_tree(&mut self, delay: Option<Duration>) -> bool {
         self.cx.pending_async.clear();
+        if let Ok(term_rect) = self.terminal.size() {
+            self.cx.terminal_size = (term_rect.width, term_rect.height);
+        }
         let _ = self.req_chan.blocking_send(AppMessage::Render(delay));
         if let Some(response) = self.render_response_chan.blocking_recv() {
             let state = if let Some(widget) = self.root_pod.as_mut() {
                 let mut state = response.state.unwrap();
                 let changes = response.view.rebuild(
                     &mut self.cx,
-                    response.prev.as_ref().unwrap(),
+                    response.prev.as_ref().unwrap_or(&response.view),
                     self.id.as_mut().unwrap(),
                     &mut state,
                     //TODO: fail more gracefully but make it explicit that this is a bug
@@ -406,6 +838,344 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
         }
     }
 
+    /// Sets the theme, which is used for the default styles of the built-in views (like [`button`](crate::button)).
+    ///
+    /// By default the theme is adapted to the color scheme of the terminal (see [`Theme::for_color_scheme`]).
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.cx.theme = Arc::new(theme);
+        self
+    }
+
+    /// Loads the theme from a JSON file (see [`Theme::from_json`] for the format) on top of the current theme,
+    /// and watches the file for changes, to apply them while the app is running.
+    ///
+    /// Errors while loading the theme are logged, and the previous theme is kept.
+    #[cfg(all(feature = "json", feature = "watch"))]
+    pub fn with_theme_file(mut self, path: impl Into<PathBuf>) -> Self {
+        use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode, DebounceEventResult};
+
+        let path = path.into();
+        let path = std::fs::canonicalize(&path).unwrap_or(path);
+        let base = Theme::clone(&self.cx.theme);
+        let load = move |path: &std::path::Path| -> Result<Theme> {
+            let mut theme = base.clone();
+            theme.apply_json(&serde_json::from_str(&std::fs::read_to_string(path)?)?)?;
+            Ok(theme)
+        };
+        match load(&path) {
+            Ok(theme) => self.cx.theme = Arc::new(theme),
+            Err(err) => tracing::error!("loading the theme {} failed: {err}", path.display()),
+        }
+
+        let (theme_tx, themes) = std::sync::mpsc::channel();
+        let request_render_notifier = Arc::clone(&self.request_render_notifier);
+        let theme_path = path.clone();
+        let debouncer = new_debouncer(
+            crate::view::WATCH_PATH_DEBOUNCE,
+            move |result: DebounceEventResult| match result {
+                Ok(events) if events.iter().any(|event| event.path == theme_path) => {
+                    match load(&theme_path) {
+                        Ok(theme) => {
+                            let _ = theme_tx.send(theme);
+                            request_render_notifier.notify_one();
+                        }
+                        Err(err) => tracing::error!(
+                            "loading the theme {} failed: {err}",
+                            theme_path.display()
+                        ),
+                    }
+                }
+                Ok(_) => (),
+                Err(err) => tracing::error!("error while watching the theme file: {err}"),
+            },
+        );
+        // The parent directory is watched, as editors often replace the file when saving it
+        let watch_path = path.parent().unwrap_or(&path);
+        match debouncer {
+            Ok(mut debouncer) => match debouncer
+                .watcher()
+                .watch(watch_path, RecursiveMode::NonRecursive)
+            {
+                Ok(()) => {
+                    self.theme_watcher = Some(ThemeWatcher {
+                        _debouncer: debouncer,
+                        themes,
+                    })
+                }
+                Err(err) => tracing::error!("could not watch {}: {err}", watch_path.display()),
+            },
+            Err(err) => tracing::error!("could not create file watcher: {err}"),
+        }
+        self
+    }
+
+    /// Controls the animations of the app with `control`, see [`AnimationControl`].
+    pub fn with_animation_control(mut self, control: AnimationControl) -> Self {
+        control.set_render_notifier(Arc::clone(&self.request_render_notifier));
+        self.animation = control;
+        self
+    }
+
+    /// Reduces the work of the app, while the terminal window isn't focused, e.g. [`UnfocusedMode::default`]
+    /// pauses animations and renders at most once per second.
+    ///
+    /// This relies on the focus events of the terminal, which aren't supported by all terminals (and multiplexers).
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # use std::time::Duration;
+    /// App::new((), |_| "Dashboard").with_unfocused_mode(UnfocusedMode {
+    ///     pause_animations: true,
+    ///     frame_interval: Duration::from_secs(5),
+    /// });
+    /// ```
+    pub fn with_unfocused_mode(mut self, mode: UnfocusedMode) -> Self {
+        self.unfocused_mode = Some(mode);
+        self
+    }
+
+    /// How long a frame may be delayed for views waiting for async results (e.g. [`defer_view`](crate::view::defer_view)),
+    /// so that results which resolve quickly are shown in the same frame. By default it's 5 ms.
+    ///
+    /// A zero delay paints every frame immediately, with the placeholders of pending async views.
+    /// See [`App::with_first_frame`] for the first frame.
+    pub fn with_render_delay(mut self, delay: Duration) -> Self {
+        self.render_delay = delay;
+        self
+    }
+
+    /// How the first frame is painted, while views are still waiting for async results, see [`FirstFrame`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # use std::time::Duration;
+    /// // e.g. the first page of a list is loaded asynchronously, which usually takes a few milliseconds
+    /// App::new((), |_| "Inbox").with_first_frame(FirstFrame::WaitForAsync(Duration::from_millis(50)));
+    /// ```
+    pub fn with_first_frame(mut self, first_frame: FirstFrame) -> Self {
+        self.first_frame = first_frame;
+        self
+    }
+
+    /// A handle to take screenshots of the running app, e.g. triggered by a button in the app.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let app = App::new((), |_| button("Hello", |_: &mut ()| ()));
+    /// let screenshots = app.screenshots();
+    /// screenshots.take(std::env::temp_dir().join("hello.html"));
+    /// ```
+    pub fn screenshots(&self) -> Screenshots {
+        self.screenshots.clone()
+    }
+
+    /// A handle to measure the latency of input events while the app is running, see [`InputLatency`].
+    pub fn input_latency(&self) -> InputLatency {
+        self.input_latency.clone()
+    }
+
+    /// A handle to count the messages, which were coalesced or dropped to keep the channels of the app from overflowing,
+    /// see [`ChannelStats`].
+    pub fn channel_stats(&self) -> ChannelStats {
+        self.channel_stats.clone()
+    }
+
+    /// Writes a screenshot of the current frame to `path` whenever `key` is pressed,
+    /// the format is chosen by the extension of `path` (see [`ScreenshotFormat::from_path`](crate::ScreenshotFormat::from_path)).
+    ///
+    /// The key is still sent to the widgets as well.
+    pub fn with_screenshot_key(mut self, key: KeyCode, path: impl Into<PathBuf>) -> Self {
+        self.screenshot_key = Some((key, path.into()));
+        self
+    }
+
+    /// Records the rendered frames with `recorder`, see [`Recorder`].
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        recorder.set_render_notifier(Arc::clone(&self.request_render_notifier));
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Shows `fallback` instead of the app, while the terminal is smaller than `width` x `height` cells,
+    /// instead of squeezing the layout of the app into a space, where it can't be used anyway.
+    ///
+    /// The app doesn't receive any events meanwhile, and continues as before, when the terminal is large enough again.
+    /// The fallback is built once, and can't change the app state.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// App::new((), |_| v_stack(("A form", button("Submit", |_: &mut ()| ()))))
+    ///     .with_min_terminal_size(40, 10, "The terminal is too small, please enlarge it to at least 40x10");
+    /// ```
+    pub fn with_min_terminal_size<F>(mut self, width: u16, height: u16, fallback: F) -> Self
+    where
+        F: View<()>,
+        F::State: 'static,
+    {
+        let (_, view_state, element) = fallback.build(&mut self.cx);
+        self.min_terminal_size = Some(MinTerminalSize {
+            width,
+            height,
+            fallback: Pod::new(element),
+            state: WidgetState::new(),
+            _view_state: Box::new(view_state),
+        });
+        self
+    }
+
+    /// Logs a warning for text, which is painted with a contrast ratio (as defined by WCAG) below `min_ratio`
+    /// between its foreground and background color, e.g. `4.5` for the WCAG AA level.
+    ///
+    /// This is meant for development, to catch unreadable style combinations (e.g. across themes).
+    /// Each combination of widget and colors is logged once with the id path of the widget.
+    /// [`Color::Reset`](crate::Color::Reset) is checked as the default colors of the terminal, if they could be queried.
+    pub fn with_contrast_check(mut self, min_ratio: f64) -> Self {
+        self.contrast_check = Some(ContrastCheck::new(min_ratio, self.cx.terminal_colors));
+        self
+    }
+
+    /// Numbers the ids of the views in the order they're allocated, independent of other apps (e.g. tests running before),
+    /// so that id paths are deterministic and can be asserted in tests, see [`Cx::stable_id`].
+    pub fn with_stable_ids(mut self) -> Self {
+        self.cx.stable_ids = Some(HashMap::new());
+        self
+    }
+
+    /// Sets the translations, which are used by [`localized`](crate::localized) views.
+    pub fn with_catalog(mut self, catalog: Catalog) -> Self {
+        self.cx.catalog = Arc::new(catalog);
+        self
+    }
+
+    /// Persists the app state across sessions in a file named after `key` in the platform's data directory,
+    /// within a directory named after the binary of the application, so that applications don't overwrite each other's state.
+    ///
+    /// The state is restored with `load`, which gets the saved string (e.g. to deserialize it with serde),
+    /// and saved with `save` on exit and shortly after it was mutated.
+    /// Only a subset of the state can be persisted by (de)serializing only the relevant parts.
+    /// The state isn't persisted, when there's no data directory (e.g. without a home directory), which is logged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// App::new(0, |count: &mut i32| button(format!("Count: {count}"), |count: &mut i32| *count += 1))
+    ///     .with_persisted_state(
+    ///         "counter",
+    ///         |count: &mut i32, saved| *count = saved.parse().unwrap_or_default(),
+    ///         |count| Some(count.to_string()),
+    ///     );
+    /// ```
+    pub fn with_persisted_state(
+        self,
+        key: &str,
+        load: impl FnOnce(&mut T, &str) + Send + 'static,
+        save: impl Fn(&T) -> Option<String> + Send + 'static,
+    ) -> Self {
+        let Some(dir) = persisted_state_dir() else {
+            tracing::warn!("There's no data directory, the state \"{key}\" isn't persisted");
+            return self;
+        };
+        let persistence = Persistence {
+            path: dir.join(format!("{key}.state")),
+            load: Some(Box::new(move |data, saved| {
+                load(data.downcast_mut().unwrap(), saved)
+            })),
+            save: Box::new(move |data| save(data.downcast_ref().unwrap())),
+            save_deadline: None,
+        };
+        let _ = self
+            .req_chan
+            .blocking_send(AppMessage::Persist(persistence));
+        self
+    }
+
+    /// Calls `on_start` with the app state after the first frame was rendered,
+    /// e.g. to start loading data, while the app already shows a loading indicator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// App::new(None, |items: &mut Option<Vec<String>>| match items {
+    ///     Some(items) => format!("{} items", items.len()),
+    ///     None => "Loading...".to_string(),
+    /// })
+    /// .on_start(|items: &mut Option<Vec<String>>| *items = Some(vec!["first".to_string()]));
+    /// ```
+    pub fn on_start(mut self, on_start: impl FnOnce(&mut T) + Send + 'static) -> Self {
+        self.on_start = Some(Box::new(move |data| on_start(data.downcast_mut().unwrap())));
+        self
+    }
+
+    /// Calls `on_quit_request` with the app state, when the app should quit (currently when `Esc` is pressed),
+    /// the app only quits, when it returns `true`.
+    ///
+    /// This can be used to ask for confirmation, when there are unsaved changes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// struct Editor {
+    ///     unsaved_changes: bool,
+    ///     confirm_quit: bool,
+    /// }
+    ///
+    /// App::new(Editor { unsaved_changes: true, confirm_quit: false }, |editor: &mut Editor| {
+    ///     if editor.confirm_quit {
+    ///         "There are unsaved changes, press Esc again to quit"
+    ///     } else {
+    ///         "Editing..."
+    ///     }
+    /// })
+    /// .on_quit_request(|editor: &mut Editor| {
+    ///     let quit = !editor.unsaved_changes || editor.confirm_quit;
+    ///     editor.confirm_quit = true;
+    ///     quit
+    /// });
+    /// ```
+    pub fn on_quit_request(
+        self,
+        mut on_quit_request: impl FnMut(&mut T) -> bool + Send + 'static,
+    ) -> Self {
+        let handler: QuitHandler =
+            Box::new(move |data| on_quit_request(data.downcast_mut().unwrap()));
+        let _ = self
+            .req_chan
+            .blocking_send(AppMessage::SetQuitHandler(handler));
+        self
+    }
+
+    /// Calls `on_terminal_event` with each event received from the terminal (before it's handled by the app),
+    /// e.g. to log them for debugging.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// App::new((), |_| "Press some keys")
+    ///     .on_terminal_event(|event: &Event| tracing::debug!("received {event:?}"));
+    /// ```
+    pub fn on_terminal_event(
+        mut self,
+        on_terminal_event: impl FnMut(&Event) + Send + 'static,
+    ) -> Self {
+        self.on_terminal_event = Some(Box::new(on_terminal_event));
+        self
+    }
+
+    /// Passes all messages sent from the widgets to the views (e.g. a button click) through `middleware`,
+    /// which can observe, transform or drop them, and observe the result of handling them, see [`Middleware`].
+    ///
+    /// Multiple middlewares are called in the order they were added.
+    pub fn with_middleware(self, middleware: impl Middleware<T> + 'static) -> Self {
+        let _ = self
+            .req_chan
+            .blocking_send(AppMessage::AddMiddleware(middleware::erase(middleware)));
+        self
+    }
+
     pub fn run(self) -> Result<()> {
         let _guard = setup_logging(tracing::Level::DEBUG)?;
         self.run_without_logging()
@@ -413,64 +1183,320 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
 
     // TODO(zoechi): setup proper configuration for App
     pub fn run_without_logging(mut self) -> Result<()> {
-        #[cfg(not(any(test, doctest, feature = "doctests")))]
-        self.init_terminal()?;
-
-        self.terminal.clear()?;
+        self.start()?;
 
         let main_loop_tracing_span = tracing::debug_span!("main loop");
-        let mut time_of_last_render = Instant::now();
-        let mut time_since_last_render_request = Duration::ZERO;
         while let Some(event) = self.event_chan.blocking_recv() {
             let mut events = vec![event];
             // batch events
             while let Ok(event) = self.event_chan.try_recv() {
                 events.push(event);
             }
+            if !self.process_events(events)? {
+                break;
+            }
+        }
+        drop(main_loop_tracing_span);
+
+        self.shutdown();
+        Ok(())
+    }
+
+    /// Handles all pending events (input, async wakes, animation ticks) and renders a frame, without blocking on new events.
+    ///
+    /// This is an alternative to [`App::run`] for embedding the app in an existing event loop (e.g. a game loop),
+    /// it has to be called regularly, e.g. once per iteration of that loop.
+    /// The terminal is set up with the first step, and restored when the app is dropped.
+    /// Returns `false`, when the app has quit.
+    ///
+    /// Like [`App::run`], this blocks on the app logic, so it must not be called from within an async context,
+    /// use e.g. [`tokio::task::spawn_blocking`] for that.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let mut app = App::new(0, |frames: &mut u32| format!("Frame {frames}"));
+    /// while app.step()? {
+    ///     // the other work of the loop, e.g. updating a simulation
+    /// #   app.handle_event(Event::Quit);
+    /// }
+    /// # anyhow::Ok(())
+    /// ```
+    pub fn step(&mut self) -> Result<bool> {
+        self.start()?;
+        if self.has_quit {
+            return Ok(false);
+        }
+        let mut events = vec![];
+        while let Ok(event) = self.event_chan.try_recv() {
+            events.push(event);
+        }
+        if events.is_empty() {
+            return Ok(true);
+        }
+        let running = self.process_events(events)?;
+        if !running {
+            self.shutdown();
+        }
+        Ok(running)
+    }
+
+    /// Dispatches `event` to the widget tree, e.g. input that is read by the embedding event loop
+    /// (with [`AppConfig::read_input`] disabled), the resulting frame is rendered with the next [`App::step`] or [`App::render_once`].
+    ///
+    /// Returns `false`, when the event has quit the app, see [`App::on_quit_request`].
+    pub fn handle_event(&mut self, event: Event) -> bool {
+        if self.has_quit {
+            return false;
+        }
+        let quit = self.handle_events(vec![event]);
+        if quit {
+            self.shutdown();
+        } else {
+            // renders the changes with the next step
+            let _ = self.event_tx.try_send(Event::Wake);
+        }
+        !quit
+    }
+
+    /// Runs the app logic, if necessary, and renders a frame.
+    ///
+    /// Unlike [`App::step`], this neither handles pending events, nor throttles rendering while the terminal isn't focused
+    /// (see [`App::with_unfocused_mode`]).
+    pub fn render_once(&mut self) -> Result<()> {
+        self.start()?;
+        if self.has_quit {
+            return Ok(());
+        }
+        #[cfg(all(feature = "json", feature = "watch"))]
+        if let Some(theme) = self
+            .theme_watcher
+            .as_ref()
+            .and_then(|watcher| watcher.themes.try_iter().last())
+        {
+            self.cx.theme = Arc::new(theme);
+        }
+
+        if std::mem::take(&mut self.render_deferred)
+            && !self.time_since_last_render_request.is_zero()
+        {
+            // running animations continue with the actual time since the last frame
+            self.time_since_last_render_request = self.time_of_last_render.elapsed();
+        }
+        let rerender_requested = self.render(self.time_since_last_render_request)?;
+        self.input_latency.frame_rendered();
+        if let Some(on_start) = self.on_start.take() {
+            let _ = self.req_chan.blocking_send(AppMessage::Start(on_start));
+        }
+        // TODO this is a workaround (I consider this at least as that) for getting animations right
+        // There's likely a cleaner solution
+        if rerender_requested {
+            self.request_render_notifier.notify_one();
+            self.time_since_last_render_request = self.time_of_last_render.elapsed();
+        } else {
+            self.time_since_last_render_request = Duration::ZERO;
+        }
+        self.time_of_last_render = Instant::now();
+        Ok(())
+    }
+
+    /// Sets up the terminal and starts reading the input, when the app is run or stepped the first time.
+    fn start(&mut self) -> Result<()> {
+        if std::mem::replace(&mut self.started, true) {
+            return Ok(());
+        }
+        #[cfg(not(any(test, doctest, feature = "doctests")))]
+        self.init_terminal()?;
+        #[cfg(not(any(test, doctest, feature = "doctests")))]
+        {
+            self.flusher = Some(Flusher::new());
+        }
+
+        self.terminal.clear()?;
+        self.time_of_last_render = Instant::now();
+
+        // spawn io event proxy thread
+        if self.read_input {
+            let event_tx = self.event_tx.clone();
+            let input_latency = self.input_latency.clone();
+            // This blocks on reading the input instead of polling it, so that it doesn't wake up, while the app is idle.
+            std::thread::spawn(move || forward_input(read, &event_tx, &input_latency));
+        }
+        Ok(())
+    }
+
+    /// Handles a batch of events and renders a frame (unless it's deferred), returns `false`, when the app should quit.
+    fn process_events(&mut self, events: Vec<Event>) -> Result<bool> {
+        let quit = self.handle_events(events);
+
+        if let Some(mode) = self
+            .unfocused_mode
+            .filter(|_| !self.terminal_focused && !quit)
+        {
+            let since_last_render = self.time_of_last_render.elapsed();
+            if since_last_render < mode.frame_interval {
+                if !self.render_deferred {
+                    self.render_deferred = true;
+                    let event_tx = self.event_tx.clone();
+                    self.cx.rt.spawn(async move {
+                        tokio::time::sleep(mode.frame_interval - since_last_render).await;
+                        let _ = event_tx.send(Event::Wake).await;
+                    });
+                }
+                return Ok(true);
+            }
+        }
+        self.render_once()?;
+        Ok(!quit)
+    }
 
-            let quit = events.iter().any(|e| matches!(e, Event::Quit));
+    /// Dispatches a batch of events to the widget tree, returns whether the app should quit.
+    fn handle_events(&mut self, mut events: Vec<Event>) -> bool {
+        let coalesced_wakes = prioritize_events(&mut events);
+        if coalesced_wakes > 0 {
+            self.input_latency.wakes_coalesced(coalesced_wakes);
+        }
 
-            if let Some(Event::Mouse(mouse)) = events
+        if let Some(on_terminal_event) = &mut self.on_terminal_event {
+            for event in events
                 .iter()
-                .rev()
-                .find(|event| matches!(event, Event::Mouse(_)))
+                .filter(|event| !matches!(event, Event::Wake | Event::Timer(_)))
             {
-                self.cursor_pos = Some(Point::new(mouse.column as f64, mouse.row as f64));
+                on_terminal_event(event);
             }
+        }
 
-            if let Some(root_pod) = self.root_pod.as_mut() {
-                let cx_state = &mut CxState::new(&mut self.events, time_since_last_render_request);
+        if let Some(event) = events
+            .iter()
+            .rev()
+            .find(|event| matches!(event, Event::FocusGained | Event::FocusLost))
+        {
+            self.terminal_focused = matches!(event, Event::FocusGained);
+        }
 
-                let mut cx = EventCx {
-                    is_handled: false,
-                    widget_state: &mut self.root_state,
-                    cx_state,
-                };
-                for event in events {
-                    // TODO filter out some events like Event::Wake?
-                    root_pod.event(&mut cx, &event);
-                }
-            }
-            self.send_events();
-
-            let rerender_requested = self.render(time_since_last_render_request)?;
-            // TODO this is a workaround (I consider this at least as that) for getting animations right
-            // There's likely a cleaner solution
-            if rerender_requested {
-                self.request_render_notifier.notify_one();
-                time_since_last_render_request = time_of_last_render.elapsed();
-            } else {
-                time_since_last_render_request = Duration::ZERO;
+        if let Some((screenshot_key, path)) = &self.screenshot_key {
+            let pressed = events.iter().any(|event| {
+                matches!(event, Event::Key(KeyEvent { code, kind: KeyEventKind::Press, .. }) if code == screenshot_key)
+            });
+            if pressed {
+                self.screenshots.take(path.clone());
             }
-            time_of_last_render = Instant::now();
+        }
 
-            if quit {
-                break;
+        if let Some(Event::Mouse(mouse)) = events
+            .iter()
+            .rev()
+            .find(|event| matches!(event, Event::Mouse(_)))
+        {
+            self.cursor_pos = Some(CellPoint::new(mouse.column as i32, mouse.row as i32));
+        }
+
+        let mut quit = false;
+        // the app doesn't receive events, while the fallback is shown instead
+        if let Some(root_pod) = self.root_pod.as_mut().filter(|_| !self.showing_fallback) {
+            let cx_state = &mut CxState::new(
+                &mut self.events,
+                &mut self.focus,
+                &mut self.mouse_captured,
+                self.time_since_last_render_request,
+                self.animation.reduce_motion(),
+            );
+
+            let mut cx = EventCx {
+                is_handled: false,
+                widget_state: &mut self.root_state,
+                cx_state,
+            };
+            for event in events {
+                cx.is_handled = false;
+                // release a stale capture, e.g. when the capturing widget was removed
+                if *cx.cx_state.mouse_captured
+                    && (matches!(event, Event::FocusLost)
+                        || !root_pod.state.flags.contains(PodFlags::HAS_MOUSE_CAPTURE))
+                {
+                    *cx.cx_state.mouse_captured = false;
+                }
+                root_pod.event(&mut cx, &event);
+                // widgets may intercept quitting, e.g. to ask for confirmation first
+                quit |= matches!(event, Event::Quit) && !cx.is_handled;
+                // Tab navigation, if no focused widget has used the key itself
+                if let Event::Key(KeyEvent {
+                    code: code @ (KeyCode::Tab | KeyCode::BackTab),
+                    kind: KeyEventKind::Press,
+                    ..
+                }) = event
+                {
+                    if !cx.is_handled {
+                        if code == KeyCode::Tab {
+                            cx.focus_next();
+                        } else {
+                            cx.focus_previous();
+                        }
+                    }
+                }
+                // spatial navigation with the arrow keys, if no focused widget has used the key itself
+                if let Event::Key(KeyEvent {
+                    code,
+                    modifiers: KeyModifiers::NONE,
+                    kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                    ..
+                }) = event
+                {
+                    let direction = match code {
+                        KeyCode::Up => Some(FocusDirection::Up),
+                        KeyCode::Down => Some(FocusDirection::Down),
+                        KeyCode::Left => Some(FocusDirection::Left),
+                        KeyCode::Right => Some(FocusDirection::Right),
+                        _ => None,
+                    };
+                    if let Some(direction) = direction.filter(|_| !cx.is_handled) {
+                        cx.focus_towards(direction);
+                    }
+                }
+                // `Alt` + a mnemonic letter activates the widget, if no focused widget has used the key itself
+                if let Event::Key(KeyEvent {
+                    code: KeyCode::Char(letter),
+                    modifiers,
+                    kind: KeyEventKind::Press,
+                    ..
+                }) = event
+                {
+                    let has_alt = modifiers - KeyModifiers::SHIFT == KeyModifiers::ALT;
+                    if has_alt
+                        && !cx.is_handled
+                        && cx.cx_state.focus.mnemonic_target(letter).is_some()
+                    {
+                        root_pod.event(&mut cx, &Event::Mnemonic(fold_mnemonic(letter)));
+                    }
+                }
             }
+            quit |= cx.cx_state.quit_requested;
+            let timers = std::mem::take(&mut cx.cx_state.timers);
+            schedule_timers(&self.cx.rt, &self.event_tx, timers);
+        } else {
+            quit = events.iter().any(|e| matches!(e, Event::Quit));
         }
-        drop(main_loop_tracing_span);
+        if quit {
+            let (response_tx, response_rx) = tokio::sync::oneshot::channel();
+            if self
+                .req_chan
+                .blocking_send(AppMessage::QuitRequest(response_tx))
+                .is_ok()
+            {
+                quit = response_rx.blocking_recv().unwrap_or(true);
+            }
+        }
+        self.send_events();
+        quit
+    }
 
-        Ok(())
+    /// Waits for the app task to finish, e.g. to save the persisted state.
+    fn shutdown(&mut self) {
+        self.has_quit = true;
+        let _ = self.req_chan.blocking_send(AppMessage::Quit);
+        if let Some(app_task) = self.app_task.take() {
+            let _ = self.cx.rt.block_on(app_task);
+        }
     }
 
     #[cfg(not(any(test, doctest, feature = "doctests")))]
@@ -510,36 +1536,126 @@ impl<T: Send + 'static, V: View<T> + 'static> App<T, V> {
     }
 }
 
+/// Sends [`Event::Timer`] for each of the `timers` requested by the widgets, when its delay has elapsed.
+fn schedule_timers(
+    rt: &tokio::runtime::Runtime,
+    event_tx: &tokio::sync::mpsc::Sender<Event>,
+    timers: Vec<(Duration, TimerToken)>,
+) {
+    for (delay, token) in timers {
+        let event_tx = event_tx.clone();
+        rt.spawn(async move {
+            tokio::time::sleep(delay).await;
+            let _ = event_tx.send(Event::Timer(token)).await;
+        });
+    }
+}
+
+/// Moves the input events of a batch in front of the wake events (which are mostly animation ticks and async tasks),
+/// so that input is handled first, and merges the wake events into a single one, as they all result in the same render.
+///
+/// Returns the number of wake events, which were dropped.
+fn prioritize_events(events: &mut Vec<Event>) -> usize {
+    let len = events.len();
+    let has_wake = events.iter().any(|event| matches!(event, Event::Wake));
+    events.retain(|event| !matches!(event, Event::Wake));
+    let dropped = len - events.len();
+    if has_wake {
+        events.push(Event::Wake);
+        dropped - 1
+    } else {
+        dropped
+    }
+}
+
+/// Forwards the input events returned by `read` to the app, until reading fails or the app has quit.
+///
+/// Quitting may be cancelled (see [`App::on_quit_request`]), so this doesn't stop at [`Event::Quit`].
+fn forward_input(
+    mut read: impl FnMut() -> std::io::Result<CxEvent>,
+    event_tx: &tokio::sync::mpsc::Sender<Event>,
+    input_latency: &InputLatency,
+) {
+    loop {
+        let event = match read() {
+            // TODO quit app at least for now, until proper key handling is implemented
+            Ok(CxEvent::Key(KeyEvent {
+                code: KeyCode::Esc, ..
+            })) => Event::Quit,
+            Ok(CxEvent::Key(key_event)) => Event::Key(key_event),
+            Ok(CxEvent::Mouse(mouse_event)) => Event::Mouse(mouse_event.into()),
+            Ok(CxEvent::FocusGained) => Event::FocusGained,
+            Ok(CxEvent::FocusLost) => Event::FocusLost,
+            // CxEvent::Paste(_) => todo!(),
+            Ok(CxEvent::Resize(width, height)) => Event::Resize { width, height },
+            Ok(_) => continue, // TODO handle other kinds of events
+            // e.g. there's no terminal to read from
+            Err(err) => {
+                tracing::error!("reading the input failed: {err}");
+                break;
+            }
+        };
+
+        input_latency.input_received();
+        // the app has quit otherwise
+        if event_tx.blocking_send(event).is_err() {
+            break;
+        }
+    }
+}
+
 /// Restore the terminal no matter how the app exits
 impl<T: Send + 'static, V: View<T> + 'static> Drop for App<T, V> {
     fn drop(&mut self) {
+        let _ = self.req_chan.try_send(AppMessage::Quit);
+        // writes the remaining frames, before the terminal is restored
+        #[cfg(not(any(test, doctest, feature = "doctests")))]
+        drop(self.flusher.take());
         #[cfg(not(any(test, doctest, feature = "doctests")))]
         self.restore_terminal()
             .unwrap_or_else(|e| eprint!("Restoring the terminal failed: {e}"));
     }
 }
 
-impl<T, V: View<T>, F: FnMut(&mut T) -> V> AppTask<T, V, F> {
+impl<T: 'static, V: View<T>, F: FnMut(&mut T) -> V> AppTask<T, V, F> {
     async fn run(&mut self) {
-        let mut deadline = None;
+        let mut deadline: Option<tokio::time::Instant> = None;
         loop {
             let rx = self.req_chan.recv();
-            let req = match deadline {
-                Some(deadline) => tokio::time::timeout_at(deadline, rx).await,
+            let save_deadline = self.persistence.as_ref().and_then(|p| p.save_deadline);
+            let timeout = match (deadline, save_deadline) {
+                (Some(deadline), Some(save_deadline)) => Some(deadline.min(save_deadline)),
+                (deadline, save_deadline) => deadline.or(save_deadline),
+            };
+            let req = match timeout {
+                Some(timeout) => tokio::time::timeout_at(timeout, rx).await,
                 None => Ok(rx.await),
             };
             match req {
                 Ok(Some(req)) => match req {
                     AppMessage::Events(events) => {
                         for event in events {
-                            let id_path = &event.id_path[1..];
-                            self.view.as_ref().unwrap().message(
-                                id_path,
+                            let data: &mut dyn Any = &mut self.data;
+                            let Some(event) = self
+                                .middlewares
+                                .iter_mut()
+                                .try_fold(event, |event, middleware| {
+                                    middleware.before(data, event)
+                                })
+                            else {
+                                continue;
+                            };
+                            let result = self.view.as_ref().unwrap().message(
+                                &event.id_path[1..],
                                 self.state.as_mut().unwrap(),
                                 event.body,
                                 &mut self.data,
                             );
+                            for middleware in &mut self.middlewares {
+                                middleware.after(&mut self.data, &event.id_path, &result);
+                            }
                         }
+                        self.mark_dirty();
                     }
                     AppMessage::Wake(id_path) => {
                         let needs_rebuild;
@@ -553,13 +1669,14 @@ impl<T, V: View<T>, F: FnMut(&mut T) -> V> AppTask<T, V, F> {
                             needs_rebuild = matches!(result, MessageResult::RequestRebuild);
                             tracing::debug!("Needs rebuild after wake: {needs_rebuild}");
                         }
+                        self.mark_dirty();
 
                         if needs_rebuild {
                             // request re-render from UI thread
                             if self.ui_state == UiState::Start {
                                 self.ui_state = UiState::WokeUI;
                                 tracing::debug!("Sending wake event");
-                                if self.event_chan.send(Event::Wake).await.is_err() {
+                                if !send_wake(&self.event_chan, &self.channel_stats) {
                                     break;
                                 }
                             }
@@ -572,38 +1689,118 @@ impl<T, V: View<T>, F: FnMut(&mut T) -> V> AppTask<T, V, F> {
                             }
                         }
                     }
+                    AppMessage::Start(callback) => {
+                        callback(&mut self.data);
+                        self.mark_dirty();
+                        // request re-render from UI thread
+                        if self.ui_state == UiState::Start {
+                            self.ui_state = UiState::WokeUI;
+                            if !send_wake(&self.event_chan, &self.channel_stats) {
+                                break;
+                            }
+                        }
+                    }
+                    AppMessage::Quit => {
+                        self.view.take();
+                        self.state.take();
+                        self.save_if_dirty();
+                        break;
+                    }
+                    AppMessage::SignalChanged => {
+                        self.signals_changed = true;
+                        // request re-render from UI thread
+                        if self.ui_state == UiState::Start {
+                            self.ui_state = UiState::WokeUI;
+                            if !send_wake(&self.event_chan, &self.channel_stats) {
+                                break;
+                            }
+                        }
+                    }
+                    AppMessage::SetQuitHandler(handler) => self.quit_handler = Some(handler),
+                    AppMessage::AddMiddleware(middleware) => self.middlewares.push(middleware),
+                    AppMessage::QuitRequest(response) => {
+                        let quit = match &mut self.quit_handler {
+                            Some(handler) => {
+                                self.data_changed = true;
+                                handler(&mut self.data)
+                            }
+                            None => true,
+                        };
+                        let _ = response.send(quit);
+                    }
+                    AppMessage::Persist(mut persistence) => {
+                        self.data_changed = true;
+                        persistence.load(&mut self.data);
+                        self.persistence = Some(persistence);
+                    }
                     AppMessage::Render(delay) => {
-                        if !delay || self.pending_async.is_empty() {
-                            tracing::debug!("Render without delay");
-                            self.render().await;
-                            deadline = None;
-                        } else {
+                        if let Some(delay) = delay.filter(|_| !self.pending_async.is_empty()) {
                             tracing::debug!(
                                 "Pending async, delay rendering by {} us",
-                                RENDER_DELAY.as_micros()
+                                delay.as_micros()
                             );
-                            deadline = Some(tokio::time::Instant::now() + RENDER_DELAY);
+                            deadline = Some(tokio::time::Instant::now() + delay);
                             self.ui_state = UiState::Delayed;
+                        } else {
+                            tracing::debug!("Render without delay");
+                            self.render().await;
+                            deadline = None;
                         }
                     }
                 },
-                Ok(None) => break,
+                Ok(None) => {
+                    self.save_if_dirty();
+                    break;
+                }
                 Err(_) => {
-                    tracing::debug!("Render after delay");
-                    self.render().await;
-                    deadline = None;
+                    let now = tokio::time::Instant::now();
+                    if save_deadline.is_some_and(|save_deadline| save_deadline <= now) {
+                        self.save_if_dirty();
+                    }
+                    if deadline.is_some_and(|deadline| deadline <= now) {
+                        tracing::debug!("Render after delay");
+                        self.render().await;
+                        deadline = None;
+                    }
                 }
             }
         }
     }
 
+    fn mark_dirty(&mut self) {
+        self.data_changed = true;
+        if let Some(persistence) = &mut self.persistence {
+            persistence.mark_dirty();
+        }
+    }
+
+    /// Saves the persisted state, if it was mutated since it was saved the last time.
+    fn save_if_dirty(&mut self) {
+        if let Some(persistence) = &mut self.persistence {
+            if persistence.save_deadline.is_some() {
+                persistence.save(&self.data);
+            }
+        }
+    }
+
     async fn render(&mut self) {
-        let view = (self.app_logic)(&mut self.data);
-        let response = RenderResponse {
-            prev: self.view.take(),
-            view,
-            state: self.state.take(),
+        // When only signals have changed, the previous view is rebuilt against itself,
+        // which rebuilds just the views subscribed to the changed signals.
+        let only_signals_changed = self.signals_changed && !self.data_changed;
+        let response = match self.view.take() {
+            Some(view) if only_signals_changed => RenderResponse {
+                prev: None,
+                view,
+                state: self.state.take(),
+            },
+            prev => RenderResponse {
+                prev,
+                view: (self.app_logic)(&mut self.data),
+                state: self.state.take(),
+            },
         };
+        self.data_changed = false;
+        self.signals_changed = false;
         if self.response_chan.send(response).await.is_err() {
             tracing::error!("error sending render response");
         }
@@ -613,5 +1810,225 @@ impl<T, V: View<T>, F: FnMut(&mut T) -> V> AppTask<T, V, F> {
             self.pending_async = pending;
         }
         self.ui_state = UiState::Start;
+
+        let unmounted = std::mem::take(
+            &mut *self
+                .unmounted
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+        if !unmounted.is_empty() {
+            for callback in unmounted {
+                callback(&mut self.data);
+            }
+            self.mark_dirty();
+            // request re-render from UI thread
+            self.ui_state = UiState::WokeUI;
+            send_wake(&self.event_chan, &self.channel_stats);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_events_are_handled_before_wakes() {
+        let key = Event::Key(KeyEvent::from(KeyCode::Enter));
+        let mut events = vec![Event::Wake, Event::Wake, key, Event::Wake, Event::FocusLost];
+        assert_eq!(prioritize_events(&mut events), 2);
+        assert!(matches!(
+            events[..],
+            [Event::Key(_), Event::FocusLost, Event::Wake]
+        ));
+    }
+
+    #[test]
+    fn input_is_read_after_quitting() {
+        let esc = || Ok(CxEvent::Key(KeyEvent::from(KeyCode::Esc)));
+        let mut input = vec![
+            esc(),
+            esc(),
+            Ok(CxEvent::Key(KeyEvent::from(KeyCode::Enter))),
+            Err(std::io::Error::other("no terminal")),
+        ]
+        .into_iter();
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::channel(8);
+        forward_input(
+            || input.next().unwrap(),
+            &event_tx,
+            &InputLatency::default(),
+        );
+        drop(event_tx);
+
+        let mut events = Vec::new();
+        while let Some(event) = event_rx.blocking_recv() {
+            events.push(event);
+        }
+        assert!(matches!(
+            events[..],
+            [Event::Quit, Event::Quit, Event::Key(_)]
+        ));
+    }
+
+    #[test]
+    fn embedded_apps_are_driven_by_the_host_loop() {
+        let config = AppConfig {
+            read_input: false,
+            ..AppConfig::default()
+        };
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let received_clone = received.clone();
+        let mut app = App::new_with_config((), |_| "Embedded", config).on_terminal_event(
+            move |event: &Event| received_clone.lock().unwrap().push(event.clone()),
+        );
+
+        assert!(app.step().unwrap());
+        let line: String = app.terminal_mut().backend().buffer().content[..8]
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert_eq!(line, "Embedded");
+
+        // the input is only passed in by the host loop
+        assert!(app.handle_event(Event::Key(KeyEvent::from(KeyCode::Char('a')))));
+        assert!(app.step().unwrap());
+        assert!(!app.handle_event(Event::Quit));
+        assert!(!app.step().unwrap());
+        assert!(matches!(
+            received.lock().unwrap()[..],
+            [Event::Start, Event::Key(_), Event::Quit]
+        ));
+    }
+
+    #[test]
+    fn tab_and_shift_tab_cycle_the_focus() {
+        use crate::view::{button, h_stack};
+
+        let config = AppConfig {
+            read_input: false,
+            ..AppConfig::default()
+        };
+        let mut app = App::new_with_config(
+            (),
+            |_| h_stack((button("A", |_: &mut ()| ()), button("B", |_: &mut ()| ()))),
+            config,
+        );
+        assert!(app.step().unwrap());
+        let chain = app.focus.focus_chain.clone();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(app.focus.focused, None);
+
+        let mut press = |code| {
+            assert!(app.handle_event(Event::Key(KeyEvent::from(code))));
+            assert!(app.step().unwrap());
+            app.focus.focused
+        };
+        assert_eq!(press(KeyCode::Tab), Some(chain[0]));
+        assert_eq!(press(KeyCode::Tab), Some(chain[1]));
+        // wraps around at both ends
+        assert_eq!(press(KeyCode::Tab), Some(chain[0]));
+        assert_eq!(press(KeyCode::BackTab), Some(chain[1]));
+        assert_eq!(press(KeyCode::BackTab), Some(chain[0]));
+    }
+
+    #[test]
+    fn unmount_handlers_are_paired_with_mount_handlers() {
+        use crate::view::{v_stack, ViewExt};
+
+        #[derive(Default)]
+        struct State {
+            show: bool,
+            mounted: u32,
+            unmounted: u32,
+        }
+
+        let config = AppConfig {
+            read_input: false,
+            ..AppConfig::default()
+        };
+        let state = State {
+            show: true,
+            ..State::default()
+        };
+        let mut app = App::new_with_config(
+            state,
+            |state: &mut State| {
+                v_stack((
+                    format!("{} {}", state.mounted, state.unmounted),
+                    state.show.then(|| {
+                        "Details"
+                            .on_mount(|state: &mut State| state.mounted += 1)
+                            .on_unmount(|state: &mut State| state.unmounted += 1)
+                    }),
+                ))
+            },
+            config,
+        )
+        // removes the details again, after they were shown in the first frame
+        .on_start(|state: &mut State| state.show = false);
+
+        let mut first_line = || {
+            app.step().unwrap();
+            app.terminal_mut().backend().buffer().content[..3]
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while first_line() != "1 1" {
+            assert!(Instant::now() < deadline, "the details weren't unmounted");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        for _ in 0..10 {
+            std::thread::sleep(Duration::from_millis(10));
+            assert_eq!(first_line(), "1 1");
+        }
+    }
+
+    #[test]
+    fn pending_async_wakes_are_coalesced() {
+        let stats = ChannelStats::default();
+        let (queue, doorbell) = WakeQueue::new(stats.clone());
+        let (first, second) = (vec![Id::next()], vec![Id::next()]);
+        queue.wake(&first);
+        queue.wake(&second);
+        queue.wake(&first);
+        assert!(doorbell.try_recv().is_ok());
+        assert!(doorbell.try_recv().is_err());
+        assert_eq!(queue.take(), [first.clone(), second]);
+        assert_eq!(stats.coalesced_async_wakes(), 1);
+
+        // the forwarding thread is notified again, once the queue was drained
+        queue.wake(&first);
+        assert!(doorbell.try_recv().is_ok());
+    }
+
+    #[test]
+    fn quit_requests_can_be_cancelled() {
+        let (started_tx, started_rx) = std::sync::mpsc::channel();
+        let (quit_requests_tx, quit_requests_rx) = std::sync::mpsc::channel();
+        let (event_tx_tx, event_tx_rx) = std::sync::mpsc::channel();
+        // the app is created within its thread, as it isn't `Send`
+        let app_thread = std::thread::spawn(move || {
+            let app = App::new(0, |_: &mut u32| "Quit twice")
+                .on_start(move |_: &mut u32| started_tx.send(()).unwrap())
+                .on_quit_request(move |requests: &mut u32| {
+                    *requests += 1;
+                    quit_requests_tx.send(*requests).unwrap();
+                    *requests == 2
+                });
+            event_tx_tx.send(app.event_tx()).unwrap();
+            app.run_without_logging().unwrap();
+        });
+        let event_tx = event_tx_rx.recv().unwrap();
+
+        started_rx.recv().unwrap();
+        event_tx.blocking_send(Event::Quit).unwrap();
+        assert_eq!(quit_requests_rx.recv().unwrap(), 1);
+        event_tx.blocking_send(Event::Quit).unwrap();
+        assert_eq!(quit_requests_rx.recv().unwrap(), 2);
+        app_thread.join().unwrap();
     }
 }
@@ -0,0 +1,189 @@
+//! Internal counters and a headless renderer for the benchmarks in `benches/`, enabled with the `bench` feature.
+
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use ratatui::{buffer::Buffer, layout::Rect};
+use xilem_core::{Id, MessageResult};
+
+use crate::{
+    app::{AppMessage, WakeQueue},
+    geometry::{Point, Size},
+    view::{Cx, View},
+    widget::{
+        BoxConstraints, Canvas, CxState, FocusState, LayoutCx, LifeCycle, LifeCycleCx, PaintCx,
+        Pod, PodFlags, WidgetState,
+    },
+};
+
+pub(crate) static WIDGETS_BUILT: AtomicUsize = AtomicUsize::new(0);
+pub(crate) static WIDGETS_CHANGED: AtomicUsize = AtomicUsize::new(0);
+pub(crate) static LAYOUTS: AtomicUsize = AtomicUsize::new(0);
+pub(crate) static PAINTS: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn increment(counter: &AtomicUsize) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// How much work was done since the counters were last taken, see [`take_counters`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counters {
+    /// The number of newly created widgets.
+    pub widgets_built: usize,
+    /// The number of widgets, which were changed by a rebuild of their view.
+    pub widgets_changed: usize,
+    /// The number of calls to `Widget::layout`.
+    pub layouts: usize,
+    /// The number of calls to `Widget::paint`.
+    pub paints: usize,
+}
+
+/// Returns the counters and resets them, they are global for all apps in the process.
+pub fn take_counters() -> Counters {
+    Counters {
+        widgets_built: WIDGETS_BUILT.swap(0, Ordering::Relaxed),
+        widgets_changed: WIDGETS_CHANGED.swap(0, Ordering::Relaxed),
+        layouts: LAYOUTS.swap(0, Ordering::Relaxed),
+        paints: PAINTS.swap(0, Ordering::Relaxed),
+    }
+}
+
+/// Renders the views of `app_logic` into a buffer, without a terminal and without event handling.
+///
+/// # Examples
+/// ```
+/// # use trui::{*, bench::*};
+/// let mut harness = Harness::new(0, |count: &mut i32| format!("Count: {count}"), 20, 1);
+/// assert_eq!(harness.frame(std::time::Duration::ZERO).widgets_built, 1);
+/// assert_eq!(harness.frame(std::time::Duration::ZERO).widgets_built, 0);
+/// ```
+pub struct Harness<T, V: View<T>, F> {
+    data: T,
+    app_logic: F,
+    view: Option<(V, V::State, Id)>,
+    root_pod: Option<Pod>,
+    root_state: WidgetState,
+    cx: Cx,
+    buffer: Buffer,
+    focus: FocusState,
+    // kept alive, so that views can send messages without errors
+    _wake_rx: std::sync::mpsc::Receiver<()>,
+    _message_rx: tokio::sync::mpsc::Receiver<AppMessage>,
+}
+
+impl<T, V: View<T>, F: FnMut(&mut T) -> V> Harness<T, V, F> {
+    pub fn new(data: T, app_logic: F, width: u16, height: u16) -> Self {
+        let rt = Arc::new(tokio::runtime::Runtime::new().unwrap());
+        let (wake_queue, wake_rx) = WakeQueue::new(Default::default());
+        let (message_tx, message_rx) = tokio::sync::mpsc::channel(1000);
+        Harness {
+            data,
+            app_logic,
+            view: None,
+            root_pod: None,
+            root_state: WidgetState::new(),
+            cx: Cx::new(&wake_queue, &message_tx, rt),
+            buffer: Buffer::empty(Rect::new(0, 0, width, height)),
+            focus: FocusState::default(),
+            _wake_rx: wake_rx,
+            _message_rx: message_rx,
+        }
+    }
+
+    /// The app state, e.g. to change it between frames.
+    pub fn data_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    /// Sends `message` to the view at `id_path` (including the id of the root view, as in [`Cx::id_path`] while it was built),
+    /// like the app does for messages of event handlers.
+    ///
+    /// Panics, if no frame was rendered yet.
+    pub fn message(&mut self, id_path: &[Id], message: Box<dyn Any>) -> MessageResult<()> {
+        let (view, state, _) = self.view.as_mut().expect("no frame was rendered yet");
+        view.message(&id_path[1..], state, message, &mut self.data)
+    }
+
+    /// The last rendered frame.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Rebuilds the widget tree, advances animations by `dt`, and lays out and paints the widgets as needed.
+    ///
+    /// Returns the counters of this frame.
+    pub fn frame(&mut self, dt: Duration) -> Counters {
+        let _ = take_counters();
+        self.cx.terminal_size = (self.buffer.area.width, self.buffer.area.height);
+        let view = (self.app_logic)(&mut self.data);
+        let mut tree_changed = false;
+        match (self.view.take(), self.root_pod.as_mut()) {
+            (Some((prev, mut state, mut id)), Some(root_pod)) => {
+                let element = root_pod
+                    .downcast_mut()
+                    .expect("the root widget changed its type, this should never happen!");
+                let changes = view.rebuild(&mut self.cx, &prev, &mut id, &mut state, element);
+                let _ = root_pod.mark(changes);
+                self.view = Some((view, state, id));
+            }
+            _ => {
+                let (id, state, element) = view.build(&mut self.cx);
+                self.root_pod = Some(Pod::new(element));
+                self.view = Some((view, state, id));
+                tree_changed = true;
+            }
+        }
+        let root_pod = self.root_pod.as_mut().unwrap();
+
+        let mut messages = Vec::new();
+        let mut mouse_captured = false;
+        let cx_state = &mut CxState::new(
+            &mut messages,
+            &mut self.focus,
+            &mut mouse_captured,
+            dt,
+            false,
+        );
+        if root_pod.state.flags.contains(PodFlags::REQUEST_ANIMATION) {
+            let cx = &mut LifeCycleCx {
+                cx_state,
+                widget_state: &mut self.root_state,
+            };
+            root_pod.lifecycle(cx, &LifeCycle::Animate);
+        }
+        let needs_layout = tree_changed
+            || root_pod
+                .state
+                .flags
+                .intersects(PodFlags::REQUEST_LAYOUT | PodFlags::TREE_CHANGED);
+        if needs_layout {
+            let size = Size::new(
+                self.buffer.area.width as f64,
+                self.buffer.area.height as f64,
+            );
+            let cx = &mut LayoutCx {
+                widget_state: &mut self.root_state,
+                cx_state,
+            };
+            root_pod.layout(cx, &BoxConstraints::tight(size).loosen());
+            root_pod.set_origin(cx, Point::ORIGIN);
+        }
+        if needs_layout || root_pod.state.flags.contains(PodFlags::REQUEST_PAINT) {
+            self.buffer.reset();
+            let cx = &mut PaintCx {
+                widget_state: &mut self.root_state,
+                cx_state,
+                canvas: &mut Canvas::new(&mut self.buffer),
+                override_style: ratatui::style::Style::default(),
+            };
+            root_pod.paint(cx);
+        }
+        take_counters()
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+/// Counts the messages between the threads of the running app, which were coalesced or dropped instead of being queued,
+/// so that a busy app doesn't block (or deadlock) on full channels.
+///
+/// It's a shared handle, which can be kept in the app state, see [`App::channel_stats`](crate::App::channel_stats).
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let app = App::new((), |_| "Hello");
+/// let stats = app.channel_stats();
+/// assert_eq!(stats.dropped_wake_events(), 0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChannelStats {
+    inner: Arc<ChannelStatsInner>,
+}
+
+#[derive(Debug, Default)]
+struct ChannelStatsInner {
+    coalesced_async_wakes: AtomicU64,
+    dropped_wake_events: AtomicU64,
+}
+
+impl ChannelStats {
+    /// The number of wake-ups of async views (e.g. a future, which has made progress),
+    /// which were merged with a wake-up of the same view, that was still waiting to be handled.
+    pub fn coalesced_async_wakes(&self) -> u64 {
+        self.inner.coalesced_async_wakes.load(Ordering::Relaxed)
+    }
+
+    /// The number of wake events (e.g. to render the next animation frame), which were dropped,
+    /// because the event queue was full (the queued events are handled and rendered anyway).
+    pub fn dropped_wake_events(&self) -> u64 {
+        self.inner.dropped_wake_events.load(Ordering::Relaxed)
+    }
+
+    pub fn reset(&self) {
+        self.inner.coalesced_async_wakes.store(0, Ordering::Relaxed);
+        self.inner.dropped_wake_events.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn async_wake_coalesced(&self) {
+        self.inner
+            .coalesced_async_wakes
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn wake_event_dropped(&self) {
+        self.inner
+            .dropped_wake_events
+            .fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,99 @@
+use std::{
+    io::{self, Stdout, Write},
+    sync::{mpsc, Arc, Condvar, Mutex},
+    thread::JoinHandle,
+};
+
+use crossterm::{
+    queue,
+    terminal::{BeginSynchronizedUpdate, EndSynchronizedUpdate},
+};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    buffer::Buffer,
+};
+
+/// Writes the painted frames to the terminal on a dedicated thread, so that a slow terminal (e.g. over SSH)
+/// doesn't block handling the events for the next frame.
+///
+/// The frames are written in order, each within a synchronized update. At most one frame is queued,
+/// while another one is written, [`Flusher::submit`] blocks otherwise.
+pub(crate) struct Flusher {
+    frames: Option<mpsc::SyncSender<Buffer>>,
+    /// The number of frames, which are submitted but not yet written.
+    pending: Arc<(Mutex<usize>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Flusher {
+    pub(crate) fn new() -> Self {
+        let (frames, frame_rx) = mpsc::sync_channel::<Buffer>(1);
+        let pending = Arc::new((Mutex::new(0), Condvar::new()));
+        let pending_clone = Arc::clone(&pending);
+        let thread = std::thread::spawn(move || {
+            let mut backend = CrosstermBackend::new(io::stdout());
+            let mut previous = Buffer::empty(Default::default());
+            while let Ok(frame) = frame_rx.recv() {
+                if let Err(err) = write_frame(&mut backend, &previous, &frame) {
+                    tracing::error!("writing the frame to the terminal failed: {err}");
+                }
+                previous = frame;
+                let (count, idle) = &*pending_clone;
+                *count.lock().unwrap() -= 1;
+                idle.notify_all();
+            }
+        });
+        Flusher {
+            frames: Some(frames),
+            pending,
+            thread: Some(thread),
+        }
+    }
+
+    /// Queues `frame` to be written to the terminal, only the cells that differ from the previous frame are written.
+    ///
+    /// When the size of the frame differs from the previous one, the terminal is expected to be cleared already.
+    pub(crate) fn submit(&self, frame: Buffer) {
+        *self.pending.0.lock().unwrap() += 1;
+        if let Some(frames) = &self.frames {
+            if frames.send(frame).is_err() {
+                *self.pending.0.lock().unwrap() -= 1;
+            }
+        }
+    }
+
+    /// Blocks until all submitted frames are written, e.g. before writing to the terminal directly.
+    pub(crate) fn wait_idle(&self) {
+        let (count, idle) = &*self.pending;
+        drop(idle.wait_while(count.lock().unwrap(), |count| *count > 0));
+    }
+}
+
+impl Drop for Flusher {
+    fn drop(&mut self) {
+        // closing the channel ends the thread, after the queued frames are written
+        self.frames = None;
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn write_frame(
+    backend: &mut CrosstermBackend<Stdout>,
+    previous: &Buffer,
+    frame: &Buffer,
+) -> io::Result<()> {
+    let empty;
+    let previous = if previous.area == frame.area {
+        previous
+    } else {
+        empty = Buffer::empty(frame.area);
+        &empty
+    };
+    queue!(io::stdout(), BeginSynchronizedUpdate)?;
+    backend.draw(previous.diff(frame).into_iter())?;
+    queue!(io::stdout(), EndSynchronizedUpdate)?;
+    Backend::flush(backend)?;
+    io::stdout().flush()
+}
@@ -95,6 +95,261 @@ impl Axis {
     }
 }
 
+/// A length on one axis, e.g. for the [`margin`](crate::ViewExt::margin) or the [`fill_max_size`](crate::ViewExt::fill_max_size) modifiers.
+///
+/// An `u16` converts to [`Length::Cells`], and an `f64` to [`Length::Percent`],
+/// which is also what plain (animated) `f64` values mean for these modifiers.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Length {
+    /// A fixed number of terminal cells.
+    Cells(u16),
+    /// A fraction of the available space, where `1.0` is all of it.
+    Percent(f64),
+    /// The size is not constrained, the widget decides.
+    #[default]
+    Auto,
+}
+
+impl Length {
+    /// Resolves the length with the `available` space on the axis.
+    ///
+    /// Returns `None` for [`Length::Auto`], and for [`Length::Percent`] when the available space is unbounded.
+    pub fn resolve(self, available: f64) -> Option<f64> {
+        match self {
+            Length::Cells(cells) => Some(cells as f64),
+            Length::Percent(percent) if available.is_finite() => Some(available * percent),
+            Length::Percent(_) | Length::Auto => None,
+        }
+    }
+
+    /// Linearly interpolates between `self` and `other`, where `ratio` is between 0.0 and 1.0.
+    ///
+    /// Cells are rounded to the nearest cell, lengths with different units switch at half of the way.
+    pub fn lerp(self, other: Length, ratio: f64) -> Length {
+        match (self, other) {
+            (Length::Cells(start), Length::Cells(end)) => {
+                let cells = start as f64 + (end as f64 - start as f64) * ratio;
+                Length::Cells(cells.round().clamp(0.0, u16::MAX as f64) as u16)
+            }
+            (Length::Percent(start), Length::Percent(end)) => {
+                Length::Percent(start + (end - start) * ratio)
+            }
+            _ if ratio < 0.5 => self,
+            _ => other,
+        }
+    }
+}
+
+impl From<u16> for Length {
+    fn from(cells: u16) -> Self {
+        Length::Cells(cells)
+    }
+}
+
+impl From<f64> for Length {
+    fn from(percent: f64) -> Self {
+        Length::Percent(percent)
+    }
+}
+
+/// The position of a terminal cell, e.g. of a mouse event.
+///
+/// It's relative to some origin (e.g. of a widget), so it can be negative.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CellPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl CellPoint {
+    pub const ORIGIN: CellPoint = CellPoint { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Self {
+        CellPoint { x, y }
+    }
+
+    /// The cell at the (sub-cell) `point`, which is rounded to the nearest cell, like it is painted.
+    pub fn from_point(point: Point) -> Self {
+        CellPoint::new(point.x.round() as i32, point.y.round() as i32)
+    }
+
+    pub fn to_point(self) -> Point {
+        Point::new(self.x as f64, self.y as f64)
+    }
+}
+
+impl std::ops::Add for CellPoint {
+    type Output = CellPoint;
+
+    fn add(self, other: CellPoint) -> CellPoint {
+        CellPoint::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl std::ops::Sub for CellPoint {
+    type Output = CellPoint;
+
+    fn sub(self, other: CellPoint) -> CellPoint {
+        CellPoint::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// A size in terminal cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CellSize {
+    pub width: u16,
+    pub height: u16,
+}
+
+impl CellSize {
+    pub const ZERO: CellSize = CellSize {
+        width: 0,
+        height: 0,
+    };
+
+    pub fn new(width: u16, height: u16) -> Self {
+        CellSize { width, height }
+    }
+
+    /// The (sub-cell) `size` rounded to the nearest number of cells, clamped to the range of `u16`.
+    pub fn from_size(size: Size) -> Self {
+        let cells = |v: f64| v.round().clamp(0.0, u16::MAX as f64) as u16;
+        CellSize::new(cells(size.width), cells(size.height))
+    }
+
+    pub fn to_size(self) -> Size {
+        Size::new(self.width as f64, self.height as f64)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+}
+
+/// A rectangle of terminal cells, from (including) `x0`/`y0` to (excluding) `x1`/`y1`.
+///
+/// # Examples
+/// ```
+/// # use trui::geometry::*;
+/// let rect = CellRect::from_rect(Rect::new(0.4, 0.0, 2.6, 1.0));
+/// assert_eq!(rect, CellRect::new(0, 0, 3, 1));
+/// assert!(rect.contains(CellPoint::new(2, 0)));
+/// assert!(!rect.contains(CellPoint::new(3, 0)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CellRect {
+    pub x0: i32,
+    pub y0: i32,
+    pub x1: i32,
+    pub y1: i32,
+}
+
+impl CellRect {
+    pub fn new(x0: i32, y0: i32, x1: i32, y1: i32) -> Self {
+        CellRect { x0, y0, x1, y1 }
+    }
+
+    pub fn from_origin_size(origin: CellPoint, size: CellSize) -> Self {
+        CellRect::new(
+            origin.x,
+            origin.y,
+            origin.x + size.width as i32,
+            origin.y + size.height as i32,
+        )
+    }
+
+    /// The cells covered by the (sub-cell) `rect`, each edge is rounded to the nearest cell, like it is painted.
+    pub fn from_rect(rect: Rect) -> Self {
+        let rect = rect.abs();
+        CellRect::from_points(
+            CellPoint::from_point(rect.origin()),
+            CellPoint::new(rect.x1.round() as i32, rect.y1.round() as i32),
+        )
+    }
+
+    fn from_points(p0: CellPoint, p1: CellPoint) -> Self {
+        CellRect::new(p0.x, p0.y, p1.x, p1.y)
+    }
+
+    pub fn to_rect(self) -> Rect {
+        Rect::new(
+            self.x0 as f64,
+            self.y0 as f64,
+            self.x1 as f64,
+            self.y1 as f64,
+        )
+    }
+
+    pub fn origin(self) -> CellPoint {
+        CellPoint::new(self.x0, self.y0)
+    }
+
+    pub fn width(self) -> u16 {
+        (self.x1 - self.x0).clamp(0, u16::MAX as i32) as u16
+    }
+
+    pub fn height(self) -> u16 {
+        (self.y1 - self.y0).clamp(0, u16::MAX as i32) as u16
+    }
+
+    pub fn size(self) -> CellSize {
+        CellSize::new(self.width(), self.height())
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.size().is_empty()
+    }
+
+    pub fn contains(self, point: CellPoint) -> bool {
+        point.x >= self.x0 && point.x < self.x1 && point.y >= self.y0 && point.y < self.y1
+    }
+
+    /// The cells contained in both rectangles, which is empty (but not necessarily zero), if they don't intersect.
+    pub fn intersect(self, other: CellRect) -> CellRect {
+        let x0 = self.x0.max(other.x0);
+        let y0 = self.y0.max(other.y0);
+        CellRect::new(
+            x0,
+            y0,
+            self.x1.min(other.x1).max(x0),
+            self.y1.min(other.y1).max(y0),
+        )
+    }
+
+    /// The rectangle translated by `offset`.
+    pub fn translate(self, offset: CellPoint) -> CellRect {
+        CellRect::from_points(
+            self.origin() + offset,
+            CellPoint::new(self.x1, self.y1) + offset,
+        )
+    }
+
+    /// The area of a [`Buffer`](ratatui::buffer::Buffer), `None` if the rectangle is outside of the range of `u16`.
+    pub fn to_buffer_area(self) -> Option<ratatui::layout::Rect> {
+        let x = u16::try_from(self.x0).ok()?;
+        let y = u16::try_from(self.y0).ok()?;
+        u16::try_from(self.x1).ok()?;
+        u16::try_from(self.y1).ok()?;
+        Some(ratatui::layout::Rect::new(
+            x,
+            y,
+            self.width(),
+            self.height(),
+        ))
+    }
+}
+
+impl From<ratatui::layout::Rect> for CellRect {
+    fn from(area: ratatui::layout::Rect) -> Self {
+        CellRect::new(
+            area.x as i32,
+            area.y as i32,
+            area.right() as i32,
+            area.bottom() as i32,
+        )
+    }
+}
+
 /// Types implementing this Trait can be used with [`Axis`] to create axis independent algorithms.
 ///
 /// Types which implement this trait must consist of to identical sets of information, which can be
@@ -164,6 +419,38 @@ impl Dim2 for Size {
     }
 }
 
+impl Dim2 for CellPoint {
+    type Scalar = i32;
+
+    fn new(x: Self::Scalar, y: Self::Scalar) -> Self {
+        CellPoint::new(x, y)
+    }
+
+    fn x(self) -> Self::Scalar {
+        self.x
+    }
+
+    fn y(self) -> Self::Scalar {
+        self.y
+    }
+}
+
+impl Dim2 for CellSize {
+    type Scalar = u16;
+
+    fn new(x: Self::Scalar, y: Self::Scalar) -> Self {
+        CellSize::new(x, y)
+    }
+
+    fn x(self) -> Self::Scalar {
+        self.width
+    }
+
+    fn y(self) -> Self::Scalar {
+        self.height
+    }
+}
+
 /// A Span is a range of values on a given [`Axis`].
 ///
 /// Its main use is to define [`Dim2`] for [`Rect`]. This in turn allows us to use Axis together
@@ -0,0 +1,171 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// An undo/redo history of snapshots of some (editing) state `T`.
+///
+/// Every edit records a snapshot of the state after the edit, which can then be undone and redone.
+/// Edits recorded in quick succession (within the [coalesce window](EditHistory::coalesce_window),
+/// e.g. typing a word) and edits within a group (see [`EditHistory::begin_group`]) are merged into a single undo step.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # use std::time::Duration;
+/// let mut history = EditHistory::new(String::new()).coalesce_window(Duration::ZERO);
+/// history.record("Hello".to_string());
+/// history.begin_group();
+/// history.record("Hello, ".to_string());
+/// history.record("Hello, World".to_string());
+/// history.end_group();
+///
+/// assert_eq!(history.undo(), Some(&"Hello".to_string()));
+/// assert_eq!(history.redo(), Some(&"Hello, World".to_string()));
+/// assert_eq!(history.redo(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EditHistory<T> {
+    entries: VecDeque<T>,
+    current: usize,
+    capacity: usize,
+    coalesce_window: Duration,
+    last_edit: Option<Instant>,
+    group_depth: usize,
+    group_recorded: bool,
+}
+
+impl<T> EditHistory<T> {
+    /// Creates a new history starting with the `initial` state,
+    /// with a capacity of 100 undo steps and a coalesce window of 500ms.
+    pub fn new(initial: T) -> Self {
+        EditHistory {
+            entries: VecDeque::from([initial]),
+            current: 0,
+            capacity: 100,
+            coalesce_window: Duration::from_millis(500),
+            last_edit: None,
+            group_depth: 0,
+            group_recorded: false,
+        }
+    }
+
+    /// The maximum number of undo steps, older steps are dropped.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self.truncate();
+        self
+    }
+
+    /// Edits recorded within this duration after the previous edit are merged into the same undo step,
+    /// [`Duration::ZERO`] disables coalescing.
+    pub fn coalesce_window(mut self, window: Duration) -> Self {
+        self.coalesce_window = window;
+        self
+    }
+
+    /// The current state.
+    pub fn current(&self) -> &T {
+        &self.entries[self.current]
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.current > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.current + 1 < self.entries.len()
+    }
+
+    /// Records the `state` after an edit, this discards all steps that could be redone.
+    pub fn record(&mut self, state: T) {
+        self.record_at(state, Instant::now());
+    }
+
+    /// Records the `state` after an edit that happened at `time`, see [`EditHistory::record`].
+    pub fn record_at(&mut self, state: T, time: Instant) {
+        let coalesce = if self.group_depth > 0 {
+            self.group_recorded
+        } else {
+            self.last_edit.is_some_and(|last_edit| {
+                time.saturating_duration_since(last_edit) < self.coalesce_window
+            })
+        };
+        self.entries.truncate(self.current + 1);
+        if coalesce && self.current > 0 {
+            self.entries[self.current] = state;
+        } else {
+            self.entries.push_back(state);
+            self.current += 1;
+            self.truncate();
+        }
+        self.group_recorded = true;
+        self.last_edit = Some(time);
+    }
+
+    /// Starts a group of edits, all edits until the matching [`EditHistory::end_group`] are merged into one undo step.
+    ///
+    /// Groups can be nested, in which case they're merged into the outermost group.
+    pub fn begin_group(&mut self) {
+        if self.group_depth == 0 {
+            self.group_recorded = false;
+        }
+        self.group_depth += 1;
+    }
+
+    /// Ends a group of edits started with [`EditHistory::begin_group`].
+    pub fn end_group(&mut self) {
+        self.group_depth = self.group_depth.saturating_sub(1);
+        if self.group_depth == 0 {
+            self.last_edit = None;
+        }
+    }
+
+    /// Prevents the next edit from being merged with the previous one (e.g. after moving the cursor).
+    pub fn break_coalescing(&mut self) {
+        self.last_edit = None;
+        self.group_recorded = false;
+    }
+
+    /// Goes back one step and returns the state to restore, if there is one.
+    pub fn undo(&mut self) -> Option<&T> {
+        if !self.can_undo() {
+            return None;
+        }
+        self.current -= 1;
+        self.break_coalescing();
+        Some(&self.entries[self.current])
+    }
+
+    /// Goes forward one previously undone step and returns the state to restore, if there is one.
+    pub fn redo(&mut self) -> Option<&T> {
+        if !self.can_redo() {
+            return None;
+        }
+        self.current += 1;
+        self.break_coalescing();
+        Some(&self.entries[self.current])
+    }
+
+    /// Handles the default bindings, `Ctrl-Z` for undo and `Ctrl-Shift-Z` or `Ctrl-Y` for redo,
+    /// and returns the state to restore, if the key was one of them and there was a step to undo/redo.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> Option<&T> {
+        if key.kind == KeyEventKind::Release || !key.modifiers.contains(KeyModifiers::CONTROL) {
+            return None;
+        }
+        match key.code {
+            KeyCode::Char('z') if !key.modifiers.contains(KeyModifiers::SHIFT) => self.undo(),
+            KeyCode::Char('Z') | KeyCode::Char('z') | KeyCode::Char('y') => self.redo(),
+            _ => None,
+        }
+    }
+
+    /// Drops the oldest steps exceeding the capacity.
+    fn truncate(&mut self) {
+        while self.entries.len() > self.capacity + 1 && self.current > 0 {
+            self.entries.pop_front();
+            self.current -= 1;
+        }
+    }
+}
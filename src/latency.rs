@@ -0,0 +1,92 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// The number of recent frames, which are used for [`InputLatency::mean`].
+const SAMPLES: usize = 100;
+
+/// Measures the input latency of the running app, i.e. the time from reading an input event from the terminal
+/// until the frame, which has handled it, was drawn.
+///
+/// It's a shared handle, which can be kept in the app state, see [`App::input_latency`](crate::App::input_latency).
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let app = App::new((), |_| button("Hello", |_: &mut ()| ()));
+/// let latency = app.input_latency();
+/// assert_eq!(latency.last(), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InputLatency {
+    inner: Arc<Mutex<InputLatencyInner>>,
+}
+
+#[derive(Debug, Default)]
+struct InputLatencyInner {
+    /// When the oldest input event, which wasn't drawn yet, was read.
+    pending: Option<Instant>,
+    samples: VecDeque<Duration>,
+    max: Duration,
+    coalesced_wakes: u64,
+}
+
+impl InputLatency {
+    /// The latency of the last frame, which has handled input.
+    pub fn last(&self) -> Option<Duration> {
+        self.inner.lock().unwrap().samples.back().copied()
+    }
+
+    /// The mean latency of the recent frames, which have handled input.
+    pub fn mean(&self) -> Option<Duration> {
+        let inner = self.inner.lock().unwrap();
+        let sum: Duration = inner.samples.iter().sum();
+        (!inner.samples.is_empty()).then(|| sum / inner.samples.len() as u32)
+    }
+
+    /// The maximum latency since the start (or the last [`InputLatency::reset`]).
+    pub fn max(&self) -> Duration {
+        self.inner.lock().unwrap().max
+    }
+
+    /// The number of wake events, which were merged into a single one, because they were queued up at the same time,
+    /// e.g. by animations, or many async tasks.
+    pub fn coalesced_wakes(&self) -> u64 {
+        self.inner.lock().unwrap().coalesced_wakes
+    }
+
+    pub fn reset(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.samples.clear();
+        inner.max = Duration::ZERO;
+        inner.coalesced_wakes = 0;
+    }
+
+    /// Called, when an input event was read from the terminal.
+    pub(crate) fn input_received(&self) {
+        self.inner
+            .lock()
+            .unwrap()
+            .pending
+            .get_or_insert_with(Instant::now);
+    }
+
+    pub(crate) fn wakes_coalesced(&self, count: usize) {
+        self.inner.lock().unwrap().coalesced_wakes += count as u64;
+    }
+
+    /// Called, when a frame was drawn, after the pending input events were handled.
+    pub(crate) fn frame_rendered(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(received) = inner.pending.take() {
+            let latency = received.elapsed();
+            if inner.samples.len() == SAMPLES {
+                inner.samples.pop_front();
+            }
+            inner.samples.push_back(latency);
+            inner.max = inner.max.max(latency);
+        }
+    }
+}
@@ -1,13 +1,47 @@
+// so that the derive macros can refer to `::trui` within this crate as well
+extern crate self as trui;
+
+mod animation;
 mod app;
+#[cfg(feature = "bench")]
+pub mod bench;
+mod channel_stats;
+#[cfg(not(any(test, doctest, feature = "doctests")))]
+mod flush;
 pub mod geometry;
+mod history;
+mod latency;
+mod middleware;
+#[cfg(feature = "preview")]
+pub mod preview;
+mod recorder;
+mod render;
+mod screenshot;
+mod terminal_colors;
 mod view;
-mod widget;
+pub mod widget;
 
 // wildcards at least temporarily for convenience...
-pub use app::App;
+pub use animation::AnimationControl;
+pub use app::{App, AppConfig, FirstFrame, UnfocusedMode};
+pub use channel_stats::ChannelStats;
+pub use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEventKind};
+pub use geometry::Length;
+pub use history::EditHistory;
+pub use latency::InputLatency;
+pub use middleware::Middleware;
 pub use ratatui::style::{Color, Modifier, Style};
+pub use recorder::Recorder;
+pub use render::render_to_string;
+pub use screenshot::{export_frame, ScreenshotFormat, Screenshots};
+pub use terminal_colors::{ColorScheme, TerminalColors};
+pub use trui_macros::Styleable;
 pub use view::*;
-pub use widget::{Canvas, CatchMouseButton, ChangeFlags};
+pub use widget::{
+    effects, Canvas, CatchMouseButton, CellRegion, ChangeFlags, DragEvent, DragPhase, Ease, Event,
+    EventCx, FocusDirection, HoverDelay, MouseEvent, ScrollEvent, StyleTransition, TimerToken,
+    WrapMode,
+};
 
 #[cfg(test)]
 mod test_helper;
@@ -0,0 +1,89 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::Message;
+
+/// Observes and transforms the messages sent from the widgets to the views, see [`App::with_middleware`](crate::App::with_middleware),
+/// e.g. for logging, metrics, recording undo steps or assertions in tests.
+///
+/// Both methods do nothing by default.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # use trui::widget::Message;
+/// /// Counts the messages and drops them, while the app is read-only.
+/// #[derive(Default)]
+/// struct ReadOnly {
+///     dropped: usize,
+/// }
+///
+/// struct Document {
+///     read_only: bool,
+///     clicks: u32,
+/// }
+///
+/// impl Middleware<Document> for ReadOnly {
+///     fn before(&mut self, document: &mut Document, message: Message) -> Option<Message> {
+///         if document.read_only {
+///             self.dropped += 1;
+///             return None;
+///         }
+///         Some(message)
+///     }
+///
+///     fn after(&mut self, _: &mut Document, id_path: &[Id], result: &MessageResult<()>) {
+///         if matches!(result, MessageResult::Stale(_)) {
+///             tracing::debug!("stale message for {id_path:?}");
+///         }
+///     }
+/// }
+///
+/// App::new(Document { read_only: true, clicks: 0 }, |document: &mut Document| {
+///     button(format!("{} clicks", document.clicks), |document: &mut Document| {
+///         document.clicks += 1
+///     })
+/// })
+/// .with_middleware(ReadOnly::default());
+/// ```
+pub trait Middleware<T: ?Sized>: Send {
+    /// Called with each message before it's passed to the view tree, the returned message is passed on instead,
+    /// or it's dropped, when `None` is returned.
+    fn before(&mut self, data: &mut T, message: Message) -> Option<Message> {
+        let _ = data;
+        Some(message)
+    }
+
+    /// Called after the message sent to the widget at `id_path` was handled by the view tree.
+    fn after(&mut self, data: &mut T, id_path: &[Id], result: &MessageResult<()>) {
+        let _ = (data, id_path, result);
+    }
+}
+
+/// A middleware for the app state `T`, which is passed as `dyn Any` between the app and its task.
+pub(crate) type AnyMiddleware = Box<dyn Middleware<dyn Any>>;
+
+pub(crate) fn erase<T: 'static>(middleware: impl Middleware<T> + 'static) -> AnyMiddleware {
+    Box::new(Erased {
+        middleware,
+        phantom: PhantomData,
+    })
+}
+
+struct Erased<T, M> {
+    middleware: M,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T: 'static, M: Middleware<T>> Middleware<dyn Any> for Erased<T, M> {
+    fn before(&mut self, data: &mut dyn Any, message: Message) -> Option<Message> {
+        self.middleware
+            .before(data.downcast_mut().unwrap(), message)
+    }
+
+    fn after(&mut self, data: &mut dyn Any, id_path: &[Id], result: &MessageResult<()>) {
+        self.middleware
+            .after(data.downcast_mut().unwrap(), id_path, result)
+    }
+}
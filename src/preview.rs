@@ -0,0 +1,147 @@
+//! Renders the same views at several terminal sizes, e.g. to check responsive layouts without resizing a terminal,
+//! enabled with the `preview` feature.
+//!
+//! Run the `preview` example for a command line tool: `cargo run --example preview --features preview -- out/`.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use ratatui::buffer::Buffer;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{bench::Harness, export_frame, ScreenshotFormat, View};
+
+/// The number of frames rendered for each size, so that views, which depend on the layout (e.g. [`responsive`](crate::responsive)),
+/// and animations without a duration settle.
+const SETTLE_FRAMES: usize = 3;
+
+/// The space between the frames in [`side_by_side`].
+const GAP: usize = 2;
+
+/// The sizes previewed by default: a small, the default and a large terminal, and a narrow one.
+pub const DEFAULT_SIZES: [(u16, u16); 4] = [(40, 12), (80, 24), (120, 40), (30, 40)];
+
+/// Renders the views of an app at several terminal sizes.
+///
+/// # Examples
+/// ```
+/// # use trui::{*, preview::*};
+/// let frames = Preview::new(3, |count: &mut i32| format!("Count: {count}").border(BorderKind::Rounded))
+///     .sizes([(12, 3), (20, 5)])
+///     .render();
+/// assert_eq!(frames[1].buffer.area.width, 20);
+/// assert!(side_by_side(&frames).starts_with("12x3"));
+/// ```
+pub struct Preview<T, F> {
+    data: T,
+    app_logic: F,
+    sizes: Vec<(u16, u16)>,
+}
+
+/// A frame rendered by [`Preview::render`].
+#[derive(Debug, Clone)]
+pub struct PreviewFrame {
+    pub width: u16,
+    pub height: u16,
+    pub buffer: Buffer,
+}
+
+impl<T: Clone, V: View<T>, F: FnMut(&mut T) -> V + Clone> Preview<T, F> {
+    /// Previews `app_logic` with the app state `data` at the [`DEFAULT_SIZES`].
+    pub fn new(data: T, app_logic: F) -> Self {
+        Preview {
+            data,
+            app_logic,
+            sizes: DEFAULT_SIZES.to_vec(),
+        }
+    }
+
+    /// The sizes as (width, height) in cells.
+    pub fn sizes(mut self, sizes: impl IntoIterator<Item = (u16, u16)>) -> Self {
+        self.sizes = sizes.into_iter().collect();
+        self
+    }
+
+    /// Renders a frame for each size, each with a fresh copy of the app state.
+    pub fn render(&self) -> Vec<PreviewFrame> {
+        self.sizes
+            .iter()
+            .map(|&(width, height)| {
+                let mut harness =
+                    Harness::new(self.data.clone(), self.app_logic.clone(), width, height);
+                for _ in 0..SETTLE_FRAMES {
+                    harness.frame(Duration::ZERO);
+                }
+                PreviewFrame {
+                    width,
+                    height,
+                    buffer: harness.buffer().clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Renders the frames and writes each to `dir` as `{name}-{width}x{height}.{extension}` in `format`,
+    /// and in plain text all side by side as `{name}.txt`.
+    ///
+    /// Returns the paths of the written files.
+    pub fn save(
+        &self,
+        dir: impl AsRef<Path>,
+        name: &str,
+        format: ScreenshotFormat,
+    ) -> io::Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let frames = self.render();
+        let extension = match format {
+            ScreenshotFormat::Plain => "txt",
+            ScreenshotFormat::Ansi => "ans",
+            ScreenshotFormat::Html => "html",
+        };
+        let mut paths = Vec::with_capacity(frames.len() + 1);
+        for frame in &frames {
+            let path = dir.join(format!(
+                "{name}-{}x{}.{extension}",
+                frame.width, frame.height
+            ));
+            std::fs::write(&path, export_frame(&frame.buffer, format))?;
+            paths.push(path);
+        }
+        let path = dir.join(format!("{name}.txt"));
+        std::fs::write(&path, side_by_side(&frames))?;
+        paths.push(path);
+        Ok(paths)
+    }
+}
+
+/// Arranges the frames next to each other as plain text, each below a `{width}x{height}` header.
+pub fn side_by_side(frames: &[PreviewFrame]) -> String {
+    let columns: Vec<Vec<String>> = frames
+        .iter()
+        .map(|frame| {
+            let mut lines = vec![format!("{}x{}", frame.width, frame.height)];
+            let text = export_frame(&frame.buffer, ScreenshotFormat::Plain);
+            lines.extend(text.lines().map(str::to_string));
+            lines.resize(frame.height as usize + 1, String::new());
+            lines
+        })
+        .collect();
+    let rows = columns.iter().map(Vec::len).max().unwrap_or(0);
+    let mut out = String::new();
+    for row in 0..rows {
+        let mut line = String::new();
+        for (frame, column) in frames.iter().zip(&columns) {
+            let cell = column.get(row).map(String::as_str).unwrap_or("");
+            line.push_str(cell);
+            let padding = (frame.width as usize).saturating_sub(cell.width()) + GAP;
+            line.push_str(&" ".repeat(padding));
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
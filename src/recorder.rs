@@ -0,0 +1,248 @@
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant, SystemTime},
+};
+
+use ratatui::buffer::{Buffer, Cell};
+use unicode_width::UnicodeWidthStr;
+
+use crate::screenshot::ansi_style;
+
+/// Records the rendered frames of an app as [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file,
+/// which can be played with `asciinema play`, e.g. for demos.
+///
+/// It's a shared handle, so a clone can be kept in the app state to start and stop recording while the app is running,
+/// after it was given to the app via [`App::with_recorder`](crate::App::with_recorder).
+/// The time while the recording is stopped is left out of the recording.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let recorder = Recorder::new(std::env::temp_dir().join("demo.cast"));
+/// App::new(recorder.clone(), |recorder: &mut Recorder| {
+///     button(
+///         if recorder.is_recording() { "Stop recording" } else { "Record" },
+///         |recorder: &mut Recorder| recorder.set_recording(!recorder.is_recording()),
+///     )
+/// })
+/// .with_recorder(recorder);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    inner: Arc<RecorderInner>,
+}
+
+#[derive(Debug)]
+struct RecorderInner {
+    state: Mutex<RecorderState>,
+    /// Requests a render of the app, to record the current frame when the recording starts.
+    render_notifier: OnceLock<Arc<tokio::sync::Notify>>,
+}
+
+#[derive(Debug)]
+struct RecorderState {
+    path: PathBuf,
+    recording: bool,
+    /// Created when the first frame is recorded.
+    file: Option<BufWriter<File>>,
+    /// The last recorded frame, the next frame is recorded as difference to it.
+    last_frame: Option<Buffer>,
+    /// The duration of the recording until it was (re)started.
+    recorded: Duration,
+    started: Option<Instant>,
+}
+
+impl Recorder {
+    /// A recorder writing to `path`, which starts recording with the app.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Recorder {
+            inner: Arc::new(RecorderInner {
+                state: Mutex::new(RecorderState {
+                    path: path.into(),
+                    recording: true,
+                    file: None,
+                    last_frame: None,
+                    recorded: Duration::ZERO,
+                    started: None,
+                }),
+                render_notifier: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// Starts or stops recording, a recording can be resumed after it was stopped.
+    pub fn set_recording(&self, recording: bool) {
+        let mut state = self.inner.state.lock().unwrap();
+        if state.recording == recording {
+            return;
+        }
+        state.recording = recording;
+        if let Some(started) = state.started.take() {
+            state.recorded += started.elapsed();
+        }
+        // the frame is completely redrawn when the recording is resumed
+        state.last_frame = None;
+        drop(state);
+        if let Some(notifier) = self.inner.render_notifier.get() {
+            notifier.notify_one();
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.inner.state.lock().unwrap().recording
+    }
+
+    pub(crate) fn set_render_notifier(&self, notifier: Arc<tokio::sync::Notify>) {
+        let _ = self.inner.render_notifier.set(notifier);
+    }
+
+    /// Whether the current frame has to be recorded, even if nothing changed, e.g. after the recording was resumed.
+    pub(crate) fn needs_frame(&self) -> bool {
+        let state = self.inner.state.lock().unwrap();
+        state.recording && state.last_frame.is_none()
+    }
+
+    /// Records the frame, which is about to be flushed to the terminal.
+    pub(crate) fn record(&self, frame: &Buffer) {
+        let mut state = self.inner.state.lock().unwrap();
+        if !state.recording {
+            return;
+        }
+        if let Err(err) = state.record(frame) {
+            tracing::error!("Recording to {} failed: {err}", state.path.display());
+            state.recording = false;
+        }
+    }
+}
+
+impl RecorderState {
+    fn record(&mut self, frame: &Buffer) -> std::io::Result<()> {
+        let area = frame.area;
+        let file = match &mut self.file {
+            Some(file) => file,
+            file @ None => {
+                let timestamp = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                let mut new_file = BufWriter::new(File::create(&self.path)?);
+                writeln!(
+                    new_file,
+                    r#"{{"version": 2, "width": {}, "height": {}, "timestamp": {timestamp}}}"#,
+                    area.width, area.height
+                )?;
+                file.insert(new_file)
+            }
+        };
+        let time =
+            (self.recorded + self.started.get_or_insert_with(Instant::now).elapsed()).as_secs_f64();
+
+        let mut output = String::new();
+        match &self.last_frame {
+            Some(last_frame) if last_frame.area == area => {
+                let mut cursor = None;
+                let mut style: Option<&Cell> = None;
+                for (x, y, cell) in last_frame.diff(frame) {
+                    if cursor != Some((x, y)) {
+                        let _ = write!(output, "\x1b[{};{}H", y + 1, x + 1);
+                    }
+                    write_cell(&mut output, &mut style, cell);
+                    cursor = Some((x + cell.symbol().width().max(1) as u16, y));
+                }
+            }
+            last_frame => {
+                if last_frame.is_some() {
+                    writeln!(
+                        file,
+                        "[{time:.6}, \"r\", \"{}x{}\"]",
+                        area.width, area.height
+                    )?;
+                }
+                output.push_str("\x1b[2J");
+                let mut style = None;
+                for (y, row) in frame.content.chunks(area.width.max(1) as usize).enumerate() {
+                    let _ = write!(output, "\x1b[{};1H", y + 1);
+                    let mut skip = 0;
+                    for cell in row {
+                        // the cells covered by wide graphemes
+                        if skip > 0 {
+                            skip -= 1;
+                            continue;
+                        }
+                        skip = cell.symbol().width().saturating_sub(1);
+                        write_cell(&mut output, &mut style, cell);
+                    }
+                }
+            }
+        }
+        if !output.is_empty() {
+            output.push_str("\x1b[0m");
+            writeln!(file, "[{time:.6}, \"o\", \"{}\"]", json_escape(&output))?;
+            file.flush()?;
+        }
+        self.last_frame = Some(frame.clone());
+        Ok(())
+    }
+}
+
+fn write_cell<'a>(output: &mut String, style: &mut Option<&'a Cell>, cell: &'a Cell) {
+    let same_style = style.is_some_and(|style| {
+        style.fg == cell.fg && style.bg == cell.bg && style.modifier == cell.modifier
+    });
+    if !same_style {
+        output.push_str(&ansi_style(cell));
+        *style = Some(cell);
+    }
+    output.push_str(cell.symbol());
+}
+
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{layout::Rect, style::Style};
+
+    use super::*;
+
+    #[test]
+    fn records_frames_as_asciicast() {
+        let path = std::env::temp_dir().join(format!("trui-recorder-{}.cast", std::process::id()));
+        let recorder = Recorder::new(&path);
+        let mut frame = Buffer::empty(Rect::new(0, 0, 4, 2));
+        frame.set_string(0, 0, "ab", Style::default());
+        recorder.record(&frame);
+        frame.set_string(1, 1, "c", Style::default());
+        recorder.record(&frame);
+        recorder.set_recording(false);
+        recorder.record(&frame);
+
+        let cast = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let lines: Vec<_> = cast.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with(r#"{"version": 2, "width": 4, "height": 2"#));
+        assert!(lines[1]
+            .contains(r#""o", "\u001b[2J\u001b[1;1H\u001b[0mab  \u001b[2;1H    \u001b[0m""#));
+        assert!(lines[2].ends_with(r#""o", "\u001b[2;2H\u001b[0mc\u001b[0m"]"#));
+    }
+}
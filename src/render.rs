@@ -0,0 +1,92 @@
+use std::{sync::Arc, time::Duration};
+
+use ratatui::{buffer::Buffer, layout::Rect};
+
+use crate::{
+    app::WakeQueue,
+    export_frame,
+    geometry::{Point, Size},
+    view::{Cx, View},
+    widget::{
+        BoxConstraints, Canvas, CxState, FocusState, LayoutCx, PaintCx, Pod, PodFlags, WidgetState,
+    },
+    ChannelStats, ScreenshotFormat,
+};
+
+/// The maximum number of layout passes, as views, which depend on the layout, may request another one.
+const MAX_LAYOUT_PASSES: usize = 3;
+
+/// Renders `view` once into a frame of `size` as (width, height) in cells and returns it as text in `format`,
+/// without a terminal, e.g. for a `--dry-run` of a command line tool, which prints a static version of its UI and exits.
+///
+/// Transitions are skipped to their end, and async views show their placeholders, as nothing is awaited.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// struct Status {
+///     done: u32,
+///     total: u32,
+/// }
+///
+/// fn status_view(status: &Status) -> impl View<Status> {
+///     format!("{}/{} done", status.done, status.total).border(BorderKind::Rounded)
+/// }
+///
+/// let text = render_to_string(
+///     status_view(&Status { done: 3, total: 5 }),
+///     (12, 3),
+///     ScreenshotFormat::Plain,
+/// );
+/// assert_eq!(text, "╭────────╮\n│3/5 done│\n╰────────╯\n");
+/// ```
+pub fn render_to_string<T, V: View<T>>(
+    view: V,
+    (width, height): (u16, u16),
+    format: ScreenshotFormat,
+) -> String {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Creating the runtime for async views");
+    // nothing is sent, as there is no event loop
+    let (wake_queue, _wake_rx) = WakeQueue::new(ChannelStats::default());
+    let (message_tx, _message_rx) = tokio::sync::mpsc::channel(1);
+    let mut cx = Cx::new(&wake_queue, &message_tx, Arc::new(rt));
+    cx.terminal_size = (width, height);
+
+    let (_, _state, element) = view.build(&mut cx);
+    let mut root_pod = Pod::new(element);
+    let mut root_state = WidgetState::new();
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+
+    let (mut messages, mut focus, mut mouse_captured) = (Vec::new(), FocusState::default(), false);
+    let cx_state = &mut CxState::new(
+        &mut messages,
+        &mut focus,
+        &mut mouse_captured,
+        Duration::ZERO,
+        true,
+    );
+    let size = Size::new(width as f64, height as f64);
+    for _ in 0..MAX_LAYOUT_PASSES {
+        let cx = &mut LayoutCx {
+            widget_state: &mut root_state,
+            cx_state,
+        };
+        root_pod.layout(cx, &BoxConstraints::tight(size).loosen());
+        root_pod.set_origin(cx, Point::ORIGIN);
+        if !root_pod.state.flags.contains(PodFlags::REQUEST_LAYOUT) {
+            break;
+        }
+    }
+    let cx = &mut PaintCx {
+        widget_state: &mut root_state,
+        cx_state,
+        canvas: &mut Canvas::new(&mut buffer),
+        override_style: ratatui::style::Style::default(),
+    };
+    root_pod.paint(cx);
+
+    export_frame(&buffer, format)
+}
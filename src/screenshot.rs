@@ -0,0 +1,300 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+};
+use unicode_width::UnicodeWidthStr;
+
+/// The format of an exported frame, see [`export_frame`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScreenshotFormat {
+    /// Only the text, without any styling.
+    #[default]
+    Plain,
+    /// Text with ANSI escape sequences for the colors and modifiers, e.g. to be shown with `cat`.
+    Ansi,
+    /// A standalone `<pre>` element with inline styles.
+    Html,
+}
+
+impl ScreenshotFormat {
+    /// The format by the extension of `path`: `.html`/`.htm` for HTML, `.ans`/`.ansi` for ANSI, otherwise plain text.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        let extension = path.as_ref().extension().and_then(|ext| ext.to_str());
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("html" | "htm") => ScreenshotFormat::Html,
+            Some("ans" | "ansi") => ScreenshotFormat::Ansi,
+            _ => ScreenshotFormat::Plain,
+        }
+    }
+}
+
+/// Requests screenshots of the running app, which are written to a file after the next frame was rendered.
+///
+/// It's a shared handle, which can be kept in the app state, see [`App::screenshots`](crate::App::screenshots).
+#[derive(Debug, Clone)]
+pub struct Screenshots {
+    requests: Arc<Mutex<Vec<PathBuf>>>,
+    render_notifier: Arc<tokio::sync::Notify>,
+}
+
+impl Screenshots {
+    pub(crate) fn new(render_notifier: Arc<tokio::sync::Notify>) -> Self {
+        Screenshots {
+            requests: Arc::default(),
+            render_notifier,
+        }
+    }
+
+    /// Writes the next frame to `path`, the format is chosen by its extension (see [`ScreenshotFormat::from_path`]).
+    pub fn take(&self, path: impl Into<PathBuf>) {
+        self.requests.lock().unwrap().push(path.into());
+        self.render_notifier.notify_one();
+    }
+
+    pub(crate) fn has_requests(&self) -> bool {
+        !self.requests.lock().unwrap().is_empty()
+    }
+
+    /// Writes `buffer` to all requested paths.
+    pub(crate) fn save(&self, buffer: &Buffer) {
+        for path in std::mem::take(&mut *self.requests.lock().unwrap()) {
+            let screenshot = export_frame(buffer, ScreenshotFormat::from_path(&path));
+            if let Err(err) = std::fs::write(&path, screenshot) {
+                tracing::error!("Saving the screenshot {} failed: {err}", path.display());
+            }
+        }
+    }
+}
+
+/// Exports a rendered frame (e.g. from a test) as text.
+///
+/// Trailing whitespace of each line is removed in the plain text format.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # use ratatui::{buffer::Buffer, layout::Rect};
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+/// buffer.set_string(0, 0, "<b>", Style::default().fg(Color::Red));
+/// assert_eq!(export_frame(&buffer, ScreenshotFormat::Plain), "<b>\n");
+/// assert!(export_frame(&buffer, ScreenshotFormat::Ansi).starts_with("\x1b[0;31m<b>\x1b[0m"));
+/// assert!(export_frame(&buffer, ScreenshotFormat::Html).contains("&lt;b&gt;"));
+/// ```
+pub fn export_frame(buffer: &Buffer, format: ScreenshotFormat) -> String {
+    let mut out = String::new();
+    if format == ScreenshotFormat::Html {
+        out.push_str("<pre style=\"font-family: monospace; line-height: 1.2;\">");
+    }
+    let width = buffer.area.width as usize;
+    for row in buffer.content.chunks(width.max(1)) {
+        let mut line = String::new();
+        let mut current: Option<&Cell> = None;
+        let mut skip = 0;
+        for cell in row {
+            // the cells covered by wide graphemes
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            skip = cell.symbol().width().saturating_sub(1);
+            let style_changed = !current.is_some_and(|current| same_style(current, cell));
+            match format {
+                ScreenshotFormat::Plain => (),
+                ScreenshotFormat::Ansi if style_changed => line.push_str(&ansi_style(cell)),
+                ScreenshotFormat::Html if style_changed => {
+                    if current.is_some() {
+                        line.push_str("</span>");
+                    }
+                    let _ = write!(line, "<span style=\"{}\">", css_style(cell));
+                }
+                _ => (),
+            }
+            current = Some(cell);
+            if format == ScreenshotFormat::Html {
+                html_escape(&mut line, cell.symbol());
+            } else {
+                line.push_str(cell.symbol());
+            }
+        }
+        match format {
+            ScreenshotFormat::Plain => out.push_str(line.trim_end()),
+            ScreenshotFormat::Ansi => {
+                out.push_str(&line);
+                out.push_str("\x1b[0m");
+            }
+            ScreenshotFormat::Html => {
+                out.push_str(&line);
+                if current.is_some() {
+                    out.push_str("</span>");
+                }
+            }
+        }
+        out.push('\n');
+    }
+    if format == ScreenshotFormat::Html {
+        out.push_str("</pre>\n");
+    }
+    out
+}
+
+fn same_style(a: &Cell, b: &Cell) -> bool {
+    a.fg == b.fg && a.bg == b.bg && a.modifier == b.modifier
+}
+
+fn html_escape(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+pub(crate) fn ansi_style(cell: &Cell) -> String {
+    let mut sgr = String::from("\x1b[0");
+    let modifiers = [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ];
+    for (modifier, code) in modifiers {
+        if cell.modifier.contains(modifier) {
+            let _ = write!(sgr, ";{code}");
+        }
+    }
+    for (color, base) in [(cell.fg, 30), (cell.bg, 40)] {
+        let _ = match color {
+            Color::Reset => Ok(()),
+            Color::Black => write!(sgr, ";{base}"),
+            Color::Red => write!(sgr, ";{}", base + 1),
+            Color::Green => write!(sgr, ";{}", base + 2),
+            Color::Yellow => write!(sgr, ";{}", base + 3),
+            Color::Blue => write!(sgr, ";{}", base + 4),
+            Color::Magenta => write!(sgr, ";{}", base + 5),
+            Color::Cyan => write!(sgr, ";{}", base + 6),
+            Color::Gray => write!(sgr, ";{}", base + 7),
+            Color::DarkGray => write!(sgr, ";{}", base + 60),
+            Color::LightRed => write!(sgr, ";{}", base + 61),
+            Color::LightGreen => write!(sgr, ";{}", base + 62),
+            Color::LightYellow => write!(sgr, ";{}", base + 63),
+            Color::LightBlue => write!(sgr, ";{}", base + 64),
+            Color::LightMagenta => write!(sgr, ";{}", base + 65),
+            Color::LightCyan => write!(sgr, ";{}", base + 66),
+            Color::White => write!(sgr, ";{}", base + 67),
+            Color::Indexed(i) => write!(sgr, ";{};5;{i}", base + 8),
+            Color::Rgb(r, g, b) => write!(sgr, ";{};2;{r};{g};{b}", base + 8),
+        };
+    }
+    sgr.push('m');
+    sgr
+}
+
+/// The color as CSS color, with the default xterm palette for the named and indexed colors.
+fn css_color(color: Color) -> Option<String> {
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some(format!("#{r:02x}{g:02x}{b:02x}")),
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        Color::Indexed(i) => i,
+    };
+    let (r, g, b) = match index {
+        0..=15 => PALETTE[index as usize],
+        // 6x6x6 color cube
+        16..=231 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = index - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        // grayscale ramp
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    };
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+fn css_style(cell: &Cell) -> String {
+    let (fg, bg) = if cell.modifier.contains(Modifier::REVERSED) {
+        (cell.bg, cell.fg)
+    } else {
+        (cell.fg, cell.bg)
+    };
+    let mut css = String::new();
+    if let Some(fg) = css_color(fg) {
+        let _ = write!(css, "color: {fg};");
+    }
+    if let Some(bg) = css_color(bg) {
+        let _ = write!(css, "background-color: {bg};");
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        css.push_str("font-weight: bold;");
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        css.push_str("opacity: 0.6;");
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        css.push_str("font-style: italic;");
+    }
+    match (
+        cell.modifier.contains(Modifier::UNDERLINED),
+        cell.modifier.contains(Modifier::CROSSED_OUT),
+    ) {
+        (true, true) => css.push_str("text-decoration: underline line-through;"),
+        (true, false) => css.push_str("text-decoration: underline;"),
+        (false, true) => css.push_str("text-decoration: line-through;"),
+        (false, false) => (),
+    }
+    if cell.modifier.contains(Modifier::HIDDEN) {
+        css.push_str("visibility: hidden;");
+    }
+    css
+}
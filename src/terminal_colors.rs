@@ -0,0 +1,178 @@
+use ratatui::style::Color;
+
+/// Whether the terminal has a light or a dark background, see [`TerminalColors::color_scheme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+/// The default foreground and background colors of the terminal, which are queried when the app is created
+/// (via the `OSC 10` and `OSC 11` escape sequences), if [`AppConfig::query_terminal_colors`](crate::AppConfig::query_terminal_colors) is enabled.
+///
+/// Views can access them via [`Cx::terminal_colors`](crate::view::Cx::terminal_colors),
+/// e.g. to adapt their colors to the background.
+/// Colors are `None`, if they weren't queried, or the terminal doesn't support the query.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerminalColors {
+    /// The default foreground color, as [`Color::Rgb`].
+    pub foreground: Option<Color>,
+    /// The default background color, as [`Color::Rgb`].
+    pub background: Option<Color>,
+}
+
+impl TerminalColors {
+    /// Whether the background is light or dark (or the foreground dark or light, if only it is known).
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let colors = TerminalColors {
+    ///     foreground: None,
+    ///     background: Some(Color::Rgb(250, 250, 245)),
+    /// };
+    /// assert_eq!(colors.color_scheme(), Some(ColorScheme::Light));
+    /// ```
+    pub fn color_scheme(&self) -> Option<ColorScheme> {
+        let is_light = |color| luminance(color).map(|l| l > 0.5);
+        match (
+            self.background.and_then(is_light),
+            self.foreground.and_then(is_light),
+        ) {
+            (Some(true), _) | (None, Some(false)) => Some(ColorScheme::Light),
+            (Some(false), _) | (None, Some(true)) => Some(ColorScheme::Dark),
+            (None, None) => None,
+        }
+    }
+}
+
+/// The (perceived) luminance in the range 0 to 1 of an RGB color.
+fn luminance(color: Color) -> Option<f64> {
+    let Color::Rgb(r, g, b) = color else {
+        return None;
+    };
+    Some((0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64) / 255.0)
+}
+
+/// Queries the default colors of the terminal, the terminal must be in raw mode.
+///
+/// The color queries are followed by a primary device attributes query, which is answered by virtually all terminals,
+/// so that unsupported color queries can be detected without waiting for the `timeout`.
+///
+/// The response is read on the calling thread, and only until the `timeout`, so that nothing is left reading the input,
+/// when the input thread of the app starts.
+#[cfg(unix)]
+#[cfg_attr(any(test, doctest, feature = "doctests"), allow(dead_code))]
+pub(crate) fn query(timeout: std::time::Duration) -> TerminalColors {
+    use std::{
+        io::{IsTerminal, Write},
+        os::fd::AsRawFd,
+        time::Instant,
+    };
+
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        return TerminalColors::default();
+    }
+    let mut stdout = std::io::stdout();
+    if write!(stdout, "\x1b]10;?\x1b\\\x1b]11;?\x1b\\\x1b[c")
+        .and_then(|()| stdout.flush())
+        .is_err()
+    {
+        return TerminalColors::default();
+    }
+
+    // The file descriptor is read directly byte by byte (instead of via the buffered `Stdin`),
+    // so that input typed after the response is left for the input thread of the app.
+    let fd = std::io::stdin().as_raw_fd();
+    let deadline = Instant::now() + timeout;
+    let mut response = Vec::new();
+    while device_attributes_end(&response).is_none() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() || !poll_readable(fd, remaining) {
+            break;
+        }
+        let mut byte = 0u8;
+        // SAFETY: at most one byte is written to `byte`
+        let read = unsafe { libc::read(fd, (&mut byte as *mut u8).cast(), 1) };
+        if read != 1 {
+            break;
+        }
+        response.push(byte);
+    }
+    parse_response(&String::from_utf8_lossy(&response))
+}
+
+/// Terminals on other platforms aren't queried.
+#[cfg(not(unix))]
+#[cfg_attr(any(test, doctest, feature = "doctests"), allow(dead_code))]
+pub(crate) fn query(_timeout: std::time::Duration) -> TerminalColors {
+    TerminalColors::default()
+}
+
+/// Waits at most `timeout` until `fd` can be read without blocking.
+#[cfg(unix)]
+#[cfg_attr(any(test, doctest, feature = "doctests"), allow(dead_code))]
+fn poll_readable(fd: std::os::fd::RawFd, timeout: std::time::Duration) -> bool {
+    let mut pollfd = libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout = timeout.as_millis().clamp(1, libc::c_int::MAX as u128) as libc::c_int;
+    // SAFETY: `pollfd` is a single valid `pollfd` for the duration of the call
+    let ready = unsafe { libc::poll(&mut pollfd, 1, timeout) };
+    ready > 0 && pollfd.revents & libc::POLLIN != 0
+}
+
+/// The end of the response to the primary device attributes query (`ESC [ ? ... c`).
+fn device_attributes_end(response: &[u8]) -> Option<usize> {
+    let start = response.windows(3).position(|w| w == b"\x1b[?")?;
+    let end = response[start..].iter().position(|&b| b == b'c')?;
+    Some(start + end + 1)
+}
+
+/// Parses the responses to the color queries, e.g. `ESC ] 11 ; rgb:ffff/ffff/ffff ESC \`.
+fn parse_response(response: &str) -> TerminalColors {
+    let mut colors = TerminalColors::default();
+    for osc in response.split("\x1b]").skip(1) {
+        let Some((code, rest)) = osc.split_once(';') else {
+            continue;
+        };
+        let value = rest.split(['\x07', '\x1b']).next().unwrap_or_default();
+        match code {
+            "10" => colors.foreground = parse_rgb(value),
+            "11" => colors.background = parse_rgb(value),
+            _ => (),
+        }
+    }
+    colors
+}
+
+/// Parses an X11 color specification like `rgb:ffff/8080/0000` (with 1 to 4 hex digits per channel).
+fn parse_rgb(value: &str) -> Option<Color> {
+    let mut channels = value.strip_prefix("rgb:")?.split('/').map(|channel| {
+        if channel.is_empty() || channel.len() > 4 {
+            return None;
+        }
+        let max = (1u32 << (4 * channel.len())) - 1;
+        let value = u32::from_str_radix(channel, 16).ok()?;
+        Some((value * 255 / max) as u8)
+    });
+    let color = Color::Rgb(channels.next()??, channels.next()??, channels.next()??);
+    channels.next().is_none().then_some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_color_query_responses() {
+        let colors =
+            parse_response("\x1b]10;rgb:0000/0000/0000\x1b\\\x1b]11;rgb:ff/ff/ee\x07\x1b[?62;22c");
+        assert_eq!(colors.foreground, Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(colors.background, Some(Color::Rgb(255, 255, 238)));
+        assert_eq!(colors.color_scheme(), Some(ColorScheme::Light));
+        assert_eq!(parse_response("\x1b[?1;2c"), TerminalColors::default());
+    }
+}
@@ -2,15 +2,20 @@ use std::env;
 use std::io::stdout;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
 
 use ratatui::layout::Size;
 use ratatui::prelude::*;
 
 use ratatui::{Terminal, TerminalOptions, Viewport};
-use tokio::sync::mpsc;
+use tokio::{runtime::Runtime, sync::mpsc};
 use xilem_core::MessageResult;
 
-use crate::widget::{BoxConstraints, ChangeFlags, Event};
+use crate::app::WakeQueue;
+use crate::widget::{
+    BoxConstraints, Canvas, ChangeFlags, CxState, Event, EventCx, FocusState, LayoutCx,
+    LifeCycleCx, Message, PaintCx, PodFlags, TimerToken, WidgetState,
+};
 use crate::widget::{Pod, Widget};
 use crate::{App, Cx, View, ViewMarker};
 
@@ -34,7 +39,8 @@ pub fn render_view<T: Send + 'static>(
     let join_handle = std::thread::spawn(move || {
         let mut app = App::new(state, move |_state| {
             debug_view(sut.clone(), message_tx.clone())
-        });
+        })
+        .with_stable_ids();
         event_tx_clone.blocking_send(app.event_tx()).unwrap();
 
         app.terminal_mut()
@@ -60,6 +66,122 @@ pub fn render_view<T: Send + 'static>(
     buffer
 }
 
+/// The state shared by the widget contexts, to drive widgets directly in unit tests, without an [`App`].
+///
+/// Every context is created with `widget_state` as the state of the driven widget (or of the parent of a [`Pod`]).
+pub struct TestCx {
+    /// The messages sent by the widgets, see [`TestCx::take_messages`].
+    pub messages: Vec<Message>,
+    pub focus: FocusState,
+    pub mouse_captured: bool,
+    pub widget_state: WidgetState,
+    /// The timers requested by the widgets, which would be scheduled by the app.
+    pub timers: Vec<(Duration, TimerToken)>,
+}
+
+impl TestCx {
+    pub fn new() -> Self {
+        TestCx {
+            messages: Vec::new(),
+            focus: FocusState::default(),
+            mouse_captured: false,
+            widget_state: WidgetState::new(),
+            timers: Vec::new(),
+        }
+    }
+
+    /// The driven widget is focused, e.g. to send it key events.
+    pub fn focused() -> Self {
+        let mut cx = Self::new();
+        cx.widget_state.flags |= PodFlags::IS_FOCUSED;
+        cx
+    }
+
+    /// The mouse is over the driven widget.
+    pub fn hot() -> Self {
+        let mut cx = Self::new();
+        cx.widget_state.flags |= PodFlags::IS_HOT;
+        cx
+    }
+
+    pub fn with_cx_state<R>(&mut self, f: impl FnOnce(&mut CxState, &mut WidgetState) -> R) -> R {
+        let mut cx_state = CxState::new(
+            &mut self.messages,
+            &mut self.focus,
+            &mut self.mouse_captured,
+            Duration::ZERO,
+            false,
+        );
+        let result = f(&mut cx_state, &mut self.widget_state);
+        self.timers.append(&mut cx_state.timers);
+        result
+    }
+
+    pub fn event_cx<R>(&mut self, f: impl FnOnce(&mut EventCx) -> R) -> R {
+        self.with_cx_state(|cx_state, widget_state| {
+            f(&mut EventCx {
+                cx_state,
+                widget_state,
+                is_handled: false,
+            })
+        })
+    }
+
+    pub fn lifecycle_cx<R>(&mut self, f: impl FnOnce(&mut LifeCycleCx) -> R) -> R {
+        self.with_cx_state(|cx_state, widget_state| {
+            f(&mut LifeCycleCx {
+                cx_state,
+                widget_state,
+            })
+        })
+    }
+
+    pub fn layout_cx<R>(&mut self, f: impl FnOnce(&mut LayoutCx) -> R) -> R {
+        self.with_cx_state(|cx_state, widget_state| {
+            f(&mut LayoutCx {
+                cx_state,
+                widget_state,
+            })
+        })
+    }
+
+    pub fn paint_cx<R>(&mut self, buffer: &mut Buffer, f: impl FnOnce(&mut PaintCx) -> R) -> R {
+        self.with_cx_state(|cx_state, widget_state| {
+            f(&mut PaintCx {
+                cx_state,
+                widget_state,
+                canvas: &mut Canvas::new(buffer),
+                override_style: Style::default(),
+            })
+        })
+    }
+
+    /// Lays out `root` with tight constraints of `size` at the origin of the window.
+    pub fn layout(&mut self, root: &mut Pod, size: kurbo::Size) {
+        self.layout_cx(|cx| {
+            root.layout(cx, &BoxConstraints::tight(size));
+            root.set_origin(cx, kurbo::Point::ORIGIN);
+        });
+    }
+
+    /// Removes the messages sent so far and returns their bodies.
+    pub fn take_messages<M: 'static>(&mut self) -> Vec<M> {
+        std::mem::take(&mut self.messages)
+            .into_iter()
+            .map(|message| *message.body.downcast().unwrap())
+            .collect()
+    }
+}
+
+/// A view context outside of an [`App`], to build views and event handlers directly in unit tests.
+///
+/// Wakes and app messages sent by async views are dropped.
+pub fn view_cx() -> Cx {
+    let (wake_queue, _wake_rx) = WakeQueue::new(Default::default());
+    let (message_tx, _message_rx) = mpsc::channel(1);
+    Cx::new(&wake_queue, &message_tx, Arc::new(Runtime::new().unwrap()))
+}
+
 /// This widget provides access to the terminal output of its children
 ///
 /// After its children were painted it calls the flush() and clones the
@@ -1,38 +1,127 @@
 mod animatables;
+mod animation_speed;
 mod border;
+mod button;
+mod collapsible;
 mod common;
+mod confirm_quit;
 mod core;
+mod date_picker;
 mod defer;
+mod diff_view;
+mod effect;
 mod events;
 mod fill_max_size;
+mod filterable;
+mod focus_scope;
+mod focus_when;
+mod freeze;
+mod heatmap;
+#[cfg(feature = "json")]
+mod json_view;
+mod keyed;
+mod layout_direction;
 mod linear_layout;
+mod localization;
 mod margin;
+mod marquee;
+mod modal;
+mod mount;
+mod number;
 mod one_of;
+mod paginated;
+#[cfg(feature = "json")]
+mod registry;
+mod reorderable_list;
+mod responsive;
+mod scoped;
 mod scroll_view;
+mod scrollbar;
+mod section;
+mod selectable_list;
+mod signal;
+mod size_limits;
+mod slider;
+mod stepper;
+mod table;
 mod text;
+mod text_input;
+mod theme;
+mod toggle;
+mod tree;
 mod use_state;
+#[cfg(feature = "watch")]
+mod watch_path;
 mod weighted_linear_layout;
+mod worker;
+#[cfg(feature = "websocket")]
+mod ws_stream;
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
 
+use crate::{widget::CellRegion, Ease, HoverDelay, Length, StyleTransition};
 use ratatui::style::{Color, Style};
-pub use xilem_core::{Id, IdPath, VecSplice};
+pub use xilem_core::{Id, IdPath, MessageResult, VecSplice};
 
 // TODO do this via a prelude instead (and possibly not wildcard export)
 pub use self::core::*;
 pub use animatables::*;
+pub use animation_speed::*;
 pub use border::*;
+pub use button::*;
+pub use collapsible::*;
 pub use common::*;
+pub use confirm_quit::*;
+pub use date_picker::*;
 pub use defer::*;
+pub use diff_view::*;
+pub use effect::*;
 pub use events::*;
 pub use fill_max_size::*;
+pub use filterable::*;
+pub use focus_scope::*;
+pub use focus_when::*;
+pub use freeze::*;
+pub use heatmap::*;
+#[cfg(feature = "json")]
+pub use json_view::*;
+pub use keyed::*;
+pub use layout_direction::*;
 pub use linear_layout::*;
+pub use localization::*;
 pub use margin::*;
+pub use marquee::*;
+pub use modal::*;
+pub use mount::*;
+pub use number::*;
 pub use one_of::*;
+pub use paginated::*;
+#[cfg(feature = "json")]
+pub use registry::*;
+pub use reorderable_list::*;
+pub use responsive::*;
+pub use scoped::*;
 pub use scroll_view::*;
+pub use scrollbar::*;
+pub use section::*;
+pub use selectable_list::*;
+pub use signal::*;
+pub use size_limits::*;
+pub use slider::*;
+pub use stepper::*;
+pub use table::*;
 pub use text::*;
+pub use text_input::*;
+pub use theme::*;
+pub use toggle::*;
+pub use tree::*;
 pub use use_state::*;
+#[cfg(feature = "watch")]
+pub use watch_path::*;
 pub use weighted_linear_layout::*;
+pub use worker::*;
+#[cfg(feature = "websocket")]
+pub use ws_stream::*;
 
 // TODO this could maybe also be added directly to `View` (possibly copying the macro expanded version of it)
 /// A trait that makes it possible to use core views such as [`Adapt`] in the continuation/builder style.
@@ -53,7 +142,33 @@ pub trait ViewExt<T, A>: View<T, A> + Sized {
         AdaptState::new(f, self)
     }
 
-    fn margin<S: Into<MarginStyle>>(self, style: S) -> Margin<Self, T, A> {
+    /// Adds a margin around this view, on the sides given by a [`Position`] (all by default).
+    ///
+    /// The amount is a [`Length`](crate::Length), i.e. a number of cells (e.g. `2`),
+    /// or a [`Length::Percent`](crate::Length::Percent) of the available space on the respective axis.
+    /// It can be animated like the length of [`ViewExt::fill_max_size`], e.g. with [`low_pass`](crate::low_pass).
+    ///
+    /// Different amounts per side are set by chaining margins.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(false, move |indented: &mut bool| {
+    /// v_stack((
+    ///     "Margin of 2 cells on all sides".margin(2),
+    ///     "Margin of 10% of the width on the left".margin((Position::LEFT, Length::Percent(0.1))),
+    ///     "Margin of 2 cells on the left, and 1 on the top"
+    ///         .margin((Position::LEFT, 2))
+    ///         .margin((Position::TOP, 1)),
+    ///     "Slides to the right, when indented"
+    ///         .margin((Position::LEFT, low_pass(0.2, if *indented { 0.2 } else { 0.0 }))),
+    /// ))
+    /// # });
+    /// ```
+    fn margin<L: Animatable<Length>, S: IntoMarginStyle<L>>(
+        self,
+        style: S,
+    ) -> Margin<Self, L, T, A> {
         let style = style.into();
         Margin {
             content: self,
@@ -127,46 +242,229 @@ pub trait ViewExt<T, A>: View<T, A> + Sized {
         }
     }
 
+    /// A [`border`](ViewExt::border), which draws itself progressively around the content,
+    /// clockwise from the top left corner, `progress` (from `0.0` to `1.0`) is how much of the outline is drawn.
+    ///
+    /// It's usually a played [`Tweenable`], e.g. to trace in the border of a panel when it appears.
+    ///
     /// # Examples
     /// ```
     /// # use trui::*;
     /// # App::new((), move |()| {
-    /// "Fill half of the parent width/height"
-    ///     .border(BorderKind::Rounded)
-    ///     .fill_max_size(0.5)
+    /// "Appearing panel".border_animated(BorderKind::Rounded, (0.0..1.0).quadratic_out_ease().play(1.0))
     /// # });
     /// ```
-    fn fill_max_size<P: Animatable<f64>, S: IntoFillMaxSizeStyle<P>>(
+    fn border_animated<S: Into<BorderStyle>, P: Animatable<f64>>(
         self,
         style: S,
-    ) -> FillMaxSize<Self, P, T, A> {
+        progress: P,
+    ) -> AnimatedBorder<Self, P, T, A> {
+        AnimatedBorder {
+            border: self.border(style),
+            progress,
+        }
+    }
+
+    /// Fills the given [`Length`](crate::Length) of the parent's width and/or height.
+    ///
+    /// A plain (or animated) `f64` is a [`Length::Percent`](crate::Length::Percent) of the available space.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new((), move |()| {
+    /// v_stack((
+    ///     "Fill half of the parent width/height"
+    ///         .border(BorderKind::Rounded)
+    ///         .fill_max_size(0.5),
+    ///     "Exactly 20 cells wide"
+    ///         .border(BorderKind::Rounded)
+    ///         .fill_max_width(Length::Cells(20)),
+    /// ))
+    /// # });
+    /// ```
+    fn fill_max_size<L: Animatable<Length>, S: IntoFillMaxSizeStyle<L>>(
+        self,
+        style: S,
+    ) -> FillMaxSize<Self, L, T, A> {
         let style = style.into();
         FillMaxSize {
             content: self,
             fill: style.fill,
-            percent: style.percent,
+            length: style.length,
             phantom: PhantomData,
         }
     }
 
-    fn fill_max_width<P: Animatable<f64>>(self, percent: P) -> FillMaxSize<Self, P, T, A> {
+    /// Fills the given [`Length`](crate::Length) of the parent's width, see [`ViewExt::fill_max_size`].
+    fn fill_max_width<L: Animatable<Length>>(self, length: L) -> FillMaxSize<Self, L, T, A> {
         FillMaxSize {
             content: self,
             fill: Fill::WIDTH,
-            percent,
+            length,
             phantom: PhantomData,
         }
     }
 
-    fn fill_max_height<P: Animatable<f64>>(self, percent: P) -> FillMaxSize<Self, P, T, A> {
+    /// Fills the given [`Length`](crate::Length) of the parent's height, see [`ViewExt::fill_max_size`].
+    fn fill_max_height<L: Animatable<Length>>(self, length: L) -> FillMaxSize<Self, L, T, A> {
         FillMaxSize {
             content: self,
             fill: Fill::HEIGHT,
-            percent,
+            length,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the minimum width of this view, within the constraints of the parent.
+    ///
+    /// Chaining it with the other size limits (like [`ViewExt::max_width`]) sets them on the same [`SizeLimits`] view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new((), move |()| {
+    /// "At least 20 cells, but at most half of the available width"
+    ///     .border(BorderKind::Rounded)
+    ///     .min_width(20)
+    ///     .max_width(Length::Percent(0.5))
+    /// # });
+    /// ```
+    fn min_width(self, width: impl Into<Length>) -> SizeLimits<Self, T, A> {
+        SizeLimits::new(self).min_width(width)
+    }
+
+    /// Sets the minimum height of this view, see [`ViewExt::min_width`].
+    fn min_height(self, height: impl Into<Length>) -> SizeLimits<Self, T, A> {
+        SizeLimits::new(self).min_height(height)
+    }
+
+    /// Sets the maximum width of this view, see [`ViewExt::min_width`].
+    fn max_width(self, width: impl Into<Length>) -> SizeLimits<Self, T, A> {
+        SizeLimits::new(self).max_width(width)
+    }
+
+    /// Sets the maximum height of this view, see [`ViewExt::min_width`].
+    fn max_height(self, height: impl Into<Length>) -> SizeLimits<Self, T, A> {
+        SizeLimits::new(self).max_height(height)
+    }
+
+    /// Moves the focus to the first focusable widget within this view, when `condition` becomes `true`
+    /// (or when it's added with `condition` being `true`), e.g. to focus a search box when '/' is pressed.
+    ///
+    /// The focus is only moved once per change, so the user can move it away again (e.g. with `Tab`),
+    /// while `condition` stays `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(false, move |searching: &mut bool| {
+    /// v_stack((
+    ///     button("Search", |searching: &mut bool| *searching = true),
+    ///     button("Search box", |searching: &mut bool| *searching = false).focus_when(*searching),
+    /// ))
+    /// # });
+    /// ```
+    fn focus_when(self, condition: bool) -> FocusWhen<Self, T, A> {
+        FocusWhen {
+            content: self,
+            condition,
             phantom: PhantomData,
         }
     }
 
+    /// Scales the speed of the animations within this view, e.g. `0.5` to play them at half the speed.
+    ///
+    /// This is combined with the global speed of [`AnimationControl`](crate::AnimationControl).
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(true, move |expanded: &mut bool| {
+    /// let width = if *expanded { 100.0 } else { 20.0 };
+    /// "Slow".fill_max_width(low_pass(0.1, width)).animation_speed(0.25)
+    /// # });
+    /// ```
+    fn animation_speed(self, speed: f64) -> AnimationSpeed<Self, T, A> {
+        AnimationSpeed {
+            content: self,
+            speed,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Transforms the painted cells of this view with `effect`, e.g. one of the [`effects`](crate::effects).
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(false, move |enabled: &mut bool| {
+    /// v_stack((
+    ///     toggle(*enabled, |enabled: &mut bool, value: bool| *enabled = value),
+    ///     button("Save", |_: &mut bool| ())
+    ///         .effect(if *enabled { effects::none } else { effects::dim }),
+    /// ))
+    /// # });
+    /// ```
+    fn effect(self, effect: fn(&mut CellRegion)) -> Effect<Self, T, A> {
+        Effect {
+            content: self,
+            effect,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Calls `handler` with the output of the event `filter`, to create custom interaction views,
+    /// e.g. for double clicks or key shortcuts, see [`EventFilter`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// #[derive(Clone, PartialEq, Default)]
+    /// struct DoubleClick {
+    ///     last_click: Option<Instant>,
+    /// }
+    ///
+    /// impl EventFilter for DoubleClick {
+    ///     type Output = ();
+    ///
+    ///     fn event(&mut self, cx: &mut EventCx, event: &Event) -> Option<()> {
+    ///         let Event::Mouse(mouse) = event else {
+    ///             return None;
+    ///         };
+    ///         if !cx.is_hot() || !matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
+    ///             return None;
+    ///         }
+    ///         let now = Instant::now();
+    ///         match self.last_click.replace(now) {
+    ///             Some(last) if now - last < Duration::from_millis(400) => {
+    ///                 self.last_click = None;
+    ///                 Some(())
+    ///             }
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # App::new(0, move |count: &mut u32| {
+    /// format!("Double clicked {count} times")
+    ///     .on_event(DoubleClick::default(), |count: &mut u32, ()| *count += 1)
+    /// # });
+    /// ```
+    fn on_event<F, H>(self, filter: F, handler: H) -> OnEvent<Self, F, H>
+    where
+        F: EventFilter,
+        H: Fn(&mut T, F::Output) -> A + Send + Sync,
+    {
+        OnEvent {
+            view: self,
+            filter,
+            handler,
+        }
+    }
+
     fn on_click<EH: EventHandler<T, A>>(self, event_handler: EH) -> OnClick<Self, EH> {
         OnClick {
             view: self,
@@ -174,10 +472,78 @@ pub trait ViewExt<T, A>: View<T, A> + Sized {
         }
     }
 
+    /// Calls `event_handler`, when this view is tapped, i.e. the left mouse button is pressed and released over it,
+    /// without moving the mouse in between (see [`OnGesture::drag_threshold`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(0, move |taps: &mut u32| {
+    /// format!("Tapped {taps} times").on_tap(|taps: &mut u32| *taps += 1)
+    /// # });
+    /// ```
+    fn on_tap<EH: EventHandler<T, A>>(self, event_handler: EH) -> OnGesture<Self, EH, ()> {
+        OnGesture::new(self, event_handler, crate::widget::GestureKind::Tap)
+    }
+
+    /// Calls `event_handler`, when the left mouse button is held over this view for `duration` without moving the mouse.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # use std::time::Duration;
+    /// # App::new(false, move |pinned: &mut bool| {
+    /// "Hold to pin".on_long_press(Duration::from_millis(600), |pinned: &mut bool| *pinned = true)
+    /// # });
+    /// ```
+    fn on_long_press<EH: EventHandler<T, A>>(
+        self,
+        duration: std::time::Duration,
+        event_handler: EH,
+    ) -> OnGesture<Self, EH, ()> {
+        OnGesture::new(
+            self,
+            event_handler,
+            crate::widget::GestureKind::LongPress(duration),
+        )
+    }
+
+    /// Calls `event_handler` with a [`DragEvent`](crate::DragEvent), when this view is dragged with the left mouse button.
+    ///
+    /// A drag starts, when the mouse moved at least [`OnGesture::drag_threshold`] cells while pressed,
+    /// and continues outside of this view, until the button is released.
+    /// It's cancelled (see [`DragPhase::Cancel`](crate::DragPhase::Cancel)), when the terminal loses focus.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// struct Splitter {
+    ///     width: i16,
+    ///     width_at_start: i16,
+    /// }
+    ///
+    /// # App::new(Splitter { width: 20, width_at_start: 20 }, move |splitter: &mut Splitter| {
+    /// "│".on_drag(|splitter: &mut Splitter, drag: DragEvent| match drag.phase {
+    ///     DragPhase::Start => splitter.width_at_start = splitter.width,
+    ///     DragPhase::Move | DragPhase::End => splitter.width = splitter.width_at_start + drag.offset_x,
+    ///     DragPhase::Cancel => splitter.width = splitter.width_at_start,
+    /// })
+    /// .drag_threshold(2)
+    /// # });
+    /// ```
+    fn on_drag<EH: EventHandler<T, A, crate::DragEvent>>(
+        self,
+        event_handler: EH,
+    ) -> OnGesture<Self, EH, crate::DragEvent> {
+        OnGesture::new(self, event_handler, crate::widget::GestureKind::Drag)
+    }
+
     fn weight<W: Animatable<f64>>(self, weight: W) -> WeightedLayoutElement<Self, W, T, A> {
         WeightedLayoutElement {
             content: self,
             weight,
+            min_size: 0,
+            max_size: None,
             phantom: PhantomData,
         }
     }
@@ -193,26 +559,205 @@ pub trait ViewExt<T, A>: View<T, A> + Sized {
         }
     }
 
+    /// Calls `event_handler` with a [`ScrollEvent`](crate::ScrollEvent) (including the modifiers and local coordinates),
+    /// when the scroll wheel is used over this view, e.g. for zooming a chart.
+    ///
+    /// The scroll events are handled by this view, so a surrounding [`scroll_view`] doesn't scroll.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(100, move |zoom: &mut i32| {
+    /// format!("Zoom: {zoom}%").on_scroll(|zoom: &mut i32, event: ScrollEvent| {
+    ///     *zoom = (*zoom - 10 * event.delta_y as i32).max(10)
+    /// })
+    /// # });
+    /// ```
+    fn on_scroll<EH: EventHandler<T, A, crate::ScrollEvent>>(
+        self,
+        event_handler: EH,
+    ) -> OnScroll<Self, EH> {
+        OnScroll {
+            view: self,
+            event_handler,
+        }
+    }
+
+    /// Calls `event_handler` with the new size of the terminal as `(width, height)` in cells, when it is resized,
+    /// e.g. to recompute the page size of a paginated list.
+    ///
+    /// The current size is available via [`Cx::terminal_size`] when building views.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new((0, 0), move |size: &mut (u16, u16)| {
+    /// format!("Terminal size: {}x{}", size.0, size.1)
+    ///     .on_resize(|size: &mut (u16, u16), new_size: (u16, u16)| *size = new_size)
+    /// # });
+    /// ```
+    fn on_resize<EH: EventHandler<T, A, (u16, u16)>>(
+        self,
+        event_handler: EH,
+    ) -> OnResize<Self, EH> {
+        OnResize {
+            view: self,
+            event_handler,
+        }
+    }
+
+    /// Calls `event_handler`, when the mouse starts hovering over this view.
+    ///
+    /// With [`OnHover::hover_delay`], it's only called after the mouse stayed over the view for the enter delay,
+    /// so that the mouse just passing over the view doesn't trigger it (e.g. for tooltips).
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # use std::time::Duration;
+    /// # App::new(0, move |hovered: &mut u32| {
+    /// format!("Hovered {hovered} times")
+    ///     .on_hover(|hovered: &mut u32| *hovered += 1)
+    ///     .hover_delay(Duration::from_millis(300))
+    /// # });
+    /// ```
     fn on_hover<EH: EventHandler<T, A>>(self, event_handler: EH) -> OnHover<Self, EH> {
         OnHover {
             view: self,
             event_handler,
+            hover_delay: HoverDelay::default(),
         }
     }
 
+    /// Calls `event_handler`, when the mouse stops hovering over this view, see [`ViewExt::on_hover`].
     fn on_blur_hover<EH: EventHandler<T, A>>(self, event_handler: EH) -> OnHoverLost<Self, EH> {
         OnHoverLost {
             view: self,
             event_handler,
+            hover_delay: HoverDelay::default(),
+        }
+    }
+
+    /// Calls `event_handler`, when this view becomes visible, i.e. it's painted within the terminal,
+    /// or the viewport of a [`scroll_view`], after it wasn't visible before (or wasn't painted yet).
+    ///
+    /// This can be used to lazily fetch data for items as they are scrolled into view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(vec![false; 100], move |loaded: &mut Vec<bool>| {
+    /// scroll_view(v_stack(
+    ///     (0..loaded.len())
+    ///         .map(|i| {
+    ///             let label = if loaded[i] { format!("Item {i}") } else { "Loading…".into() };
+    ///             label.on_appear(move |loaded: &mut Vec<bool>| loaded[i] = true)
+    ///         })
+    ///         .collect::<Vec<_>>(),
+    /// ))
+    /// # });
+    /// ```
+    fn on_appear<EH: EventHandler<T, A>>(self, event_handler: EH) -> OnAppear<Self, EH> {
+        OnAppear {
+            view: self,
+            event_handler,
+        }
+    }
+
+    /// Calls `event_handler`, when this view isn't visible anymore (e.g. it was scrolled out of view), see [`ViewExt::on_appear`].
+    fn on_disappear<EH: EventHandler<T, A>>(self, event_handler: EH) -> OnDisappear<Self, EH> {
+        OnDisappear {
+            view: self,
+            event_handler,
+        }
+    }
+
+    /// Calls `event_handler` once, after the widget of this view was added to the widget tree,
+    /// e.g. to acquire resources tied to this subtree, see also [`ViewExt::on_unmount`].
+    fn on_mount<EH: EventHandler<T, A>>(self, event_handler: EH) -> OnMount<Self, EH> {
+        OnMount {
+            view: self,
+            event_handler,
+        }
+    }
+
+    /// Calls `handler` with the app state, after this view was removed from the view tree (e.g. to release resources acquired in [`ViewExt::on_mount`]).
+    ///
+    /// The handler is called outside of the view tree, so it can't return an action.
+    /// It's not called, when the app exits.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # #[derive(Default)]
+    /// # struct AppState { show_details: bool, subscriptions: u32 }
+    /// # App::new(AppState::default(), move |state: &mut AppState| {
+    /// v_stack((
+    ///     format!("Subscriptions: {}", state.subscriptions),
+    ///     state.show_details.then(|| {
+    ///         "Details"
+    ///             .on_mount(|state: &mut AppState| state.subscriptions += 1)
+    ///             .on_unmount(|state: &mut AppState| state.subscriptions -= 1)
+    ///     }),
+    /// ))
+    /// # });
+    /// ```
+    fn on_unmount<F>(self, handler: F) -> OnUnmount<Self, F>
+    where
+        T: 'static,
+        F: Fn(&mut T) + Clone + Send + Sync + 'static,
+    {
+        OnUnmount {
+            view: self,
+            handler,
         }
     }
 
+    /// Patches `style` over the style of this view, while it's hovered (with an optional [`StyleOnHover::hover_delay`]).
     fn on_hover_style<VS>(self, style: Style) -> StyleOnHover<Self>
     where
         VS: View<T, A>,
         Self: Styleable<Output = VS>,
     {
-        StyleOnHover { view: self, style }
+        StyleOnHover {
+            view: self,
+            style,
+            hover_delay: HoverDelay::default(),
+            transition: StyleTransition::default(),
+        }
+    }
+
+    /// Fades `style` in and out over `duration` along the `ease` curve, while this view is hovered,
+    /// instead of switching it instantly like [`ViewExt::on_hover_style`].
+    ///
+    /// The colors are interpolated in RGB, modifiers (like bold) are switched halfway through.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # use std::time::Duration;
+    /// # App::new(0, move |clicks: &mut u32| {
+    /// "Click me"
+    ///     .border(BorderKind::Rounded)
+    ///     .on_hover_style_animated(
+    ///         Style::default().fg(Color::Rgb(255, 136, 0)),
+    ///         Duration::from_millis(150),
+    ///         Ease::QuadraticOut,
+    ///     )
+    ///     .on_click(|clicks: &mut u32| *clicks += 1)
+    /// # });
+    /// ```
+    fn on_hover_style_animated<VS>(
+        self,
+        style: Style,
+        duration: Duration,
+        ease: Ease,
+    ) -> StyleOnHover<Self>
+    where
+        VS: View<T, A>,
+        Self: Styleable<Output = VS>,
+    {
+        self.on_hover_style(style).transition((duration, ease))
     }
 
     fn on_hover_fg<VS>(self, color: Color) -> StyleOnHover<Self>
@@ -236,7 +781,26 @@ pub trait ViewExt<T, A>: View<T, A> + Sized {
         VS: View<T, A>,
         Self: Styleable<Output = VS>,
     {
-        StyleOnPressed { view: self, style }
+        StyleOnPressed {
+            view: self,
+            style,
+            transition: StyleTransition::default(),
+        }
+    }
+
+    /// Fades `style` in and out over `duration` along the `ease` curve, while this view is pressed,
+    /// see [`ViewExt::on_hover_style_animated`].
+    fn on_pressed_style_animated<VS>(
+        self,
+        style: Style,
+        duration: Duration,
+        ease: Ease,
+    ) -> StyleOnPressed<Self>
+    where
+        VS: View<T, A>,
+        Self: Styleable<Output = VS>,
+    {
+        self.on_pressed_style(style).transition((duration, ease))
     }
 
     fn on_pressed_fg<VS>(self, color: Color) -> StyleOnPressed<Self>
@@ -254,6 +818,77 @@ pub trait ViewExt<T, A>: View<T, A> + Sized {
     {
         self.on_pressed_style(Style::default().bg(color))
     }
+
+    /// Attach a long-lived background service (e.g. a file watcher or socket listener) to this view.
+    ///
+    /// `stream_fn` is called once when the view is built, the stream is kept alive across rebuilds,
+    /// and every item is passed to `update_fn`. It's stopped when the view is removed or the app exits.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # use std::time::Duration;
+    /// # App::new(0, move |ticks: &mut u64| {
+    /// format!("Ticks: {ticks}").worker(
+    ///     || {
+    ///         let interval = tokio::time::interval(Duration::from_secs(1));
+    ///         futures_util::stream::unfold(interval, |mut interval| async move {
+    ///             interval.tick().await;
+    ///             Some(((), interval))
+    ///         })
+    ///     },
+    ///     |ticks: &mut u64, ()| *ticks += 1,
+    /// )
+    /// # });
+    /// ```
+    fn worker<SE, S, SF, UF>(self, stream_fn: SF, update_fn: UF) -> Worker<Self, SE, SF, UF, T, A>
+    where
+        SE: Send + 'static,
+        S: futures_util::Stream<Item = SE> + Send + 'static,
+        SF: Fn() -> S + Send + Sync,
+        UF: Fn(&mut T, SE) + Send + Sync,
+    {
+        Worker {
+            view: self,
+            stream_fn,
+            update_fn,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Watch a file or directory (non-recursively) for changes, while this view is alive.
+    ///
+    /// Events are debounced by [`WATCH_PATH_DEBOUNCE`], for other options use [`watch_path_stream`] with [`ViewExt::worker`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(Vec::new(), move |changed: &mut Vec<String>| {
+    /// v_stack(changed.iter().map(|path| path.clone()).collect::<Vec<_>>()).watch_path(
+    ///     ".",
+    ///     |changed: &mut Vec<String>, event: DebouncedEvent| {
+    ///         changed.push(event.path.display().to_string())
+    ///     },
+    /// )
+    /// # });
+    /// ```
+    #[cfg(feature = "watch")]
+    fn watch_path<UF>(self, path: impl Into<std::path::PathBuf>, update_fn: UF) -> impl View<T, A>
+    where
+        UF: Fn(&mut T, DebouncedEvent) + Send + Sync,
+    {
+        let path = path.into();
+        self.worker(
+            move || {
+                watch_path_stream(
+                    path.clone(),
+                    RecursiveMode::NonRecursive,
+                    WATCH_PATH_DEBOUNCE,
+                )
+            },
+            update_fn,
+        )
+    }
 }
 
 impl<T, A, V: View<T, A>> ViewExt<T, A> for V {}
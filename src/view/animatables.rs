@@ -2,7 +2,10 @@ use std::{ops::Range, time::Duration};
 
 use xilem_core::{Id, MessageResult};
 
+pub use crate::widget::animatables::Repeat;
+
 use crate::{
+    geometry::Length,
     widget::{self, animatables::AnimatableElement, ChangeFlags},
     Cx,
 };
@@ -161,8 +164,8 @@ impl Animatable<u32> for u32 {
 
     type Element = u32;
 
-    fn build(&self, _cx: &mut Cx) -> (Id, Self::State, Self::Element) {
-        (Id::next(), (), *self)
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        (cx.next_id(), (), *self)
     }
 
     fn rebuild(
@@ -196,8 +199,43 @@ impl Animatable<f64> for f64 {
 
     type Element = f64;
 
-    fn build(&self, _cx: &mut Cx) -> (Id, Self::State, Self::Element) {
-        (Id::next(), (), *self)
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        (cx.next_id(), (), *self)
+    }
+
+    fn rebuild(
+        &self,
+        _cx: &mut Cx,
+        prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        value: &mut Self::Element,
+    ) -> ChangeFlags {
+        if self != prev {
+            *value = *self;
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+    ) -> MessageResult<()> {
+        MessageResult::Stale(message)
+    }
+}
+
+impl Animatable<Length> for Length {
+    type State = ();
+
+    type Element = Length;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        (cx.next_id(), (), *self)
     }
 
     fn rebuild(
@@ -226,6 +264,74 @@ impl Animatable<f64> for f64 {
     }
 }
 
+/// A plain `f64` is a [`Length::Percent`]
+impl Animatable<Length> for f64 {
+    type State = ();
+
+    type Element = Length;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        (cx.next_id(), (), Length::Percent(*self))
+    }
+
+    fn rebuild(
+        &self,
+        _cx: &mut Cx,
+        prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        value: &mut Self::Element,
+    ) -> ChangeFlags {
+        if self != prev {
+            *value = Length::Percent(*self);
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+    ) -> MessageResult<()> {
+        MessageResult::Stale(message)
+    }
+}
+
+/// The animated `f64` is a [`Length::Percent`]
+impl<AT: Animatable<f64>> Animatable<Length> for LowPassIIR<AT> {
+    type State = <Self as Animatable<f64>>::State;
+
+    type Element = widget::animatables::AsPercent<<Self as Animatable<f64>>::Element>;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = <Self as Animatable<f64>>::build(self, cx);
+        (id, state, widget::animatables::AsPercent::new(element))
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        <Self as Animatable<f64>>::rebuild(self, cx, prev, id, state, &mut element.inner)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+    ) -> MessageResult<()> {
+        <Self as Animatable<f64>>::message(self, id_path, state, message)
+    }
+}
+
 pub trait Tweenable<V>: Send + Sync {
     /// Associated state for the tweenable.
     type State: Send;
@@ -269,6 +375,17 @@ pub trait Tweenable<V>: Send + Sync {
         }
     }
 
+    /// Holds the start value for `delay`, before the tweenable is played
+    fn delay(self, delay: Duration) -> Delay<Self>
+    where
+        Self: Sized,
+    {
+        Delay {
+            tweenable: self,
+            delay,
+        }
+    }
+
     /// Maps a function on the value of the `Tweenable`
     fn map<VO>(self, f: fn(&V) -> VO) -> Map<Self, V, VO>
     where
@@ -285,6 +402,8 @@ pub trait Tweenable<V>: Send + Sync {
         PlayTween {
             play_speed,
             tweenable: self,
+            repeat: Repeat::default(),
+            ping_pong: false,
         }
     }
 
@@ -399,6 +518,44 @@ impl<A: Animatable<f64>> Tweenable<f64> for Range<A> {
 }
 
 // Sequence of multiple tweenables, not sure yet whether this should be done via tuples (as that syntax is already used by ViewSequences)
+/// Interpolates between two lengths with the same unit, see [`Length::lerp`]
+impl Tweenable<Length> for Range<Length> {
+    type State = ();
+
+    type Element = widget::animatables::TweenableRange<Length, Length>;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let element = widget::animatables::TweenableRange::from_lengths(self.start, self.end);
+        (cx.next_id(), (), element)
+    }
+
+    fn rebuild(
+        &self,
+        _cx: &mut Cx,
+        prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        if self != prev {
+            element.start = self.start;
+            element.end = self.end;
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+    ) -> MessageResult<()> {
+        MessageResult::Stale(message)
+    }
+}
+
 macro_rules! impl_tweenable_for_tuple {
     ($( $t:ident),* ; $( $i:tt ),*) => {
         impl<V: 'static, $($t: Tweenable<V>),*> Tweenable<V> for ($($t,)*) {
@@ -462,6 +619,99 @@ impl_tweenable_for_tuple!(T0, T1, T2, T3, T4, T5, T6; 0, 1, 2, 3, 4, 5, 6);
 impl_tweenable_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7; 0, 1, 2, 3, 4, 5, 6, 7);
 impl_tweenable_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8; 0, 1, 2, 3, 4, 5, 6, 7, 8);
 impl_tweenable_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+impl_tweenable_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+impl_tweenable_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
+/// Plays the tweenables of a tuple at the same time (in contrast to the tuple itself, which plays them one after another),
+/// each with its own duration, the value is the tuple of their values.
+///
+/// The parallel group finishes, when the longest tweenable has finished.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # use std::time::Duration;
+/// # App::new(1.0, move |play_speed: &mut f64| {
+/// // a fast grow combined with a slower fade in, the animation finishes after 2 seconds
+/// let width = parallel((
+///     0.2..1.0,
+///     (0.5..1.0).duration(Duration::from_secs(2)),
+/// ))
+/// .map(|(grow, fade_in)| Length::Percent(grow * fade_in))
+/// .play(*play_speed);
+/// "Growing".fill_max_width(width)
+/// # });
+/// ```
+pub fn parallel<P>(tweenables: P) -> Parallel<P> {
+    Parallel { tweenables }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Parallel<P> {
+    tweenables: P,
+}
+
+macro_rules! impl_tweenable_for_parallel {
+    ($( $v:ident, $t:ident, $i:tt );*) => {
+        impl<$($v: Clone + Send + Sync + 'static, $t: Tweenable<$v>),*> Tweenable<($($v,)*)> for Parallel<($($t,)*)> {
+            type State = ($((Id, $t::State),)*);
+
+            type Element = widget::animatables::Parallel<($($t::Element,)*), ($($v,)*)>;
+
+            fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+                let (id, (state, element)) = cx.with_new_id(|cx| {
+                    let b = ($(self.tweenables.$i.build(cx),)*);
+                    let element = widget::animatables::Parallel::new(($(b.$i.2,)*));
+                    (($((b.$i.0, b.$i.1),)*), element)
+                });
+                (id, state, element)
+            }
+
+            fn rebuild(
+                &self,
+                cx: &mut Cx,
+                prev: &Self,
+                id: &mut Id,
+                state: &mut Self::State,
+                element: &mut Self::Element,
+            ) -> ChangeFlags {
+                cx.with_id(*id, |cx| {
+                    $(self.tweenables.$i.rebuild(
+                        cx,
+                        &prev.tweenables.$i,
+                        &mut state.$i.0,
+                        &mut state.$i.1,
+                        &mut element.tweenables.$i,
+                    )) | *
+                })
+            }
+
+            fn message(
+                &self,
+                id_path: &[Id],
+                state: &mut Self::State,
+                message: Box<dyn std::any::Any>,
+            ) -> MessageResult<()> {
+                match id_path {
+                    $([id, rest_path @ ..] if *id == state.$i.0 => self.tweenables.$i.message(rest_path, &mut state.$i.1, message),)*
+                    [..] => MessageResult::Stale(message),
+                }
+            }
+        }
+    };
+}
+
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4; V5, T5, 5);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4; V5, T5, 5; V6, T6, 6);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4; V5, T5, 5; V6, T6, 6; V7, T7, 7);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4; V5, T5, 5; V6, T6, 6; V7, T7, 7; V8, T8, 8);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4; V5, T5, 5; V6, T6, 6; V7, T7, 7; V8, T8, 8; V9, T9, 9);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4; V5, T5, 5; V6, T6, 6; V7, T7, 7; V8, T8, 8; V9, T9, 9; V10, T10, 10);
+impl_tweenable_for_parallel!(V0, T0, 0; V1, T1, 1; V2, T2, 2; V3, T3, 3; V4, T4, 4; V5, T5, 5; V6, T6, 6; V7, T7, 7; V8, T8, 8; V9, T9, 9; V10, T10, 10; V11, T11, 11);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Map<T, V, VO> {
@@ -599,11 +849,134 @@ impl<V, T: Tweenable<V>> Tweenable<V> for WithDuration<T> {
     }
 }
 
+/// Holds the start value of the tweenable it composes for a delay, see [`Tweenable::delay`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Delay<T> {
+    pub(crate) tweenable: T,
+    pub(crate) delay: Duration,
+}
+
+impl<V, T: Tweenable<V>> Tweenable<V> for Delay<T> {
+    type State = T::State;
+
+    type Element = widget::animatables::Delay<T::Element>;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = self.tweenable.build(cx);
+        (
+            id,
+            state,
+            widget::animatables::Delay::new(element, self.delay),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let mut changeflags = ChangeFlags::empty();
+        if self.delay != prev.delay {
+            element.delay = self.delay;
+            changeflags |= ChangeFlags::ANIMATION;
+        }
+        changeflags
+            | self
+                .tweenable
+                .rebuild(cx, &prev.tweenable, id, state, &mut element.tweenable)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+    ) -> MessageResult<()> {
+        self.tweenable.message(id_path, state, message)
+    }
+}
+
+/// Creates the tweenables for the items of a list, which are delayed by `delay_per_item` times their index,
+/// so that the items animate one after another (a cascade), when they're played as they appear.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # use std::time::Duration;
+/// # App::new(vec!["first", "second", "third"], move |items: &mut Vec<&'static str>| {
+/// let cascade = stagger(Duration::from_millis(100), Length::Percent(0.0)..Length::Percent(1.0));
+/// v_stack(
+///     items
+///         .iter()
+///         .enumerate()
+///         .map(|(index, item)| (*item).fill_max_width(cascade.item(index).play(1.0)))
+///         .collect::<Vec<_>>(),
+/// )
+/// # });
+/// ```
+pub fn stagger<T>(delay_per_item: Duration, tweenable: T) -> Stagger<T> {
+    Stagger {
+        delay_per_item,
+        tweenable,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Stagger<T> {
+    delay_per_item: Duration,
+    tweenable: T,
+}
+
+impl<T: Clone> Stagger<T> {
+    /// The tweenable for the item at `index`, delayed by `index` times the delay per item.
+    pub fn item(&self, index: usize) -> Delay<T> {
+        Delay {
+            tweenable: self.tweenable.clone(),
+            delay: self.delay_per_item * index as u32,
+        }
+    }
+}
+
 // TODO Duration could also be animated, but I'm not sure it's worth the complexity (vs benefit)...
 #[derive(Clone, Debug)]
 pub struct PlayTween<PS, TW> {
     play_speed: PS,
     tweenable: TW,
+    repeat: Repeat,
+    ping_pong: bool,
+}
+
+impl<PS, TW> PlayTween<PS, TW> {
+    /// Plays the tween multiple times, e.g. `.repeat(3)` or `.repeat(Repeat::Forever)` for looping animations.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new((), move |_: &mut ()| {
+    /// // a pulsing indicator
+    /// "Loading"
+    ///     .fill_max_width(
+    ///         (Length::Percent(0.5)..Length::Percent(1.0))
+    ///             .quadratic_in_out_ease()
+    ///             .play(1.0)
+    ///             .repeat(Repeat::Forever)
+    ///             .ping_pong(),
+    ///     )
+    /// # });
+    /// ```
+    pub fn repeat(mut self, repeat: impl Into<Repeat>) -> Self {
+        self.repeat = repeat.into();
+        self
+    }
+
+    /// Every other play of a [repeated](PlayTween::repeat) tween is played backwards, instead of starting from the beginning again.
+    pub fn ping_pong(mut self) -> Self {
+        self.ping_pong = true;
+        self
+    }
 }
 
 impl<V, PS, TW> Animatable<V> for PlayTween<PS, TW>
@@ -621,8 +994,12 @@ where
             let (play_speed_id, play_speed_state, play_speed_element) = self.play_speed.build(cx);
             let (tweenable_id, tweenable_state, tweenable_element) = self.tweenable.build(cx);
 
-            let element =
-                widget::animatables::PlayTween::new(play_speed_element, tweenable_element);
+            let element = widget::animatables::PlayTween::new(
+                play_speed_element,
+                tweenable_element,
+                self.repeat,
+                self.ping_pong,
+            );
             (
                 (
                     play_speed_id,
@@ -644,21 +1021,23 @@ where
         (play_speed_id, play_speed_state, tweenable_id, tweenable_state): &mut Self::State,
         element: &mut Self::Element,
     ) -> ChangeFlags {
-        cx.with_id(*id, |cx| {
-            self.play_speed.rebuild(
-                cx,
-                &prev.play_speed,
-                play_speed_id,
-                play_speed_state,
-                &mut element.play_speed,
-            ) | self.tweenable.rebuild(
-                cx,
-                &prev.tweenable,
-                tweenable_id,
-                tweenable_state,
-                &mut element.tweenable,
-            )
-        })
+        let changeflags = element.set_repeat(self.repeat, self.ping_pong);
+        changeflags
+            | cx.with_id(*id, |cx| {
+                self.play_speed.rebuild(
+                    cx,
+                    &prev.play_speed,
+                    play_speed_id,
+                    play_speed_state,
+                    &mut element.play_speed,
+                ) | self.tweenable.rebuild(
+                    cx,
+                    &prev.tweenable,
+                    tweenable_id,
+                    tweenable_state,
+                    &mut element.tweenable,
+                )
+            })
     }
 
     fn message(
@@ -0,0 +1,57 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::{widget::ChangeFlags, Cx, View, ViewMarker};
+
+/// See [`ViewExt::animation_speed`](crate::ViewExt::animation_speed).
+pub struct AnimationSpeed<V, T, A> {
+    pub(crate) content: V,
+    pub(crate) speed: f64,
+    pub(crate) phantom: PhantomData<fn() -> (T, A)>,
+}
+
+impl<V, T, A> ViewMarker for AnimationSpeed<V, T, A> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for AnimationSpeed<V, T, A> {
+    type State = V::State;
+
+    type Element = crate::widget::AnimationSpeed;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = self.content.build(cx);
+        let element = crate::widget::AnimationSpeed::new(element, self.speed);
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let changeflags = element.set_speed(self.speed);
+
+        let content_el = element
+            .content
+            .downcast_mut()
+            .expect("The animation speed widget changed its type, this should never happen!");
+
+        let content_changeflags = self
+            .content
+            .rebuild(cx, &prev.content, id, state, content_el);
+        changeflags | element.content.mark(content_changeflags)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.content.message(id_path, state, message, app_state)
+    }
+}
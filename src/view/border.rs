@@ -5,9 +5,9 @@ use crate::{
     BorderStyle,
 };
 
-use super::{BorderKind, Borders, Cx, Styleable, View, ViewMarker};
+use super::{Animatable, BorderKind, Borders, Cx, Styleable, View, ViewMarker};
 use ratatui::style::{Color, Style};
-use xilem_core::MessageResult;
+use xilem_core::{Id, MessageResult};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct Border<V, T, A> {
@@ -67,6 +67,109 @@ impl<T, A, V: View<T, A>> View<T, A> for Border<V, T, A> {
     }
 }
 
+/// A border, which is traced in by an animated progress, see [`ViewExt::border_animated`](crate::ViewExt::border_animated).
+pub struct AnimatedBorder<V, P, T, A> {
+    pub(crate) border: Border<V, T, A>,
+    pub(crate) progress: P,
+}
+
+impl<T, A, V, P> ViewMarker for AnimatedBorder<V, P, T, A> {}
+
+impl<T, A, V: View<T, A>, P: Animatable<f64>> View<T, A> for AnimatedBorder<V, P, T, A> {
+    type State = (Id, V::State, Id, P::State);
+
+    type Element = widget::Border;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let border = &self.border;
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (content_id, content_state, element) = border.content.build(cx);
+            let (progress_id, progress_state, progress_element) = self.progress.build(cx);
+            let element = widget::Border::animated(
+                element,
+                border.borders,
+                border.style,
+                border.kind,
+                progress_element,
+            );
+            (
+                (content_id, content_state, progress_id, progress_state),
+                element,
+            )
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (content_id, content_state, progress_id, progress_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let border = &self.border;
+        let changeflags = element.set_borders(border.borders)
+            | element.set_style(border.style)
+            | element.set_kind(border.kind);
+        changeflags
+            | cx.with_id(*id, |cx| {
+                let progress_element = element
+                    .progress_animatable
+                    .as_mut()
+                    .expect("The animated border lost its progress, this should never happen!")
+                    .as_any_mut()
+                    .downcast_mut()
+                    .unwrap();
+                let progress_changeflags = self.progress.rebuild(
+                    cx,
+                    &prev.progress,
+                    progress_id,
+                    progress_state,
+                    progress_element,
+                );
+
+                let content_el = element.content.downcast_mut().expect(
+                    "The border content widget changed its type, this should never happen!",
+                );
+                let content_changeflags = border.content.rebuild(
+                    cx,
+                    &prev.border.content,
+                    content_id,
+                    content_state,
+                    content_el,
+                );
+                progress_changeflags | element.content.mark(content_changeflags)
+            })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (content_id, content_state, progress_id, progress_state): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [id, rest_path @ ..] if *id == *content_id => {
+                self.border
+                    .content
+                    .message(rest_path, content_state, message, app_state)
+            }
+            [id, rest_path @ ..] if *id == *progress_id => {
+                match self.progress.message(rest_path, progress_state, message) {
+                    MessageResult::Action(_) | MessageResult::RequestRebuild => {
+                        MessageResult::RequestRebuild
+                    }
+                    MessageResult::Nop => MessageResult::Nop,
+                    MessageResult::Stale(message) => MessageResult::Stale(message),
+                }
+            }
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
+
 impl<V, T, A> Styleable for Border<V, T, A> {
     type Output = Self;
 
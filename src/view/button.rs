@@ -0,0 +1,249 @@
+use ratatui::style::{Color, Modifier, Style};
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, fold_mnemonic, ChangeFlags};
+
+use super::{ButtonTheme, Cx, EventHandler, Styleable, View, ViewMarker};
+
+/// A button, see [`button`].
+pub struct Button<V, EH> {
+    pub(crate) label: V,
+    pub(crate) on_press: EH,
+    pub(crate) disabled: bool,
+    pub(crate) style: Style,
+    pub(crate) hover_style: Style,
+    pub(crate) pressed_style: Style,
+    pub(crate) focused_style: Style,
+    pub(crate) disabled_style: Style,
+    /// The preferred mnemonic letters, see [`Button::mnemonic`].
+    pub(crate) mnemonics: Vec<char>,
+}
+
+/// A button with a border around `label`, calling `on_press` when it's clicked,
+/// or when `Enter` or `Space` is pressed while it's focused.
+///
+/// A button can be focused by clicking on it or via `Tab`/`Shift+Tab`.
+/// Its default styles come from the [`ButtonTheme`] of the app, the styles set on the button are patched over these.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(0, move |count: &mut i32| {
+/// button(format!("Count: {count}"), |count: &mut i32| *count += 1)
+///     .hover_style(Style::default().fg(Color::Yellow))
+///     .disabled(*count >= 10)
+/// # });
+/// ```
+pub fn button<V, EH>(label: V, on_press: EH) -> Button<V, EH> {
+    Button {
+        label,
+        on_press,
+        disabled: false,
+        style: Style::default(),
+        hover_style: Style::default(),
+        pressed_style: Style::default(),
+        focused_style: Style::default(),
+        disabled_style: Style::default(),
+        mnemonics: Vec::new(),
+    }
+}
+
+/// A [`button`] with the label `markup`, in which the letter after `&` is its mnemonic (see [`Button::mnemonic`]),
+/// e.g. `"&File"` is pressed with `Alt+f`, `&&` is a literal `&`.
+///
+/// When the marked letter is already used by another widget, the next free letter of the label is used instead.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(Vec::new(), move |log: &mut Vec<&'static str>| {
+/// h_stack((
+///     mnemonic_button("&Save", |log: &mut Vec<&'static str>| log.push("save")),
+///     // `s` is taken, so this is pressed with `Alt+e`
+///     mnemonic_button("&Select all", |log: &mut Vec<&'static str>| log.push("select all")),
+/// ))
+/// # });
+/// ```
+pub fn mnemonic_button<EH>(markup: &str, on_press: EH) -> Button<String, EH> {
+    let (label, marked) = parse_mnemonic(markup);
+    let mut mnemonics: Vec<char> = marked.into_iter().collect();
+    for c in label.chars().filter(|c| c.is_alphanumeric()) {
+        let c = fold_mnemonic(c);
+        if !mnemonics.contains(&c) {
+            mnemonics.push(c);
+        }
+    }
+    Button {
+        mnemonics,
+        ..button(label, on_press)
+    }
+}
+
+/// Removes the `&` markers from `markup`, returns the label and the (first) marked letter.
+fn parse_mnemonic(markup: &str) -> (String, Option<char>) {
+    let mut label = String::with_capacity(markup.len());
+    let mut marked = None;
+    let mut chars = markup.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('&', Some('&')) => {
+                label.push('&');
+                chars.next();
+            }
+            ('&', Some(next)) => {
+                marked = marked.or(Some(fold_mnemonic(next)));
+            }
+            _ => label.push(c),
+        }
+    }
+    (label, marked)
+}
+
+impl<V, EH> Button<V, EH> {
+    /// A disabled button can't be focused or pressed.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn hover_style(mut self, style: Style) -> Self {
+        self.hover_style = style;
+        self
+    }
+
+    pub fn pressed_style(mut self, style: Style) -> Self {
+        self.pressed_style = style;
+        self
+    }
+
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Presses the button with `Alt` and `letter` (case-insensitive), the first occurrence of the letter in the label is underlined.
+    ///
+    /// The mnemonic only works within the focus scope (e.g. a dialog), which currently has the focus,
+    /// and when the letter isn't already used by a widget earlier in the focus chain, see also [`mnemonic_button`].
+    pub fn mnemonic(mut self, letter: char) -> Self {
+        self.mnemonics = vec![fold_mnemonic(letter)];
+        self
+    }
+
+    fn styles(&self, cx: &Cx) -> ButtonTheme {
+        let theme = cx.theme().button;
+        ButtonTheme {
+            style: theme.style.patch(self.style),
+            hover: theme.hover.patch(self.hover_style),
+            pressed: theme.pressed.patch(self.pressed_style),
+            focused: theme.focused.patch(self.focused_style),
+            disabled: theme.disabled.patch(self.disabled_style),
+            mnemonic: theme.mnemonic,
+            border: theme.border,
+        }
+    }
+}
+
+impl<V, EH> ViewMarker for Button<V, EH> {}
+
+impl<T, A, V, EH> View<T, A> for Button<V, EH>
+where
+    V: View<T, A>,
+    EH: EventHandler<T, A>,
+{
+    type State = (V::State, Id, (Id, EH::State));
+
+    type Element = widget::Button;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (child_id, state, element) = self.label.build(cx);
+            let mut element =
+                widget::Button::new(element, cx.id_path(), self.styles(cx), self.disabled);
+            let _ = element.set_mnemonics(&self.mnemonics);
+            ((state, child_id, self.on_press.build(cx)), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (state, child_id, (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let mut changeflags = element.set_styles(self.styles(cx))
+                | element.set_disabled(self.disabled)
+                | element.set_mnemonics(&self.mnemonics);
+            let border = element.border_mut();
+            let label_changeflags = self.label.rebuild(
+                cx,
+                &prev.label,
+                child_id,
+                state,
+                border
+                    .content
+                    .downcast_mut()
+                    .expect("The button label widget changed its type, this should never happen!"),
+            );
+            let label_changeflags = border.content.mark(label_changeflags);
+            changeflags |= element.content.mark(label_changeflags);
+            changeflags | self.on_press.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (state, child_id, (eh_id, eh_state)): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == child_id => {
+                self.label.message(rest_path, state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_press
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_press.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
+
+impl<V, EH> Styleable for Button<V, EH> {
+    type Output = Self;
+
+    fn fg(mut self, color: Color) -> Self::Output {
+        self.style.fg = Some(color);
+        self
+    }
+
+    fn bg(mut self, color: Color) -> Self::Output {
+        self.style.bg = Some(color);
+        self
+    }
+
+    fn style(mut self, style: Style) -> Self::Output {
+        self.style = style;
+        self
+    }
+
+    fn modifier(mut self, modifier: Modifier) -> Self::Output {
+        self.style = self.style.add_modifier(modifier);
+        self
+    }
+
+    fn current_style(&self) -> Style {
+        self.style
+    }
+}
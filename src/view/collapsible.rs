@@ -0,0 +1,273 @@
+use std::{any::Any, sync::Arc};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{v_stack, Cx, EventHandler, LinearLayout, View, ViewMarker};
+
+/// A header with collapsible content, see [`collapsible`].
+pub struct Collapsible<H, C, EH> {
+    header: H,
+    content: C,
+    expanded: bool,
+    on_toggle: EH,
+}
+
+/// Shows `header` with a chevron in front of it, and `content` below it, when `expanded` is true.
+///
+/// `on_toggle` is called with the new value, when the header is clicked, or when `Enter` or `Space` is pressed while it's focused.
+/// The height of the content is animated when it's expanded or collapsed.
+/// See [`accordion`] for a group of collapsibles, of which only one is expanded at a time.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(false, move |expanded: &mut bool| {
+/// collapsible(
+///     "Details",
+///     v_stack(("Size: 42 KiB", "Modified: yesterday")),
+///     *expanded,
+///     |expanded: &mut bool, value: bool| *expanded = value,
+/// )
+/// # });
+/// ```
+pub fn collapsible<H, C, EH>(
+    header: H,
+    content: C,
+    expanded: bool,
+    on_toggle: EH,
+) -> Collapsible<H, C, EH> {
+    Collapsible {
+        header,
+        content,
+        expanded,
+        on_toggle,
+    }
+}
+
+impl<H, C, EH> ViewMarker for Collapsible<H, C, EH> {}
+
+impl<T, A, H, C, EH> View<T, A> for Collapsible<H, C, EH>
+where
+    H: View<T, A>,
+    H::Element: 'static,
+    C: View<T, A>,
+    C::Element: 'static,
+    EH: EventHandler<T, A, bool>,
+{
+    type State = ((Id, H::State), (Id, C::State), (Id, EH::State));
+
+    type Element = widget::Collapsible;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (header_id, header_state, header) = self.header.build(cx);
+            let (content_id, content_state, content) = self.content.build(cx);
+            let element = widget::Collapsible::new(
+                cx.id_path(),
+                header,
+                content,
+                self.expanded,
+                cx.theme().collapsible,
+            );
+            let state = (
+                (header_id, header_state),
+                (content_id, content_state),
+                self.on_toggle.build(cx),
+            );
+            (state, element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        ((header_id, header_state), (content_id, content_state), (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let header_el = element.header.downcast_mut().expect(
+                "The collapsible header widget changed its type, this should never happen!",
+            );
+            let header_changeflags =
+                self.header
+                    .rebuild(cx, &prev.header, header_id, header_state, header_el);
+            let content_el = element.content.downcast_mut().expect(
+                "The collapsible content widget changed its type, this should never happen!",
+            );
+            let content_changeflags =
+                self.content
+                    .rebuild(cx, &prev.content, content_id, content_state, content_el);
+            element.header.mark(header_changeflags)
+                | element.content.mark(content_changeflags)
+                | element.set_expanded(self.expanded)
+                | element.set_styles(cx.theme().collapsible)
+                | self.on_toggle.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        ((header_id, header_state), (content_id, content_state), (eh_id, eh_state)): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == header_id => {
+                self.header
+                    .message(rest_path, header_state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == content_id => {
+                self.content
+                    .message(rest_path, content_state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_toggle
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_toggle.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
+
+/// Maps the toggle of a collapsible within an [`accordion`] to the newly expanded section.
+pub struct AccordionHandler<F> {
+    index: usize,
+    on_change: Arc<F>,
+}
+
+impl<T, A, F> EventHandler<T, A, bool> for AccordionHandler<F>
+where
+    F: Fn(&mut T, Option<usize>) -> A + Send + Sync,
+{
+    type State = ();
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+        (cx.next_id(), ())
+    }
+
+    fn rebuild(&self, _cx: &mut Cx, _id: &Id, _state: &mut Self::State) -> ChangeFlags {
+        ChangeFlags::empty()
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        _state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        debug_assert!(id_path.is_empty());
+        let expanded = *message.downcast::<bool>().unwrap();
+        let section = expanded.then_some(self.index);
+        MessageResult::Action((self.on_change)(app_state, section))
+    }
+}
+
+/// See [`accordion`].
+pub type Accordion<T, A, H, C, F> = LinearLayout<T, A, Vec<Collapsible<H, C, AccordionHandler<F>>>>;
+
+/// A vertical group of [`collapsible`] sections, of which at most one (the `expanded` index) is expanded at a time.
+///
+/// Each section is a tuple of the header and the content.
+/// `on_change` is called with the index of the section, that should be expanded, when a header is toggled,
+/// or with `None`, when the expanded section is collapsed.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(None, move |expanded: &mut Option<usize>| {
+/// accordion(
+///     [("General", "Name, description"), ("Advanced", "Caching, logging")],
+///     *expanded,
+///     |expanded: &mut Option<usize>, section| *expanded = section,
+/// )
+/// # });
+/// ```
+pub fn accordion<T, A, H, C, F>(
+    sections: impl IntoIterator<Item = (H, C)>,
+    expanded: Option<usize>,
+    on_change: F,
+) -> Accordion<T, A, H, C, F>
+where
+    H: View<T, A>,
+    H::Element: 'static,
+    C: View<T, A>,
+    C::Element: 'static,
+    F: Fn(&mut T, Option<usize>) -> A + Send + Sync,
+{
+    let on_change = Arc::new(on_change);
+    let sections = sections
+        .into_iter()
+        .enumerate()
+        .map(|(index, (header, content))| {
+            let on_change = AccordionHandler {
+                index,
+                on_change: on_change.clone(),
+            };
+            collapsible(header, content, expanded == Some(index), on_change)
+        })
+        .collect();
+    v_stack(sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        test_helper::{view_cx, TestCx},
+        widget::Pod,
+    };
+
+    /// Lays out an accordion with three sections, and returns the heights of the sections.
+    fn section_heights(expanded: Option<usize>) -> Vec<f64> {
+        let sections = [("A", "a\na"), ("B", "b\nb"), ("C", "c\nc")];
+        let accordion = accordion(sections, expanded, |_: &mut Option<usize>, _| ());
+        let (_, _, element) = View::<Option<usize>>::build(&accordion, &mut view_cx());
+        let mut root = Pod::new(element);
+        TestCx::new().layout(&mut root, Size::new(10.0, 10.0));
+        let layout = root.downcast_ref::<widget::LinearLayout>().unwrap();
+        layout
+            .children
+            .iter()
+            .map(|child| child.size().height)
+            .collect()
+    }
+
+    #[test]
+    fn accordions_expand_one_section_at_a_time() {
+        assert_eq!(section_heights(Some(1)), [1.0, 3.0, 1.0]);
+        assert_eq!(section_heights(None), [1.0, 1.0, 1.0]);
+
+        let on_change = Arc::new(|expanded: &mut Option<usize>, section: Option<usize>| {
+            *expanded = section;
+        });
+        let toggle = |index, value: bool, expanded: &mut Option<usize>| {
+            let handler = AccordionHandler {
+                index,
+                on_change: on_change.clone(),
+            };
+            let _ = EventHandler::<_, (), bool>::message(
+                &handler,
+                &[],
+                &mut (),
+                Box::new(value),
+                expanded,
+            );
+        };
+        let mut expanded = Some(1);
+        // expanding another section collapses the expanded one
+        toggle(0, true, &mut expanded);
+        assert_eq!(expanded, Some(0));
+        assert_eq!(section_heights(expanded), [3.0, 1.0, 1.0]);
+        // collapsing the expanded section leaves all sections collapsed
+        toggle(0, false, &mut expanded);
+        assert_eq!(expanded, None);
+    }
+}
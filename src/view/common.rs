@@ -81,6 +81,16 @@ bitflags! {
     }
 }
 
+impl Position {
+    /// Swaps left and right, e.g. for right-to-left layouts.
+    pub fn mirrored(self) -> Self {
+        let mut mirrored = self - Position::HORIZONTAL;
+        mirrored.set(Position::LEFT, self.contains(Position::RIGHT));
+        mirrored.set(Position::RIGHT, self.contains(Position::LEFT));
+        mirrored
+    }
+}
+
 bitflags! {
     /// Bitflags that can be composed to set the visible borders essentially on the block widget.
     #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -119,6 +129,24 @@ impl BorderKind {
     }
 }
 
+/// Views, whose style can be changed, the style of wrapper views is usually applied to their content.
+///
+/// For wrapper views, it can be derived, to delegate to the field marked with `#[styleable]`.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// #[derive(Styleable)]
+/// struct Labeled<V> {
+///     #[styleable]
+///     content: V,
+///     label: &'static str,
+/// }
+///
+/// let labeled = Labeled { content: "Hello", label: "greeting" }.fg(Color::Red);
+/// assert_eq!(labeled.current_style().fg, Some(Color::Red));
+/// assert_eq!(labeled.label, "greeting");
+/// ```
 pub trait Styleable {
     type Output;
     fn fg(self, color: Color) -> Self::Output;
@@ -0,0 +1,135 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, View, ViewMarker};
+
+/// Asks for confirmation before quitting the app, see [`confirm_quit`].
+pub struct ConfirmQuit<T, A, D, V> {
+    dirty: bool,
+    dialog: D,
+    content: V,
+    phantom: PhantomData<fn() -> (T, A)>,
+}
+
+/// Intercepts quitting the app (with `Esc`), while `dirty` is true (e.g. when there are unsaved changes),
+/// and shows `dialog` with a "Quit" and a "Cancel" button on top of `content` instead.
+///
+/// The app only quits, when it's confirmed with the quit button, `y`, or by pressing `Esc` again,
+/// `n` or the cancel button close the dialog.
+/// While the dialog is shown, the focus is trapped in it (`Tab` and the arrow keys switch between the buttons),
+/// afterwards it's restored to the widget that was focused before.
+///
+/// A quit handler set with [`App::on_quit_request`](crate::App::on_quit_request) is still asked after the confirmation.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// struct Editor {
+///     text: String,
+///     saved: String,
+/// }
+///
+/// # App::new(Editor { text: "draft".into(), saved: String::new() }, |editor: &mut Editor| {
+/// confirm_quit(
+///     editor.text != editor.saved,
+///     "Discard the unsaved changes?",
+///     v_stack((
+///         editor.text.clone(),
+///         button("Save", |editor: &mut Editor| editor.saved = editor.text.clone()),
+///     )),
+/// )
+/// # });
+/// ```
+pub fn confirm_quit<T, A, D: View<T, A>, V: View<T, A>>(
+    dirty: bool,
+    dialog: D,
+    content: V,
+) -> ConfirmQuit<T, A, D, V> {
+    ConfirmQuit {
+        dirty,
+        dialog,
+        content,
+        phantom: PhantomData,
+    }
+}
+
+impl<T, A, D, V> ViewMarker for ConfirmQuit<T, A, D, V> {}
+
+impl<T, A, D, V> View<T, A> for ConfirmQuit<T, A, D, V>
+where
+    D: View<T, A>,
+    D::Element: 'static,
+    V: View<T, A>,
+    V::Element: 'static,
+{
+    type State = ((Id, V::State), (Id, D::State));
+
+    type Element = widget::ConfirmQuit;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (content_id, content_state, content) = self.content.build(cx);
+            let (dialog_id, dialog_state, dialog) = self.dialog.build(cx);
+            let element =
+                widget::ConfirmQuit::new(content, dialog, self.dirty, cx.theme().confirm_quit);
+            (
+                ((content_id, content_state), (dialog_id, dialog_state)),
+                element,
+            )
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        ((content_id, content_state), (dialog_id, dialog_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let mut changeflags =
+                element.set_dirty(self.dirty) | element.set_styles(cx.theme().confirm_quit);
+
+            let content_el = element.content.downcast_mut().expect(
+                "The confirm quit content widget changed its type, this should never happen!",
+            );
+            let content_changeflags =
+                self.content
+                    .rebuild(cx, &prev.content, content_id, content_state, content_el);
+            changeflags |= element.content.mark(content_changeflags);
+
+            let dialog_el = element.dialog.downcast_mut().expect(
+                "The confirm quit dialog widget changed its type, this should never happen!",
+            );
+            let dialog_changeflags =
+                self.dialog
+                    .rebuild(cx, &prev.dialog, dialog_id, dialog_state, dialog_el);
+            changeflags | element.dialog.mark(dialog_changeflags)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        ((content_id, content_state), (dialog_id, dialog_state)): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == content_id => {
+                self.content
+                    .message(rest_path, content_state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == dialog_id => {
+                self.dialog
+                    .message(rest_path, dialog_state, message, app_state)
+            }
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
@@ -1,14 +1,23 @@
 use std::{
-    collections::HashSet,
-    sync::{mpsc::SyncSender, Arc},
+    collections::{HashMap, HashSet},
+    sync::Arc,
 };
 
 use futures_task::{ArcWake, Waker};
 use tokio::runtime::Runtime;
 
-use crate::widget::{AnyWidget, ChangeFlags, Pod, Widget};
+use crate::{
+    app::{AppMessage, WakeQueue},
+    terminal_colors::{ColorScheme, TerminalColors},
+    widget::{AnyWidget, ChangeFlags, Pod, Widget},
+};
 use xilem_core::{Id, IdPath};
 
+use super::{
+    filterable::TextHighlight, localization::system_locale, Catalog, LayoutDirection, Theme,
+    UnmountQueue,
+};
+
 xilem_core::generate_view_trait!(View, Widget, Cx, ChangeFlags; (ViewMarker + Send + Sync), (Send));
 xilem_core::generate_viewsequence_trait! {ViewSequence, View, ViewMarker, ElementsSplice, Widget, Cx, ChangeFlags, Pod; (Send + Sync), (Send)}
 xilem_core::generate_anyview_trait! {AnyView, View, ViewMarker, Cx, ChangeFlags, AnyWidget; (Send + Sync), (Send)}
@@ -17,23 +26,132 @@ xilem_core::generate_adapt_view! {View, Cx, ChangeFlags; + Send + Sync}
 xilem_core::generate_adapt_state_view! {View, Cx, ChangeFlags; + Send + Sync}
 xilem_core::generate_rc_view!(std::sync::Arc, View, ViewMarker, Cx, ChangeFlags, AnyView, AnyWidget; Send);
 
+// xilem_core implements `ViewSequence` only for tuples of up to 10 elements,
+// the larger tuples avoid nesting them in e.g. long forms.
+macro_rules! impl_view_sequence_for_tuple {
+    ($( $t:ident),* ; $( $i:tt ),*) => {
+        impl<T, A, $( $t: ViewSequence<T, A> ),*> ViewSequence<T, A> for ($( $t, )*) {
+            type State = ($( $t::State, )*);
+
+            fn build(&self, cx: &mut Cx, elements: &mut dyn ElementsSplice) -> Self::State {
+                ($( self.$i.build(cx, elements), )*)
+            }
+
+            fn rebuild(
+                &self,
+                cx: &mut Cx,
+                prev: &Self,
+                state: &mut Self::State,
+                elements: &mut dyn ElementsSplice,
+            ) -> ChangeFlags {
+                ChangeFlags::empty()
+                    $(| self.$i.rebuild(cx, &prev.$i, &mut state.$i, elements))*
+            }
+
+            fn message(
+                &self,
+                id_path: &[Id],
+                state: &mut Self::State,
+                message: Box<dyn std::any::Any>,
+                app_state: &mut T,
+            ) -> xilem_core::MessageResult<A> {
+                xilem_core::MessageResult::Stale(message)
+                    $(.or(|message| self.$i.message(id_path, &mut state.$i, message, app_state)))*
+            }
+
+            fn count(&self, state: &Self::State) -> usize {
+                0 $(+ self.$i.count(&state.$i))*
+            }
+        }
+    };
+}
+
+impl_view_sequence_for_tuple!(V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+impl_view_sequence_for_tuple!(V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
 pub struct Cx {
     id_path: IdPath,
-    req_chan: SyncSender<IdPath>,
+    wake_queue: WakeQueue,
+    pub(crate) app_chan: tokio::sync::mpsc::Sender<AppMessage>,
     pub rt: Arc<Runtime>,
     pub(crate) pending_async: HashSet<Id>,
+    pub(crate) theme: Arc<Theme>,
+    /// Set within a [`filterable`](super::filterable), to highlight matches in text views.
+    pub(crate) highlight: Option<Arc<TextHighlight>>,
+    /// The size of the terminal (in cells), updated before each build/rebuild of the view tree.
+    pub(crate) terminal_size: (u16, u16),
+    pub(crate) catalog: Arc<Catalog>,
+    /// Set within a [`locale`](super::locale), the locale of the system otherwise.
+    pub(crate) locale: Arc<str>,
+    /// Set within a [`layout_direction`](super::layout_direction), left-to-right otherwise.
+    pub(crate) layout_direction: LayoutDirection,
+    /// Queried when the app starts.
+    pub(crate) terminal_colors: TerminalColors,
+    /// The ids allocated by this app, numbered in order, if enabled with [`App::with_stable_ids`](crate::App::with_stable_ids).
+    pub(crate) stable_ids: Option<HashMap<Id, u64>>,
+    /// Filled by the views removed while (re)building, see [`ViewExt::on_unmount`](super::ViewExt::on_unmount).
+    pub(crate) unmounted: UnmountQueue,
 }
 
 impl Cx {
-    pub(crate) fn new(req_chan: &SyncSender<IdPath>, rt: Arc<Runtime>) -> Self {
+    pub(crate) fn new(
+        wake_queue: &WakeQueue,
+        app_chan: &tokio::sync::mpsc::Sender<AppMessage>,
+        rt: Arc<Runtime>,
+    ) -> Self {
         Cx {
             id_path: Vec::new(),
-            req_chan: req_chan.clone(),
+            wake_queue: wake_queue.clone(),
+            app_chan: app_chan.clone(),
             rt,
             pending_async: HashSet::new(),
+            theme: Arc::default(),
+            highlight: None,
+            terminal_size: (0, 0),
+            catalog: Arc::default(),
+            locale: system_locale(),
+            layout_direction: LayoutDirection::default(),
+            terminal_colors: TerminalColors::default(),
+            stable_ids: None,
+            unmounted: UnmountQueue::default(),
         }
     }
 
+    /// The theme of the app, see [`App::with_theme`](crate::App::with_theme).
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// The size of the terminal as (width, height) in cells.
+    pub fn terminal_size(&self) -> (u16, u16) {
+        self.terminal_size
+    }
+
+    /// The default colors of the terminal, if they were queried, see [`AppConfig::query_terminal_colors`](crate::AppConfig::query_terminal_colors).
+    pub fn terminal_colors(&self) -> TerminalColors {
+        self.terminal_colors
+    }
+
+    /// Whether the terminal has a light or a dark background, if it could be detected.
+    pub fn color_scheme(&self) -> Option<ColorScheme> {
+        self.terminal_colors.color_scheme()
+    }
+
+    /// The current locale, see [`locale`](super::locale).
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// The current layout direction, see [`layout_direction`](super::layout_direction).
+    pub fn layout_direction(&self) -> LayoutDirection {
+        self.layout_direction
+    }
+
+    /// The translation of `key` in the current locale from the app's [`Catalog`], or the key itself if there's none.
+    pub fn translate<'a>(&'a self, key: &'a str) -> &'a str {
+        self.catalog.translate(&self.locale, key).unwrap_or(key)
+    }
+
     pub fn push(&mut self, id: Id) {
         self.id_path.push(id);
     }
@@ -64,13 +182,37 @@ impl Cx {
     ///
     /// Also an ergonomic helper.
     pub fn with_new_id<T, F: FnOnce(&mut Cx) -> T>(&mut self, f: F) -> (Id, T) {
-        let id = Id::next();
+        let id = self.next_id();
         self.push(id);
         let result = f(self);
         self.pop();
         (id, result)
     }
 
+    /// Allocates a new id, views should use this instead of [`Id::next`], so that the id is numbered in the [stable ids](Cx::stable_id).
+    pub fn next_id(&mut self) -> Id {
+        let id = Id::next();
+        if let Some(stable_ids) = &mut self.stable_ids {
+            let number = stable_ids.len() as u64 + 1;
+            stable_ids.insert(id, number);
+        }
+        id
+    }
+
+    /// The number of `id` within this app, when enabled with [`App::with_stable_ids`](crate::App::with_stable_ids).
+    ///
+    /// Ids are allocated from a global counter, so they differ between runs, e.g. depending on which tests ran before.
+    /// The ids allocated by an app are numbered from 1 in the order they were allocated instead,
+    /// which is deterministic, so they can be asserted in tests.
+    pub fn stable_id(&self, id: Id) -> Option<u64> {
+        self.stable_ids.as_ref()?.get(&id).copied()
+    }
+
+    /// The [stable ids](Cx::stable_id) of the ids in `id_path`.
+    pub fn stable_id_path(&self, id_path: &[Id]) -> Option<Vec<u64>> {
+        id_path.iter().map(|id| self.stable_id(*id)).collect()
+    }
+
     /// Run some logic within a new Pod context and return the newly created Pod,
     ///
     /// This logic is usually `View::build` to wrap the returned element into a Pod.
@@ -104,7 +246,7 @@ impl Cx {
     pub fn waker(&self) -> Waker {
         futures_task::waker(Arc::new(MyWaker {
             id_path: self.id_path.clone(),
-            req_chan: self.req_chan.clone(),
+            wake_queue: self.wake_queue.clone(),
         }))
     }
 
@@ -120,12 +262,12 @@ impl Cx {
 
 struct MyWaker {
     id_path: IdPath,
-    req_chan: SyncSender<IdPath>,
+    wake_queue: WakeQueue,
 }
 
 impl ArcWake for MyWaker {
     fn wake_by_ref(arc_self: &Arc<Self>) {
-        let _ = arc_self.req_chan.send(arc_self.id_path.clone());
+        arc_self.wake_queue.wake(&arc_self.id_path);
     }
 }
 
@@ -145,3 +287,68 @@ where
         Box::from(self)
     }
 }
+
+impl<T, A> dyn AnyView<T, A> {
+    /// Returns the view as `V`, if it is of that type, e.g. to inspect views assembled at runtime.
+    ///
+    /// Only the outermost view is checked, views wrapped by other views (like a [`border`](super::ViewExt::border)) are not found.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let view: Box<dyn AnyView<()>> = "Hello".to_string().boxed();
+    /// assert_eq!(view.downcast_ref::<String>().map(String::as_str), Some("Hello"));
+    /// assert!(!view.is::<&str>());
+    /// ```
+    pub fn downcast_ref<V: 'static>(&self) -> Option<&V> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Whether the view is of type `V`, see [`downcast_ref`](Self::downcast_ref).
+    pub fn is<V: 'static>(&self) -> bool {
+        self.as_any().is::<V>()
+    }
+}
+
+/// Wraps `view` with `wrap` only if `condition` is true, as boxed view,
+/// so that both branches have the same type without using [`OneOf2`](super::OneOf2).
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(true, move |highlighted: &mut bool| {
+/// dyn_view_if(*highlighted, "Important", |view| view.border(BorderKind::Rounded).fg(Color::Red))
+/// # });
+/// ```
+pub fn dyn_view_if<T, A, V, W>(
+    condition: bool,
+    view: V,
+    wrap: impl FnOnce(V) -> W,
+) -> Box<dyn AnyView<T, A>>
+where
+    V: IntoBoxedView<T, A>,
+    W: IntoBoxedView<T, A>,
+{
+    if condition {
+        wrap(view).boxed()
+    } else {
+        view.boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_ids_are_numbered_in_order() {
+        let (wake_queue, _wake_rx) = WakeQueue::new(Default::default());
+        let (message_tx, _message_rx) = tokio::sync::mpsc::channel(1);
+        let mut cx = Cx::new(&wake_queue, &message_tx, Arc::new(Runtime::new().unwrap()));
+        let unnumbered = cx.next_id();
+        cx.stable_ids = Some(HashMap::new());
+        let (outer, inner) = cx.with_new_id(|cx| cx.with_new_id(|_| ()).0);
+        assert_eq!(cx.stable_id(unnumbered), None);
+        assert_eq!(cx.stable_id_path(&[outer, inner]), Some(vec![1, 2]));
+    }
+}
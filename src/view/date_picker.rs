@@ -0,0 +1,277 @@
+use std::fmt;
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, EventHandler, View, ViewMarker};
+
+/// A date in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    /// From 1 (January) to 12 (December)
+    pub month: u8,
+    /// From 1 to the number of days in the month
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a new date, the month and day are clamped to valid values.
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        let month = month.clamp(1, 12);
+        Date {
+            year,
+            month,
+            day: day.clamp(1, days_in_month(year, month)),
+        }
+    }
+
+    /// The number of days since 1970-01-01.
+    pub fn days_since_epoch(&self) -> i64 {
+        // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// The inverse of [`Date::days_since_epoch`].
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (year_of_era + era * 400 + (month <= 2) as i64) as i32;
+        Date { year, month, day }
+    }
+
+    /// The day of the week, from 0 (Monday) to 6 (Sunday).
+    pub fn weekday(&self) -> u8 {
+        // 1970-01-01 was a Thursday
+        (self.days_since_epoch() + 3).rem_euclid(7) as u8
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Date::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Adds (or subtracts) months, the day is clamped to the number of days in the resulting month.
+    pub fn add_months(&self, months: i32) -> Self {
+        let month = self.year * 12 + self.month as i32 - 1 + months;
+        Date::new(
+            month.div_euclid(12),
+            (month.rem_euclid(12) + 1) as u8,
+            self.day,
+        )
+    }
+
+    /// The first day of the month of this date.
+    pub fn first_of_month(&self) -> Self {
+        Date { day: 1, ..*self }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A time of the day with minute precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    /// From 0 to 23
+    pub hour: u8,
+    /// From 0 to 59
+    pub minute: u8,
+}
+
+impl Time {
+    /// Creates a new time, the hour and minute are clamped to valid values.
+    pub fn new(hour: u8, minute: u8) -> Self {
+        Time {
+            hour: hour.min(23),
+            minute: minute.min(59),
+        }
+    }
+
+    /// Adds (or subtracts) minutes, wrapping around at midnight.
+    pub fn add_minutes(&self, minutes: i32) -> Self {
+        let minutes = (self.hour as i32 * 60 + self.minute as i32 + minutes).rem_euclid(24 * 60);
+        Time {
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// The value of a [`date_picker`], the time can only be selected, when it's `Some`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: Option<Time>,
+}
+
+impl From<Date> for DateTime {
+    fn from(date: Date) -> Self {
+        DateTime { date, time: None }
+    }
+}
+
+impl From<(Date, Time)> for DateTime {
+    fn from((date, time): (Date, Time)) -> Self {
+        DateTime {
+            date,
+            time: Some(time),
+        }
+    }
+}
+
+/// The default number of minutes the time of a [`date_picker`] is changed with.
+const DEFAULT_MINUTE_STEP: u8 = 15;
+
+/// A calendar grid, see [`date_picker`].
+pub struct DatePicker<EH> {
+    pub(crate) selected: DateTime,
+    pub(crate) minute_step: u8,
+    pub(crate) on_change: EH,
+}
+
+/// A calendar grid showing the month of the `selected` date, with buttons to navigate to the previous or next month.
+///
+/// When `selected` contains a time, an additional row allows to change the time.
+/// `on_change` is called with the new [`DateTime`], when a day is clicked, or when the time is changed.
+///
+/// While focused, the following keys can be used:
+///
+/// - Arrow keys: move the cursor across the days (and months)
+/// - `PageUp`/`PageDown`: go to the previous/next month
+/// - `Enter`/`Space`: select the day under the cursor
+/// - `-`/`+`: change the time by [`DatePicker::minute_step`]
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(Date::new(2024, 2, 29), move |date: &mut Date| {
+/// v_stack((
+///     format!("Selected: {date}"),
+///     date_picker(*date, |date: &mut Date, value: DateTime| *date = value.date),
+/// ))
+/// # });
+/// ```
+pub fn date_picker<EH>(selected: impl Into<DateTime>, on_change: EH) -> DatePicker<EH> {
+    DatePicker {
+        selected: selected.into(),
+        minute_step: DEFAULT_MINUTE_STEP,
+        on_change,
+    }
+}
+
+impl<EH> DatePicker<EH> {
+    /// The number of minutes the time is changed with `-`/`+` or the arrow buttons, 15 by default.
+    pub fn minute_step(mut self, minute_step: u8) -> Self {
+        self.minute_step = minute_step.max(1);
+        self
+    }
+}
+
+impl<EH> ViewMarker for DatePicker<EH> {}
+
+impl<T, A, EH: EventHandler<T, A, DateTime>> View<T, A> for DatePicker<EH> {
+    type State = (Id, EH::State);
+
+    type Element = widget::DatePicker;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let element = widget::DatePicker::new(
+                cx.id_path(),
+                self.selected,
+                self.minute_step,
+                cx.theme().date_picker,
+            );
+            (self.on_change.build(cx), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (eh_id, eh_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            element.set_selected(self.selected)
+                | element.set_minute_step(self.minute_step)
+                | element.set_styles(cx.theme().date_picker)
+                | self.on_change.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (eh_id, eh_state): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_change
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_change.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_arithmetic() {
+        let date = Date::new(2024, 2, 29);
+        assert_eq!(Date::new(1970, 1, 1).days_since_epoch(), 0);
+        assert_eq!(Date::from_days_since_epoch(date.days_since_epoch()), date);
+        assert_eq!(date.weekday(), 3); // Thursday
+        assert_eq!(date.add_days(1), Date::new(2024, 3, 1));
+        assert_eq!(date.add_days(-366), Date::new(2023, 2, 28));
+        assert_eq!(date.add_months(12), Date::new(2025, 2, 28));
+        assert_eq!(date.add_months(-2), Date::new(2023, 12, 29));
+        assert_eq!(Time::new(23, 50).add_minutes(15), Time::new(0, 5));
+    }
+}
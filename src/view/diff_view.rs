@@ -0,0 +1,554 @@
+use std::{any::Any, borrow::Cow, sync::Arc};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use xilem_core::{Id, MessageResult};
+
+use super::{
+    scroll_view, v_stack, Cx, DiffTheme, LinearLayout, ScrollAxes, ScrollView, View, ViewMarker,
+    WrappedText,
+};
+use crate::{widget::ChangeFlags, Style};
+
+/// How a [`diff_view`] shows the changes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLayout {
+    /// The removed lines are shown above the added lines, prefixed with `-` and `+`.
+    #[default]
+    Unified,
+    /// The old text is shown on the left, and the new text on the right.
+    SideBySide,
+}
+
+/// An edit of the shortest edit script, with the indices into the old and new sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// The shortest edit script from `old` to `new` with the algorithm of Eugene W. Myers.
+fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let offset = n + m;
+    // the furthest x on each diagonal k = x - y, for each number of edits d
+    let mut v = vec![0; 2 * offset as usize + 2];
+    let mut trace = Vec::new();
+    let follows_down = |v: &[isize], k: isize, d: isize| {
+        k == -d || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize])
+    };
+    'search: for d in 0..=offset {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if follows_down(&v, k, d) {
+                v[(k + 1 + offset) as usize]
+            } else {
+                v[(k - 1 + offset) as usize] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[(k + offset) as usize] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k = if follows_down(v, k, d) { k + 1 } else { k - 1 };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Equal(x as usize, y as usize));
+        }
+        if d > 0 {
+            edits.push(if x == prev_x {
+                Edit::Insert(prev_y as usize)
+            } else {
+                Edit::Delete(prev_x as usize)
+            });
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+    edits.reverse();
+    edits
+}
+
+/// The words of a line, and whether they were changed.
+type Words<'a> = Vec<(&'a str, bool)>;
+
+/// One side of a row, the line number (starting at 1) and the words of the line.
+type Side<'a> = Option<(usize, Words<'a>)>;
+
+enum Row<'a> {
+    Hunk {
+        old_start: usize,
+        old_len: usize,
+        new_start: usize,
+        new_len: usize,
+    },
+    /// Removed lines only have the old, and added lines only the new side,
+    /// unchanged lines (and changed lines side by side) have both sides.
+    Line {
+        old: Side<'a>,
+        new: Side<'a>,
+        changed: bool,
+    },
+}
+
+/// Diffs the words of a removed and an added line, which are shown as a change of the same line.
+fn diff_words<'a>(old: &'a str, new: &'a str) -> (Words<'a>, Words<'a>) {
+    let old: Vec<_> = old.split_word_bounds().collect();
+    let new: Vec<_> = new.split_word_bounds().collect();
+    let mut old_words: Words = old.iter().map(|w| (*w, false)).collect();
+    let mut new_words: Words = new.iter().map(|w| (*w, false)).collect();
+    for edit in diff(&old, &new) {
+        match edit {
+            Edit::Delete(i) => old_words[i].1 = true,
+            Edit::Insert(i) => new_words[i].1 = true,
+            Edit::Equal(..) => (),
+        }
+    }
+    (old_words, new_words)
+}
+
+/// The hunks with the changed lines and `context` unchanged lines around them, in the `layout`.
+fn rows<'a>(old: &'a str, new: &'a str, context: usize, layout: DiffLayout) -> Vec<Row<'a>> {
+    let old_lines: Vec<_> = old.lines().collect();
+    let new_lines: Vec<_> = new.lines().collect();
+    let edits = diff(&old_lines, &new_lines);
+
+    // the distance of each edit to the nearest change
+    let mut distance = vec![usize::MAX; edits.len()];
+    let mut last_change = None;
+    for (i, edit) in edits.iter().enumerate() {
+        if !matches!(edit, Edit::Equal(..)) {
+            last_change = Some(i);
+        }
+        distance[i] = last_change.map_or(usize::MAX, |c| i - c);
+    }
+    last_change = None;
+    for (i, edit) in edits.iter().enumerate().rev() {
+        if !matches!(edit, Edit::Equal(..)) {
+            last_change = Some(i);
+        }
+        distance[i] = distance[i].min(last_change.map_or(usize::MAX, |c| c - i));
+    }
+
+    let mut rows = Vec::new();
+    let (mut old_pos, mut new_pos) = (0, 0);
+    let mut i = 0;
+    while i < edits.len() {
+        if distance[i] > context {
+            match edits[i] {
+                Edit::Equal(..) => (old_pos, new_pos) = (old_pos + 1, new_pos + 1),
+                Edit::Delete(_) => old_pos += 1,
+                Edit::Insert(_) => new_pos += 1,
+            }
+            i += 1;
+            continue;
+        }
+        let end = (i..edits.len())
+            .find(|&j| distance[j] > context)
+            .unwrap_or(edits.len());
+        let hunk = &edits[i..end];
+        let old_len = hunk
+            .iter()
+            .filter(|e| !matches!(e, Edit::Insert(_)))
+            .count();
+        let new_len = hunk
+            .iter()
+            .filter(|e| !matches!(e, Edit::Delete(_)))
+            .count();
+        // like in `diff -u`, an empty range starts at the line before it
+        rows.push(Row::Hunk {
+            old_start: old_pos + (old_len > 0) as usize,
+            old_len,
+            new_start: new_pos + (new_len > 0) as usize,
+            new_len,
+        });
+        let mut j = 0;
+        while j < hunk.len() {
+            if let Edit::Equal(o, n) = hunk[j] {
+                let words = vec![(old_lines[o], false)];
+                rows.push(Row::Line {
+                    old: Some((o + 1, words.clone())),
+                    new: Some((n + 1, words)),
+                    changed: false,
+                });
+                j += 1;
+                continue;
+            }
+            let (mut deleted, mut inserted) = (Vec::new(), Vec::new());
+            while let Some(edit) = hunk.get(j) {
+                match *edit {
+                    Edit::Delete(o) => deleted.push((o + 1, vec![(old_lines[o], true)])),
+                    Edit::Insert(n) => inserted.push((n + 1, vec![(new_lines[n], true)])),
+                    Edit::Equal(..) => break,
+                }
+                j += 1;
+            }
+            // the removed and added lines are paired, to highlight the changed words within them
+            for ((_, old_words), (_, new_words)) in deleted.iter_mut().zip(&mut inserted) {
+                (*old_words, *new_words) = diff_words(old_words[0].0, new_words[0].0);
+            }
+            match layout {
+                DiffLayout::Unified => {
+                    rows.extend(deleted.into_iter().map(|old| Row::Line {
+                        old: Some(old),
+                        new: None,
+                        changed: true,
+                    }));
+                    rows.extend(inserted.into_iter().map(|new| Row::Line {
+                        old: None,
+                        new: Some(new),
+                        changed: true,
+                    }));
+                }
+                DiffLayout::SideBySide => {
+                    let mut deleted = deleted.into_iter();
+                    let mut inserted = inserted.into_iter();
+                    loop {
+                        match (deleted.next(), inserted.next()) {
+                            (None, None) => break,
+                            (old, new) => rows.push(Row::Line {
+                                old,
+                                new,
+                                changed: true,
+                            }),
+                        }
+                    }
+                }
+            }
+        }
+        old_pos += old_len;
+        new_pos += new_len;
+        i = end;
+    }
+    rows
+}
+
+fn expand_tabs(text: &str) -> Cow<'_, str> {
+    if text.contains('\t') {
+        text.replace('\t', "    ").into()
+    } else {
+        text.into()
+    }
+}
+
+/// Appends a side of a row with the gutter (line numbers and sign) to `pieces`, returns the width of the line.
+fn push_side(
+    pieces: &mut Vec<(String, Style)>,
+    gutter: String,
+    sign: char,
+    words: &Words,
+    style: Style,
+    word_style: Style,
+    theme: &DiffTheme,
+) -> usize {
+    pieces.push((gutter, theme.gutter));
+    pieces.push((format!("{sign} "), style));
+    let mut width = 0;
+    for (word, changed) in words {
+        let word = expand_tabs(word).into_owned();
+        width += word.width();
+        let style = if *changed {
+            style.patch(word_style)
+        } else {
+            style
+        };
+        pieces.push((word, style));
+    }
+    width
+}
+
+/// A scrollable diff of two texts, see [`diff_view`].
+pub struct DiffView {
+    old: Arc<str>,
+    new: Arc<str>,
+    layout: DiffLayout,
+    context: usize,
+}
+
+/// Shows the changes from `old` to `new` text line by line, with the changed words highlighted within changed lines.
+///
+/// Only the changed lines with 3 unchanged lines around them are shown (see [`DiffView::context`]),
+/// below hunk headers like `@@ -1,4 +1,5 @@`.
+/// The view scrolls both horizontally and vertically, and the diff is only computed again, when one of the texts changes.
+/// The styles are taken from [`DiffTheme`].
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(false, |side_by_side: &mut bool| {
+/// v_stack((
+///     toggle(*side_by_side, |side_by_side: &mut bool, on| *side_by_side = on),
+///     diff_view("let x = 1;\nprintln!(\"{x}\");\n", "let x = 2;\nprintln!(\"{x}\");\nx\n")
+///         .layout(if *side_by_side { DiffLayout::SideBySide } else { DiffLayout::Unified })
+///         .context(1),
+/// ))
+/// # });
+/// ```
+pub fn diff_view(old: impl Into<Arc<str>>, new: impl Into<Arc<str>>) -> DiffView {
+    DiffView {
+        old: old.into(),
+        new: new.into(),
+        layout: DiffLayout::default(),
+        context: 3,
+    }
+}
+
+impl DiffView {
+    pub fn layout(mut self, layout: DiffLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// The number of unchanged lines shown around the changed lines.
+    pub fn context(mut self, lines: usize) -> Self {
+        self.context = lines;
+        self
+    }
+
+    /// The rows of the diff as pieces of styled text.
+    fn lines(&self, theme: &DiffTheme) -> Vec<Vec<(String, Style)>> {
+        let rows = rows(&self.old, &self.new, self.context, self.layout);
+        let line_count = self.old.lines().count().max(self.new.lines().count());
+        let number_width = line_count.to_string().len();
+        let number = |side: &Side| match side {
+            Some((number, _)) => format!("{number:>number_width$} "),
+            None => " ".repeat(number_width + 1),
+        };
+        let left_width = rows
+            .iter()
+            .filter_map(|row| match row {
+                Row::Line {
+                    old: Some((_, words)),
+                    ..
+                } => Some(words.iter().map(|(w, _)| expand_tabs(w).width()).sum()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+
+        rows.iter()
+            .map(|row| {
+                let mut pieces = Vec::new();
+                let (old, new, changed) = match row {
+                    Row::Hunk {
+                        old_start,
+                        old_len,
+                        new_start,
+                        new_len,
+                    } => {
+                        let header = format!("@@ -{old_start},{old_len} +{new_start},{new_len} @@");
+                        return vec![(header, theme.hunk)];
+                    }
+                    Row::Line { old, new, changed } => (old, new, *changed),
+                };
+                let (old_sign, old_style, new_sign, new_style) = if changed {
+                    ('-', theme.removed, '+', theme.added)
+                } else {
+                    (' ', theme.context, ' ', theme.context)
+                };
+                match self.layout {
+                    DiffLayout::Unified => {
+                        let gutter = number(old) + &number(new);
+                        let (sign, style, word_style, words) = match (old, new) {
+                            (Some((_, words)), None) => {
+                                (old_sign, old_style, theme.removed_word, words)
+                            }
+                            (_, Some((_, words))) => (new_sign, new_style, theme.added_word, words),
+                            (None, None) => return pieces,
+                        };
+                        push_side(&mut pieces, gutter, sign, words, style, word_style, theme);
+                    }
+                    DiffLayout::SideBySide => {
+                        let width = match old {
+                            Some((_, words)) => push_side(
+                                &mut pieces,
+                                number(old),
+                                old_sign,
+                                words,
+                                old_style,
+                                theme.removed_word,
+                                theme,
+                            ),
+                            None => {
+                                pieces.push((" ".repeat(number_width + 3), theme.gutter));
+                                0
+                            }
+                        };
+                        let padding = " ".repeat(left_width - width);
+                        pieces.push((padding + " │ ", theme.gutter));
+                        if let Some((_, words)) = new {
+                            push_side(
+                                &mut pieces,
+                                number(new),
+                                new_sign,
+                                words,
+                                new_style,
+                                theme.added_word,
+                                theme,
+                            );
+                        }
+                    }
+                }
+                pieces
+            })
+            .collect()
+    }
+
+    fn content<T, A>(&self, theme: &DiffTheme) -> Content<T, A> {
+        let lines = self
+            .lines(theme)
+            .into_iter()
+            .map(WrappedText::new)
+            .collect();
+        scroll_view(v_stack(lines)).axes(ScrollAxes::Both)
+    }
+}
+
+type Content<T, A> = ScrollView<T, A, LinearLayout<T, A, Vec<WrappedText>>>;
+
+impl ViewMarker for DiffView {}
+
+impl<T, A> View<T, A> for DiffView {
+    /// The content with the diff, and the theme it was built with.
+    type State = (
+        Content<T, A>,
+        <Content<T, A> as View<T, A>>::State,
+        DiffTheme,
+    );
+
+    type Element = crate::widget::ScrollView;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let theme = cx.theme().diff;
+        let content = self.content(&theme);
+        let (id, state, element) = content.build(cx);
+        (id, (content, state, theme), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (content, state, theme): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let unchanged = self.old == prev.old
+            && self.new == prev.new
+            && self.layout == prev.layout
+            && self.context == prev.context
+            && *theme == cx.theme().diff;
+        if unchanged {
+            return ChangeFlags::empty();
+        }
+        *theme = cx.theme().diff;
+        let new_content = self.content(theme);
+        let changeflags = new_content.rebuild(cx, content, id, state, element);
+        *content = new_content;
+        changeflags
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (content, state, _): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        content.message(id_path, state, message, app_state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(lines: &[Vec<(String, Style)>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|pieces| pieces.iter().map(|(text, _)| text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn diffs_sequences() {
+        let old: Vec<_> = "ABCABBA".chars().collect();
+        let new: Vec<_> = "CBABAC".chars().collect();
+        let edits = diff(&old, &new);
+        // the edit distance of the example in the paper of Myers is 5
+        let changes = edits
+            .iter()
+            .filter(|e| !matches!(e, Edit::Equal(..)))
+            .count();
+        assert_eq!(changes, 5);
+        let mut applied = Vec::new();
+        for edit in edits {
+            match edit {
+                Edit::Equal(o, n) => {
+                    assert_eq!(old[o], new[n]);
+                    applied.push(old[o]);
+                }
+                Edit::Insert(n) => applied.push(new[n]),
+                Edit::Delete(_) => (),
+            }
+        }
+        assert_eq!(applied, new);
+        assert!(diff::<char>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn shows_hunks_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\ni\n";
+        let view = diff_view(old, new).context(1);
+        assert_eq!(
+            text(&view.lines(&DiffTheme::default())),
+            [
+                "@@ -4,3 +4,3 @@",
+                "4 4   d",
+                "5   - e",
+                "  5 + E",
+                "6 6   f",
+                "@@ -8,1 +8,2 @@",
+                "8 8   h",
+                "  9 + i",
+            ]
+        );
+        let view = view.layout(DiffLayout::SideBySide).context(0);
+        let lines = view.lines(&DiffTheme::default());
+        assert_eq!(
+            text(&lines),
+            [
+                "@@ -5,1 +5,1 @@",
+                "5 - e │ 5 + E",
+                "@@ -8,0 +9,1 @@",
+                "      │ 9 + i"
+            ]
+        );
+        // the changed word is highlighted
+        assert_eq!(
+            lines[1][2],
+            (
+                "e".to_string(),
+                DiffTheme::default()
+                    .removed
+                    .patch(DiffTheme::default().removed_word)
+            )
+        );
+    }
+}
@@ -1,10 +1,18 @@
-use super::{Cx, PendingTask, Styleable, View, ViewMarker};
-use crate::widget::{self, CatchMouseButton, ChangeFlags};
+use super::{Cx, DateTime, PendingTask, View, ViewMarker};
+use crate::widget::{
+    self, CatchMouseButton, ChangeFlags, Event, EventCx, HoverDelay, StyleTransition,
+};
+use crate::Styleable;
 use futures_util::{Future, Stream, StreamExt};
 use ratatui::style::Style;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::task::Waker;
-use std::{marker::PhantomData, sync::Arc};
-use tokio::{runtime::Runtime, sync::mpsc::Receiver, task::JoinHandle};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+use tokio::{runtime::Runtime, sync::Semaphore, task::JoinHandle};
 use xilem_core::{AsyncWake, Id, MessageResult};
 
 pub trait EventHandler<T, A = (), E = ()>: Send + Sync {
@@ -26,6 +34,172 @@ pub trait EventHandler<T, A = (), E = ()>: Send + Sync {
     // fn keep_alive(&self) -> bool;
 }
 
+/// A trait that makes it possible to adapt event handlers in the continuation/builder style.
+pub trait EventHandlerExt<T, A, E>: EventHandler<T, A, E> + Sized {
+    /// Maps events of another type (e.g. from a different view) to the event type of this handler.
+    fn map_event<E2, F>(self, f: F) -> MapEvent<Self, F, E>
+    where
+        F: Fn(E2) -> E + Send + Sync,
+    {
+        MapEvent {
+            handler: self,
+            f,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Only handles events for which `predicate` returns `true`, other events are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # use crossterm::event::MouseEventKind;
+    /// # App::new(0, move |clicks: &mut u32| {
+    /// format!("Clicks (scrolling is ignored): {clicks}").on_mouse(
+    ///     (|clicks: &mut u32, _event: MouseEvent| *clicks += 1).filter(|event: &MouseEvent| {
+    ///         !matches!(event.kind, MouseEventKind::ScrollUp | MouseEventKind::ScrollDown)
+    ///     }),
+    /// )
+    /// # });
+    /// ```
+    fn filter<F>(self, predicate: F) -> FilterEvent<Self, F>
+    where
+        F: Fn(&E) -> bool + Send + Sync,
+    {
+        FilterEvent {
+            handler: self,
+            predicate,
+        }
+    }
+
+    /// Only handles events, when `condition` returns `true` for the current app state,
+    /// e.g. to only act, when the app is in a particular mode.
+    fn when<F>(self, condition: F) -> When<Self, F>
+    where
+        F: Fn(&T) -> bool + Send + Sync,
+    {
+        When {
+            handler: self,
+            condition,
+        }
+    }
+}
+
+impl<T, A, E, EH: EventHandler<T, A, E>> EventHandlerExt<T, A, E> for EH {}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MapEvent<EH, F, E> {
+    handler: EH,
+    f: F,
+    phantom: PhantomData<fn() -> E>,
+}
+
+impl<T, A, E, E2, EH, F> EventHandler<T, A, E2> for MapEvent<EH, F, E>
+where
+    E: 'static,
+    E2: 'static,
+    EH: EventHandler<T, A, E>,
+    F: Fn(E2) -> E + Send + Sync,
+{
+    type State = EH::State;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+        self.handler.build(cx)
+    }
+
+    fn rebuild(&self, cx: &mut Cx, id: &Id, state: &mut Self::State) -> ChangeFlags {
+        self.handler.rebuild(cx, id, state)
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        let message: Box<dyn std::any::Any> = if id_path.is_empty() && message.is::<E2>() {
+            Box::new((self.f)(*message.downcast::<E2>().unwrap()))
+        } else {
+            message
+        };
+        self.handler.message(id_path, state, message, app_state)
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FilterEvent<EH, F> {
+    handler: EH,
+    predicate: F,
+}
+
+impl<T, A, E, EH, F> EventHandler<T, A, E> for FilterEvent<EH, F>
+where
+    E: 'static,
+    EH: EventHandler<T, A, E>,
+    F: Fn(&E) -> bool + Send + Sync,
+{
+    type State = EH::State;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+        self.handler.build(cx)
+    }
+
+    fn rebuild(&self, cx: &mut Cx, id: &Id, state: &mut Self::State) -> ChangeFlags {
+        self.handler.rebuild(cx, id, state)
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match message.downcast_ref::<E>() {
+            Some(event) if id_path.is_empty() && !(self.predicate)(event) => MessageResult::Nop,
+            _ => self.handler.message(id_path, state, message, app_state),
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct When<EH, F> {
+    handler: EH,
+    condition: F,
+}
+
+impl<T, A, E, EH, F> EventHandler<T, A, E> for When<EH, F>
+where
+    E: 'static,
+    EH: EventHandler<T, A, E>,
+    F: Fn(&T) -> bool + Send + Sync,
+{
+    type State = EH::State;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+        self.handler.build(cx)
+    }
+
+    fn rebuild(&self, cx: &mut Cx, id: &Id, state: &mut Self::State) -> ChangeFlags {
+        self.handler.rebuild(cx, id, state)
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        if id_path.is_empty() && message.is::<E>() && !(self.condition)(app_state) {
+            MessageResult::Nop
+        } else {
+            self.handler.message(id_path, state, message, app_state)
+        }
+    }
+}
+
 // TODO A blanket implementation for F where <E, F: Fn(&mut T, E) -> A>
 //      needs the negative bounds feature (E: !() because of the implementation below)
 //      I think it makes sense to be more explicit and implement it for concrete events,
@@ -36,8 +210,8 @@ pub trait EventHandler<T, A = (), E = ()>: Send + Sync {
 impl<T, A, F: Fn(&mut T) -> A + Send + Sync> EventHandler<T, A> for F {
     type State = ();
 
-    fn build(&self, _cx: &mut Cx) -> (Id, Self::State) {
-        (Id::next(), ())
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+        (cx.next_id(), ())
     }
 
     fn rebuild(&self, _cx: &mut Cx, _id: &Id, _state: &mut Self::State) -> ChangeFlags {
@@ -61,8 +235,8 @@ macro_rules! impl_callback_event_handler {
         impl<T, A, F: Fn(&mut T, $event) -> A + Send + Sync> EventHandler<T, A, $event> for F {
             type State = ();
 
-            fn build(&self, _cx: &mut Cx) -> (Id, Self::State) {
-                (Id::next(), ())
+            fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+                (cx.next_id(), ())
             }
 
             fn rebuild(&self, _cx: &mut Cx, _id: &Id, _state: &mut Self::State) -> ChangeFlags {
@@ -84,52 +258,150 @@ macro_rules! impl_callback_event_handler {
     };
 }
 
-/// This currently broadcasts the messages to each of the sub event handlers.
-/// TODO should this filter instead, or is this usable at all?
-impl<T, A, E: Clone + 'static, E1: EventHandler<T, A, E>, E2: EventHandler<T, A, E>>
-    EventHandler<T, A, E> for (E1, E2)
-{
-    type State = ((Id, E1::State), (Id, E2::State));
-
-    fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
-        cx.with_new_id(|cx| (self.0.build(cx), self.1.build(cx)))
+/// Combines the results of two event handlers, which both received the same message.
+///
+/// An action is preferred over a rebuild request, which is preferred over a handled message ([`MessageResult::Nop`]),
+/// the result is only stale, if both results are stale. If both results are actions, the first one is used.
+pub fn combine_message_results<A>(a: MessageResult<A>, b: MessageResult<A>) -> MessageResult<A> {
+    match (a, b) {
+        (MessageResult::Action(a), _) => MessageResult::Action(a),
+        (_, MessageResult::Action(b)) => MessageResult::Action(b),
+        (MessageResult::RequestRebuild, _) | (_, MessageResult::RequestRebuild) => {
+            MessageResult::RequestRebuild
+        }
+        (MessageResult::Nop, _) | (_, MessageResult::Nop) => MessageResult::Nop,
+        (stale @ MessageResult::Stale(_), MessageResult::Stale(_)) => stale,
     }
+}
 
-    fn rebuild(&self, cx: &mut Cx, _id: &Id, state: &mut Self::State) -> ChangeFlags {
-        self.0.rebuild(cx, &state.0 .0, &mut state.0 .1)
-            | self.1.rebuild(cx, &state.1 .0, &mut state.1 .1)
-    }
+/// Dispatches a message only to the first event handler of a tuple that handles it (i.e. doesn't return [`MessageResult::Stale`]),
+/// instead of broadcasting it to all of them.
+///
+/// See [`first_handled`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FirstHandled<EH>(pub EH);
 
-    fn message(
-        &self,
-        id_path: &[xilem_core::Id],
-        state: &mut Self::State,
-        message: Box<dyn std::any::Any>,
-        app_state: &mut T,
-    ) -> MessageResult<A> {
-        match id_path {
-            [id, rest_path @ ..] if *id == state.0 .0 => {
-                self.0
-                    .message(rest_path, &mut state.0 .1, message, app_state)
-            }
-            [id, rest_path @ ..] if *id == state.1 .0 => {
-                self.1
-                    .message(rest_path, &mut state.1 .1, message, app_state)
-            }
-            [] if message.downcast_ref::<E>().is_some() => {
-                let message = message.downcast::<E>().unwrap();
-                let res1 = self
-                    .0
-                    .message(&[], &mut state.0 .1, message.clone(), app_state);
-                let res2 = self.1.message(&[], &mut state.1 .1, message, app_state);
-                // TODO currently the second message result will be discarded...
-                res1.or(|_| res2)
+/// Dispatches a message only to the first event handler in `handlers` that handles it,
+/// e.g. `first_handled((only_in_edit_mode, fallback))`.
+pub fn first_handled<EH>(handlers: EH) -> FirstHandled<EH> {
+    FirstHandled(handlers)
+}
+
+macro_rules! impl_event_handler_for_tuple {
+    ($first:ident: $first_i:tt, $($eh:ident: $i:tt),+) => {
+        /// Broadcasts a message to each of the sub event handlers,
+        /// the results are combined with [`combine_message_results`].
+        impl<T, A, E, $first, $($eh),+> EventHandler<T, A, E> for ($first, $($eh),+)
+        where
+            E: Clone + 'static,
+            $first: EventHandler<T, A, E>,
+            $($eh: EventHandler<T, A, E>,)+
+        {
+            type State = ((Id, $first::State), $((Id, $eh::State)),+);
+
+            fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+                cx.with_new_id(|cx| (self.$first_i.build(cx), $(self.$i.build(cx)),+))
+            }
+
+            fn rebuild(&self, cx: &mut Cx, _id: &Id, state: &mut Self::State) -> ChangeFlags {
+                self.$first_i.rebuild(cx, &state.$first_i.0, &mut state.$first_i.1)
+                    $(| self.$i.rebuild(cx, &state.$i.0, &mut state.$i.1))+
+            }
+
+            fn message(
+                &self,
+                id_path: &[xilem_core::Id],
+                state: &mut Self::State,
+                message: Box<dyn std::any::Any>,
+                app_state: &mut T,
+            ) -> MessageResult<A> {
+                match id_path {
+                    [id, rest_path @ ..] if *id == state.$first_i.0 => {
+                        self.$first_i.message(rest_path, &mut state.$first_i.1, message, app_state)
+                    }
+                    $(
+                    [id, rest_path @ ..] if *id == state.$i.0 => {
+                        self.$i.message(rest_path, &mut state.$i.1, message, app_state)
+                    }
+                    )+
+                    [] if message.downcast_ref::<E>().is_some() => {
+                        let message = message.downcast::<E>().unwrap();
+                        let result = self.$first_i.message(
+                            &[],
+                            &mut state.$first_i.1,
+                            Box::new(E::clone(&message)),
+                            app_state,
+                        );
+                        $(
+                        let result = combine_message_results(
+                            result,
+                            self.$i.message(&[], &mut state.$i.1, Box::new(E::clone(&message)), app_state),
+                        );
+                        )+
+                        result
+                    }
+                    [..] => MessageResult::Stale(message),
+                }
             }
-            [..] => MessageResult::Stale(message),
         }
-    }
+
+        impl<T, A, E, $first, $($eh),+> EventHandler<T, A, E> for FirstHandled<($first, $($eh),+)>
+        where
+            E: 'static,
+            $first: EventHandler<T, A, E>,
+            $($eh: EventHandler<T, A, E>,)+
+        {
+            type State = ((Id, $first::State), $((Id, $eh::State)),+);
+
+            fn build(&self, cx: &mut Cx) -> (Id, Self::State) {
+                cx.with_new_id(|cx| (self.0.$first_i.build(cx), $(self.0.$i.build(cx)),+))
+            }
+
+            fn rebuild(&self, cx: &mut Cx, _id: &Id, state: &mut Self::State) -> ChangeFlags {
+                self.0.$first_i.rebuild(cx, &state.$first_i.0, &mut state.$first_i.1)
+                    $(| self.0.$i.rebuild(cx, &state.$i.0, &mut state.$i.1))+
+            }
+
+            fn message(
+                &self,
+                id_path: &[xilem_core::Id],
+                state: &mut Self::State,
+                message: Box<dyn std::any::Any>,
+                app_state: &mut T,
+            ) -> MessageResult<A> {
+                match id_path {
+                    [id, rest_path @ ..] if *id == state.$first_i.0 => {
+                        self.0.$first_i.message(rest_path, &mut state.$first_i.1, message, app_state)
+                    }
+                    $(
+                    [id, rest_path @ ..] if *id == state.$i.0 => {
+                        self.0.$i.message(rest_path, &mut state.$i.1, message, app_state)
+                    }
+                    )+
+                    [] if message.downcast_ref::<E>().is_some() => self
+                        .0
+                        .$first_i
+                        .message(&[], &mut state.$first_i.1, message, app_state)
+                        $(.or(|message| self.0.$i.message(&[], &mut state.$i.1, message, app_state)))+,
+                    [..] => MessageResult::Stale(message),
+                }
+            }
+        }
+    };
 }
 
+impl_event_handler_for_tuple!(E1: 0, E2: 1);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4, E6: 5);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4, E6: 5, E7: 6);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4, E6: 5, E7: 6, E8: 7);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4, E6: 5, E7: 6, E8: 7, E9: 8);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4, E6: 5, E7: 6, E8: 7, E9: 8, E10: 9);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4, E6: 5, E7: 6, E8: 7, E9: 8, E10: 9, E11: 10);
+impl_event_handler_for_tuple!(E1: 0, E2: 1, E3: 2, E4: 3, E5: 4, E6: 5, E7: 6, E8: 7, E9: 8, E10: 9, E11: 10, E12: 11);
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum StreamMessage<E> {
     Begin(E),
@@ -137,44 +409,186 @@ pub enum StreamMessage<E> {
     Finished,
 }
 
+/// How items of a stream are buffered, until they are processed by the update function of [`stream`].
+///
+/// All buffered items are processed at once, when the app state is updated, so this mostly matters for
+/// streams that produce items faster than the app is updated (e.g. tick data or logs).
+pub enum StreamBuffering<E> {
+    /// Buffer up to `n` items, the stream is not polled further (backpressure), until there's space again.
+    Bounded(usize),
+    /// Buffer up to `n` items, when the buffer is full, the oldest item is dropped.
+    DropOldest(usize),
+    /// Fold all pending items into a single one, e.g. to only keep the latest value, or to sum up values.
+    Coalesce(Arc<dyn Fn(E, E) -> E + Send + Sync>),
+}
+
+impl<E> StreamBuffering<E> {
+    /// Fold all pending items with `fold` into a single one, see [`StreamBuffering::Coalesce`].
+    pub fn coalesce(fold: impl Fn(E, E) -> E + Send + Sync + 'static) -> Self {
+        StreamBuffering::Coalesce(Arc::new(fold))
+    }
+}
+
+impl<E> Default for StreamBuffering<E> {
+    fn default() -> Self {
+        StreamBuffering::Bounded(1000)
+    }
+}
+
+impl<E> Clone for StreamBuffering<E> {
+    fn clone(&self) -> Self {
+        match self {
+            StreamBuffering::Bounded(n) => StreamBuffering::Bounded(*n),
+            StreamBuffering::DropOldest(n) => StreamBuffering::DropOldest(*n),
+            StreamBuffering::Coalesce(fold) => StreamBuffering::Coalesce(fold.clone()),
+        }
+    }
+}
+
+impl<E> std::fmt::Debug for StreamBuffering<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamBuffering::Bounded(n) => f.debug_tuple("Bounded").field(n).finish(),
+            StreamBuffering::DropOldest(n) => f.debug_tuple("DropOldest").field(n).finish(),
+            StreamBuffering::Coalesce(_) => f.write_str("Coalesce"),
+        }
+    }
+}
+
+/// Fold functions are compared by identity, i.e. clones of the same [`StreamBuffering::Coalesce`] are equal.
+impl<E> PartialEq for StreamBuffering<E> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (StreamBuffering::Bounded(a), StreamBuffering::Bounded(b))
+            | (StreamBuffering::DropOldest(a), StreamBuffering::DropOldest(b)) => a == b,
+            (StreamBuffering::Coalesce(a), StreamBuffering::Coalesce(b)) => {
+                std::ptr::eq(Arc::as_ptr(a).cast::<()>(), Arc::as_ptr(b).cast::<()>())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<E> Eq for StreamBuffering<E> {}
+
+impl<E> std::hash::Hash for StreamBuffering<E> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            StreamBuffering::Bounded(n) | StreamBuffering::DropOldest(n) => n.hash(state),
+            StreamBuffering::Coalesce(fold) => Arc::as_ptr(fold).cast::<()>().hash(state),
+        }
+    }
+}
+
+/// The buffer shared between the spawned stream task and the [`StreamEventHandlerState`].
+struct StreamBuffer<E> {
+    queue: Mutex<VecDeque<E>>,
+    buffering: StreamBuffering<E>,
+    /// Only used for [`StreamBuffering::Bounded`]
+    free_slots: Semaphore,
+    finished: AtomicBool,
+}
+
+impl<E> StreamBuffer<E> {
+    fn new(buffering: StreamBuffering<E>) -> Self {
+        let free_slots = match buffering {
+            StreamBuffering::Bounded(n) => Semaphore::new(n.max(1)),
+            _ => Semaphore::new(0),
+        };
+        StreamBuffer {
+            queue: Mutex::new(VecDeque::new()),
+            buffering,
+            free_slots,
+            finished: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns whether the buffer was empty before,
+    /// i.e. whether the consumer has to be woken up, as it drains the buffer completely on each wake.
+    async fn push(&self, item: E) -> bool {
+        if let StreamBuffering::Bounded(_) = self.buffering {
+            if let Ok(permit) = self.free_slots.acquire().await {
+                permit.forget();
+            }
+        }
+        let mut queue = self.queue.lock().unwrap();
+        let was_empty = queue.is_empty();
+        match &self.buffering {
+            StreamBuffering::Bounded(_) => queue.push_back(item),
+            StreamBuffering::DropOldest(n) => {
+                if queue.len() >= (*n).max(1) {
+                    queue.pop_front();
+                }
+                queue.push_back(item);
+            }
+            StreamBuffering::Coalesce(fold) => {
+                let item = match queue.pop_back() {
+                    Some(pending) => fold(pending, item),
+                    None => item,
+                };
+                queue.push_back(item);
+            }
+        }
+        was_empty
+    }
+
+    fn pop(&self) -> Option<E> {
+        let item = self.queue.lock().unwrap().pop_front();
+        if item.is_some() && matches!(self.buffering, StreamBuffering::Bounded(_)) {
+            self.free_slots.add_permits(1);
+        }
+        item
+    }
+}
+
 pub struct StreamEventHandlerState<E> {
     waker: Waker,
     runtime: Arc<Runtime>,
-    chan: Option<Receiver<Option<E>>>,
+    buffer: Option<Arc<StreamBuffer<E>>>,
     started: bool,
     is_streaming: bool,
     join_handle: Option<JoinHandle<()>>,
 }
 
 impl<E: Send + 'static> StreamEventHandlerState<E> {
-    fn new(waker: Waker, runtime: Arc<Runtime>) -> Self {
+    pub(crate) fn new(waker: Waker, runtime: Arc<Runtime>) -> Self {
         Self {
             waker,
             runtime,
-            chan: None,
+            buffer: None,
             join_handle: None,
             started: false,
             is_streaming: false,
         }
     }
 
-    fn dispatch<S: Stream<Item = E> + Send + 'static>(&mut self, stream: S) {
+    pub(crate) fn dispatch<S: Stream<Item = E> + Send + 'static>(
+        &mut self,
+        stream: S,
+        buffering: &StreamBuffering<E>,
+    ) {
         let waker = self.waker.clone();
 
-        let (stream_tx, stream_rx) = tokio::sync::mpsc::channel(1000);
+        let buffer = Arc::new(StreamBuffer::new(buffering.clone()));
 
-        self.chan = Some(stream_rx);
+        self.buffer = Some(buffer.clone());
+
+        // A previous stream would otherwise write into a buffer that is not read anymore
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.abort();
+        }
 
         let join_handle = self.runtime.spawn(async move {
             let mut stream = Box::pin(stream);
 
             while let Some(s) = stream.next().await {
-                if (stream_tx.send(Some(s)).await).is_ok() {
+                if buffer.push(s).await {
                     waker.wake_by_ref();
-                } else {
-                    break;
                 }
             }
+            buffer.finished.store(true, Ordering::Release);
+            waker.wake_by_ref();
         });
 
         self.started = true;
@@ -182,18 +596,22 @@ impl<E: Send + 'static> StreamEventHandlerState<E> {
         self.join_handle = Some(join_handle);
     }
 
-    fn poll(&mut self) -> Option<StreamMessage<E>> {
-        match self.chan.as_mut().unwrap().try_recv() {
-            Ok(Some(message)) if self.started => {
+    pub(crate) fn poll(&mut self) -> Option<StreamMessage<E>> {
+        let buffer = self.buffer.as_ref()?;
+        // Load this before popping, so that no item is missed, which is pushed between popping and loading
+        let finished = buffer.finished.load(Ordering::Acquire);
+        match buffer.pop() {
+            Some(message) if self.started => {
                 self.started = false;
                 Some(StreamMessage::Begin(message))
             }
-            Ok(Some(message)) => Some(StreamMessage::Update(message)),
-            Ok(None) => {
+            Some(message) => Some(StreamMessage::Update(message)),
+            None if finished => {
+                self.buffer = None;
                 self.is_streaming = false;
                 Some(StreamMessage::Finished)
             }
-            Err(_) => {
+            None => {
                 self.is_streaming = false;
                 None
             }
@@ -201,19 +619,47 @@ impl<E: Send + 'static> StreamEventHandlerState<E> {
     }
 }
 
-#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Stops the spawned stream task, when the state is dropped (e.g. because the view was removed from the tree)
+impl<E> Drop for StreamEventHandlerState<E> {
+    fn drop(&mut self) {
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.abort();
+        }
+    }
+}
+
+// Not `Copy`, as the buffering may contain a fold function
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct StreamEventHandler<T, A, E, S, SF, UF> {
     #[allow(clippy::complexity)]
     phantom: PhantomData<fn() -> (T, A, E, S)>,
     stream_fn: SF,
     update_fn: UF,
+    buffering: StreamBuffering<E>,
+}
+
+impl<T, A, E, S, SF, UF> StreamEventHandler<T, A, E, S, SF, UF> {
+    /// Sets how items are buffered until they're processed, by default up to 1000 items are buffered.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // Only the latest price is of interest, when the UI can't keep up
+    /// stream(|_, ()| price_ticks(), |state: &mut f64, message| { ... })
+    ///     .buffering(StreamBuffering::coalesce(|_older, newer| newer))
+    /// ```
+    pub fn buffering(mut self, buffering: StreamBuffering<E>) -> Self {
+        self.buffering = buffering;
+        self
+    }
 }
 
 impl<T, A, SE, S, SF, UF, E: 'static> EventHandler<T, A, E>
     for StreamEventHandler<T, A, SE, S, SF, UF>
 where
     SE: Send + Sync + 'static,
-    S: Stream<Item = SE> + Send + Sync + 'static,
+    // only moved into the spawned task, so e.g. streams holding a connection future don't have to be `Sync`
+    S: Stream<Item = SE> + Send + 'static,
     SF: Fn(&mut T, E) -> S + Send + Sync,
     UF: Fn(&mut T, StreamMessage<SE>) + Send + Sync,
 {
@@ -255,10 +701,10 @@ where
                 MessageResult::Nop
             }
         } else if message.downcast_ref::<E>().is_some() {
-            state.dispatch((self.stream_fn)(
-                app_state,
-                *message.downcast::<E>().unwrap(),
-            ));
+            state.dispatch(
+                (self.stream_fn)(app_state, *message.downcast::<E>().unwrap()),
+                &self.buffering,
+            );
             MessageResult::Nop
         } else {
             MessageResult::Stale(message)
@@ -280,6 +726,7 @@ where
         phantom: PhantomData,
         stream_fn,
         update_fn,
+        buffering: StreamBuffering::default(),
     }
 }
 
@@ -376,6 +823,17 @@ where
 }
 
 impl_callback_event_handler!(widget::MouseEvent);
+impl_callback_event_handler!(widget::ScrollEvent);
+impl_callback_event_handler!(widget::DragEvent);
+impl_callback_event_handler!(bool);
+impl_callback_event_handler!(f64);
+impl_callback_event_handler!(i64);
+impl_callback_event_handler!(String);
+impl_callback_event_handler!(DateTime);
+impl_callback_event_handler!((u16, u16));
+impl_callback_event_handler!((usize, usize));
+impl_callback_event_handler!((usize, u16));
+impl_callback_event_handler!(std::collections::BTreeSet<usize>);
 
 // TODO some description
 // TODO Is this view useful at all? Should this be already abstracted (e.g. via the other views such as Hoverable, or Clickable)
@@ -464,51 +922,25 @@ where
     }
 }
 macro_rules! styled_event_views {
-    ($($name:ident),*) => {
+    ($($name:ident { $($field:ident: $ty:ty),* }),*) => {
         $(
-        #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Styleable)]
         pub struct $name<V> {
+            #[styleable]
             pub(crate) view: V,
             pub(crate) style: Style,
+            $(pub(crate) $field: $ty,)*
         }
 
         impl<V> ViewMarker for $name<V> {}
 
-        impl<V: Styleable> Styleable for $name<V>
-        {
-            type Output = $name<V::Output>;
-
-            fn fg(self, color: ratatui::style::Color) -> Self::Output {
-                $name {
-                    view: self.view.fg(color),
-                    style: self.style,
-                }
-            }
-
-            fn bg(self, color: ratatui::style::Color) -> Self::Output {
-                $name {
-                    view: self.view.bg(color),
-                    style: self.style,
-                }
-            }
-
-            fn modifier(self, modifier: ratatui::style::Modifier) -> Self::Output {
-                $name {
-                    view: self.view.modifier(modifier),
-                    style: self.style,
-                }
-            }
-
-            fn style(self, style: ratatui::style::Style) -> Self::Output {
-                $name {
-                    view: self.view.style(style),
-                    style: self.style,
-                }
-            }
-
-            fn current_style(&self) -> Style {
-                self.view.current_style()
+        impl<V> $name<V> {
+            $(
+            pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+                self.$field = $field.into();
+                self
             }
+            )*
         }
         )*
     }
@@ -528,7 +960,10 @@ where
     fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
         let (id, state, element) = self.view.build(cx);
 
-        (id, state, widget::StyleOnHover::new(element, self.style))
+        let mut element = widget::StyleOnHover::new(element, self.style);
+        element.hover.delay = self.hover_delay;
+        element.fade.transition = self.transition;
+        (id, state, element)
     }
 
     fn rebuild(
@@ -544,6 +979,8 @@ where
             element.style = self.style;
             changeflags |= ChangeFlags::PAINT;
         }
+        element.hover.delay = self.hover_delay;
+        element.fade.transition = self.transition;
         let content_changeflags = self.view.rebuild(
             cx,
             &prev.view,
@@ -578,10 +1015,9 @@ where
         let (id, (state, element)) = cx.with_new_id(|cx| {
             let (child_id, state, element) = self.view.build(cx);
 
-            (
-                (state, child_id),
-                widget::StyleOnPressed::new(element, self.style),
-            )
+            let mut element = widget::StyleOnPressed::new(element, self.style);
+            element.fade.transition = self.transition;
+            ((state, child_id), element)
         });
         (id, state, element)
     }
@@ -599,6 +1035,7 @@ where
             element.style = self.style;
             changeflags |= ChangeFlags::PAINT;
         }
+        element.fade.transition = self.transition;
         changeflags | cx.with_id(*id, |cx| {
             let element_changeflags = self.view.rebuild(
                 cx,
@@ -629,41 +1066,217 @@ where
     }
 }
 
-styled_event_views!(StyleOnHover, StyleOnPressed);
-
-// TODO own state (id_path etc.)
-macro_rules! event_views {
-    ($($name:ident),*) => {
-        $(
-        #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
-        pub struct $name<V, EH> {
-            pub(crate) view: V,
-            pub(crate) event_handler: EH,
-        }
+styled_event_views!(
+    StyleOnHover {
+        hover_delay: HoverDelay,
+        transition: StyleTransition
+    },
+    StyleOnPressed {
+        transition: StyleTransition
+    }
+);
 
-        impl<V, EH> ViewMarker for $name<V, EH> {}
+/// See [`ViewExt::on_scroll`](super::ViewExt::on_scroll).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OnScroll<V, EH> {
+    pub(crate) view: V,
+    pub(crate) event_handler: EH,
+}
 
-        impl<T, A, V, EH> View<T, A> for $name<V, EH>
-        where
-            V: View<T, A>,
-            EH: EventHandler<T, A>,
-        {
-            type State = (V::State, Id, (Id, EH::State));
+impl<V, EH> ViewMarker for OnScroll<V, EH> {}
 
-            type Element = widget::$name;
+impl<T, A, V, EH> View<T, A> for OnScroll<V, EH>
+where
+    V: View<T, A>,
+    EH: EventHandler<T, A, widget::ScrollEvent>,
+{
+    type State = (V::State, Id, (Id, EH::State));
 
-            fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
-                let (id, (state, element)) = cx.with_new_id(|cx| {
-                    let (child_id, state, element) = self.view.build(cx);
+    type Element = widget::OnScroll;
 
-                    ((state, child_id, self.event_handler.build(cx)), widget::$name::new(element, cx.id_path()))
-                });
-                (id, state, element)
-            }
+    fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (child_id, state, element) = self.view.build(cx);
 
-            fn rebuild(
-                &self,
-                cx: &mut Cx,
+            (
+                (state, child_id, self.event_handler.build(cx)),
+                widget::OnScroll::new(element, cx.id_path()),
+            )
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut xilem_core::Id,
+        (state, child_id, (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let content_changeflags = self.view.rebuild(
+                cx,
+                &prev.view,
+                child_id,
+                state,
+                element.element.downcast_mut().expect(
+                    "The on scroll content widget changed its type, this should never happen!",
+                ),
+            );
+
+            element.element.mark(content_changeflags)
+                | self.event_handler.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        (state, child_id, (event_handler_id, event_handler_state)): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> xilem_core::MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == child_id => {
+                self.view.message(rest_path, state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == event_handler_id => {
+                self.event_handler
+                    .message(rest_path, event_handler_state, message, app_state)
+            }
+            [] => self
+                .event_handler
+                .message(&[], event_handler_state, message, app_state),
+            [..] => xilem_core::MessageResult::Stale(message),
+        }
+    }
+}
+
+/// See [`ViewExt::on_resize`](super::ViewExt::on_resize).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OnResize<V, EH> {
+    pub(crate) view: V,
+    pub(crate) event_handler: EH,
+}
+
+impl<V, EH> ViewMarker for OnResize<V, EH> {}
+
+impl<T, A, V, EH> View<T, A> for OnResize<V, EH>
+where
+    V: View<T, A>,
+    EH: EventHandler<T, A, (u16, u16)>,
+{
+    type State = (V::State, Id, (Id, EH::State));
+
+    type Element = widget::OnResize;
+
+    fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (child_id, state, element) = self.view.build(cx);
+
+            (
+                (state, child_id, self.event_handler.build(cx)),
+                widget::OnResize::new(element, cx.id_path()),
+            )
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut xilem_core::Id,
+        (state, child_id, (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let content_changeflags = self.view.rebuild(
+                cx,
+                &prev.view,
+                child_id,
+                state,
+                element.element.downcast_mut().expect(
+                    "The on resize content widget changed its type, this should never happen!",
+                ),
+            );
+
+            element.element.mark(content_changeflags)
+                | self.event_handler.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        (state, child_id, (event_handler_id, event_handler_state)): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> xilem_core::MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == child_id => {
+                self.view.message(rest_path, state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == event_handler_id => {
+                self.event_handler
+                    .message(rest_path, event_handler_state, message, app_state)
+            }
+            [] => self
+                .event_handler
+                .message(&[], event_handler_state, message, app_state),
+            [..] => xilem_core::MessageResult::Stale(message),
+        }
+    }
+}
+
+// TODO own state (id_path etc.)
+macro_rules! event_views {
+    ($($name:ident { $($field:ident: $ty:ty => $setter:ident),* }),*) => {
+        $(
+        #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Styleable)]
+        pub struct $name<V, EH> {
+            #[styleable]
+            pub(crate) view: V,
+            pub(crate) event_handler: EH,
+            $(pub(crate) $field: $ty,)*
+        }
+
+        impl<V, EH> ViewMarker for $name<V, EH> {}
+
+        impl<V, EH> $name<V, EH> {
+            $(
+            pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+                self.$field = $field.into();
+                self
+            }
+            )*
+        }
+
+        impl<T, A, V, EH> View<T, A> for $name<V, EH>
+        where
+            V: View<T, A>,
+            EH: EventHandler<T, A>,
+        {
+            type State = (V::State, Id, (Id, EH::State));
+
+            type Element = widget::$name;
+
+            fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
+                let (id, (state, element)) = cx.with_new_id(|cx| {
+                    let (child_id, state, element) = self.view.build(cx);
+
+                    #[allow(unused_mut)]
+                    let mut element = widget::$name::new(element, cx.id_path());
+                    $(let _ = element.$setter(self.$field);)*
+                    ((state, child_id, self.event_handler.build(cx)), element)
+                });
+                (id, state, element)
+            }
+
+            fn rebuild(
+                &self,
+                cx: &mut Cx,
                 prev: &Self,
                 id: &mut xilem_core::Id,
                 (state, child_id, (eh_id, eh_state)): &mut Self::State,
@@ -678,7 +1291,10 @@ macro_rules! event_views {
                         element.element.downcast_mut().unwrap(),
                     );
 
-                    element.element.mark(element_changeflags) | self.event_handler.rebuild(cx, eh_id, eh_state)
+                    #[allow(unused_mut)]
+                    let mut changeflags = element.element.mark(element_changeflags);
+                    $(changeflags |= element.$setter(self.$field);)*
+                    changeflags | self.event_handler.rebuild(cx, eh_id, eh_state)
                 })
             }
 
@@ -704,52 +1320,127 @@ macro_rules! event_views {
                 }
             }
         }
+        )*
+    };
+}
 
-        impl<V: Styleable, EH> Styleable for $name<V, EH>
-        {
-            type Output = $name<<V as Styleable>::Output, EH>;
+event_views!(
+    OnHover { hover_delay: HoverDelay => set_hover_delay },
+    OnHoverLost { hover_delay: HoverDelay => set_hover_delay },
+    OnAppear {},
+    OnDisappear {},
+    OnMount {}
+);
+
+/// A gesture handler, see [`ViewExt::on_tap`](super::ViewExt::on_tap), [`ViewExt::on_long_press`](super::ViewExt::on_long_press)
+/// and [`ViewExt::on_drag`](super::ViewExt::on_drag).
+///
+/// `E` is the event of the gesture, `()` for taps and long presses, and [`DragEvent`](crate::DragEvent) for drags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Styleable)]
+pub struct OnGesture<V, EH, E> {
+    #[styleable]
+    pub(crate) view: V,
+    pub(crate) event_handler: EH,
+    pub(crate) kind: widget::GestureKind,
+    pub(crate) drag_threshold: u16,
+    pub(crate) phantom: PhantomData<fn() -> E>,
+}
 
-            fn fg(self, color: ratatui::style::Color) -> Self::Output {
-                $name {
-                    view: self.view.fg(color),
-                    event_handler: self.event_handler,
-                }
-            }
+impl<V, EH, E> OnGesture<V, EH, E> {
+    pub(crate) fn new(view: V, event_handler: EH, kind: widget::GestureKind) -> Self {
+        OnGesture {
+            view,
+            event_handler,
+            kind,
+            drag_threshold: widget::DEFAULT_DRAG_THRESHOLD,
+            phantom: PhantomData,
+        }
+    }
 
-            fn bg(self, color: ratatui::style::Color) -> Self::Output {
-                $name {
-                    view: self.view.bg(color),
-                    event_handler: self.event_handler,
-                }
-            }
+    /// The distance in cells (default `1`), which the mouse has to move while pressed to start a drag.
+    ///
+    /// Taps and long presses are cancelled, when the mouse moves this far,
+    /// a larger threshold makes them more tolerant to an unsteady hand.
+    pub fn drag_threshold(mut self, threshold: u16) -> Self {
+        self.drag_threshold = threshold.max(1);
+        self
+    }
+}
 
-            fn modifier(self, modifier: ratatui::style::Modifier) -> Self::Output {
-                $name {
-                    view: self.view.modifier(modifier),
-                    event_handler: self.event_handler,
-                }
-            }
+impl<V, EH, E> ViewMarker for OnGesture<V, EH, E> {}
 
-            fn style(self, style: ratatui::style::Style) -> Self::Output {
-                $name {
-                    view: self.view.style(style),
-                    event_handler: self.event_handler,
-                }
-            }
+impl<T, A, V, EH, E> View<T, A> for OnGesture<V, EH, E>
+where
+    V: View<T, A>,
+    V::Element: 'static,
+    EH: EventHandler<T, A, E>,
+{
+    type State = (V::State, Id, (Id, EH::State));
 
-            fn current_style(&self) -> Style {
-                self.view.current_style()
+    type Element = widget::OnGesture;
+
+    fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (child_id, state, element) = self.view.build(cx);
+            let mut element = widget::OnGesture::new(element, cx.id_path(), self.kind);
+            let _ = element.set_drag_threshold(self.drag_threshold);
+            ((state, child_id, self.event_handler.build(cx)), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut xilem_core::Id,
+        (state, child_id, (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let element_changeflags = self.view.rebuild(
+                cx,
+                &prev.view,
+                child_id,
+                state,
+                element.element.downcast_mut().expect(
+                    "The gesture content widget changed its type, this should never happen!",
+                ),
+            );
+            element.element.mark(element_changeflags)
+                | element.set_kind(self.kind)
+                | element.set_drag_threshold(self.drag_threshold)
+                | self.event_handler.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        (state, child_id, (event_handler_id, event_handler_state)): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> xilem_core::MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == child_id => {
+                self.view.message(rest_path, state, message, app_state)
             }
+            [first, rest_path @ ..] if first == event_handler_id => {
+                self.event_handler
+                    .message(rest_path, event_handler_state, message, app_state)
+            }
+            [] => self
+                .event_handler
+                .message(&[], event_handler_state, message, app_state),
+            [..] => xilem_core::MessageResult::Stale(message),
         }
-        )*
-    };
+    }
 }
 
-event_views!(OnHover, OnHoverLost);
-
 // TODO this should probably be generated by the macro above (but for better IDE experience and easier prototyping this not yet)
-#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Styleable)]
 pub struct OnClick<V, EH> {
+    #[styleable]
     pub(crate) view: V,
     pub(crate) event_handler: EH,
 }
@@ -825,38 +1516,304 @@ where
     }
 }
 
-impl<V: Styleable, EH> Styleable for OnClick<V, EH> {
-    type Output = OnClick<<V as Styleable>::Output, EH>;
+/// The interaction logic of a custom event view, see [`ViewExt::on_event`](super::ViewExt::on_event).
+///
+/// The filter gets the events of the wrapped view (after the view handled them itself),
+/// and returns the event for the event handler, if any.
+/// State (e.g. the time of the last click) can be kept in the filter itself, it's reset when the filter changes.
+pub trait EventFilter: Clone + PartialEq + Send + Sync + 'static {
+    /// The event, that is passed to the event handler.
+    type Output: Send + 'static;
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) -> Option<Self::Output>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Styleable)]
+pub struct OnEvent<V, F, H> {
+    #[styleable]
+    pub(crate) view: V,
+    pub(crate) filter: F,
+    pub(crate) handler: H,
+}
+
+impl<V, F, H> ViewMarker for OnEvent<V, F, H> {}
 
-    fn fg(self, color: ratatui::style::Color) -> Self::Output {
-        OnClick {
-            view: self.view.fg(color),
-            event_handler: self.event_handler,
+fn boxed_filter<F: EventFilter>(mut filter: F) -> widget::BoxedEventFilter {
+    Box::new(move |cx, event| {
+        filter
+            .event(cx, event)
+            .map(|output| Box::new(output) as Box<dyn std::any::Any + Send>)
+    })
+}
+
+impl<T, A, V, F, H> View<T, A> for OnEvent<V, F, H>
+where
+    V: View<T, A>,
+    F: EventFilter,
+    H: Fn(&mut T, F::Output) -> A + Send + Sync,
+{
+    type State = (V::State, Id);
+
+    type Element = widget::OnEvent;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (child_id, state, element) = self.view.build(cx);
+            let element =
+                widget::OnEvent::new(element, cx.id_path(), boxed_filter(self.filter.clone()));
+            ((state, child_id), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (state, child_id): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        if self.filter != prev.filter {
+            element.filter = boxed_filter(self.filter.clone());
         }
+        cx.with_id(*id, |cx| {
+            let changeflags = self.view.rebuild(
+                cx,
+                &prev.view,
+                child_id,
+                state,
+                element.element.downcast_mut().expect(
+                    "The on_event content widget changed its type, this should never happen!",
+                ),
+            );
+            element.element.mark(changeflags)
+        })
     }
 
-    fn bg(self, color: ratatui::style::Color) -> Self::Output {
-        OnClick {
-            view: self.view.bg(color),
-            event_handler: self.event_handler,
+    fn message(
+        &self,
+        id_path: &[Id],
+        (state, child_id): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == child_id => {
+                self.view.message(rest_path, state, message, app_state)
+            }
+            [] => match message.downcast::<F::Output>() {
+                Ok(event) => MessageResult::Action((self.handler)(app_state, *event)),
+                Err(message) => MessageResult::Stale(message),
+            },
+            [..] => MessageResult::Stale(message),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::test_helper::view_cx;
+
+    /// Dispatches a stream, which produces the items `1..=10` as fast as possible.
+    fn fast_producer(buffering: StreamBuffering<u32>) -> StreamEventHandlerState<u32> {
+        let rt = Arc::new(Runtime::new().unwrap());
+        let mut state = StreamEventHandlerState::new(futures_task::noop_waker(), rt);
+        state.dispatch(futures_util::stream::iter(1..=10), &buffering);
+        state
+    }
 
-    fn modifier(self, modifier: ratatui::style::Modifier) -> Self::Output {
-        OnClick {
-            view: self.view.modifier(modifier),
-            event_handler: self.event_handler,
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(Instant::now() < deadline, "timed out");
+            std::thread::sleep(Duration::from_millis(1));
         }
     }
 
-    fn style(self, style: ratatui::style::Style) -> Self::Output {
-        OnClick {
-            view: self.view.style(style),
-            event_handler: self.event_handler,
+    /// Waits until the stream has finished, and processes all buffered items at once.
+    fn consume_finished(mut state: StreamEventHandlerState<u32>) -> Vec<StreamMessage<u32>> {
+        let buffer = state.buffer.clone().unwrap();
+        wait_until(|| buffer.finished.load(Ordering::Acquire));
+        std::iter::from_fn(|| state.poll()).collect()
+    }
+
+    /// Builds `handler` and sends it `event`, like the view it's attached to.
+    fn handle<T, E: 'static, EH: EventHandler<T, (), E>>(
+        handler: &EH,
+        event: E,
+        app_state: &mut T,
+    ) -> MessageResult<()> {
+        let (_, mut state) = handler.build(&mut view_cx());
+        handler.message(&[], &mut state, Box::new(event), app_state)
+    }
+
+    #[test]
+    fn tuples_broadcast_events_to_all_handlers() {
+        let handlers = (
+            |log: &mut Vec<String>, n: i64| log.push(format!("a{n}")),
+            |log: &mut Vec<String>, n: i64| log.push(format!("b{n}")),
+            |log: &mut Vec<String>, n: i64| log.push(format!("c{n}")),
+        );
+        let mut log = Vec::new();
+        assert!(matches!(
+            handle(&handlers, 1_i64, &mut log),
+            MessageResult::Action(())
+        ));
+        assert_eq!(log, ["a1", "b1", "c1"]);
+    }
+
+    #[test]
+    fn tuples_route_messages_with_an_id_path_to_a_single_handler() {
+        let handlers = (
+            |log: &mut Vec<String>, n: i64| log.push(format!("a{n}")),
+            |log: &mut Vec<String>, n: i64| log.push(format!("b{n}")),
+        );
+        let (_, mut state) = EventHandler::<_, (), i64>::build(&handlers, &mut view_cx());
+        let second = state.1 .0;
+        let mut log = Vec::new();
+        let result = EventHandler::<_, (), i64>::message(
+            &handlers,
+            &[second],
+            &mut state,
+            Box::new(2_i64),
+            &mut log,
+        );
+        assert!(matches!(result, MessageResult::Action(())));
+        assert_eq!(log, ["b2"]);
+
+        let result = EventHandler::<_, (), i64>::message(
+            &handlers,
+            &[Id::next()],
+            &mut state,
+            Box::new(3_i64),
+            &mut log,
+        );
+        assert!(matches!(result, MessageResult::Stale(_)));
+        assert_eq!(log, ["b2"]);
+    }
+
+    #[test]
+    fn message_results_are_combined_by_priority() {
+        use MessageResult::*;
+
+        let stale = || Stale(Box::new(()));
+        assert!(matches!(
+            combine_message_results(Action(1), Action(2)),
+            Action(1)
+        ));
+        assert!(matches!(
+            combine_message_results(stale(), Action(2)),
+            Action(2)
+        ));
+        assert!(matches!(
+            combine_message_results(Nop, RequestRebuild::<i32>),
+            RequestRebuild
+        ));
+        assert!(matches!(combine_message_results(stale(), Nop::<i32>), Nop));
+        assert!(matches!(
+            combine_message_results::<i32>(stale(), stale()),
+            Stale(_)
+        ));
+    }
+
+    #[test]
+    fn mapped_events_are_converted_before_handling() {
+        let add = |sum: &mut i64, n: i64| *sum += n;
+        let handler = add.map_event(|big: bool| if big { 10_i64 } else { 1 });
+        let mut sum = 0;
+        handle(&handler, true, &mut sum);
+        handle(&handler, false, &mut sum);
+        assert_eq!(sum, 11);
+    }
+
+    #[test]
+    fn filtered_events_are_ignored() {
+        let handler = (|sum: &mut i64, n: i64| *sum += n).filter(|n: &i64| *n > 0);
+        let mut sum = 0;
+        assert!(matches!(
+            handle(&handler, -5_i64, &mut sum),
+            MessageResult::Nop
+        ));
+        assert!(matches!(
+            handle(&handler, 3_i64, &mut sum),
+            MessageResult::Action(())
+        ));
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    fn events_are_only_handled_when_the_condition_holds() {
+        let handler = (|sum: &mut i64, n: i64| *sum += n).when(|sum: &i64| *sum < 10);
+        let mut sum = 0;
+        for _ in 0..5 {
+            handle(&handler, 4_i64, &mut sum);
         }
+        assert_eq!(sum, 12);
     }
 
-    fn current_style(&self) -> Style {
-        self.view.current_style()
+    #[test]
+    fn bounded_buffering_applies_backpressure() {
+        let mut state = fast_producer(StreamBuffering::Bounded(3));
+        let buffer = state.buffer.clone().unwrap();
+        wait_until(|| buffer.queue.lock().unwrap().len() == 3);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(buffer.queue.lock().unwrap().len(), 3);
+        assert!(!buffer.finished.load(Ordering::Acquire));
+
+        let mut received = Vec::new();
+        wait_until(|| {
+            assert!(buffer.queue.lock().unwrap().len() <= 3);
+            match state.poll() {
+                Some(StreamMessage::Begin(n) | StreamMessage::Update(n)) => {
+                    received.push(n);
+                    false
+                }
+                Some(StreamMessage::Finished) => true,
+                None => false,
+            }
+        });
+        assert_eq!(received, (1..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn drop_oldest_buffering_keeps_the_latest_items() {
+        let state = fast_producer(StreamBuffering::DropOldest(3));
+        assert_eq!(
+            consume_finished(state),
+            [
+                StreamMessage::Begin(8),
+                StreamMessage::Update(9),
+                StreamMessage::Update(10),
+                StreamMessage::Finished
+            ]
+        );
+    }
+
+    #[test]
+    fn coalesce_buffering_folds_pending_items() {
+        let state = fast_producer(StreamBuffering::coalesce(|sum, n| sum + n));
+        assert_eq!(
+            consume_finished(state),
+            [StreamMessage::Begin(55), StreamMessage::Finished]
+        );
+    }
+
+    #[test]
+    fn stream_buffering_is_compared_by_policy() {
+        let latest = StreamBuffering::<u32>::coalesce(|_older, newer| newer);
+        assert_eq!(latest, latest.clone());
+        assert_ne!(latest, StreamBuffering::coalesce(|_older, newer| newer));
+        assert_eq!(
+            StreamBuffering::<u32>::default(),
+            StreamBuffering::Bounded(1000)
+        );
+        assert_ne!(
+            StreamBuffering::<u32>::Bounded(3),
+            StreamBuffering::DropOldest(3)
+        );
     }
 }
@@ -3,45 +3,45 @@ use std::marker::PhantomData;
 use xilem_core::{Id, MessageResult};
 
 use crate::{
+    geometry::Length,
     widget::{self, ChangeFlags},
     Animatable, Cx, Fill, View, ViewMarker,
 };
 
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct FillMaxSize<V, P, T, A> {
+pub struct FillMaxSize<V, L, T, A> {
     pub(crate) content: V,
     // TODO making this animatable would be great too
     pub(crate) fill: Fill,
-    pub(crate) percent: P,
+    pub(crate) length: L,
     pub(crate) phantom: PhantomData<fn() -> (T, A)>,
 }
 
-impl<T, A, P, V> ViewMarker for FillMaxSize<V, P, T, A> {}
+impl<T, A, L, V> ViewMarker for FillMaxSize<V, L, T, A> {}
 
-pub struct FillMaxSizeState<CS, PS> {
+pub struct FillMaxSizeState<CS, LS> {
     content_state: CS,
     content_id: Id,
-    percent_state: PS,
-    percent_id: Id,
-    // percent: f64,
+    length_state: LS,
+    length_id: Id,
 }
 
-impl<T, A, P: Animatable<f64>, V: View<T, A>> View<T, A> for FillMaxSize<V, P, T, A> {
-    type State = FillMaxSizeState<V::State, P::State>;
+impl<T, A, L: Animatable<Length>, V: View<T, A>> View<T, A> for FillMaxSize<V, L, T, A> {
+    type State = FillMaxSizeState<V::State, L::State>;
 
-    type Element = widget::FillMaxSize<P::Element>;
+    type Element = widget::FillMaxSize<L::Element>;
 
     fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
         let (id, (state, element)) = cx.with_new_id(|cx| {
             let (content_id, content_state, element) = self.content.build(cx);
-            let (percent_id, percent_state, percent_element) = self.percent.build(cx);
-            let element = widget::FillMaxSize::new(element, self.fill, percent_element);
+            let (length_id, length_state, length_element) = self.length.build(cx);
+            let element = widget::FillMaxSize::new(element, self.fill, length_element);
             (
                 FillMaxSizeState {
                     content_state,
                     content_id,
-                    percent_state,
-                    percent_id,
+                    length_state,
+                    length_id,
                 },
                 element,
             )
@@ -60,12 +60,12 @@ impl<T, A, P: Animatable<f64>, V: View<T, A>> View<T, A> for FillMaxSize<V, P, T
         let mut changeflags = ChangeFlags::empty();
         changeflags |= element.set_fill(self.fill);
         cx.with_id(*id, |cx| {
-            changeflags |= self.percent.rebuild(
+            changeflags |= self.length.rebuild(
                 cx,
-                &prev.percent,
-                &mut state.percent_id,
-                &mut state.percent_state,
-                &mut element.percent,
+                &prev.length,
+                &mut state.length_id,
+                &mut state.length_state,
+                &mut element.length,
             );
 
             let content_el = element
@@ -97,10 +97,10 @@ impl<T, A, P: Animatable<f64>, V: View<T, A>> View<T, A> for FillMaxSize<V, P, T
                 self.content
                     .message(rest_path, &mut state.content_state, message, app_state)
             }
-            [id, rest_path @ ..] if *id == state.percent_id => {
+            [id, rest_path @ ..] if *id == state.length_id => {
                 match self
-                    .percent
-                    .message(rest_path, &mut state.percent_state, message)
+                    .length
+                    .message(rest_path, &mut state.length_state, message)
                 {
                     MessageResult::Action(_) | MessageResult::RequestRebuild => {
                         MessageResult::RequestRebuild
@@ -115,29 +115,29 @@ impl<T, A, P: Animatable<f64>, V: View<T, A>> View<T, A> for FillMaxSize<V, P, T
 }
 
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct FillMaxSizeStyle<P> {
+pub struct FillMaxSizeStyle<L> {
     pub fill: Fill,
-    pub percent: P,
+    pub length: L,
 }
 
 pub trait IntoFillMaxSizeStyle<An>: Sized
 where
-    An: Animatable<f64>,
+    An: Animatable<Length>,
 {
     fn into(self) -> FillMaxSizeStyle<An>;
 }
 
-impl<P: Animatable<f64>> IntoFillMaxSizeStyle<P> for FillMaxSizeStyle<P> {
-    fn into(self) -> FillMaxSizeStyle<P> {
+impl<L: Animatable<Length>> IntoFillMaxSizeStyle<L> for FillMaxSizeStyle<L> {
+    fn into(self) -> FillMaxSizeStyle<L> {
         self
     }
 }
 
-impl<P: Animatable<f64>> IntoFillMaxSizeStyle<P> for P {
-    fn into(self) -> FillMaxSizeStyle<P> {
+impl<L: Animatable<Length>> IntoFillMaxSizeStyle<L> for L {
+    fn into(self) -> FillMaxSizeStyle<L> {
         FillMaxSizeStyle {
             fill: Fill::ALL,
-            percent: self,
+            length: self,
         }
     }
 }
@@ -146,7 +146,7 @@ impl IntoFillMaxSizeStyle<f64> for Fill {
     fn into(self) -> FillMaxSizeStyle<f64> {
         FillMaxSizeStyle {
             fill: self,
-            percent: 1.0,
+            length: 1.0,
         }
     }
 }
@@ -156,7 +156,7 @@ impl IntoFillMaxSizeStyle<f64> for () {
     fn into(self) -> FillMaxSizeStyle<f64> {
         FillMaxSizeStyle {
             fill: Fill::ALL,
-            percent: 1.0,
+            length: 1.0,
         }
     }
 }
@@ -0,0 +1,243 @@
+use std::{cmp::Reverse, ops::Range, sync::Arc};
+
+use ratatui::style::Style;
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::ChangeFlags;
+
+use super::{v_stack, Cx, LinearLayout, View, ViewMarker};
+
+/// A match of a query within a text, see [`Matcher`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextMatch {
+    /// Higher is better, it's used to rank the items of [`filterable`].
+    pub score: i64,
+    /// The matched byte ranges of the text, which are highlighted.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Decides whether (and how well) a query matches a text, used by [`filterable`].
+///
+/// Every text should match an empty query.
+pub trait Matcher: Send + Sync {
+    fn find(&self, query: &str, text: &str) -> Option<TextMatch>;
+}
+
+fn chars_eq(a: char, b: char, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        a == b
+    } else {
+        a == b || a.to_lowercase().eq(b.to_lowercase())
+    }
+}
+
+/// Matches texts that contain the query, earlier matches are ranked higher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubstringMatcher {
+    pub case_sensitive: bool,
+}
+
+impl Matcher for SubstringMatcher {
+    fn find(&self, query: &str, text: &str) -> Option<TextMatch> {
+        if query.is_empty() {
+            return Some(TextMatch::default());
+        }
+        text.char_indices().find_map(|(start, _)| {
+            let mut text_chars = text[start..].char_indices();
+            for q in query.chars() {
+                match text_chars.next() {
+                    Some((_, c)) if chars_eq(c, q, self.case_sensitive) => (),
+                    _ => return None,
+                }
+            }
+            let end = text_chars.next().map_or(text.len(), |(i, _)| start + i);
+            let range = start..end;
+            Some(TextMatch {
+                score: -(start as i64),
+                ranges: vec![range],
+            })
+        })
+    }
+}
+
+/// Matches texts that contain all characters of the query in order, but not necessarily consecutive (case insensitive).
+///
+/// Consecutive matches and matches at the start of words are ranked higher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FuzzyMatcher;
+
+impl Matcher for FuzzyMatcher {
+    fn find(&self, query: &str, text: &str) -> Option<TextMatch> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut score = 0;
+        let mut query_chars = query.chars().peekable();
+        let mut prev_char = None;
+        for (i, c) in text.char_indices() {
+            let Some(&q) = query_chars.peek() else {
+                break;
+            };
+            if chars_eq(c, q, false) {
+                query_chars.next();
+                score += 16;
+                let is_word_start = !prev_char.is_some_and(char::is_alphanumeric);
+                if is_word_start {
+                    score += 8;
+                }
+                match ranges.last_mut() {
+                    Some(range) if range.end == i => {
+                        range.end = i + c.len_utf8();
+                        score += 8;
+                    }
+                    _ => ranges.push(i..i + c.len_utf8()),
+                }
+            } else if !ranges.is_empty() {
+                // penalize gaps after the first match
+                score -= 1;
+            }
+            prev_char = Some(c);
+        }
+        query_chars
+            .peek()
+            .is_none()
+            .then_some(TextMatch { score, ranges })
+    }
+}
+
+/// The query and style, which text views use to highlight matches within a [`filterable`].
+#[derive(Clone)]
+pub(crate) struct TextHighlight {
+    pub(crate) query: String,
+    pub(crate) matcher: Arc<dyn Matcher>,
+    pub(crate) style: Style,
+}
+
+impl TextHighlight {
+    pub(crate) fn ranges(&self, text: &str) -> Vec<Range<usize>> {
+        if self.query.is_empty() {
+            return Vec::new();
+        }
+        self.matcher
+            .find(&self.query, text)
+            .map(|m| m.ranges)
+            .unwrap_or_default()
+    }
+}
+
+/// A list filtered and ranked by a query, see [`filterable`].
+pub struct Filterable<T, A, V> {
+    content: LinearLayout<T, A, Vec<V>>,
+    query: String,
+    matcher: Arc<dyn Matcher>,
+    highlight_style: Option<Style>,
+}
+
+/// Shows the items, whose text matches `query` (as a substring, case insensitive) in a vertical stack, ranked by the match.
+///
+/// Each item is a tuple of the text to match and its view.
+/// Matched substrings are highlighted within all text views (e.g. `String`) inside the items,
+/// with [`Filterable::highlight_style`] (or [`FilterableTheme::highlight`](crate::view::FilterableTheme::highlight) by default).
+/// See [`filterable_with`] for a different [`Matcher`], e.g. [`FuzzyMatcher`].
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(String::from("an"), move |query: &mut String| {
+/// let fruits = ["Apple", "Banana", "Mango", "Orange"];
+/// v_stack((
+///     format!("Search: {query}"),
+///     filterable(fruits.map(|fruit| (fruit, fruit.to_string())), query),
+/// ))
+/// # });
+/// ```
+pub fn filterable<T, A, S, V>(
+    items: impl IntoIterator<Item = (S, V)>,
+    query: &str,
+) -> Filterable<T, A, V>
+where
+    S: AsRef<str>,
+    V: View<T, A>,
+{
+    filterable_with(items, query, SubstringMatcher::default())
+}
+
+/// Like [`filterable`], but with a custom [`Matcher`].
+pub fn filterable_with<T, A, S, V, M>(
+    items: impl IntoIterator<Item = (S, V)>,
+    query: &str,
+    matcher: M,
+) -> Filterable<T, A, V>
+where
+    S: AsRef<str>,
+    V: View<T, A>,
+    M: Matcher + 'static,
+{
+    let mut matches: Vec<_> = items
+        .into_iter()
+        .filter_map(|(text, view)| Some((matcher.find(query, text.as_ref())?.score, view)))
+        .collect();
+    // the sort is stable, so equally ranked items keep their order
+    matches.sort_by_key(|(score, _)| Reverse(*score));
+    Filterable {
+        content: v_stack(matches.into_iter().map(|(_, view)| view).collect()),
+        query: query.to_string(),
+        matcher: Arc::new(matcher),
+        highlight_style: None,
+    }
+}
+
+impl<T, A, V> Filterable<T, A, V> {
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = Some(style);
+        self
+    }
+
+    /// Runs `f` with the highlight of this filterable set in `cx`, so that text views within it can use it.
+    fn with_highlight<R>(&self, cx: &mut Cx, f: impl FnOnce(&mut Cx) -> R) -> R {
+        let highlight = TextHighlight {
+            query: self.query.clone(),
+            matcher: self.matcher.clone(),
+            style: self
+                .highlight_style
+                .unwrap_or(cx.theme().filterable.highlight),
+        };
+        let prev = cx.highlight.replace(Arc::new(highlight));
+        let result = f(cx);
+        cx.highlight = prev;
+        result
+    }
+}
+
+impl<T, A, V> ViewMarker for Filterable<T, A, V> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for Filterable<T, A, V> {
+    type State = <LinearLayout<T, A, Vec<V>> as View<T, A>>::State;
+
+    type Element = <LinearLayout<T, A, Vec<V>> as View<T, A>>::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        self.with_highlight(cx, |cx| self.content.build(cx))
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        self.with_highlight(cx, |cx| {
+            self.content.rebuild(cx, &prev.content, id, state, element)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.content.message(id_path, state, message, app_state)
+    }
+}
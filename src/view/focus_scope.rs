@@ -0,0 +1,87 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::{widget::ChangeFlags, Cx, View, ViewMarker};
+
+/// Confines `Tab` navigation to the focusable widgets within `content`, e.g. for dialogs or panels.
+///
+/// When the scope is removed, the focus is restored to the widget that was focused before it was added.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), move |()| {
+/// focus_scope(v_stack((
+///     "Delete the file?",
+///     h_stack((button("Yes", |_: &mut ()| ()), button("No", |_: &mut ()| ()))),
+/// )))
+/// .autofocus(true)
+/// # });
+/// ```
+pub fn focus_scope<T, A, V: View<T, A>>(content: V) -> FocusScope<V, T, A> {
+    FocusScope {
+        content,
+        autofocus: false,
+        phantom: PhantomData,
+    }
+}
+
+pub struct FocusScope<V, T, A> {
+    content: V,
+    autofocus: bool,
+    phantom: PhantomData<fn() -> (T, A)>,
+}
+
+impl<V, T, A> FocusScope<V, T, A> {
+    /// Focuses the first focusable widget within the scope, when it's added.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+}
+
+impl<V, T, A> ViewMarker for FocusScope<V, T, A> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for FocusScope<V, T, A> {
+    type State = V::State;
+
+    type Element = crate::widget::FocusScope;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = self.content.build(cx);
+        let element = crate::widget::FocusScope::new(element, self.autofocus);
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let changeflags = element.set_autofocus(self.autofocus);
+
+        let content_el = element
+            .content
+            .downcast_mut()
+            .expect("The focus scope widget changed its type, this should never happen!");
+
+        let content_changeflags = self
+            .content
+            .rebuild(cx, &prev.content, id, state, content_el);
+        changeflags | element.content.mark(content_changeflags)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.content.message(id_path, state, message, app_state)
+    }
+}
@@ -0,0 +1,60 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::{widget::ChangeFlags, Cx, View, ViewMarker};
+
+/// See [`ViewExt::focus_when`](crate::ViewExt::focus_when).
+pub struct FocusWhen<V, T, A> {
+    pub(crate) content: V,
+    pub(crate) condition: bool,
+    pub(crate) phantom: PhantomData<fn() -> (T, A)>,
+}
+
+impl<V, T, A> ViewMarker for FocusWhen<V, T, A> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for FocusWhen<V, T, A> {
+    type State = V::State;
+
+    type Element = crate::widget::FocusWhen;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = self.content.build(cx);
+        let element = crate::widget::FocusWhen::new(element, self.condition);
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let mut changeflags = ChangeFlags::empty();
+        if self.condition && !prev.condition {
+            changeflags |= element.request_focus();
+        }
+
+        let content_el = element
+            .content
+            .downcast_mut()
+            .expect("The focus when widget changed its type, this should never happen!");
+
+        let content_changeflags = self
+            .content
+            .rebuild(cx, &prev.content, id, state, content_el);
+        changeflags | element.content.mark(content_changeflags)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.content.message(id_path, state, message, app_state)
+    }
+}
@@ -0,0 +1,91 @@
+use std::{any::Any, sync::Arc};
+
+use xilem_core::{Id, MessageResult};
+
+use super::{Cx, View, ViewMarker};
+use crate::widget::{self, ChangeFlags};
+
+pub struct Freeze<V> {
+    frozen: bool,
+    content: Arc<V>,
+}
+
+/// While `frozen` is true, `content` isn't rebuilt or laid out, and the cells it has painted last are shown instead.
+///
+/// This is useful for content, which is expensive to build or lay out, but static while it's hidden, e.g. a tab in the background.
+/// The frozen content doesn't receive any events, its animations are paused and it can't be focused.
+/// When it's thawed, it's rebuilt with the current view.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// struct Dashboard {
+///     tab: usize,
+///     rows: Vec<String>,
+/// }
+///
+/// # App::new(Dashboard { tab: 0, rows: vec!["cpu".into(), "memory".into()] }, |dashboard: &mut Dashboard| {
+/// v_stack((
+///     button("Switch tab", |dashboard: &mut Dashboard| dashboard.tab = 1 - dashboard.tab),
+///     freeze(
+///         dashboard.tab != 0,
+///         v_stack(dashboard.rows.iter().map(|row| row.clone()).collect::<Vec<_>>()),
+///     ),
+/// ))
+/// # });
+/// ```
+pub fn freeze<V>(frozen: bool, content: V) -> Freeze<V> {
+    Freeze {
+        frozen,
+        content: Arc::new(content),
+    }
+}
+
+impl<V> ViewMarker for Freeze<V> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for Freeze<V>
+where
+    V::Element: 'static,
+{
+    /// The state of the content, and the view the content was rebuilt with last, as it isn't the previous view, when it was frozen.
+    type State = (V::State, Arc<V>);
+
+    type Element = widget::Freeze;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = self.content.build(cx);
+        let element = widget::Freeze::new(element, self.frozen);
+        (id, (state, self.content.clone()), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (state, content): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let changeflags = element.set_frozen(self.frozen);
+        if self.frozen {
+            return changeflags;
+        }
+        let content_el = element
+            .content
+            .downcast_mut()
+            .expect("The freeze content widget changed its type, this should never happen!");
+        let content_changeflags = self.content.rebuild(cx, content, id, state, content_el);
+        *content = self.content.clone();
+        changeflags | element.content.mark(content_changeflags)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (state, content): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        content.message(id_path, state, message, app_state)
+    }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use ratatui::style::Color;
+use xilem_core::{Id, MessageResult};
+
+use super::{Cx, View, ViewMarker};
+use crate::widget::{self, ChangeFlags, TooltipFn};
+
+/// The default color scale of a [`heatmap`], from no to high activity like GitHub contributions.
+pub const HEATMAP_GREENS: [Color; 5] = [
+    Color::Rgb(0x16, 0x1b, 0x22),
+    Color::Rgb(0x0e, 0x44, 0x29),
+    Color::Rgb(0x00, 0x6d, 0x32),
+    Color::Rgb(0x26, 0xa6, 0x41),
+    Color::Rgb(0x39, 0xd3, 0x53),
+];
+
+pub struct Heatmap {
+    values: Vec<Vec<f64>>,
+    colors: Vec<Color>,
+    range: Option<(f64, f64)>,
+    tooltip: TooltipFn,
+}
+
+/// A grid of colored cells for the rows of `values` (like GitHub contributions), `NaN` values are left empty.
+///
+/// The values are mapped to the colors of the scale (by default [`HEATMAP_GREENS`]) from the smallest to the largest value,
+/// see [`Heatmap::range`] to set a fixed range.
+/// The value under the mouse is shown in a tooltip above it, see [`Heatmap::tooltip`].
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(vec![vec![0.0, 3.0, 1.0], vec![5.0, f64::NAN, 2.0]], |commits: &mut Vec<Vec<f64>>| {
+/// heatmap(commits.clone())
+///     .range(0.0, 5.0)
+///     .tooltip(|weekday, week, commits| format!("{commits} commits on day {weekday} of week {week}"))
+/// # });
+/// ```
+pub fn heatmap(values: Vec<Vec<f64>>) -> Heatmap {
+    Heatmap {
+        values,
+        colors: HEATMAP_GREENS.to_vec(),
+        range: None,
+        tooltip: Arc::new(|_, _, value| value.to_string()),
+    }
+}
+
+impl Heatmap {
+    /// The color scale from the smallest to the largest value.
+    pub fn colors(mut self, colors: impl IntoIterator<Item = Color>) -> Self {
+        self.colors = colors.into_iter().collect();
+        self
+    }
+
+    /// Maps the values from `min` to `max` to the color scale, instead of the smallest to the largest value.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// The text of the tooltip for the hovered value, which is called with the row, column and the value.
+    pub fn tooltip(
+        mut self,
+        tooltip: impl Fn(usize, usize, f64) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.tooltip = Arc::new(tooltip);
+        self
+    }
+}
+
+impl ViewMarker for Heatmap {}
+
+impl<T, A> View<T, A> for Heatmap {
+    type State = ();
+
+    type Element = widget::Heatmap;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, element) = cx.with_new_id(|cx| {
+            widget::Heatmap::new(
+                self.values.clone(),
+                self.colors.clone(),
+                self.range,
+                self.tooltip.clone(),
+                cx.theme().heatmap,
+            )
+        });
+        (id, (), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        element.set_values(&self.values)
+            | element.set_colors(&self.colors)
+            | element.set_range(self.range)
+            | element.set_tooltip(self.tooltip.clone())
+            | element.set_styles(cx.theme().heatmap)
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        _app_state: &mut T,
+    ) -> MessageResult<A> {
+        MessageResult::Stale(message)
+    }
+}
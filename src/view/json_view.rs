@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags, TreeMessage};
+
+use super::{Cx, JsonTheme, TreeNode, View, ViewMarker};
+
+type CopyPathFn<T, A> = Arc<dyn Fn(&mut T, String) -> A + Send + Sync>;
+
+/// An inspector for JSON values, see [`json_view`].
+pub struct JsonView<T, A = ()> {
+    pub(crate) value: Arc<Value>,
+    pub(crate) on_copy_path: Option<CopyPathFn<T, A>>,
+}
+
+/// Displays a JSON value as a [`tree`](crate::view::tree), objects and arrays can be expanded and collapsed.
+///
+/// Values are styled based on their type (see [`JsonTheme`]).
+/// The tree is only recreated, when the value has changed, so passing the same `Arc<Value>` on every rebuild is cheap.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(String::new(), move |path: &mut String| {
+/// let value = serde_json::json!({ "users": [{ "name": "Ferris" }] });
+/// v_stack((
+///     format!("Copied: {path}"),
+///     json_view(value).on_copy_path(|path: &mut String, copied| *path = copied),
+/// ))
+/// # });
+/// ```
+pub fn json_view<T, A>(value: impl Into<Arc<Value>>) -> JsonView<T, A> {
+    JsonView {
+        value: value.into(),
+        on_copy_path: None,
+    }
+}
+
+impl<T, A> JsonView<T, A> {
+    /// Called with the path of a node (e.g. `.users[0].name`), when it's activated
+    /// (i.e. its label is clicked, or `Enter` is pressed while it's under the cursor).
+    pub fn on_copy_path(mut self, f: impl Fn(&mut T, String) -> A + Send + Sync + 'static) -> Self {
+        self.on_copy_path = Some(Arc::new(f));
+        self
+    }
+}
+
+/// Converts a JSON value into tree nodes, which have their path as key.
+fn json_nodes(value: &Value, path: &str, theme: &JsonTheme) -> Vec<TreeNode<String>> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| {
+                let is_identifier = !key.is_empty()
+                    && !key.starts_with(|c: char| c.is_ascii_digit())
+                    && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                let path = if is_identifier {
+                    format!("{path}.{key}")
+                } else {
+                    let key = serde_json::to_string(key).expect("strings are valid JSON");
+                    format!("{path}[{key}]")
+                };
+                json_node(key, value, path, theme)
+            })
+            .collect(),
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                json_node(&index.to_string(), value, format!("{path}[{index}]"), theme)
+            })
+            .collect(),
+        scalar => vec![json_node(
+            "",
+            scalar,
+            if path.is_empty() {
+                ".".into()
+            } else {
+                path.into()
+            },
+            theme,
+        )],
+    }
+}
+
+fn json_node(key: &str, value: &Value, path: String, theme: &JsonTheme) -> TreeNode<String> {
+    let (summary, style) = match value {
+        Value::Object(map) => (format!("{{…}} {} keys", map.len()), theme.container),
+        Value::Array(values) => (format!("[…] {} items", values.len()), theme.container),
+        Value::String(string) => (format!("{string:?}"), theme.string),
+        Value::Number(number) => (number.to_string(), theme.number),
+        Value::Bool(bool) => (bool.to_string(), theme.bool),
+        Value::Null => ("null".into(), theme.null),
+    };
+    let label = if key.is_empty() {
+        summary
+    } else {
+        format!("{key}: {summary}")
+    };
+    match value {
+        Value::Object(_) | Value::Array(_) => {
+            let children = json_nodes(value, &path, theme);
+            TreeNode::branch(path, label, children).style(style)
+        }
+        _ => TreeNode::leaf(path, label).style(style),
+    }
+}
+
+impl<T, A> ViewMarker for JsonView<T, A> {}
+
+impl<T: 'static, A: 'static> View<T, A> for JsonView<T, A> {
+    type State = ();
+
+    type Element = widget::Tree<String>;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, element) = cx.with_new_id(|cx| {
+            let nodes = json_nodes(&self.value, "", &cx.theme().json);
+            widget::Tree::new(cx.id_path(), nodes, cx.theme().tree)
+        });
+        (id, (), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let mut changeflags = element.set_styles(cx.theme().tree);
+        if !Arc::ptr_eq(&self.value, &prev.value) && self.value != prev.value {
+            changeflags |= element.set_nodes(json_nodes(&self.value, "", &cx.theme().json));
+        }
+        changeflags
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        _state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        if !id_path.is_empty() {
+            return MessageResult::Stale(message);
+        }
+        match message.downcast::<TreeMessage<String>>() {
+            Ok(message) => match (*message, &self.on_copy_path) {
+                (TreeMessage::Activate(path), Some(on_copy_path)) => {
+                    MessageResult::Action(on_copy_path(app_state, path))
+                }
+                _ => MessageResult::Nop,
+            },
+            Err(message) => MessageResult::Stale(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::view::TreeChildren;
+
+    fn paths(nodes: &[TreeNode<String>]) -> Vec<String> {
+        nodes
+            .iter()
+            .flat_map(|node| {
+                let children = match &node.children {
+                    TreeChildren::Loaded(children) => paths(children),
+                    _ => Vec::new(),
+                };
+                std::iter::once(node.key.clone()).chain(children)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keys_are_escaped_as_json_strings() {
+        let value = json!({ "users": [{ "first name": "Ferris", "say \"hi\"\n": true }] });
+        let nodes = json_nodes(&value, "", &JsonTheme::default());
+        assert_eq!(
+            paths(&nodes),
+            [
+                ".users",
+                ".users[0]",
+                r#".users[0]["first name"]"#,
+                r#".users[0]["say \"hi\"\n"]"#,
+            ]
+        );
+    }
+}
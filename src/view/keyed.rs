@@ -0,0 +1,238 @@
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{ChangeFlags, Pod};
+
+use super::{Cx, ElementsSplice, View, ViewSequence};
+
+/// A view sequence of `(key, view)` pairs, see [`keyed`].
+pub struct Keyed<K, V> {
+    items: Vec<(K, V)>,
+}
+
+/// A sequence of views, which are identified by their key instead of their position.
+///
+/// When the items are reordered, the widgets are moved with their state (e.g. the offset of a
+/// [`scroll_view`](crate::view::scroll_view) or the cursor of a text input) instead of being rebuilt at the new position.
+/// Items with a new key are built, and items whose key is gone are removed.
+///
+/// The keys should be unique, when a key appears multiple times, only the first item keeps its state.
+///
+/// Messages are routed directly to the child via an index of the child ids,
+/// instead of asking every child in turn like a `Vec` of views, which makes a difference for long lists.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let mut files = vec!["b.rs", "a.rs", "c.rs"];
+/// files.sort();
+/// App::new(files, |files| {
+///     v_stack(keyed(files.iter().map(|&file| (file, scroll_view(file)))))
+/// });
+/// ```
+pub fn keyed<K, V>(items: impl IntoIterator<Item = (K, V)>) -> Keyed<K, V> {
+    Keyed {
+        items: items.into_iter().collect(),
+    }
+}
+
+/// The state of a [`Keyed`] sequence, one slot per element in the parent.
+pub struct KeyedState<K, S> {
+    /// `None` for a placeholder, that was left behind by a child which was moved further back, see [`Pod::take`].
+    slots: Vec<Option<KeyedChild<K, S>>>,
+    /// The index of the slot and of the item by the id of the child, for routing messages.
+    index: HashMap<Id, (usize, usize)>,
+}
+
+impl<K, S> KeyedState<K, S> {
+    fn new(slots: Vec<Option<KeyedChild<K, S>>>) -> Self {
+        let mut state = KeyedState {
+            slots,
+            index: HashMap::new(),
+        };
+        state.update_index();
+        state
+    }
+
+    fn update_index(&mut self) {
+        self.index.clear();
+        // the children are in the order of the items, the placeholders in between have no item
+        let children = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, slot)| Some((i, slot.as_ref()?)));
+        for (item, (slot, child)) in children.enumerate() {
+            self.index.entry(child.id).or_insert((slot, item));
+        }
+    }
+}
+
+struct KeyedChild<K, S> {
+    key: K,
+    id: Id,
+    state: S,
+}
+
+/// A child that was taken out of the elements, while it's moved to another position,
+/// so that neither its widget nor its view state is dropped.
+struct GhostPod<S> {
+    pod: Pod,
+    id: Id,
+    state: S,
+}
+
+impl<T, A, K, V> ViewSequence<T, A> for Keyed<K, V>
+where
+    K: Clone + Eq + Hash + Send + Sync,
+    V: View<T, A>,
+    V::Element: 'static,
+{
+    type State = KeyedState<K, V::State>;
+
+    fn build(&self, cx: &mut Cx, elements: &mut dyn ElementsSplice) -> Self::State {
+        let slots = self
+            .items
+            .iter()
+            .map(|(key, view)| {
+                let (id, state, pod) = cx.with_new_pod(|cx| view.build(cx));
+                elements.push(pod, cx);
+                Some(KeyedChild {
+                    key: key.clone(),
+                    id,
+                    state,
+                })
+            })
+            .collect();
+        KeyedState::new(slots)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        state: &mut Self::State,
+        elements: &mut dyn ElementsSplice,
+    ) -> ChangeFlags {
+        let prev_views: HashMap<&K, &V> = prev.items.iter().map(|(k, v)| (k, v)).collect();
+        let new_keys: HashSet<&K> = self.items.iter().map(|(key, _)| key).collect();
+        // the keys of the children, which are not yet visited
+        let mut remaining: HashSet<K> = state
+            .slots
+            .iter()
+            .flatten()
+            .map(|c| c.key.clone())
+            .collect();
+        let mut old_slots = std::mem::take(&mut state.slots).into_iter().peekable();
+        let mut stash: HashMap<K, GhostPod<V::State>> = HashMap::new();
+        let mut changeflags = ChangeFlags::empty();
+
+        for (key, view) in &self.items {
+            // Remove placeholders and children that are gone, and move children out of the way,
+            // when the child for `key` is further back.
+            while let Some(slot) = old_slots.peek() {
+                match slot {
+                    Some(child) if child.key == *key => break,
+                    Some(child) if new_keys.contains(&child.key) => {
+                        if !remaining.contains(key) {
+                            // the child for `key` is new or stashed, and can be inserted in front
+                            break;
+                        }
+                        let child = old_slots.next().flatten().unwrap();
+                        remaining.remove(&child.key);
+                        let pod = elements.mutate(cx).take();
+                        state.slots.push(None);
+                        let ghost = GhostPod {
+                            pod,
+                            id: child.id,
+                            state: child.state,
+                        };
+                        stash.entry(child.key).or_insert(ghost);
+                    }
+                    _ => {
+                        if let Some(child) = old_slots.next().flatten() {
+                            remaining.remove(&child.key);
+                        }
+                        elements.delete(1, cx);
+                    }
+                }
+                changeflags |= ChangeFlags::tree_structure();
+            }
+
+            let reused = match old_slots.peek() {
+                Some(Some(child)) if child.key == *key => old_slots.next().flatten(),
+                _ => None,
+            };
+            let child = if let Some(mut child) = reused {
+                remaining.remove(key);
+                let prev_view = prev_views[key];
+                let pod = elements.mutate(cx);
+                let flags = cx.with_pod(pod, |el, cx| {
+                    view.rebuild(cx, prev_view, &mut child.id, &mut child.state, el)
+                });
+                changeflags |= elements.mark(flags, cx);
+                child
+            } else if let Some(mut ghost) = stash.remove(key) {
+                let prev_view = prev_views[key];
+                let flags = cx.with_pod(&mut ghost.pod, |el, cx| {
+                    view.rebuild(cx, prev_view, &mut ghost.id, &mut ghost.state, el)
+                });
+                changeflags |= ghost.pod.mark(flags) | ChangeFlags::tree_structure();
+                elements.push(ghost.pod, cx);
+                KeyedChild {
+                    key: key.clone(),
+                    id: ghost.id,
+                    state: ghost.state,
+                }
+            } else {
+                let (id, child_state, pod) = cx.with_new_pod(|cx| view.build(cx));
+                elements.push(pod, cx);
+                changeflags |= ChangeFlags::tree_structure();
+                KeyedChild {
+                    key: key.clone(),
+                    id,
+                    state: child_state,
+                }
+            };
+            state.slots.push(Some(child));
+        }
+
+        let n_delete = old_slots.count();
+        if n_delete > 0 {
+            elements.delete(n_delete, cx);
+            changeflags |= ChangeFlags::tree_structure();
+        }
+        state.update_index();
+        changeflags
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        let Some((first, rest_path)) = id_path.split_first() else {
+            return MessageResult::Stale(message);
+        };
+        let Some(&(slot, item)) = state.index.get(first) else {
+            return MessageResult::Stale(message);
+        };
+        match (state.slots[slot].as_mut(), self.items.get(item)) {
+            (Some(child), Some((_, view))) => {
+                view.message(rest_path, &mut child.state, message, app_state)
+            }
+            _ => MessageResult::Stale(message),
+        }
+    }
+
+    fn count(&self, state: &Self::State) -> usize {
+        state.slots.len()
+    }
+}
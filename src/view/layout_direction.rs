@@ -0,0 +1,90 @@
+use std::any::Any;
+
+use xilem_core::{Id, MessageResult};
+
+use super::{Cx, View, ViewMarker};
+use crate::widget::ChangeFlags;
+
+/// The direction in which horizontal content flows, see [`layout_direction`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    /// For right-to-left scripts like Arabic or Hebrew.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub fn is_rtl(self) -> bool {
+        self == LayoutDirection::RightToLeft
+    }
+}
+
+/// Sets the layout direction for `content`.
+///
+/// With [`LayoutDirection::RightToLeft`], horizontal stacks and margins are mirrored,
+/// and text is aligned to the right with right-to-left as base direction for bidirectional text.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), move |()| {
+/// layout_direction(
+///     LayoutDirection::RightToLeft,
+///     h_stack(("שלום", "עולם".margin((Position::LEFT, 1)))),
+/// )
+/// # });
+/// ```
+pub fn layout_direction<V>(direction: LayoutDirection, content: V) -> WithLayoutDirection<V> {
+    WithLayoutDirection { direction, content }
+}
+
+pub struct WithLayoutDirection<V> {
+    direction: LayoutDirection,
+    content: V,
+}
+
+impl<V> WithLayoutDirection<V> {
+    /// Runs `f` with the layout direction set in `cx`, so that views within it can use it.
+    fn with_direction<R>(&self, cx: &mut Cx, f: impl FnOnce(&mut Cx) -> R) -> R {
+        let prev = std::mem::replace(&mut cx.layout_direction, self.direction);
+        let result = f(cx);
+        cx.layout_direction = prev;
+        result
+    }
+}
+
+impl<V> ViewMarker for WithLayoutDirection<V> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for WithLayoutDirection<V> {
+    type State = V::State;
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        self.with_direction(cx, |cx| self.content.build(cx))
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        self.with_direction(cx, |cx| {
+            self.content.rebuild(cx, &prev.content, id, state, element)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.content.message(id_path, state, message, app_state)
+    }
+}
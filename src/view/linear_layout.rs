@@ -6,14 +6,115 @@ use crate::{
 use std::{any::Any, marker::PhantomData};
 use xilem_core::{Id, VecSplice};
 
+/// How the children of a [`LinearLayout`] are arranged along its main axis (e.g. vertically for a [`v_stack`]),
+/// when there's more space than the children need.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MainAxisAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// The remaining space is distributed evenly between the children.
+    SpaceBetween,
+}
+
+/// How the children of a [`LinearLayout`] are aligned on its cross axis (e.g. horizontally for a [`v_stack`]).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CrossAxisAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// The children fill the cross axis, i.e. they are as wide (for a [`v_stack`]) as the available space,
+    /// or the widest child, if the space is unbounded.
+    Stretch,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct LinearLayout<T, A, VT> {
     children: VT,
     axis: Axis,
     spacing: f64,
+    main_axis_alignment: MainAxisAlignment,
+    cross_axis_alignment: CrossAxisAlignment,
+    reverse: bool,
     phantom: PhantomData<fn() -> (T, A)>,
 }
 
+impl<T, A, VT> LinearLayout<T, A, VT> {
+    /// The number of empty cells between the children.
+    pub fn gap(mut self, cells: u16) -> Self {
+        self.spacing = cells as f64;
+        self
+    }
+
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new((), move |()| {
+    /// h_stack(("Left", "Right")).main_axis_alignment(MainAxisAlignment::SpaceBetween)
+    /// # });
+    /// ```
+    pub fn main_axis_alignment(mut self, alignment: MainAxisAlignment) -> Self {
+        self.main_axis_alignment = alignment;
+        self
+    }
+
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new((), move |()| {
+    /// v_stack(("Title", "Centered below the title"))
+    ///     .cross_axis_alignment(CrossAxisAlignment::Center)
+    ///     .gap(1)
+    /// # });
+    /// ```
+    pub fn cross_axis_alignment(mut self, alignment: CrossAxisAlignment) -> Self {
+        self.cross_axis_alignment = alignment;
+        self
+    }
+
+    /// Arranges the children in reverse order, i.e. the first child at the end of the main axis.
+    pub fn reverse(mut self) -> Self {
+        self.reverse = !self.reverse;
+        self
+    }
+
+    /// The alignments and whether the children are reversed,
+    /// mirrored horizontally in a right-to-left [`layout_direction`](super::layout_direction).
+    fn arrangement(&self, cx: &Cx) -> (MainAxisAlignment, CrossAxisAlignment, bool) {
+        if !cx.layout_direction().is_rtl() {
+            return (
+                self.main_axis_alignment,
+                self.cross_axis_alignment,
+                self.reverse,
+            );
+        }
+        match self.axis {
+            Axis::Horizontal => {
+                let main_axis_alignment = match self.main_axis_alignment {
+                    MainAxisAlignment::Start => MainAxisAlignment::End,
+                    MainAxisAlignment::End => MainAxisAlignment::Start,
+                    alignment => alignment,
+                };
+                (
+                    main_axis_alignment,
+                    self.cross_axis_alignment,
+                    !self.reverse,
+                )
+            }
+            Axis::Vertical => {
+                let cross_axis_alignment = match self.cross_axis_alignment {
+                    CrossAxisAlignment::Start => CrossAxisAlignment::End,
+                    CrossAxisAlignment::End => CrossAxisAlignment::Start,
+                    alignment => alignment,
+                };
+                (self.main_axis_alignment, cross_axis_alignment, self.reverse)
+            }
+        }
+    }
+}
+
 impl<T, A, VT> ViewMarker for LinearLayout<T, A, VT> {}
 
 impl<T, A, VT: ViewSequence<T, A>> View<T, A> for LinearLayout<T, A, VT> {
@@ -28,7 +129,11 @@ impl<T, A, VT: ViewSequence<T, A>> View<T, A> for LinearLayout<T, A, VT> {
             self.children
                 .build(cx, &mut VecSplice::new(&mut elements, &mut scratch))
         });
-        let column = widget::LinearLayout::new(elements, self.spacing, self.axis);
+        let (main_axis_alignment, cross_axis_alignment, reverse) = self.arrangement(cx);
+        let mut column = widget::LinearLayout::new(elements, self.spacing, self.axis);
+        let _ = column.set_main_axis_alignment(main_axis_alignment)
+            | column.set_cross_axis_alignment(cross_axis_alignment)
+            | column.set_reverse(reverse);
         (id, state, column)
     }
 
@@ -40,13 +145,20 @@ impl<T, A, VT: ViewSequence<T, A>> View<T, A> for LinearLayout<T, A, VT> {
         state: &mut Self::State,
         element: &mut Self::Element,
     ) -> ChangeFlags {
+        let (main_axis_alignment, cross_axis_alignment, reverse) = self.arrangement(cx);
+        let changeflags = element.set_spacing(self.spacing)
+            | element.set_main_axis_alignment(main_axis_alignment)
+            | element.set_cross_axis_alignment(cross_axis_alignment)
+            | element.set_reverse(reverse);
+
         let mut scratch = vec![];
         let mut splice = VecSplice::new(&mut element.children, &mut scratch);
 
-        cx.with_id(*id, |cx| {
-            self.children
-                .rebuild(cx, &prev.children, state, &mut splice)
-        })
+        changeflags
+            | cx.with_id(*id, |cx| {
+                self.children
+                    .rebuild(cx, &prev.children, state, &mut splice)
+            })
     }
 
     fn message(
@@ -60,20 +172,53 @@ impl<T, A, VT: ViewSequence<T, A>> View<T, A> for LinearLayout<T, A, VT> {
     }
 }
 
+/// Stacks the `children` horizontally, they're given as a [`ViewSequence`], e.g. a tuple of up to 12 views or a `Vec` of views.
+///
+/// When the children don't fit into the available width, it's shared between them by their intrinsic widths (see [`Widget::measure`](crate::widget::Widget::measure)),
+/// i.e. wrapped text is wrapped to its share of the width and gets as many lines as it needs.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let text = render_to_string::<(), _>(
+///     h_stack(("one two three".wrapped(), "four five".wrapped())),
+///     (10, 3),
+///     ScreenshotFormat::Plain,
+/// );
+/// assert_eq!(text, "one  four\ntwo  five\nthree\n");
+/// ```
 pub fn h_stack<T, A, VT: ViewSequence<T, A>>(children: VT) -> LinearLayout<T, A, VT> {
     LinearLayout {
         children,
         spacing: 0.0,
         axis: Axis::Horizontal,
+        main_axis_alignment: MainAxisAlignment::Start,
+        cross_axis_alignment: CrossAxisAlignment::Start,
+        reverse: false,
         phantom: PhantomData,
     }
 }
 
+/// Stacks the `children` vertically, they're given as a [`ViewSequence`], e.g. a tuple of up to 12 views or a `Vec` of views.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), move |()| {
+/// v_stack((
+///     "January", "February", "March", "April", "May", "June",
+///     "July", "August", "September", "October", "November", "December",
+/// ))
+/// # });
+/// ```
 pub fn v_stack<T, A, VT: ViewSequence<T, A>>(children: VT) -> LinearLayout<T, A, VT> {
     LinearLayout {
         children,
         spacing: 0.0,
         axis: Axis::Vertical,
+        main_axis_alignment: MainAxisAlignment::Start,
+        cross_axis_alignment: CrossAxisAlignment::Start,
+        reverse: false,
         phantom: PhantomData,
     }
 }
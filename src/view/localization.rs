@@ -0,0 +1,359 @@
+use std::{any::Any, borrow::Cow, collections::HashMap, sync::Arc};
+
+use ratatui::style::{Color, Modifier, Style};
+use xilem_core::{Id, MessageResult};
+
+use super::{common::Styleable, Cx, View, ViewMarker};
+use crate::widget::{self, ChangeFlags};
+
+/// The translations of an app, used by [`localized`] (and [`tr!`](crate::tr)) views,
+/// set for the whole app via [`App::with_catalog`](crate::App::with_catalog).
+///
+/// Locales are matched ignoring the encoding (e.g. `de_AT.UTF-8` is `de-AT`),
+/// and if there's no translation for the region, the one for the language (e.g. `de`) is used,
+/// then the one of the fallback locale, and finally the key itself.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    translations: HashMap<String, HashMap<String, String>>,
+    fallback: Option<String>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the translations (key, text) for the `locale`.
+    pub fn translations<K, V>(
+        mut self,
+        locale: &str,
+        translations: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.translations
+            .entry(normalize_locale(locale))
+            .or_default()
+            .extend(
+                translations
+                    .into_iter()
+                    .map(|(key, text)| (key.into(), text.into())),
+            );
+        self
+    }
+
+    /// The locale, that is used if a key isn't translated for the current locale.
+    pub fn fallback(mut self, locale: &str) -> Self {
+        self.fallback = Some(normalize_locale(locale));
+        self
+    }
+
+    /// Looks up the translation of `key` for the `locale`.
+    pub fn translate(&self, locale: &str, key: &str) -> Option<&str> {
+        let locale = normalize_locale(locale);
+        let language = locale.split('-').next().unwrap_or_default();
+        let lookup = |locale: &str| Some(self.translations.get(locale)?.get(key)?.as_str());
+        lookup(&locale)
+            .or_else(|| lookup(language))
+            .or_else(|| lookup(self.fallback.as_deref()?))
+    }
+}
+
+/// Strips the encoding and modifier (e.g. `.UTF-8`) and uses `-` as separator, e.g. `de_AT.UTF-8` becomes `de-AT`.
+pub(super) fn normalize_locale(locale: &str) -> String {
+    locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-")
+}
+
+/// The locale of the system from the `LC_ALL`, `LC_MESSAGES` or `LANG` environment variables, `en` if neither is set.
+pub(crate) fn system_locale() -> Arc<str> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|locale| !locale.is_empty() && locale != "C" && locale != "POSIX")
+        .map(|locale| normalize_locale(&locale).into())
+        .unwrap_or_else(|| "en".into())
+}
+
+/// Sets the locale for the localized views within `content`, e.g. to switch the language at runtime.
+///
+/// By default the locale of the system is used.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new("de".to_string(), move |language: &mut String| {
+/// locale(
+///     language.clone(),
+///     v_stack((
+///         localized("greeting"),
+///         button("English", |language: &mut String| *language = "en".into()),
+///         button("Deutsch", |language: &mut String| *language = "de".into()),
+///     )),
+/// )
+/// # }).with_catalog(
+/// #     Catalog::new()
+/// #         .translations("en", [("greeting", "Hello")])
+/// #         .translations("de", [("greeting", "Hallo")]),
+/// # );
+/// ```
+pub fn locale<V>(locale: impl Into<Arc<str>>, content: V) -> Locale<V> {
+    Locale {
+        locale: locale.into(),
+        content,
+    }
+}
+
+pub struct Locale<V> {
+    locale: Arc<str>,
+    content: V,
+}
+
+impl<V> Locale<V> {
+    /// Runs `f` with the locale set in `cx`, so that localized views within it can use it.
+    fn with_locale<R>(&self, cx: &mut Cx, f: impl FnOnce(&mut Cx) -> R) -> R {
+        let prev = std::mem::replace(&mut cx.locale, self.locale.clone());
+        let result = f(cx);
+        cx.locale = prev;
+        result
+    }
+}
+
+impl<V> ViewMarker for Locale<V> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for Locale<V> {
+    type State = V::State;
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        self.with_locale(cx, |cx| self.content.build(cx))
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        self.with_locale(cx, |cx| {
+            self.content.rebuild(cx, &prev.content, id, state, element)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.content.message(id_path, state, message, app_state)
+    }
+}
+
+/// A text view, which shows the translation of `key` in the current [`locale`] from the app's [`Catalog`].
+///
+/// Placeholders like `{name}` in the translation are replaced with the arguments given with [`Localized::arg`],
+/// see also the [`tr!`](crate::tr) macro.
+pub fn localized(key: impl Into<Cow<'static, str>>) -> Localized {
+    Localized {
+        key: key.into(),
+        args: Vec::new(),
+        style: Style::default(),
+    }
+}
+
+/// Creates a [`localized`] text view, with optional arguments for the placeholders.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(3, move |count: &mut i32| {
+/// v_stack((tr!("title"), tr!("items", count = count)))
+/// # }).with_catalog(Catalog::new().translations("en", [("title", "Shop"), ("items", "{count} items")]));
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::localized($key)$(.arg(stringify!($name), $value))*
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Localized {
+    key: Cow<'static, str>,
+    args: Vec<(Cow<'static, str>, String)>,
+    style: Style,
+}
+
+impl Localized {
+    /// Replaces the placeholder `{name}` in the translation with `value`.
+    pub fn arg(mut self, name: impl Into<Cow<'static, str>>, value: impl ToString) -> Self {
+        self.args.push((name.into(), value.to_string()));
+        self
+    }
+
+    fn text(&self, cx: &Cx) -> Cow<'static, str> {
+        substitute_args(cx.translate(&self.key), &self.args).into()
+    }
+}
+
+/// Replaces the placeholders in `text` in a single pass, so that placeholders within the values are kept as they are.
+///
+/// Unknown placeholders and unmatched braces are kept as well.
+fn substitute_args(text: &str, args: &[(Cow<'static, str>, String)]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let placeholder = &rest[start..];
+        let arg = placeholder.find('}').and_then(|end| {
+            let (_, value) = args.iter().find(|(name, _)| name == &placeholder[1..end])?;
+            Some((value, end))
+        });
+        match arg {
+            Some((value, end)) => {
+                result.push_str(value);
+                rest = &placeholder[end + 1..];
+            }
+            None => {
+                result.push('{');
+                rest = &placeholder[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+impl ViewMarker for Localized {}
+
+impl<T, A> View<T, A> for Localized {
+    type State = ();
+
+    type Element = widget::Text;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, mut element) = cx.with_new_id(|cx| widget::Text::new(self.text(cx), self.style));
+        let _ = element.set_rtl(cx.layout_direction().is_rtl());
+        (id, (), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        // the locale may have changed, even when the key didn't
+        element.set_text(self.text(cx))
+            | element.set_style(self.style)
+            | element.set_rtl(cx.layout_direction().is_rtl())
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        _message: Box<dyn Any>,
+        _app_state: &mut T,
+    ) -> MessageResult<A> {
+        MessageResult::Nop
+    }
+}
+
+impl Styleable for Localized {
+    type Output = Self;
+
+    fn fg(mut self, color: Color) -> Self::Output {
+        self.style.fg = Some(color);
+        self
+    }
+
+    fn bg(mut self, color: Color) -> Self::Output {
+        self.style.bg = Some(color);
+        self
+    }
+
+    fn modifier(mut self, modifier: Modifier) -> Self::Output {
+        self.style = self.style.add_modifier(modifier);
+        self
+    }
+
+    fn style(mut self, style: Style) -> Self::Output {
+        self.style = style;
+        self
+    }
+
+    fn current_style(&self) -> Style {
+        self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::runtime::Runtime;
+
+    use crate::app::WakeQueue;
+
+    use super::*;
+
+    fn catalog() -> Catalog {
+        Catalog::new()
+            .translations("de_AT", [("greeting", "Servus")])
+            .translations("de", [("greeting", "Hallo"), ("farewell", "Tschüss")])
+            .translations(
+                "en",
+                [
+                    ("greeting", "Hello"),
+                    ("farewell", "Bye"),
+                    ("thanks", "Thanks"),
+                ],
+            )
+            .fallback("en")
+    }
+
+    #[test]
+    fn translations_fall_back_from_the_region_to_the_language_and_the_fallback_locale() {
+        let catalog = catalog();
+        assert_eq!(catalog.translate("de_AT.UTF-8", "greeting"), Some("Servus"));
+        assert_eq!(catalog.translate("de-AT", "farewell"), Some("Tschüss"));
+        assert_eq!(catalog.translate("de_CH", "greeting"), Some("Hallo"));
+        assert_eq!(catalog.translate("de_AT.UTF-8", "thanks"), Some("Thanks"));
+        assert_eq!(catalog.translate("de_AT.UTF-8", "missing"), None);
+    }
+
+    #[test]
+    fn localized_text_falls_back_to_the_key() {
+        let (wake_queue, _wake_rx) = WakeQueue::new(Default::default());
+        let (message_tx, _message_rx) = tokio::sync::mpsc::channel(1);
+        let mut cx = Cx::new(&wake_queue, &message_tx, Arc::new(Runtime::new().unwrap()));
+        cx.catalog = Arc::new(catalog());
+        cx.locale = "de_AT.UTF-8".into();
+        assert_eq!(localized("greeting").text(&cx), "Servus");
+        assert_eq!(localized("thanks").text(&cx), "Thanks");
+        assert_eq!(localized("missing").text(&cx), "missing");
+    }
+
+    #[test]
+    fn arguments_are_substituted_in_a_single_pass() {
+        let args = [
+            (Cow::Borrowed("name"), "{count}".to_string()),
+            (Cow::Borrowed("count"), "3".to_string()),
+        ];
+        assert_eq!(
+            substitute_args("{name} has {count} {items} {", &args),
+            "{count} has 3 {items} {"
+        );
+    }
+}
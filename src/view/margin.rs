@@ -1,30 +1,61 @@
 use std::marker::PhantomData;
 
-use xilem_core::MessageResult;
+use xilem_core::{Id, MessageResult};
 
 use crate::{
+    geometry::Length,
     widget::{self, ChangeFlags},
-    Cx, Position, View, ViewMarker,
+    Animatable, Cx, Position, View, ViewMarker,
 };
 
-#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct Margin<V, T, A> {
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Margin<V, L, T, A> {
     pub(crate) content: V,
-    pub(crate) amount: u16,
+    pub(crate) amount: L,
     pub(crate) position: Position,
     pub(crate) phantom: PhantomData<fn() -> (T, A)>,
 }
 
-impl<T, A, V> ViewMarker for Margin<V, T, A> {}
+impl<V, L, T, A> Margin<V, L, T, A> {
+    /// The sides of the margin, mirrored in a right-to-left [`layout_direction`](super::layout_direction).
+    fn position(&self, cx: &Cx) -> Position {
+        if cx.layout_direction().is_rtl() {
+            self.position.mirrored()
+        } else {
+            self.position
+        }
+    }
+}
+
+impl<T, A, L, V> ViewMarker for Margin<V, L, T, A> {}
 
-impl<T, A, V: View<T, A>> View<T, A> for Margin<V, T, A> {
-    type State = V::State;
+pub struct MarginState<CS, LS> {
+    content_state: CS,
+    content_id: Id,
+    amount_state: LS,
+    amount_id: Id,
+}
+
+impl<T, A, L: Animatable<Length>, V: View<T, A>> View<T, A> for Margin<V, L, T, A> {
+    type State = MarginState<V::State, L::State>;
 
-    type Element = widget::Margin;
+    type Element = widget::Margin<L::Element>;
 
-    fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
-        let (id, state, element) = self.content.build(cx);
-        let element = widget::Margin::new(element, self.position, self.amount);
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (content_id, content_state, element) = self.content.build(cx);
+            let (amount_id, amount_state, amount_element) = self.amount.build(cx);
+            let element = widget::Margin::new(element, self.position(cx), amount_element);
+            (
+                MarginState {
+                    content_state,
+                    content_id,
+                    amount_state,
+                    amount_id,
+                },
+                element,
+            )
+        });
         (id, state, element)
     }
 
@@ -32,59 +63,137 @@ impl<T, A, V: View<T, A>> View<T, A> for Margin<V, T, A> {
         &self,
         cx: &mut Cx,
         prev: &Self,
-        id: &mut xilem_core::Id,
+        id: &mut Id,
         state: &mut Self::State,
         element: &mut Self::Element,
-    ) -> crate::widget::ChangeFlags {
-        let mut changeflags = ChangeFlags::empty();
-        changeflags |= element.set_amount(self.amount);
-        changeflags |= element.set_position(self.position);
-
-        let content_el = element
-            .content
-            .downcast_mut()
-            .expect("The margin widget changed its type, this should never happen!");
-
-        let content_changeflags = self
-            .content
-            .rebuild(cx, &prev.content, id, state, content_el);
-        changeflags | element.content.mark(content_changeflags)
+    ) -> ChangeFlags {
+        let mut changeflags = element.set_position(self.position(cx));
+        cx.with_id(*id, |cx| {
+            changeflags |= self.amount.rebuild(
+                cx,
+                &prev.amount,
+                &mut state.amount_id,
+                &mut state.amount_state,
+                &mut element.amount,
+            );
+
+            let content_el = element
+                .content
+                .downcast_mut()
+                .expect("The margin widget changed its type, this should never happen!");
+
+            let content_changeflags = self.content.rebuild(
+                cx,
+                &prev.content,
+                &mut state.content_id,
+                &mut state.content_state,
+                content_el,
+            );
+            changeflags | element.content.mark(content_changeflags)
+        })
     }
 
     fn message(
         &self,
-        id_path: &[xilem_core::Id],
+        id_path: &[Id],
         state: &mut Self::State,
         message: Box<dyn std::any::Any>,
         app_state: &mut T,
     ) -> MessageResult<A> {
-        self.content.message(id_path, state, message, app_state)
+        match id_path {
+            [id, rest_path @ ..] if *id == state.content_id => {
+                self.content
+                    .message(rest_path, &mut state.content_state, message, app_state)
+            }
+            [id, rest_path @ ..] if *id == state.amount_id => {
+                match self
+                    .amount
+                    .message(rest_path, &mut state.amount_state, message)
+                {
+                    MessageResult::Action(_) | MessageResult::RequestRebuild => {
+                        MessageResult::RequestRebuild
+                    }
+                    MessageResult::Nop => MessageResult::Nop,
+                    MessageResult::Stale(message) => MessageResult::Stale(message),
+                }
+            }
+            [..] => MessageResult::Stale(message),
+        }
     }
 }
 
-#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct MarginStyle {
-    pub amount: u16,
+/// The sides and the amount of a [`Margin`], see [`ViewExt::margin`](crate::ViewExt::margin).
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct MarginStyle<L = Length> {
+    pub amount: L,
     pub position: Position,
 }
 
-impl From<u16> for MarginStyle {
-    fn from(amount: u16) -> Self {
-        Self {
-            amount,
+pub trait IntoMarginStyle<L>: Sized
+where
+    L: Animatable<Length>,
+{
+    fn into(self) -> MarginStyle<L>;
+}
+
+impl<L: Animatable<Length>> IntoMarginStyle<L> for MarginStyle<L> {
+    fn into(self) -> MarginStyle<L> {
+        self
+    }
+}
+
+/// On all sides.
+impl<L: Animatable<Length>> IntoMarginStyle<L> for L {
+    fn into(self) -> MarginStyle<L> {
+        MarginStyle {
+            amount: self,
             position: Position::ALL,
         }
     }
 }
 
-impl From<(u16, Position)> for MarginStyle {
-    fn from((amount, position): (u16, Position)) -> Self {
-        Self { amount, position }
+impl<L: Animatable<Length>> IntoMarginStyle<L> for (L, Position) {
+    fn into(self) -> MarginStyle<L> {
+        MarginStyle {
+            amount: self.0,
+            position: self.1,
+        }
     }
 }
 
-impl From<(Position, u16)> for MarginStyle {
-    fn from((position, amount): (Position, u16)) -> Self {
-        Self { amount, position }
+impl<L: Animatable<Length>> IntoMarginStyle<L> for (Position, L) {
+    fn into(self) -> MarginStyle<L> {
+        MarginStyle {
+            amount: self.1,
+            position: self.0,
+        }
+    }
+}
+
+/// An amount in cells on all sides.
+impl IntoMarginStyle<Length> for u16 {
+    fn into(self) -> MarginStyle<Length> {
+        MarginStyle {
+            amount: Length::Cells(self),
+            position: Position::ALL,
+        }
+    }
+}
+
+impl IntoMarginStyle<Length> for (u16, Position) {
+    fn into(self) -> MarginStyle<Length> {
+        MarginStyle {
+            amount: Length::Cells(self.0),
+            position: self.1,
+        }
+    }
+}
+
+impl IntoMarginStyle<Length> for (Position, u16) {
+    fn into(self) -> MarginStyle<Length> {
+        MarginStyle {
+            amount: Length::Cells(self.1),
+            position: self.0,
+        }
     }
 }
@@ -0,0 +1,128 @@
+use std::{any::Any, borrow::Cow};
+
+use ratatui::style::{Color, Modifier, Style};
+use xilem_core::{Id, MessageResult};
+
+use super::{common::Styleable, Cx, Text, View, ViewMarker};
+use crate::widget::{self, ChangeFlags};
+
+/// A single line of text, that scrolls horizontally with `speed` (in cells per second) in a loop, when it doesn't fit,
+/// e.g. for long paths or the currently playing song in a status bar.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), move |_: &mut ()| {
+/// marquee("Now playing: A very long song title - An even longer artist name".fg(Color::Cyan), 8.0)
+///     .gap(5)
+///     .pause_on_hover(true)
+///     .max_width(30)
+/// # });
+/// ```
+pub fn marquee(text: impl Into<Text>, speed: f64) -> Marquee {
+    let text = text.into();
+    Marquee {
+        text: text.text,
+        style: text.style,
+        speed,
+        gap: 3,
+        pause_on_hover: false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marquee {
+    text: Cow<'static, str>,
+    style: Style,
+    speed: f64,
+    gap: usize,
+    pause_on_hover: bool,
+}
+
+impl Marquee {
+    /// The space (in cells) between the end and the repeated start of the text, by default 3.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Stops scrolling while the mouse is over the text, e.g. to read it.
+    pub fn pause_on_hover(mut self, pause_on_hover: bool) -> Self {
+        self.pause_on_hover = pause_on_hover;
+        self
+    }
+}
+
+impl ViewMarker for Marquee {}
+
+impl<T, A> View<T, A> for Marquee {
+    type State = ();
+
+    type Element = widget::Marquee;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, element) = cx.with_new_id(|_| {
+            widget::Marquee::new(
+                self.text.clone(),
+                self.style,
+                self.speed,
+                self.gap,
+                self.pause_on_hover,
+            )
+        });
+        (id, (), element)
+    }
+
+    fn rebuild(
+        &self,
+        _cx: &mut Cx,
+        _prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        element.set_text(self.text.clone())
+            | element.set_style(self.style)
+            | element.set_speed(self.speed)
+            | element.set_gap(self.gap)
+            | element.set_pause_on_hover(self.pause_on_hover)
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        _message: Box<dyn Any>,
+        _app_state: &mut T,
+    ) -> MessageResult<A> {
+        MessageResult::Nop
+    }
+}
+
+impl Styleable for Marquee {
+    type Output = Self;
+
+    fn fg(mut self, color: Color) -> Self::Output {
+        self.style.fg = Some(color);
+        self
+    }
+
+    fn bg(mut self, color: Color) -> Self::Output {
+        self.style.bg = Some(color);
+        self
+    }
+
+    fn modifier(mut self, modifier: Modifier) -> Self::Output {
+        self.style = self.style.add_modifier(modifier);
+        self
+    }
+
+    fn style(mut self, style: Style) -> Self::Output {
+        self.style = style;
+        self
+    }
+
+    fn current_style(&self) -> Style {
+        self.style
+    }
+}
@@ -0,0 +1,272 @@
+use std::{any::Any, fmt};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Color, Modifier, Style};
+use xilem_core::{Id, MessageResult};
+
+use super::{Cx, Styleable, Text, View, ViewMarker};
+use crate::widget::{self, ChangeFlags};
+
+/// The input mode of a [`modal`] view, inspired by vim.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Typed characters are only used for key bindings.
+    #[default]
+    Normal,
+    /// Typed characters are passed to the content, e.g. to a text input.
+    Insert,
+    /// Like [`Mode::Normal`], but meant for selecting things.
+    Visual,
+}
+
+impl Mode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mode::Normal => "NORMAL",
+            Mode::Insert => "INSERT",
+            Mode::Visual => "VISUAL",
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A key (with modifiers) of a key binding, see [`Modal::on_key`].
+///
+/// Characters can be used directly, `Shift` is ignored for them, as it's already part of the character (e.g. `'I'`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl Key {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Key { code, modifiers }
+    }
+
+    /// The character `c` pressed with `Ctrl`.
+    pub fn ctrl(c: char) -> Self {
+        Key::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    pub(crate) fn matches(&self, event: &KeyEvent) -> bool {
+        let ignored = match self.code {
+            KeyCode::Char(_) => KeyModifiers::SHIFT,
+            _ => KeyModifiers::empty(),
+        };
+        event.code == self.code && event.modifiers - ignored == self.modifiers - ignored
+    }
+}
+
+impl From<char> for Key {
+    fn from(c: char) -> Self {
+        Key::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+}
+
+impl From<KeyCode> for Key {
+    fn from(code: KeyCode) -> Self {
+        Key::new(code, KeyModifiers::NONE)
+    }
+}
+
+enum KeyAction<T, A> {
+    SetMode(Mode),
+    Handler(Box<dyn Fn(&mut T) -> A + Send + Sync>),
+}
+
+struct KeyBinding<T, A> {
+    mode: Mode,
+    key: Key,
+    action: KeyAction<T, A>,
+}
+
+/// Modal input for its content, see [`modal`].
+pub struct Modal<T, A, V, F> {
+    content: V,
+    mode: Mode,
+    on_mode_change: F,
+    bindings: Vec<KeyBinding<T, A>>,
+}
+
+/// Handles the keyboard input of `content` depending on the current `mode`, with separate key bindings per mode.
+///
+/// The mode is kept in the app state, `on_mode_change` is called with the new mode, when a key bound with [`Modal::switch`] is pressed.
+/// By default `i` switches to [`Mode::Insert`] and `v` to [`Mode::Visual`], and `Ctrl+c` switches back to [`Mode::Normal`]
+/// (`Esc` is reserved for quitting the app).
+///
+/// The key bindings of the current mode take precedence over the content.
+/// Typed characters (without `Ctrl` or `Alt`) are only passed to the content in the insert mode, so that text inputs don't receive them otherwise,
+/// other keys like `Tab`, `Enter` or `Space` always reach the content.
+/// The modal is focused, when nothing else is, so that the key bindings work right from the start.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// struct Editor {
+///     mode: Mode,
+///     lines: u32,
+/// }
+///
+/// # App::new(Editor { mode: Mode::Normal, lines: 1 }, |editor: &mut Editor| {
+/// modal(
+///     editor.mode,
+///     |editor: &mut Editor, mode| editor.mode = mode,
+///     v_stack((
+///         format!("{} lines", editor.lines),
+///         mode_indicator(editor.mode),
+///     )),
+/// )
+/// .on_key(Mode::Normal, 'o', |editor: &mut Editor| {
+///     editor.lines += 1;
+///     editor.mode = Mode::Insert;
+/// })
+/// .switch(Mode::Visual, 'y', Mode::Normal)
+/// # });
+/// ```
+pub fn modal<T, A, V, F>(mode: Mode, on_mode_change: F, content: V) -> Modal<T, A, V, F>
+where
+    V: View<T, A>,
+    F: Fn(&mut T, Mode) -> A + Send + Sync,
+{
+    Modal {
+        content,
+        mode,
+        on_mode_change,
+        bindings: Vec::new(),
+    }
+    .switch(Mode::Normal, 'i', Mode::Insert)
+    .switch(Mode::Normal, 'v', Mode::Visual)
+    .switch(Mode::Insert, Key::ctrl('c'), Mode::Normal)
+    .switch(Mode::Visual, Key::ctrl('c'), Mode::Normal)
+}
+
+impl<T, A, V, F> Modal<T, A, V, F> {
+    /// Switches to the mode `to`, when `key` is pressed in `mode`.
+    ///
+    /// Later bindings of the same key take precedence, so the default bindings can be overridden.
+    pub fn switch(mut self, mode: Mode, key: impl Into<Key>, to: Mode) -> Self {
+        self.bindings.push(KeyBinding {
+            mode,
+            key: key.into(),
+            action: KeyAction::SetMode(to),
+        });
+        self
+    }
+
+    /// Calls `handler`, when `key` is pressed in `mode`.
+    ///
+    /// Later bindings of the same key take precedence, so the default bindings can be overridden.
+    pub fn on_key(
+        mut self,
+        mode: Mode,
+        key: impl Into<Key>,
+        handler: impl Fn(&mut T) -> A + Send + Sync + 'static,
+    ) -> Self {
+        self.bindings.push(KeyBinding {
+            mode,
+            key: key.into(),
+            action: KeyAction::Handler(Box::new(handler)),
+        });
+        self
+    }
+
+    fn keys(&self) -> Vec<(Mode, Key)> {
+        // reversed, so that the first match in the widget is the latest binding
+        self.bindings
+            .iter()
+            .rev()
+            .map(|binding| (binding.mode, binding.key))
+            .collect()
+    }
+}
+
+impl<T, A, V, F> ViewMarker for Modal<T, A, V, F> {}
+
+impl<T, A, V, F> View<T, A> for Modal<T, A, V, F>
+where
+    V: View<T, A>,
+    V::Element: 'static,
+    F: Fn(&mut T, Mode) -> A + Send + Sync,
+{
+    type State = (Id, V::State);
+
+    type Element = widget::Modal;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (content_id, content_state, content) = self.content.build(cx);
+            let element = widget::Modal::new(cx.id_path(), content, self.mode, self.keys());
+            ((content_id, content_state), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (content_id, content_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let content_el = element
+                .content
+                .downcast_mut()
+                .expect("The modal content widget changed its type, this should never happen!");
+            let changeflags =
+                self.content
+                    .rebuild(cx, &prev.content, content_id, content_state, content_el);
+            element.content.mark(changeflags)
+                | element.set_mode(self.mode)
+                | element.set_bindings(self.keys())
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (content_id, content_state): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == content_id => {
+                self.content
+                    .message(rest_path, content_state, message, app_state)
+            }
+            [] => {
+                let Some(&index) = message.downcast_ref::<usize>() else {
+                    return MessageResult::Stale(message);
+                };
+                // the widget got the bindings in reversed order
+                let Some(binding) = self.bindings.iter().rev().nth(index) else {
+                    return MessageResult::Stale(message);
+                };
+                match &binding.action {
+                    KeyAction::SetMode(mode) => {
+                        MessageResult::Action((self.on_mode_change)(app_state, *mode))
+                    }
+                    KeyAction::Handler(handler) => MessageResult::Action(handler(app_state)),
+                }
+            }
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
+
+/// Shows the current `mode` like vim, e.g. ` INSERT ` (in the style of the mode).
+pub fn mode_indicator(mode: Mode) -> Text {
+    let style = match mode {
+        Mode::Normal => Style::default().add_modifier(Modifier::REVERSED),
+        Mode::Insert => Style::default().fg(Color::Black).bg(Color::Green),
+        Mode::Visual => Style::default().fg(Color::Black).bg(Color::Magenta),
+    };
+    Text::from(format!(" {mode} ")).style(style.add_modifier(Modifier::BOLD))
+}
@@ -0,0 +1,94 @@
+use std::{
+    any::Any,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::ChangeFlags;
+
+use super::{Cx, View, ViewMarker};
+
+/// A handler, that is called by the app with its state (as `&mut dyn Any`), see [`UnmountGuard`].
+pub(crate) type UnmountCallback = Box<dyn FnOnce(&mut dyn Any) + Send>;
+
+/// The handlers of the views removed by a rebuild, the app calls them after the rebuild, see [`Cx`].
+pub(crate) type UnmountQueue = Arc<Mutex<Vec<UnmountCallback>>>;
+
+/// Queues the unmount handler, when it's dropped together with the view state.
+pub struct UnmountGuard {
+    callback: Option<UnmountCallback>,
+    queue: UnmountQueue,
+}
+
+impl UnmountGuard {
+    fn set_handler<T: 'static, F: Fn(&mut T) + Send + 'static>(&mut self, handler: F) {
+        self.callback = Some(Box::new(move |app_state: &mut dyn Any| {
+            if let Some(app_state) = app_state.downcast_mut::<T>() {
+                handler(app_state);
+            }
+        }));
+    }
+}
+
+impl Drop for UnmountGuard {
+    fn drop(&mut self) {
+        if let Some(callback) = self.callback.take() {
+            // this may be dropped while unwinding, so don't panic on a poisoned lock
+            let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+            queue.push(callback);
+        }
+    }
+}
+
+/// See [`ViewExt::on_unmount`](super::ViewExt::on_unmount).
+pub struct OnUnmount<V, F> {
+    pub(crate) view: V,
+    pub(crate) handler: F,
+}
+
+impl<V, F> ViewMarker for OnUnmount<V, F> {}
+
+impl<T, A, V, F> View<T, A> for OnUnmount<V, F>
+where
+    T: 'static,
+    V: View<T, A>,
+    F: Fn(&mut T) + Clone + Send + Sync + 'static,
+{
+    type State = (V::State, UnmountGuard);
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, state, element) = self.view.build(cx);
+        let mut guard = UnmountGuard {
+            callback: None,
+            queue: cx.unmounted.clone(),
+        };
+        guard.set_handler(self.handler.clone());
+        (id, (state, guard), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (state, guard): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        // the handler may capture something, that has changed
+        guard.set_handler(self.handler.clone());
+        self.view.rebuild(cx, &prev.view, id, state, element)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (state, _): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.view.message(id_path, state, message, app_state)
+    }
+}
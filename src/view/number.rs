@@ -0,0 +1,351 @@
+use std::{any::Any, fmt::Write, sync::Arc, time::Duration};
+
+use ratatui::style::{Color, Modifier, Style};
+use xilem_core::{Id, MessageResult};
+
+use super::{common::Styleable, localization::normalize_locale, Cx, View, ViewMarker};
+use crate::widget::{self, ChangeFlags};
+
+/// The characters, which separate the decimals and the groups of thousands of numbers in a locale.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// assert_eq!(NumberSeparators::for_locale("en_US.UTF-8"), NumberSeparators { decimal: '.', group: ',' });
+/// assert_eq!(NumberSeparators::for_locale("de-AT"), NumberSeparators { decimal: ',', group: '.' });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberSeparators {
+    pub decimal: char,
+    pub group: char,
+}
+
+impl NumberSeparators {
+    /// The separators of the `locale` (e.g. `de-AT`), the english ones, if the locale isn't known.
+    pub fn for_locale(locale: &str) -> Self {
+        let locale = normalize_locale(locale);
+        let mut parts = locale.split('-');
+        let language = parts.next().unwrap_or_default();
+        let region = parts.next().unwrap_or_default();
+        let (decimal, group) = match (language, region) {
+            (_, "CH" | "LI") => ('.', '’'),
+            (
+                "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+                | "sr" | "vi",
+                _,
+            ) => (',', '.'),
+            // a no-break space, so that numbers aren't wrapped
+            (
+                "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "nn" | "no" | "uk" | "hu"
+                | "bg" | "et" | "lv" | "lt",
+                _,
+            ) => (',', '\u{a0}'),
+            _ => ('.', ','),
+        };
+        NumberSeparators { decimal, group }
+    }
+}
+
+/// A value, which is formatted as text with the separators of the current locale, see [`number`], [`duration`] and [`bytes`].
+pub trait FormatValue: PartialEq + Send + Sync {
+    fn format(&self, separators: NumberSeparators, text: &mut String);
+}
+
+/// Writes the formatted `number` (as written by `format!`) with the `decimal` separator, and `group` separators between thousands.
+fn write_number(number: &str, decimal: char, group: Option<char>, text: &mut String) {
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", number),
+    };
+    text.push_str(sign);
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+    // e.g. `inf` or `NaN`
+    if !integer.bytes().all(|b| b.is_ascii_digit()) {
+        text.push_str(number);
+        return;
+    }
+    for (i, digit) in integer.chars().enumerate() {
+        match group {
+            Some(group) if i > 0 && (integer.len() - i) % 3 == 0 => text.push(group),
+            _ => (),
+        }
+        text.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        text.push(decimal);
+        text.push_str(fraction);
+    }
+}
+
+/// A number, see [`number`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Number {
+    value: f64,
+    precision: Option<usize>,
+    separators: bool,
+}
+
+impl FormatValue for Number {
+    fn format(&self, separators: NumberSeparators, text: &mut String) {
+        let number = match self.precision {
+            Some(precision) => format!("{:.precision$}", self.value),
+            None => self.value.to_string(),
+        };
+        let group = self.separators.then_some(separators.group);
+        write_number(&number, separators.decimal, group, text);
+    }
+}
+
+impl FormatValue for Duration {
+    /// Formats the duration with its two largest units, e.g. `1h 05m`, `2m 30s`, `1.5s` or `250ms`.
+    fn format(&self, separators: NumberSeparators, text: &mut String) {
+        let secs = self.as_secs();
+        let (days, hours, minutes) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60);
+        let _ = match secs {
+            0 => write!(text, "{}ms", self.as_millis()),
+            1..=59 => {
+                write_number(
+                    &format!("{:.1}", self.as_secs_f64()),
+                    separators.decimal,
+                    None,
+                    text,
+                );
+                write!(text, "s")
+            }
+            60..=3599 => write!(text, "{minutes}m {:02}s", secs % 60),
+            3600..=86399 => write!(text, "{hours}h {minutes:02}m"),
+            _ => write!(text, "{days}d {hours:02}h"),
+        };
+    }
+}
+
+/// A number of bytes, see [`bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes(pub u64);
+
+impl FormatValue for Bytes {
+    /// Formats the bytes with binary units, e.g. `512 B` or `1.5 KiB`.
+    fn format(&self, separators: NumberSeparators, text: &mut String) {
+        const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+        if self.0 < 1024 {
+            let _ = write!(text, "{} B", self.0);
+            return;
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write_number(&format!("{value:.1}"), separators.decimal, None, text);
+        text.push(' ');
+        text.push_str(UNITS[unit]);
+    }
+}
+
+/// A text view of a value, which is formatted with the separators of the current [`locale`](super::locale).
+///
+/// It's only formatted again, when the value (or the locale) changes, not every time the view is rebuilt,
+/// so it's cheap to show many frequently rebuilt values, e.g. the counters of a dashboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formatted<F> {
+    value: F,
+    style: Style,
+}
+
+impl<F: FormatValue> Formatted<F> {
+    fn text(&self, locale: &str) -> String {
+        let mut text = String::new();
+        self.value
+            .format(NumberSeparators::for_locale(locale), &mut text);
+        text
+    }
+}
+
+/// Shows `value` as number, with as many decimals as needed by default, see [`Formatted::precision`].
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(1234567.891, |value: &mut f64| {
+/// v_stack((
+///     number(*value).precision(2).separators(true),
+///     locale("de", number(*value).precision(1)),
+///     number(42),
+/// ))
+/// # });
+/// ```
+pub fn number(value: impl Into<f64>) -> Formatted<Number> {
+    Formatted {
+        value: Number {
+            value: value.into(),
+            precision: None,
+            separators: false,
+        },
+        style: Style::default(),
+    }
+}
+
+impl Formatted<Number> {
+    /// Rounds the number to `precision` decimals.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.value.precision = Some(precision);
+        self
+    }
+
+    /// Whether the thousands are separated, e.g. `1,234,567`.
+    pub fn separators(mut self, separators: bool) -> Self {
+        self.value.separators = separators;
+        self
+    }
+}
+
+/// Shows the duration `value` with its two largest units, e.g. `1h 05m`, `2m 30s`, `1.5s` or `250ms`.
+///
+/// # Examples
+/// ```
+/// # use std::time::Duration;
+/// # use trui::*;
+/// # App::new(Duration::from_secs(3900), |uptime: &mut Duration| {
+/// h_stack(("Uptime: ", duration(*uptime)))
+/// # });
+/// ```
+pub fn duration(value: Duration) -> Formatted<Duration> {
+    Formatted {
+        value,
+        style: Style::default(),
+    }
+}
+
+/// Shows the number of bytes `value` with binary units, e.g. `512 B` or `1.5 KiB`.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(1536, |received: &mut u64| {
+/// h_stack(("Received: ", bytes(*received)))
+/// # });
+/// ```
+pub fn bytes(value: u64) -> Formatted<Bytes> {
+    Formatted {
+        value: Bytes(value),
+        style: Style::default(),
+    }
+}
+
+impl<F> ViewMarker for Formatted<F> {}
+
+impl<T, A, F: FormatValue> View<T, A> for Formatted<F> {
+    /// The locale the value was formatted with.
+    type State = Arc<str>;
+
+    type Element = widget::Text;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, mut element) =
+            cx.with_new_id(|cx| widget::Text::new(self.text(cx.locale()).into(), self.style));
+        let _ = element.set_rtl(cx.layout_direction().is_rtl());
+        (id, cx.locale.clone(), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        _id: &mut Id,
+        locale: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let mut changeflags = ChangeFlags::empty();
+        if self.value != prev.value || **locale != *cx.locale {
+            *locale = cx.locale.clone();
+            changeflags |= element.set_text(self.text(locale).into());
+        }
+        changeflags
+            | element.set_style(self.style)
+            | element.set_rtl(cx.layout_direction().is_rtl())
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        _message: Box<dyn Any>,
+        _app_state: &mut T,
+    ) -> MessageResult<A> {
+        MessageResult::Nop
+    }
+}
+
+impl<F> Styleable for Formatted<F> {
+    type Output = Self;
+
+    fn fg(mut self, color: Color) -> Self::Output {
+        self.style.fg = Some(color);
+        self
+    }
+
+    fn bg(mut self, color: Color) -> Self::Output {
+        self.style.bg = Some(color);
+        self
+    }
+
+    fn modifier(mut self, modifier: Modifier) -> Self::Output {
+        self.style = self.style.add_modifier(modifier);
+        self
+    }
+
+    fn style(mut self, style: Style) -> Self::Output {
+        self.style = style;
+        self
+    }
+
+    fn current_style(&self) -> Style {
+        self.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(value: impl FormatValue, locale: &str) -> String {
+        let mut text = String::new();
+        value.format(NumberSeparators::for_locale(locale), &mut text);
+        text
+    }
+
+    #[test]
+    fn formats_numbers_with_locale_separators() {
+        let value = |value: f64| Number {
+            value,
+            precision: Some(2),
+            separators: true,
+        };
+        assert_eq!(format(value(-1234567.891), "en"), "-1,234,567.89");
+        assert_eq!(format(value(1234.5), "de_DE.UTF-8"), "1.234,50");
+        assert_eq!(format(value(123.0), "fr"), "123,00");
+        assert_eq!(format(value(f64::INFINITY), "en"), "inf");
+        let unseparated = Number {
+            value: 1234.5,
+            precision: None,
+            separators: false,
+        };
+        assert_eq!(format(unseparated, "en"), "1234.5");
+    }
+
+    #[test]
+    fn formats_durations_and_bytes() {
+        assert_eq!(format(Duration::from_millis(250), "en"), "250ms");
+        assert_eq!(format(Duration::from_millis(1500), "de"), "1,5s");
+        assert_eq!(format(Duration::from_secs(150), "en"), "2m 30s");
+        assert_eq!(format(Duration::from_secs(3900), "en"), "1h 05m");
+        assert_eq!(format(Duration::from_secs(90000), "en"), "1d 01h");
+        assert_eq!(format(Bytes(512), "en"), "512 B");
+        assert_eq!(format(Bytes(1536), "en"), "1.5 KiB");
+        assert_eq!(format(Bytes(5 << 30), "en"), "5.0 GiB");
+    }
+}
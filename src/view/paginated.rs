@@ -0,0 +1,151 @@
+use std::future::Future;
+
+use super::{defer, v_stack, EventHandler, LinearLayout, View};
+
+/// A page of items returned by the fetch function of [`load_next_page`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<I> {
+    pub items: Vec<I>,
+    /// Whether there are more pages after this one.
+    pub has_more: bool,
+}
+
+/// The state of a list that is loaded page by page, to be stored in the app state.
+///
+/// It keeps track of the next page and whether a page is currently loading,
+/// so that a page is never requested twice. See [`paginated`] and [`load_next_page`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paginated<I> {
+    pub items: Vec<I>,
+    next_page: usize,
+    is_loading: bool,
+    has_more: bool,
+    error: Option<String>,
+}
+
+impl<I> Default for Paginated<I> {
+    fn default() -> Self {
+        Paginated {
+            items: Vec::new(),
+            next_page: 0,
+            is_loading: false,
+            has_more: true,
+            error: None,
+        }
+    }
+}
+
+impl<I> Paginated<I> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// The error of the last failed page load.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Marks the next page as loading and returns its index (starting at 0),
+    /// if there are more pages and no page is currently loading.
+    pub fn begin_load(&mut self) -> Option<usize> {
+        if self.is_loading || !self.has_more {
+            return None;
+        }
+        self.is_loading = true;
+        self.error = None;
+        Some(self.next_page)
+    }
+
+    /// Appends the items of a loaded page, or stores the error, in which case the same page is loaded again next time.
+    pub fn finish_load(&mut self, result: Result<Page<I>, String>) {
+        self.is_loading = false;
+        match result {
+            Ok(page) => {
+                self.items.extend(page.items);
+                self.has_more = page.has_more;
+                self.next_page += 1;
+            }
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    /// Removes all items, so that the list is loaded from the first page again.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Shows the items of a [`Paginated`] list with `item_view`, followed by a loading indicator or the error of the last page load.
+///
+/// Together with [`ScrollView::on_reach_end`](crate::view::ScrollView::on_reach_end) and [`load_next_page`]
+/// this can be used for infinite lists.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// async fn fetch(page: usize) -> Result<Page<String>, String> {
+///     let items = (0..20).map(|i| format!("Item {}", page * 20 + i)).collect();
+///     Ok(Page { items, has_more: page < 10 })
+/// }
+///
+/// # App::new(Paginated::new(), move |feed: &mut Paginated<String>| {
+/// scroll_view(paginated(feed, |item: &String| item.clone()))
+///     .on_reach_end(load_next_page(|feed: &mut Paginated<String>| feed, fetch))
+/// # });
+/// ```
+pub fn paginated<T, A, I, V, F>(
+    list: &Paginated<I>,
+    item_view: F,
+) -> LinearLayout<T, A, (Vec<V>, String)>
+where
+    V: View<T, A>,
+    F: Fn(&I) -> V,
+{
+    let footer = if list.is_loading {
+        "Loading…".to_string()
+    } else if let Some(error) = &list.error {
+        format!("Error: {error}")
+    } else {
+        String::new()
+    };
+    v_stack((list.items.iter().map(item_view).collect(), footer))
+}
+
+/// An event handler, that loads the next page of the [`Paginated`] list returned by `list` with `fetch`,
+/// if it isn't already loading, and there are more pages.
+///
+/// `fetch` is called with the index of the page (starting at 0), see [`paginated`] for an example.
+pub fn load_next_page<T, A, E, I, L, FF, F>(list: L, fetch: FF) -> impl EventHandler<T, A, E>
+where
+    E: 'static,
+    I: Send + Sync + 'static,
+    L: Fn(&mut T) -> &mut Paginated<I> + Clone + Send + Sync,
+    FF: Fn(usize) -> F + Send + Sync,
+    F: Future<Output = Result<Page<I>, String>> + Send + Sync + 'static,
+{
+    let list_clone = list.clone();
+    defer(
+        move |app_state: &mut T, _: E| {
+            let future = list(app_state).begin_load().map(&fetch);
+            async move {
+                match future {
+                    Some(future) => Some(future.await),
+                    None => None,
+                }
+            }
+        },
+        move |app_state: &mut T, result| {
+            if let Some(result) = result {
+                list_clone(app_state).finish_load(result);
+            }
+        },
+    )
+}
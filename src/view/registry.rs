@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+
+use super::{h_stack, v_stack, AnyView, IntoBoxedView};
+
+type Factory<T, A> =
+    Box<dyn Fn(&Value, &ViewRegistry<T, A>) -> Result<Box<dyn AnyView<T, A>>> + Send + Sync>;
+
+/// Creates views by name from JSON data, e.g. for dashboards, where the layout is described in a config file.
+///
+/// A view is described by a string (a text view) or an object with a `"type"`,
+/// which is the name of the registered factory, and its properties.
+/// The types `"text"` (with a `"text"` property) and `"v_stack"`/`"h_stack"` (with `"children"`) are built in.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let mut registry = ViewRegistry::<serde_json::Value>::new();
+/// registry.register("gauge", |config, _| {
+///     let label = config["label"].as_str().unwrap_or_default().to_string();
+///     let value = config["value"].as_f64().unwrap_or_default();
+///     Ok(format!("{label}: {:.0}%", value * 100.0).boxed())
+/// });
+/// let config = serde_json::json!({
+///     "type": "v_stack",
+///     "children": ["Dashboard", { "type": "gauge", "label": "CPU", "value": 0.42 }],
+/// });
+/// App::new(config, move |config: &mut serde_json::Value| {
+///     registry
+///         .view_from_config(config)
+///         .unwrap_or_else(|err| format!("Invalid dashboard: {err}").boxed())
+/// });
+/// ```
+pub struct ViewRegistry<T, A = ()> {
+    factories: HashMap<String, Factory<T, A>>,
+}
+
+impl<T: 'static, A: 'static> ViewRegistry<T, A> {
+    /// A registry with the built-in types.
+    pub fn new() -> Self {
+        let mut registry = ViewRegistry {
+            factories: HashMap::new(),
+        };
+        registry.register("text", |config, _| {
+            let text = config["text"]
+                .as_str()
+                .ok_or_else(|| anyhow!("text: \"text\" has to be a string"))?;
+            Ok(text.to_string().boxed())
+        });
+        registry.register("v_stack", |config, registry| {
+            Ok(v_stack(registry.children(config)?).boxed())
+        });
+        registry.register("h_stack", |config, registry| {
+            Ok(h_stack(registry.children(config)?).boxed())
+        });
+        registry
+    }
+
+    /// Registers the `factory` for views of the type `name`, replacing a previously registered factory.
+    ///
+    /// The factory gets the JSON object describing the view, and the registry, to create child views.
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        F: Fn(&Value, &ViewRegistry<T, A>) -> Result<Box<dyn AnyView<T, A>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.factories.insert(name.into(), Box::new(factory));
+    }
+
+    /// Creates the view described by `config`.
+    pub fn view_from_config(&self, config: &Value) -> Result<Box<dyn AnyView<T, A>>> {
+        match config {
+            Value::String(text) => Ok(text.clone().boxed()),
+            Value::Object(object) => {
+                let Some(Value::String(name)) = object.get("type") else {
+                    bail!("a view has to have a \"type\"");
+                };
+                let factory = self
+                    .factories
+                    .get(name)
+                    .ok_or_else(|| anyhow!("unknown view type \"{name}\""))?;
+                factory(config, self)
+            }
+            _ => bail!("a view has to be a string or an object"),
+        }
+    }
+
+    /// Creates the views of the `"children"` array of `config`, e.g. for container views.
+    pub fn children(&self, config: &Value) -> Result<Vec<Box<dyn AnyView<T, A>>>> {
+        let Some(children) = config["children"].as_array() else {
+            bail!("\"children\" has to be an array");
+        };
+        children
+            .iter()
+            .map(|child| self.view_from_config(child))
+            .collect()
+    }
+}
+
+impl<T: 'static, A: 'static> Default for ViewRegistry<T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
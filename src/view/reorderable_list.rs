@@ -0,0 +1,108 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult, VecSplice};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, EventHandler, View, ViewMarker, ViewSequence};
+
+/// Rows that can be reordered, see [`reorderable_list`].
+pub struct ReorderableList<T, A, VT, EH> {
+    items: VT,
+    on_reorder: EH,
+    phantom: PhantomData<fn() -> (T, A)>,
+}
+
+/// Stacks the `items` vertically with a drag handle in front of each row, by which the rows can be reordered.
+///
+/// A row is grabbed by pressing the mouse on its handle, while it's dragged a gap opens at the drop position.
+/// Rows can also be moved with `Alt` + `Up`/`Down`, while the row (or something in it) is focused.
+/// `on_reorder` is called with the old and the new index of the moved row as `(from, to)`,
+/// the row isn't moved until the app state is changed accordingly.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(vec!["Milk", "Eggs", "Bread"], move |items: &mut Vec<&'static str>| {
+/// reorderable_list(
+///     items.clone(),
+///     |items: &mut Vec<&'static str>, (from, to): (usize, usize)| {
+///         let item = items.remove(from);
+///         items.insert(to, item);
+///     },
+/// )
+/// # });
+/// ```
+pub fn reorderable_list<T, A, VT: ViewSequence<T, A>, EH: EventHandler<T, A, (usize, usize)>>(
+    items: VT,
+    on_reorder: EH,
+) -> ReorderableList<T, A, VT, EH> {
+    ReorderableList {
+        items,
+        on_reorder,
+        phantom: PhantomData,
+    }
+}
+
+impl<T, A, VT, EH> ViewMarker for ReorderableList<T, A, VT, EH> {}
+
+impl<T, A, VT, EH> View<T, A> for ReorderableList<T, A, VT, EH>
+where
+    VT: ViewSequence<T, A>,
+    EH: EventHandler<T, A, (usize, usize)>,
+{
+    type State = (VT::State, (Id, EH::State));
+
+    type Element = widget::ReorderableList;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let mut elements = vec![];
+        let mut scratch = vec![];
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let items_state = self
+                .items
+                .build(cx, &mut VecSplice::new(&mut elements, &mut scratch));
+            let element =
+                widget::ReorderableList::new(cx.id_path(), elements, cx.theme().reorderable_list);
+            ((items_state, self.on_reorder.build(cx)), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (items_state, (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let changeflags = element.set_styles(cx.theme().reorderable_list);
+
+        let mut scratch = vec![];
+        let mut splice = VecSplice::new(&mut element.children, &mut scratch);
+
+        changeflags
+            | cx.with_id(*id, |cx| {
+                self.items
+                    .rebuild(cx, &prev.items, items_state, &mut splice)
+                    | self.on_reorder.rebuild(cx, eh_id, eh_state)
+            })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (items_state, (eh_id, eh_state)): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_reorder
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_reorder.message(&[], eh_state, message, app_state),
+            _ => self.items.message(id_path, items_state, message, app_state),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::{widget::ChangeFlags, Cx, View, ViewMarker};
+
+/// A coarse classification of the terminal size, see [`responsive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SizeClass {
+    Compact,
+    Medium,
+    Wide,
+}
+
+/// The terminal sizes (in cells) at which the [`SizeClass`] changes.
+///
+/// The size class is the smaller one of the classes of the width and the height,
+/// by default only the width is considered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Breakpoints {
+    /// The minimum width for [`SizeClass::Medium`], 80 by default.
+    pub medium_width: u16,
+    /// The minimum width for [`SizeClass::Wide`], 120 by default.
+    pub wide_width: u16,
+    /// The minimum height for [`SizeClass::Medium`], 0 by default.
+    pub medium_height: u16,
+    /// The minimum height for [`SizeClass::Wide`], 0 by default.
+    pub wide_height: u16,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Breakpoints {
+            medium_width: 80,
+            wide_width: 120,
+            medium_height: 0,
+            wide_height: 0,
+        }
+    }
+}
+
+impl Breakpoints {
+    pub fn size_class(&self, width: u16, height: u16) -> SizeClass {
+        let class = |size, medium, wide| {
+            if size >= wide {
+                SizeClass::Wide
+            } else if size >= medium {
+                SizeClass::Medium
+            } else {
+                SizeClass::Compact
+            }
+        };
+        class(width, self.medium_width, self.wide_width).min(class(
+            height,
+            self.medium_height,
+            self.wide_height,
+        ))
+    }
+}
+
+/// A view, that builds its content with `view_fn` depending on the [`SizeClass`] of the terminal,
+/// and rebuilds it when the terminal is resized across one of the [`Breakpoints`].
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), move |()| {
+/// responsive(|size_class| match size_class {
+///     SizeClass::Compact => OneOf2::A("List"),
+///     SizeClass::Medium | SizeClass::Wide => OneOf2::B(h_stack(("List", "Details"))),
+/// })
+/// # });
+/// ```
+pub fn responsive<T, A, V, F>(view_fn: F) -> Responsive<T, A, V, F>
+where
+    V: View<T, A>,
+    F: Fn(SizeClass) -> V + Send + Sync,
+{
+    Responsive {
+        view_fn,
+        breakpoints: Breakpoints::default(),
+        phantom: PhantomData,
+    }
+}
+
+pub struct Responsive<T, A, V, F> {
+    view_fn: F,
+    breakpoints: Breakpoints,
+    #[allow(clippy::type_complexity)]
+    phantom: PhantomData<fn() -> (T, A, V)>,
+}
+
+impl<T, A, V, F> Responsive<T, A, V, F> {
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+}
+
+pub struct ResponsiveState<T, A, V: View<T, A>> {
+    view: V,
+    child_id: Id,
+    view_state: V::State,
+}
+
+impl<T, A, V, F> ViewMarker for Responsive<T, A, V, F> {}
+
+impl<T, A, V, F> View<T, A> for Responsive<T, A, V, F>
+where
+    V: View<T, A>,
+    F: Fn(SizeClass) -> V + Send + Sync,
+{
+    type State = ResponsiveState<T, A, V>;
+
+    type Element = crate::widget::Responsive;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (width, height) = cx.terminal_size();
+        let size_class = self.breakpoints.size_class(width, height);
+        let view = (self.view_fn)(size_class);
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (child_id, view_state, element) = view.build(cx);
+            let element =
+                crate::widget::Responsive::new(element, cx.id_path(), self.breakpoints, size_class);
+            (
+                ResponsiveState {
+                    view,
+                    child_id,
+                    view_state,
+                },
+                element,
+            )
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let (width, height) = cx.terminal_size();
+        let size_class = self.breakpoints.size_class(width, height);
+        let view = (self.view_fn)(size_class);
+        cx.with_id(*id, |cx| {
+            element.set_size_class(cx.id_path(), self.breakpoints, size_class);
+            let content_el = element
+                .content
+                .downcast_mut()
+                .expect("The responsive widget changed its type, this should never happen!");
+            let changeflags = view.rebuild(
+                cx,
+                &state.view,
+                &mut state.child_id,
+                &mut state.view_state,
+                content_el,
+            );
+            state.view = view;
+            element.content.mark(changeflags)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if *first == state.child_id => {
+                state
+                    .view
+                    .message(rest_path, &mut state.view_state, message, app_state)
+            }
+            // the terminal was resized across a breakpoint
+            [] => MessageResult::RequestRebuild,
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    any::Any,
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::ChangeFlags;
+
+use super::{Cx, View, ViewMarker};
+
+/// The versions of all [`Tracked`] values, so that two different values never share a version.
+static NEXT_VERSION: AtomicU64 = AtomicU64::new(1);
+
+fn next_version() -> u64 {
+    NEXT_VERSION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A part of the app state, whose mutations are tracked, so that the views built from it with [`scoped`]
+/// are only rebuilt, when it has changed.
+///
+/// Every mutable access (via [`DerefMut`]) counts as a mutation, even when nothing has actually changed.
+/// The value is shared with the scoped views, and is only cloned on a mutation, while a view still refers to it.
+pub struct Tracked<S> {
+    value: Arc<S>,
+    version: u64,
+}
+
+impl<S> Tracked<S> {
+    pub fn new(value: S) -> Self {
+        Tracked {
+            value: Arc::new(value),
+            version: next_version(),
+        }
+    }
+
+    /// The version of the value, which changes with every mutation.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl<S> Deref for Tracked<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.value
+    }
+}
+
+impl<S: Clone> DerefMut for Tracked<S> {
+    fn deref_mut(&mut self) -> &mut S {
+        self.version = next_version();
+        Arc::make_mut(&mut self.value)
+    }
+}
+
+impl<S> Clone for Tracked<S> {
+    fn clone(&self) -> Self {
+        Tracked {
+            value: self.value.clone(),
+            version: self.version,
+        }
+    }
+}
+
+impl<S: Default> Default for Tracked<S> {
+    fn default() -> Self {
+        Tracked::new(S::default())
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Tracked<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<S> From<S> for Tracked<S> {
+    fn from(value: S) -> Self {
+        Tracked::new(value)
+    }
+}
+
+/// Builds the view returned by `f` from a [`Tracked`] part of the app state,
+/// and skips calling `f` and rebuilding the view entirely, as long as that part wasn't mutated.
+///
+/// This is similar to [`memoize`](crate::memoize), but it's driven by tracking the mutations of the state,
+/// instead of comparing copied props for equality.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// struct AppState {
+///     log: Tracked<Vec<String>>,
+///     count: u32,
+/// }
+///
+/// let state = AppState { log: Tracked::new(vec!["started".into()]), count: 0 };
+/// App::new(state, |state| {
+///     v_stack((
+///         // only rebuilt, when the log was mutated, not when the count changes
+///         scoped(&state.log, |log| v_stack(log.clone())),
+///         button(format!("Count: {}", state.count), |state: &mut AppState| {
+///             state.count += 1;
+///             state.log.push(format!("count is {}", state.count));
+///         }),
+///     ))
+/// });
+/// ```
+pub fn scoped<S, F, V>(state: &Tracked<S>, f: F) -> Scoped<S, F>
+where
+    F: Fn(&S) -> V,
+{
+    Scoped {
+        state: state.clone(),
+        f,
+    }
+}
+
+/// See [`scoped`].
+pub struct Scoped<S, F> {
+    state: Tracked<S>,
+    f: F,
+}
+
+pub struct ScopedState<V, S> {
+    version: u64,
+    view: V,
+    view_state: S,
+}
+
+impl<S, F> ViewMarker for Scoped<S, F> {}
+
+impl<T, A, S, F, V> View<T, A> for Scoped<S, F>
+where
+    S: Send + Sync,
+    F: Fn(&S) -> V + Send + Sync,
+    V: View<T, A>,
+{
+    type State = ScopedState<V, V::State>;
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let view = (self.f)(&self.state);
+        let (id, view_state, element) = view.build(cx);
+        let state = ScopedState {
+            version: self.state.version(),
+            view,
+            view_state,
+        };
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        if self.state.version() == state.version {
+            return ChangeFlags::empty();
+        }
+        state.version = self.state.version();
+        let view = (self.f)(&self.state);
+        let changeflags = view.rebuild(cx, &state.view, id, &mut state.view_state, element);
+        state.view = view;
+        changeflags
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        state
+            .view
+            .message(id_path, &mut state.view_state, message, app_state)
+    }
+}
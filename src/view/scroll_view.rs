@@ -4,14 +4,100 @@ use crate::{view::View, widget::ChangeFlags};
 
 use xilem_core::{Id, MessageResult};
 
-use super::{Cx, ViewMarker, ViewSequence};
+use super::{Cx, EventHandler, ViewMarker, ViewSequence};
 
+/// The axes on which a [`scroll_view`] can be scrolled.
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScrollAxes {
+    /// The content is as wide as the scroll view.
+    #[default]
+    Vertical,
+    /// The content is as high as the scroll view.
+    Horizontal,
+    Both,
+}
+
+impl ScrollAxes {
+    pub fn horizontal(self) -> bool {
+        matches!(self, ScrollAxes::Horizontal | ScrollAxes::Both)
+    }
+
+    pub fn vertical(self) -> bool {
+        matches!(self, ScrollAxes::Vertical | ScrollAxes::Both)
+    }
+}
+
+/// How a [`scroll_view`] follows the mouse wheel, see [`ScrollView::behavior`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollBehavior {
+    /// The ratio (between `0.0` and `1.0`), by which the distance to the scroll target shrinks every 10 ms,
+    /// `1.0` jumps directly to the target.
+    pub decay: f64,
+    /// The number of rows (or columns), the content can be scrolled beyond its ends with the mouse wheel,
+    /// before it bounces back. Only has an effect with a smooth behavior.
+    pub overscroll: f64,
+}
+
+impl Default for ScrollBehavior {
+    fn default() -> Self {
+        ScrollBehavior::INSTANT
+    }
+}
+
+impl ScrollBehavior {
+    /// Jumps directly to the scroll target, without overscroll, this is the default.
+    pub const INSTANT: ScrollBehavior = ScrollBehavior {
+        decay: 1.0,
+        overscroll: 0.0,
+    };
+
+    /// Animates the offset towards the scroll target.
+    pub fn smooth() -> Self {
+        ScrollBehavior {
+            decay: 0.2,
+            overscroll: 0.0,
+        }
+    }
+
+    /// Allows scrolling `rows` beyond the ends of the content with the mouse wheel, after which it bounces back.
+    pub fn overscroll(mut self, rows: u16) -> Self {
+        self.overscroll = rows as f64;
+        self
+    }
+
+    pub fn is_smooth(&self) -> bool {
+        self.decay < 1.0
+    }
+}
+
+/// A scrollable view, see [`scroll_view`].
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub struct ScrollView<T, A, C> {
     child: C,
+    axes: ScrollAxes,
+    line_scroll: u16,
+    page_scroll: Option<u16>,
+    focusable: bool,
+    behavior: ScrollBehavior,
     phantom: PhantomData<fn() -> (T, A)>,
 }
 
+/// Scrolls `child` vertically (or on the [`ScrollView::axes`]) with the mouse wheel, and with the keyboard
+/// (arrow keys, `PageUp`/`PageDown`, `Home`/`End`), while it or one of its descendants is focused.
+///
+/// `Shift` + mouse wheel scrolls horizontally.
+/// By default it jumps to the new offset, a smooth animation can be configured with [`ScrollView::behavior`].
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let lines: Vec<String> = (0..100).map(|i| format!("Line {i}")).collect();
+/// App::new((), move |_| scroll_view(v_stack(lines.clone())).page_scroll(10).focusable());
+///
+/// // a wide table
+/// let rows: Vec<String> = (0..100).map(|i| format!("{i:>3} {}", "| cell ".repeat(30))).collect();
+/// App::new((), move |_| scroll_view(v_stack(rows.clone())).axes(ScrollAxes::Both));
+/// ```
 pub fn scroll_view<T, A, C>(child: C) -> ScrollView<T, A, C> {
     ScrollView::new(child)
 }
@@ -20,9 +106,140 @@ impl<T, A, C> ScrollView<T, A, C> {
     pub fn new(child: C) -> Self {
         ScrollView {
             child,
+            axes: ScrollAxes::Vertical,
+            line_scroll: 1,
+            page_scroll: None,
+            focusable: false,
+            behavior: ScrollBehavior::INSTANT,
             phantom: Default::default(),
         }
     }
+
+    /// The axes, on which the content can be scrolled, the content is unbounded on these axes.
+    pub fn axes(mut self, axes: ScrollAxes) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// The number of rows (or columns) scrolled with the mouse wheel and the arrow keys, 1 by default.
+    pub fn line_scroll(mut self, rows: u16) -> Self {
+        self.line_scroll = rows;
+        self
+    }
+
+    /// The number of rows scrolled with `PageUp`/`PageDown`, by default the height of the viewport minus one row.
+    pub fn page_scroll(mut self, rows: u16) -> Self {
+        self.page_scroll = Some(rows);
+        self
+    }
+
+    /// Makes the scroll view itself focusable (with `Tab` or by clicking it),
+    /// so that it can be scrolled with the keyboard, even if it has no focusable content.
+    pub fn focusable(mut self) -> Self {
+        self.focusable = true;
+        self
+    }
+
+    /// How the scroll view follows the mouse wheel and keyboard, e.g. smoothly with an elastic bounce at the ends:
+    ///
+    /// ```
+    /// # use trui::*;
+    /// let lines: Vec<String> = (0..100).map(|i| format!("Line {i}")).collect();
+    /// App::new((), move |_| {
+    ///     scroll_view(v_stack(lines.clone())).behavior(ScrollBehavior::smooth().overscroll(3))
+    /// });
+    /// ```
+    pub fn behavior(mut self, behavior: ScrollBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    /// Calls `handler` when the viewport is scrolled near the end of the content, e.g. for loading more items of an infinite list.
+    ///
+    /// It's called once, when less than [`OnReachEnd::threshold`] rows are remaining below the viewport
+    /// (which is also the case for content that doesn't fill the viewport, e.g. before the first page was loaded),
+    /// and only again after the content has grown (or was scrolled back up) sufficiently.
+    /// See [`paginated`](crate::view::paginated) for an example.
+    pub fn on_reach_end<EH>(self, handler: EH) -> OnReachEnd<Self, EH> {
+        OnReachEnd {
+            view: self,
+            handler,
+            threshold: DEFAULT_REACH_END_THRESHOLD,
+        }
+    }
+}
+
+/// The default number of rows remaining below the viewport, at which [`ScrollView::on_reach_end`] is triggered.
+const DEFAULT_REACH_END_THRESHOLD: f64 = 3.0;
+
+/// See [`ScrollView::on_reach_end`].
+pub struct OnReachEnd<V, EH> {
+    view: V,
+    handler: EH,
+    threshold: f64,
+}
+
+impl<V, EH> OnReachEnd<V, EH> {
+    /// The number of rows remaining below the viewport, at which the handler is called, 3 by default.
+    pub fn threshold(mut self, rows: u16) -> Self {
+        self.threshold = rows as f64;
+        self
+    }
+}
+
+impl<V, EH> ViewMarker for OnReachEnd<V, EH> {}
+
+impl<T, A, V, EH> View<T, A> for OnReachEnd<V, EH>
+where
+    V: View<T, A, Element = crate::widget::ScrollView>,
+    EH: EventHandler<T, A>,
+{
+    type State = (V::State, Id, (Id, EH::State));
+
+    type Element = crate::widget::ScrollView;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (child_id, state, mut element) = self.view.build(cx);
+            let _ = element.set_reach_end(cx.id_path(), self.threshold);
+            ((state, child_id, self.handler.build(cx)), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (state, child_id, (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            self.view.rebuild(cx, &prev.view, child_id, state, element)
+                | element.set_reach_end(cx.id_path(), self.threshold)
+                | self.handler.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (state, child_id, (eh_id, eh_state)): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == child_id => {
+                self.view.message(rest_path, state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == eh_id => self
+                .handler
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.handler.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
 }
 
 impl<T, A, VT: ViewSequence<T, A>> ViewMarker for ScrollView<T, A, VT> {}
@@ -37,7 +254,14 @@ where
 
     fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
         let (id, child_state, child_element) = self.child.build(cx);
-        let element = crate::widget::ScrollView::new(child_element);
+        let mut element = crate::widget::ScrollView::new(child_element);
+        let _ = element.set_scroll_amounts(
+            self.line_scroll as f64,
+            self.page_scroll.map(|rows| rows as f64),
+        );
+        let _ = element.set_focusable(self.focusable);
+        let _ = element.set_axes(self.axes);
+        let _ = element.set_behavior(self.behavior);
         (id, child_state, element)
     }
 
@@ -49,8 +273,14 @@ where
         state: &mut Self::State,
         element: &mut Self::Element,
     ) -> ChangeFlags {
+        let changeflags = element.set_scroll_amounts(
+            self.line_scroll as f64,
+            self.page_scroll.map(|rows| rows as f64),
+        ) | element.set_focusable(self.focusable)
+            | element.set_axes(self.axes)
+            | element.set_behavior(self.behavior);
         let child_el = element.child_mut().downcast_mut().unwrap();
-        self.child.rebuild(cx, &prev.child, id, state, child_el)
+        changeflags | self.child.rebuild(cx, &prev.child, id, state, child_el)
     }
 
     fn message(
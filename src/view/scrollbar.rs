@@ -0,0 +1,118 @@
+use xilem_core::{Id, MessageResult};
+
+use crate::{
+    geometry::Axis,
+    widget::{self, ChangeFlags},
+};
+
+use super::{Cx, EventHandler, View, ViewMarker};
+
+/// A scrollbar for a custom viewport, see [`scrollbar`].
+pub struct Scrollbar<EH> {
+    orientation: Axis,
+    position: f64,
+    page_size: f64,
+    on_scroll: EH,
+}
+
+/// A scrollbar along `orientation` for a viewport, that's scrolled by the app (e.g. a table or a text viewer).
+///
+/// `position` is the scroll position from `0.0` (at the start) to `1.0` (at the end),
+/// and `page_size` the visible part of the content from `0.0` to `1.0` (everything is visible), which is the length of the thumb.
+///
+/// `on_scroll` is called with the new position, when the thumb is dragged, the track is clicked (which scrolls by a page),
+/// or the mouse wheel is used over the scrollbar.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # use trui::geometry::Axis;
+/// struct Viewer {
+///     lines: Vec<String>,
+///     first_line: usize,
+/// }
+///
+/// const VISIBLE_LINES: usize = 10;
+///
+/// # App::new(Viewer { lines: (0..100).map(|i| format!("line {i}")).collect(), first_line: 0 }, |viewer: &mut Viewer| {
+/// let scrollable = viewer.lines.len().saturating_sub(VISIBLE_LINES).max(1);
+/// h_stack((
+///     v_stack(viewer.lines[viewer.first_line..][..VISIBLE_LINES].to_vec()),
+///     scrollbar(
+///         Axis::Vertical,
+///         viewer.first_line as f64 / scrollable as f64,
+///         VISIBLE_LINES as f64 / viewer.lines.len() as f64,
+///         move |viewer: &mut Viewer, position: f64| {
+///             viewer.first_line = (position * scrollable as f64).round() as usize
+///         },
+///     ),
+/// ))
+/// # });
+/// ```
+pub fn scrollbar<EH>(
+    orientation: Axis,
+    position: f64,
+    page_size: f64,
+    on_scroll: EH,
+) -> Scrollbar<EH> {
+    Scrollbar {
+        orientation,
+        position,
+        page_size,
+        on_scroll,
+    }
+}
+
+impl<EH> ViewMarker for Scrollbar<EH> {}
+
+impl<T, A, EH: EventHandler<T, A, f64>> View<T, A> for Scrollbar<EH> {
+    type State = (Id, EH::State);
+
+    type Element = widget::Scrollbar;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let element = widget::Scrollbar::new(
+                cx.id_path(),
+                self.orientation,
+                self.position,
+                self.page_size,
+                cx.theme().scrollbar,
+            );
+            (self.on_scroll.build(cx), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (eh_id, eh_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            element.set_axis(self.orientation)
+                | element.set_position(self.position, self.page_size)
+                | element.set_styles(cx.theme().scrollbar)
+                | self.on_scroll.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (eh_id, eh_state): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_scroll
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_scroll.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::{any::Any, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, View, ViewMarker};
+
+pub struct Section<T, A, H, C> {
+    header: H,
+    content: C,
+    phantom: PhantomData<fn() -> (T, A)>,
+}
+
+/// A section of a (scrollable) list with a `header` above the `content`.
+///
+/// Within a [`scroll_view`](crate::view::scroll_view), the header sticks to the top of the viewport,
+/// while the content of the section is visible, until it's pushed out by the header of the next section.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), move |_| {
+/// let days = [("Monday", ["Deploy", "Fix bug"]), ("Tuesday", ["Review", "Release"])];
+/// scroll_view(v_stack(Vec::from(
+///     days.map(|(day, logs)| section(day.fg(Color::Yellow), v_stack(logs.to_vec()))),
+/// )))
+/// # });
+/// ```
+pub fn section<T, A, H, C>(header: H, content: C) -> Section<T, A, H, C> {
+    Section {
+        header,
+        content,
+        phantom: PhantomData,
+    }
+}
+
+impl<T, A, H, C> ViewMarker for Section<T, A, H, C> {}
+
+impl<T, A, H: View<T, A>, C: View<T, A>> View<T, A> for Section<T, A, H, C>
+where
+    H::Element: 'static,
+    C::Element: 'static,
+{
+    type State = ((Id, H::State), (Id, C::State));
+
+    type Element = widget::Section;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (header_id, header_state, header) = self.header.build(cx);
+            let (content_id, content_state, content) = self.content.build(cx);
+            (
+                ((header_id, header_state), (content_id, content_state)),
+                widget::Section::new(header, content),
+            )
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        ((header_id, header_state), (content_id, content_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            let header_el = element
+                .header
+                .downcast_mut()
+                .expect("The section header widget changed its type, this should never happen!");
+            let header_changeflags =
+                self.header
+                    .rebuild(cx, &prev.header, header_id, header_state, header_el);
+            let content_el = element
+                .content
+                .downcast_mut()
+                .expect("The section content widget changed its type, this should never happen!");
+            let content_changeflags =
+                self.content
+                    .rebuild(cx, &prev.content, content_id, content_state, content_el);
+            element.header.mark(header_changeflags) | element.content.mark(content_changeflags)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        ((header_id, header_state), (content_id, content_state)): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == header_id => {
+                self.header
+                    .message(rest_path, header_state, message, app_state)
+            }
+            [first, rest_path @ ..] if first == content_id => {
+                self.content
+                    .message(rest_path, content_state, message, app_state)
+            }
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
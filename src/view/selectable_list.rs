@@ -0,0 +1,131 @@
+use std::{any::Any, collections::BTreeSet, marker::PhantomData};
+
+use xilem_core::{Id, MessageResult, VecSplice};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, EventHandler, View, ViewMarker, ViewSequence};
+
+/// Rows with a checkbox column, of which several can be selected, see [`selectable_list`].
+pub struct SelectableList<T, A, VT, EH> {
+    items: VT,
+    selection: BTreeSet<usize>,
+    on_selection_change: EH,
+    phantom: PhantomData<fn() -> (T, A)>,
+}
+
+/// Stacks the `items` vertically with a checkbox in front of each row, the indices of the checked rows are the `selection`.
+///
+/// While the list is focused, `Up`/`Down` (or `Home`/`End`) move the cursor, `Space` toggles the row at the cursor,
+/// `Shift` + `Up`/`Down` extends a range from the row, where it started, and `Ctrl+A` selects all rows.
+/// With the mouse, clicking a checkbox toggles its row, and `Shift` + click selects a range.
+///
+/// `on_selection_change` is called with the new selection,
+/// the checkboxes aren't changed until the app state is changed accordingly.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # use std::collections::BTreeSet;
+/// struct Mailbox {
+///     subjects: Vec<&'static str>,
+///     selection: BTreeSet<usize>,
+/// }
+///
+/// # App::new(Mailbox { subjects: vec!["Hello", "Invoice", "Re: Hello"], selection: BTreeSet::new() }, |mailbox: &mut Mailbox| {
+/// v_stack((
+///     format!("{} selected", mailbox.selection.len()),
+///     selectable_list(
+///         mailbox.subjects.clone(),
+///         mailbox.selection.clone(),
+///         |mailbox: &mut Mailbox, selection: BTreeSet<usize>| mailbox.selection = selection,
+///     ),
+/// ))
+/// # });
+/// ```
+pub fn selectable_list<T, A, VT, EH>(
+    items: VT,
+    selection: BTreeSet<usize>,
+    on_selection_change: EH,
+) -> SelectableList<T, A, VT, EH>
+where
+    VT: ViewSequence<T, A>,
+    EH: EventHandler<T, A, BTreeSet<usize>>,
+{
+    SelectableList {
+        items,
+        selection,
+        on_selection_change,
+        phantom: PhantomData,
+    }
+}
+
+impl<T, A, VT, EH> ViewMarker for SelectableList<T, A, VT, EH> {}
+
+impl<T, A, VT, EH> View<T, A> for SelectableList<T, A, VT, EH>
+where
+    VT: ViewSequence<T, A>,
+    EH: EventHandler<T, A, BTreeSet<usize>>,
+{
+    type State = (VT::State, (Id, EH::State));
+
+    type Element = widget::SelectableList;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let mut elements = vec![];
+        let mut scratch = vec![];
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let items_state = self
+                .items
+                .build(cx, &mut VecSplice::new(&mut elements, &mut scratch));
+            let element = widget::SelectableList::new(
+                cx.id_path(),
+                elements,
+                self.selection.clone(),
+                cx.theme().selectable_list,
+            );
+            ((items_state, self.on_selection_change.build(cx)), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (items_state, (eh_id, eh_state)): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let changeflags = element.set_styles(cx.theme().selectable_list)
+            | element.set_selection(self.selection.clone());
+
+        let mut scratch = vec![];
+        let mut splice = VecSplice::new(&mut element.children, &mut scratch);
+
+        changeflags
+            | cx.with_id(*id, |cx| {
+                self.items
+                    .rebuild(cx, &prev.items, items_state, &mut splice)
+                    | self.on_selection_change.rebuild(cx, eh_id, eh_state)
+            })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (items_state, (eh_id, eh_state)): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_selection_change
+                .message(rest_path, eh_state, message, app_state),
+            [] => self
+                .on_selection_change
+                .message(&[], eh_state, message, app_state),
+            _ => self.items.message(id_path, items_state, message, app_state),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::{app::AppMessage, widget::ChangeFlags};
+
+use super::{Cx, View, ViewMarker};
+
+/// A shared value outside of the app state, whose changes only rebuild the views subscribed to it, see [`subscribe`].
+///
+/// It can be cloned into event handlers and async tasks, all clones refer to the same value.
+/// When the value is changed (with [`Signal::set`] or [`Signal::update`]), and nothing else has changed
+/// since the last render, the app logic isn't run again. Instead only the views subscribed to the signal are rebuilt
+/// with the new value, while the rest of the view tree is kept as is.
+///
+/// This is an opt-in escape hatch for very large apps, where running the app logic and diffing the whole view tree
+/// on every change is too slow, usually the app state should be preferred.
+pub struct Signal<T> {
+    inner: Arc<SignalInner<T>>,
+}
+
+struct SignalInner<T> {
+    value: Mutex<T>,
+    version: AtomicU64,
+    /// The app channels of the subscribed views by their id.
+    subscribers: Mutex<HashMap<Id, tokio::sync::mpsc::Sender<AppMessage>>>,
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Signal {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Default> Default for Signal<T> {
+    fn default() -> Self {
+        Signal::new(T::default())
+    }
+}
+
+impl<T> Signal<T> {
+    pub fn new(value: T) -> Self {
+        Signal {
+            inner: Arc::new(SignalInner {
+                value: Mutex::new(value),
+                version: AtomicU64::new(0),
+                subscribers: Mutex::default(),
+            }),
+        }
+    }
+
+    /// Returns a clone of the current value.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.with(T::clone)
+    }
+
+    /// Calls `f` with the current value, the signal is locked meanwhile.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.inner.value.lock().unwrap())
+    }
+
+    /// Replaces the value and rebuilds the subscribed views.
+    pub fn set(&self, value: T) {
+        self.update(|current| *current = value);
+    }
+
+    /// Mutates the value with `f` and rebuilds the subscribed views.
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let result = f(&mut self.inner.value.lock().unwrap());
+        self.inner.version.fetch_add(1, Ordering::SeqCst);
+        self.notify();
+        result
+    }
+
+    fn version(&self) -> u64 {
+        self.inner.version.load(Ordering::SeqCst)
+    }
+
+    fn notify(&self) {
+        let subscribers = self.inner.subscribers.lock().unwrap();
+        let mut notified: Vec<&tokio::sync::mpsc::Sender<AppMessage>> = Vec::new();
+        for app_chan in subscribers.values() {
+            if !notified.iter().any(|chan| chan.same_channel(app_chan)) {
+                // This may be called within the async app task, so don't block
+                let _ = app_chan.try_send(AppMessage::SignalChanged);
+                notified.push(app_chan);
+            }
+        }
+    }
+
+    fn subscribe(&self, id: Id, cx: &Cx) -> Subscription<T> {
+        let mut subscribers = self.inner.subscribers.lock().unwrap();
+        subscribers.insert(id, cx.app_chan.clone());
+        Subscription {
+            signal: self.clone(),
+            id,
+        }
+    }
+}
+
+/// Unsubscribes the view from the signal, when it's dropped together with the view state.
+pub struct Subscription<T> {
+    signal: Signal<T>,
+    id: Id,
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        let mut subscribers = self.signal.inner.subscribers.lock().unwrap();
+        subscribers.remove(&self.id);
+    }
+}
+
+/// Builds the view returned by `f` with the current value of `signal`, and rebuilds it whenever the value has changed.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// let progress = Signal::new(0u32);
+/// let progress_clone = progress.clone();
+/// std::thread::spawn(move || {
+///     for _ in 0..100 {
+///         progress_clone.update(|progress| *progress += 1);
+///     }
+/// });
+/// App::new((), move |()| {
+///     v_stack((
+///         "A large view tree, which isn't rebuilt when the progress changes",
+///         subscribe(&progress, |progress| format!("{progress}%")),
+///     ))
+/// });
+/// ```
+pub fn subscribe<T, F, V>(signal: &Signal<T>, f: F) -> Subscribe<T, F>
+where
+    F: Fn(&T) -> V,
+{
+    Subscribe {
+        signal: signal.clone(),
+        f,
+    }
+}
+
+/// See [`subscribe`].
+pub struct Subscribe<T, F> {
+    signal: Signal<T>,
+    f: F,
+}
+
+pub struct SubscribeState<T, V, S> {
+    version: u64,
+    view: V,
+    view_state: S,
+    subscription: Subscription<T>,
+}
+
+impl<T, F> ViewMarker for Subscribe<T, F> {}
+
+impl<T, A, ST, F, V> View<T, A> for Subscribe<ST, F>
+where
+    ST: Send + 'static,
+    F: Fn(&ST) -> V + Send + Sync,
+    V: View<T, A>,
+{
+    type State = SubscribeState<ST, V, V::State>;
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let version = self.signal.version();
+        let view = self.signal.with(&self.f);
+        let (id, view_state, element) = view.build(cx);
+        let subscription = self.signal.subscribe(id, cx);
+        let state = SubscribeState {
+            version,
+            view,
+            view_state,
+            subscription,
+        };
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        if !Arc::ptr_eq(&self.signal.inner, &prev.signal.inner) {
+            state.subscription = self.signal.subscribe(*id, cx);
+        }
+        let version = self.signal.version();
+        // When the app logic wasn't run, `self` is `prev`, and only a changed value has to be rebuilt
+        if std::ptr::eq(self, prev) && version == state.version {
+            return ChangeFlags::empty();
+        }
+        state.version = version;
+        let view = self.signal.with(&self.f);
+        let prev_id = *id;
+        let changeflags = view.rebuild(cx, &state.view, id, &mut state.view_state, element);
+        if *id != prev_id {
+            state.subscription = self.signal.subscribe(*id, cx);
+        }
+        state.view = view;
+        changeflags
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        state
+            .view
+            .message(id_path, &mut state.view_state, message, app_state)
+    }
+}
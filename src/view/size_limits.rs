@@ -0,0 +1,96 @@
+use std::marker::PhantomData;
+
+use xilem_core::MessageResult;
+
+use crate::{
+    geometry::Length,
+    widget::{self, ChangeFlags},
+    Cx, View, ViewMarker,
+};
+
+/// Limits the size of its content, see [`ViewExt::min_width`](crate::ViewExt::min_width) and friends.
+///
+/// The limits can be chained, e.g. `view.min_width(10).max_width(Length::Percent(0.5))`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SizeLimits<V, T, A> {
+    pub(crate) content: V,
+    pub(crate) min: (Length, Length),
+    pub(crate) max: (Length, Length),
+    pub(crate) phantom: PhantomData<fn() -> (T, A)>,
+}
+
+impl<V, T, A> SizeLimits<V, T, A> {
+    pub(crate) fn new(content: V) -> Self {
+        SizeLimits {
+            content,
+            min: (Length::Auto, Length::Auto),
+            max: (Length::Auto, Length::Auto),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn min_width(mut self, width: impl Into<Length>) -> Self {
+        self.min.0 = width.into();
+        self
+    }
+
+    pub fn min_height(mut self, height: impl Into<Length>) -> Self {
+        self.min.1 = height.into();
+        self
+    }
+
+    pub fn max_width(mut self, width: impl Into<Length>) -> Self {
+        self.max.0 = width.into();
+        self
+    }
+
+    pub fn max_height(mut self, height: impl Into<Length>) -> Self {
+        self.max.1 = height.into();
+        self
+    }
+}
+
+impl<V, T, A> ViewMarker for SizeLimits<V, T, A> {}
+
+impl<T, A, V: View<T, A>> View<T, A> for SizeLimits<V, T, A> {
+    type State = V::State;
+
+    type Element = widget::SizeLimits;
+
+    fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
+        let (id, state, element) = self.content.build(cx);
+        let element = widget::SizeLimits::new(element, self.min, self.max);
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut xilem_core::Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let changeflags = element.set_limits(self.min, self.max);
+
+        let content_el = element
+            .content
+            .downcast_mut()
+            .expect("The size limits widget changed its type, this should never happen!");
+
+        let content_changeflags = self
+            .content
+            .rebuild(cx, &prev.content, id, state, content_el);
+        changeflags | element.content.mark(content_changeflags)
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.content.message(id_path, state, message, app_state)
+    }
+}
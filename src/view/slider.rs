@@ -0,0 +1,110 @@
+use std::ops::RangeInclusive;
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, EventHandler, View, ViewMarker};
+
+/// The number of steps in the range, that are used by default for adjusting the slider with the arrow keys.
+const DEFAULT_STEPS: f64 = 20.0;
+
+/// A horizontal slider, see [`slider`].
+pub struct Slider<EH> {
+    pub(crate) range: RangeInclusive<f64>,
+    pub(crate) value: f64,
+    pub(crate) step: Option<f64>,
+    pub(crate) on_change: EH,
+}
+
+/// A horizontal slider showing `value` within `range`, it fills the available width.
+///
+/// The value can be changed by clicking or dragging with the mouse,
+/// or with the arrow keys (by [`Slider::step`]) and `Home`/`End`, while it's focused.
+/// `on_change` is called with the new value, and the thumb smoothly moves to the new position.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(0.5, move |volume: &mut f64| {
+/// v_stack((
+///     format!("Volume: {:.0}%", *volume * 100.0),
+///     slider(0.0..=1.0, *volume, |volume: &mut f64, value: f64| *volume = value).step(0.1),
+/// ))
+/// # });
+/// ```
+pub fn slider<EH>(range: RangeInclusive<f64>, value: f64, on_change: EH) -> Slider<EH> {
+    Slider {
+        range,
+        value,
+        step: None,
+        on_change,
+    }
+}
+
+impl<EH> Slider<EH> {
+    /// The amount the value changes with the arrow keys, by default it's a twentieth of the range.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    fn resolved_step(&self) -> f64 {
+        self.step
+            .unwrap_or_else(|| (self.range.end() - self.range.start()) / DEFAULT_STEPS)
+    }
+}
+
+impl<EH> ViewMarker for Slider<EH> {}
+
+impl<T, A, EH: EventHandler<T, A, f64>> View<T, A> for Slider<EH> {
+    type State = (Id, EH::State);
+
+    type Element = widget::Slider;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let element = widget::Slider::new(
+                cx.id_path(),
+                self.range.clone(),
+                self.value,
+                self.resolved_step(),
+                cx.theme().slider,
+            );
+            (self.on_change.build(cx), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (eh_id, eh_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            element.set_value(self.range.clone(), self.value)
+                | element.set_step(self.resolved_step())
+                | element.set_styles(cx.theme().slider)
+                | self.on_change.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (eh_id, eh_state): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_change
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_change.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
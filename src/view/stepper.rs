@@ -0,0 +1,101 @@
+use std::ops::RangeInclusive;
+
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, EventHandler, View, ViewMarker};
+
+/// A number input, see [`stepper`].
+pub struct Stepper<EH> {
+    pub(crate) value: i64,
+    pub(crate) range: RangeInclusive<i64>,
+    pub(crate) step: i64,
+    pub(crate) on_change: EH,
+}
+
+/// A number input showing `value`, with `[-]` and `[+]` buttons that change it by `step`.
+///
+/// While focused, the value can be changed with the arrow keys (or `+`),
+/// or typed directly and committed with `Enter`, typed input is discarded when the stepper loses focus.
+/// `on_change` is called with the new value, which is always clamped to `range`.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(4, move |tab_width: &mut i64| {
+/// h_stack((
+///     "Tab width: ",
+///     stepper(*tab_width, 1..=16, 1, |tab_width: &mut i64, value: i64| {
+///         *tab_width = value
+///     }),
+/// ))
+/// # });
+/// ```
+pub fn stepper<EH>(
+    value: i64,
+    range: RangeInclusive<i64>,
+    step: i64,
+    on_change: EH,
+) -> Stepper<EH> {
+    Stepper {
+        value,
+        range,
+        step,
+        on_change,
+    }
+}
+
+impl<EH> ViewMarker for Stepper<EH> {}
+
+impl<T, A, EH: EventHandler<T, A, i64>> View<T, A> for Stepper<EH> {
+    type State = (Id, EH::State);
+
+    type Element = widget::Stepper;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let element = widget::Stepper::new(
+                cx.id_path(),
+                self.value,
+                self.range.clone(),
+                self.step,
+                cx.theme().stepper,
+            );
+            (self.on_change.build(cx), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (eh_id, eh_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            element.set_value(self.value)
+                | element.set_range(self.range.clone(), self.step)
+                | element.set_styles(cx.theme().stepper)
+                | self.on_change.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (eh_id, eh_state): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_change
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_change.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::{any::Any, borrow::Cow};
+
+use xilem_core::{Id, MessageResult};
+
+use crate::{
+    widget::{self, ChangeFlags},
+    WrapMode,
+};
+
+use super::{Cx, EventHandler, View, ViewMarker};
+
+/// A column of a [`table`] with its title and width in cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableColumn {
+    pub title: Cow<'static, str>,
+    pub width: u16,
+    /// The column can't be resized below this width, 1 by default.
+    pub min_width: u16,
+    /// The column can't be resized beyond this width, unbounded by default.
+    pub max_width: u16,
+}
+
+impl TableColumn {
+    pub fn new(title: impl Into<Cow<'static, str>>, width: u16) -> Self {
+        TableColumn {
+            title: title.into(),
+            width,
+            min_width: 1,
+            max_width: u16::MAX,
+        }
+    }
+
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    pub(crate) fn clamped_width(&self) -> u16 {
+        self.width
+            .clamp(self.min_width, self.max_width.max(self.min_width))
+    }
+}
+
+/// Rows of text in resizable columns, see [`table`].
+pub struct Table {
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<String>>,
+    wrap: Option<WrapMode>,
+}
+
+/// Shows the `rows` of text in `columns` with a header, cells that don't fit into their column are cut off.
+///
+/// The columns can be resized by dragging the separator after them in the header with the mouse,
+/// or while the table is focused by selecting a column with `Left`/`Right` and resizing it with `Shift` + `Left`/`Right` (or `-`/`+`),
+/// within the [`TableColumn::min_width`] and [`TableColumn::max_width`] of the column.
+///
+/// The resized widths are kept, until the width of the column in `columns` changes,
+/// see [`Table::on_column_resize`] to persist them.
+/// Wide tables can be wrapped in a [`scroll_view`](crate::view::scroll_view) with [`ScrollAxes::Horizontal`](crate::view::ScrollAxes::Horizontal),
+/// the separators can also be dragged while it's scrolled.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), |_| {
+/// table(
+///     vec![TableColumn::new("Name", 12).min_width(4), TableColumn::new("Size", 6)],
+///     vec![
+///         vec!["Cargo.toml".into(), "1.2K".into()],
+///         vec!["README.md".into(), "5.0K".into()],
+///     ],
+/// )
+/// # });
+/// ```
+pub fn table(columns: Vec<TableColumn>, rows: Vec<Vec<String>>) -> Table {
+    Table {
+        columns,
+        rows,
+        wrap: None,
+    }
+}
+
+impl Table {
+    /// Wraps the text of the cells to the width of their column, instead of cutting it off,
+    /// each row is as high as its cell with the most lines.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let text = render_to_string::<(), _>(
+    ///     table(
+    ///         vec![TableColumn::new("Task", 9), TableColumn::new("Due", 5)],
+    ///         vec![vec!["Write the release notes".into(), "Fri".into()]],
+    ///     )
+    ///     .wrap(WrapMode::Word),
+    ///     (16, 4),
+    ///     ScreenshotFormat::Plain,
+    /// );
+    /// assert_eq!(
+    ///     text,
+    ///     "Task     │Due  │\nWrite the│Fri  │\nrelease  │     │\nnotes    │     │\n"
+    /// );
+    /// ```
+    pub fn wrap(mut self, mode: WrapMode) -> Self {
+        self.wrap = Some(mode);
+        self
+    }
+
+    /// Calls `handler` with the index and the new width of a column, when it was resized by the user,
+    /// e.g. to persist the widths and pass them as [`TableColumn::width`] the next time the app is started.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(vec![20, 8], |widths: &mut Vec<u16>| {
+    /// table(
+    ///     vec![TableColumn::new("Subject", widths[0]), TableColumn::new("From", widths[1])],
+    ///     vec![vec!["Hello".into(), "Ada".into()]],
+    /// )
+    /// .on_column_resize(|widths: &mut Vec<u16>, (column, width): (usize, u16)| {
+    ///     widths[column] = width
+    /// })
+    /// # });
+    /// ```
+    pub fn on_column_resize<EH>(self, handler: EH) -> OnColumnResize<EH> {
+        OnColumnResize {
+            table: self,
+            handler,
+        }
+    }
+
+    fn build_element(&self, cx: &mut Cx) -> widget::Table {
+        let mut element =
+            widget::Table::new(self.columns.clone(), self.rows.clone(), cx.theme().table);
+        let _ = element.set_wrap(self.wrap);
+        element
+    }
+
+    fn rebuild_element(&self, cx: &mut Cx, element: &mut widget::Table) -> ChangeFlags {
+        element.set_styles(cx.theme().table)
+            | element.set_columns(self.columns.clone())
+            | element.set_rows(self.rows.clone())
+            | element.set_wrap(self.wrap)
+    }
+}
+
+impl ViewMarker for Table {}
+
+impl<T, A> View<T, A> for Table {
+    type State = ();
+
+    type Element = widget::Table;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, element) = cx.with_new_id(|cx| self.build_element(cx));
+        (id, (), element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        _id: &mut Id,
+        _state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        self.rebuild_element(cx, element)
+    }
+
+    fn message(
+        &self,
+        _id_path: &[Id],
+        _state: &mut Self::State,
+        message: Box<dyn Any>,
+        _app_state: &mut T,
+    ) -> MessageResult<A> {
+        MessageResult::Stale(message)
+    }
+}
+
+/// See [`Table::on_column_resize`].
+pub struct OnColumnResize<EH> {
+    table: Table,
+    handler: EH,
+}
+
+impl<EH> ViewMarker for OnColumnResize<EH> {}
+
+impl<T, A, EH> View<T, A> for OnColumnResize<EH>
+where
+    EH: EventHandler<T, A, (usize, u16)>,
+{
+    type State = (Id, EH::State);
+
+    type Element = widget::Table;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let mut element = self.table.build_element(cx);
+            let _ = element.set_on_resize(cx.id_path());
+            (self.handler.build(cx), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (eh_id, eh_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            self.table.rebuild_element(cx, element)
+                | element.set_on_resize(cx.id_path())
+                | self.handler.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (eh_id, eh_state): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .handler
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.handler.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
@@ -1,7 +1,7 @@
-use std::borrow::Cow;
+use std::{any::Any, borrow::Cow};
 
 use super::{common::Styleable, Cx, View, ViewMarker};
-use crate::widget::{self, ChangeFlags};
+use crate::widget::{self, ChangeFlags, SelectableElement, WrapMode};
 use ratatui::style::{Color, Modifier, Style};
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -34,8 +34,8 @@ impl From<Cow<'static, str>> for Text {
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Text {
-    text: Cow<'static, str>,
-    style: Style,
+    pub(crate) text: Cow<'static, str>,
+    pub(crate) style: Style,
 }
 
 impl<T: Into<Text>> ViewMarker for T {}
@@ -47,16 +47,17 @@ impl<T, A, S: Into<Text> + Clone + Send + Sync + Eq> View<T, A> for S {
 
     fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
         let text = self.clone().into();
-        let (id, element) = cx.with_new_id(|_| widget::Text {
-            text: text.text,
-            style: text.style,
-        });
+        let (id, mut element) = cx.with_new_id(|_| widget::Text::new(text.text, text.style));
+        if let Some(highlight) = &cx.highlight {
+            let _ = element.set_highlights(highlight.ranges(&element.text), highlight.style);
+        }
+        let _ = element.set_rtl(cx.layout_direction().is_rtl());
         (id, (), element)
     }
 
     fn rebuild(
         &self,
-        _cx: &mut Cx,
+        cx: &mut Cx,
         prev: &Self,
         _id: &mut xilem_core::Id,
         _state: &mut Self::State,
@@ -68,7 +69,14 @@ impl<T, A, S: Into<Text> + Clone + Send + Sync + Eq> View<T, A> for S {
             changeflags |= element.set_text(text.text.clone());
             changeflags |= element.set_style(text.style);
         }
-        changeflags
+        // the query may have changed, even when the text didn't
+        changeflags |= match &cx.highlight {
+            Some(highlight) => {
+                element.set_highlights(highlight.ranges(&element.text), highlight.style)
+            }
+            None => element.set_highlights(Vec::new(), Style::default()),
+        };
+        changeflags | element.set_rtl(cx.layout_direction().is_rtl())
     }
 
     fn message(
@@ -143,8 +151,49 @@ impl Styleable for Text {
     }
 }
 
+/// Text that is wrapped to the available width, created with [`ToWrappedText::wrapped`].
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new((), move |()| {
+/// v_stack((
+///     "Lorem ipsum dolor sit amet, consetetur sadipscing elitr".wrapped(),
+///     "see https://example.com/a/very/long/path"
+///         .wrapped()
+///         .wrap_mode(WrapMode::Anywhere),
+///     ("Summary: ".fg(Color::Red), "Lorem ipsum dolor sit amet")
+///         .wrapped()
+///         .max_lines(2),
+/// ))
+/// # });
+/// ```
 pub struct WrappedText {
     words: Vec<(String, Style)>,
+    mode: WrapMode,
+    max_lines: Option<usize>,
+}
+
+impl WrappedText {
+    pub(crate) fn new(words: Vec<(String, Style)>) -> Self {
+        WrappedText {
+            words,
+            mode: WrapMode::default(),
+            max_lines: None,
+        }
+    }
+
+    /// How lines are broken, by default on the whitespace between words.
+    pub fn wrap_mode(mut self, mode: WrapMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Limits the text to `max_lines`, if it's longer, the last line ends with an ellipsis.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
 }
 
 pub trait ToWrappedText {
@@ -154,13 +203,12 @@ pub trait ToWrappedText {
 impl<T: Into<Text>> ToWrappedText for T {
     fn wrapped(self) -> WrappedText {
         let text = self.into();
-        WrappedText {
-            words: text
-                .text
+        WrappedText::new(
+            text.text
                 .split_word_bounds()
                 .map(|s| (s.into(), text.style))
                 .collect(),
-        }
+        )
         // WrappedText { text: vec![self.into()] }
     }
 }
@@ -185,7 +233,7 @@ impl<T1: Into<Text>, T2: Into<Text>> ToWrappedText for (T1, T2) {
         {
             words.push(w);
         }
-        WrappedText { words }
+        WrappedText::new(words)
     }
 }
 
@@ -197,7 +245,9 @@ impl<T, A> View<T, A> for WrappedText {
     type Element = widget::WrappedText;
 
     fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
-        let (id, element) = cx.with_new_id(|_| widget::WrappedText::new(self.words.clone()));
+        let (id, element) = cx.with_new_id(|_| {
+            widget::WrappedText::new(self.words.clone(), self.mode, self.max_lines)
+        });
         (id, (), element)
     }
 
@@ -210,6 +260,8 @@ impl<T, A> View<T, A> for WrappedText {
         element: &mut Self::Element,
     ) -> ChangeFlags {
         element.set_words(&self.words)
+            | element.set_mode(self.mode)
+            | element.set_max_lines(self.max_lines)
     }
 
     fn message(
@@ -222,3 +274,114 @@ impl<T, A> View<T, A> for WrappedText {
         xilem_core::MessageResult::Stale(message)
     }
 }
+
+/// Text, which can be selected by dragging the mouse or with `Shift` + arrow keys, see [`ToSelectableText::selectable`].
+pub struct SelectableText<V, F> {
+    content: V,
+    on_select: F,
+    style: Option<Style>,
+}
+
+pub trait ToSelectableText: Sized {
+    /// Makes the text selectable by dragging the mouse over it, or with `Shift` + arrow keys (and `Home`/`End`), when it's focused.
+    ///
+    /// `on_select` is called with the selected text, whenever the selection changes, e.g. to copy it to the clipboard.
+    /// Wrapped lines are joined with a space, other lines with a newline.
+    /// The selection is drawn with [`TextTheme::selection`](crate::view::TextTheme::selection)
+    /// patched over the style of the text, or with [`SelectableText::selection_style`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(String::new(), |copied: &mut String| {
+    /// v_stack((
+    ///     "Read-only, but copyable".selectable(|copied: &mut String, text: &str| {
+    ///         *copied = text.to_string();
+    ///     }),
+    ///     ("Lorem ipsum ".fg(Color::Red), "dolor sit amet")
+    ///         .wrapped()
+    ///         .selectable(|copied: &mut String, text: &str| *copied = text.to_string())
+    ///         .selection_style(Style::default().bg(Color::Blue)),
+    ///     format!("copied: {copied}"),
+    /// ))
+    /// # });
+    /// ```
+    fn selectable<F>(self, on_select: F) -> SelectableText<Self, F> {
+        SelectableText {
+            content: self,
+            on_select,
+            style: None,
+        }
+    }
+}
+
+impl<T: Into<Text>> ToSelectableText for T {}
+
+impl ToSelectableText for WrappedText {}
+
+impl<V, F> SelectableText<V, F> {
+    /// Patched over the style of the selected text instead of [`TextTheme::selection`](crate::view::TextTheme::selection).
+    pub fn selection_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl<V, F> ViewMarker for SelectableText<V, F> {}
+
+impl<T, A, V, F> View<T, A> for SelectableText<V, F>
+where
+    V: View<T, A>,
+    V::Element: SelectableElement,
+    F: Fn(&mut T, &str) -> A + Send + Sync,
+{
+    type State = (xilem_core::Id, V::State);
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (xilem_core::Id, Self::State, Self::Element) {
+        let style = self.style.unwrap_or(cx.theme().text.selection);
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let (content_id, content_state, mut element) = self.content.build(cx);
+            let _ = element.set_selectable(cx.id_path(), style);
+            ((content_id, content_state), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut xilem_core::Id,
+        (content_id, content_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let style = self.style.unwrap_or(cx.theme().text.selection);
+        cx.with_id(*id, |cx| {
+            self.content
+                .rebuild(cx, &prev.content, content_id, content_state, element)
+                | element.set_selectable(cx.id_path(), style)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[xilem_core::Id],
+        (content_id, content_state): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> xilem_core::MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == content_id => {
+                self.content
+                    .message(rest_path, content_state, message, app_state)
+            }
+            [] => match message.downcast::<String>() {
+                Ok(text) => xilem_core::MessageResult::Action((self.on_select)(app_state, &text)),
+                Err(message) => xilem_core::MessageResult::Stale(message),
+            },
+            [..] => xilem_core::MessageResult::Stale(message),
+        }
+    }
+}
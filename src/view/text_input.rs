@@ -0,0 +1,165 @@
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, EventHandler, View, ViewMarker};
+
+/// The character, that hides the characters of a [`TextInput::masked`] input.
+const MASK: char = '•';
+
+/// A single line text field, see [`text_input`].
+pub struct TextInput<EH> {
+    value: String,
+    on_change: EH,
+    mask: Option<char>,
+    filter: Option<fn(char) -> bool>,
+    max_length: Option<usize>,
+    validator: Option<fn(&str) -> bool>,
+}
+
+/// A single line text field showing `value`, which can be edited while it's focused.
+///
+/// `Left`/`Right` (or `Home`/`End`) move the cursor, and clicking places it at the clicked character.
+/// Edits can be undone with `Ctrl-Z` and redone with `Ctrl-Shift-Z` (or `Ctrl-Y`), see [`EditHistory`](crate::EditHistory).
+/// `on_change` is called with the new value after every edit, the field shows `value`,
+/// so the change is discarded, when the app state isn't updated.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(String::new(), move |name: &mut String| {
+/// h_stack((
+///     "Name: ",
+///     text_input(name.clone(), |name: &mut String, value: String| *name = value),
+/// ))
+/// # });
+/// ```
+pub fn text_input<EH>(value: impl Into<String>, on_change: EH) -> TextInput<EH> {
+    TextInput {
+        value: value.into(),
+        on_change,
+        mask: None,
+        filter: None,
+        max_length: None,
+        validator: None,
+    }
+}
+
+impl<EH> TextInput<EH> {
+    /// Shows every character as a bullet (`•`), e.g. for passwords, the value itself stays unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let text = render_to_string::<(), _>(
+    ///     text_input("secret", |_: &mut (), _: String| ()).masked(),
+    ///     (8, 1),
+    ///     ScreenshotFormat::Plain,
+    /// );
+    /// assert_eq!(text, "••••••\n");
+    /// ```
+    pub fn masked(self) -> Self {
+        self.mask(MASK)
+    }
+
+    /// Shows every character as `mask`, see [`TextInput::masked`].
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Only characters, for which `filter` returns `true`, can be typed.
+    ///
+    /// The value itself isn't filtered, e.g. when it was set by the app.
+    pub fn filter(mut self, filter: fn(char) -> bool) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Only digits (`0`-`9`) can be typed, see [`TextInput::filter`].
+    pub fn numeric(self) -> Self {
+        self.filter(|c| c.is_ascii_digit())
+    }
+
+    /// No more characters can be typed, when the value has `max_length` characters.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Styles the field with [`TextInputTheme::invalid`](crate::TextInputTheme::invalid), while `validator` returns `false` for the value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new(String::new(), move |port: &mut String| {
+    /// text_input(port.clone(), |port: &mut String, value: String| *port = value)
+    ///     .numeric()
+    ///     .max_length(5)
+    ///     .validate(|value| value.parse::<u16>().is_ok())
+    /// # });
+    /// ```
+    pub fn validate(mut self, validator: fn(&str) -> bool) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        self.validator
+            .is_none_or(|validator| validator(&self.value))
+    }
+}
+
+impl<EH> ViewMarker for TextInput<EH> {}
+
+impl<T, A, EH: EventHandler<T, A, String>> View<T, A> for TextInput<EH> {
+    type State = (Id, EH::State);
+
+    type Element = widget::TextInput;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let mut element =
+                widget::TextInput::new(cx.id_path(), self.value.clone(), cx.theme().text_input);
+            let _ = element.set_mask(self.mask);
+            element.set_filter(self.filter, self.max_length);
+            let _ = element.set_valid(self.is_valid());
+            (self.on_change.build(cx), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (eh_id, eh_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        element.set_filter(self.filter, self.max_length);
+        cx.with_id(*id, |cx| {
+            element.set_value(self.value.clone())
+                | element.set_mask(self.mask)
+                | element.set_valid(self.is_valid())
+                | element.set_styles(cx.theme().text_input)
+                | self.on_change.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (eh_id, eh_state): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_change
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_change.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
@@ -0,0 +1,604 @@
+use ratatui::style::{Color, Modifier, Style};
+
+use super::BorderKind;
+use crate::ColorScheme;
+
+/// Default styles for the built-in views, e.g. used by [`button`](crate::view::button).
+///
+/// The theme is set for the whole app via [`App::with_theme`](crate::App::with_theme),
+/// views can access it while building via [`Cx::theme`](crate::view::Cx::theme).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Theme {
+    pub button: ButtonTheme,
+    pub toggle: ToggleTheme,
+    pub slider: SliderTheme,
+    pub scrollbar: ScrollbarTheme,
+    pub stepper: StepperTheme,
+    pub text_input: TextInputTheme,
+    pub date_picker: DatePickerTheme,
+    pub tree: TreeTheme,
+    #[cfg(feature = "json")]
+    pub json: JsonTheme,
+    pub filterable: FilterableTheme,
+    pub collapsible: CollapsibleTheme,
+    pub text: TextTheme,
+    pub heatmap: HeatmapTheme,
+    pub diff: DiffTheme,
+    pub reorderable_list: ReorderableListTheme,
+    pub selectable_list: SelectableListTheme,
+    pub table: TableTheme,
+    pub confirm_quit: ConfirmQuitTheme,
+}
+
+impl Theme {
+    /// The default theme adjusted for terminals with a light background,
+    /// i.e. yellow and cyan accents are replaced with magenta and blue.
+    pub fn light() -> Self {
+        let mut theme = Theme::default();
+        theme.stepper.focused = theme.stepper.focused.fg(Color::Magenta);
+        theme.text_input.focused = theme.text_input.focused.fg(Color::Magenta);
+        theme.tree.indicator = theme.tree.indicator.fg(Color::Magenta);
+        #[cfg(feature = "json")]
+        {
+            theme.json.number = theme.json.number.fg(Color::Blue);
+            theme.json.bool = theme.json.bool.fg(Color::Magenta);
+        }
+        theme.filterable.highlight = theme.filterable.highlight.fg(Color::Magenta);
+        theme.reorderable_list.focused = theme.reorderable_list.focused.fg(Color::Magenta);
+        theme.reorderable_list.indicator = theme.reorderable_list.indicator.fg(Color::Magenta);
+        theme.selectable_list.focused = theme.selectable_list.focused.fg(Color::Magenta);
+        theme.table.resizing = theme.table.resizing.fg(Color::Magenta);
+        theme
+    }
+
+    /// The default theme for the `scheme`, see [`TerminalColors::color_scheme`](crate::TerminalColors::color_scheme).
+    ///
+    /// This is used by default, when the color scheme of the terminal could be detected.
+    pub fn for_color_scheme(scheme: ColorScheme) -> Self {
+        match scheme {
+            ColorScheme::Light => Theme::light(),
+            ColorScheme::Dark => Theme::default(),
+        }
+    }
+}
+
+/// The styles of a [`button`](crate::view::button) in its different states.
+///
+/// The state styles are patched over the base `style` (in the order hover, focused, pressed).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonTheme {
+    pub style: Style,
+    pub hover: Style,
+    pub pressed: Style,
+    pub focused: Style,
+    pub disabled: Style,
+    /// Patched over the mnemonic letter in the label, see [`Button::mnemonic`](crate::view::Button::mnemonic).
+    pub mnemonic: Style,
+    pub border: BorderKind,
+}
+
+impl Default for ButtonTheme {
+    fn default() -> Self {
+        ButtonTheme {
+            style: Style::default(),
+            hover: Style::default().fg(Color::Green),
+            pressed: Style::default().fg(Color::Blue),
+            focused: Style::default().add_modifier(Modifier::BOLD),
+            disabled: Style::default().fg(Color::DarkGray),
+            mnemonic: Style::default().add_modifier(Modifier::UNDERLINED),
+            border: BorderKind::ThickStraight,
+        }
+    }
+}
+
+/// The styles of a [`toggle`](crate::view::toggle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToggleTheme {
+    pub on: Style,
+    pub off: Style,
+    /// Patched over the `on` or `off` style, when the toggle is focused.
+    pub focused: Style,
+}
+
+impl Default for ToggleTheme {
+    fn default() -> Self {
+        ToggleTheme {
+            on: Style::default().fg(Color::Green),
+            off: Style::default().fg(Color::DarkGray),
+            focused: Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+/// The styles of a [`slider`](crate::view::slider).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliderTheme {
+    pub track: Style,
+    pub thumb: Style,
+    /// Patched over the thumb style, when the slider is focused.
+    pub focused: Style,
+}
+
+impl Default for SliderTheme {
+    fn default() -> Self {
+        SliderTheme {
+            track: Style::default().fg(Color::DarkGray),
+            thumb: Style::default(),
+            focused: Style::default().fg(Color::Yellow),
+        }
+    }
+}
+
+/// The styles of a [`stepper`](crate::view::stepper).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepperTheme {
+    /// The style of the number field.
+    pub style: Style,
+    /// The style of the `[-]` and `[+]` buttons.
+    pub buttons: Style,
+    /// Patched over the style of the number field, when the stepper is focused.
+    pub focused: Style,
+}
+
+impl Default for StepperTheme {
+    fn default() -> Self {
+        StepperTheme {
+            style: Style::default(),
+            buttons: Style::default().fg(Color::Green),
+            focused: Style::default().fg(Color::Yellow),
+        }
+    }
+}
+
+/// The styles of a [`text_input`](crate::view::text_input).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextInputTheme {
+    /// The style of the whole field, underlined by default, so that its extent is visible.
+    pub style: Style,
+    /// Patched over the style, when the text input is focused.
+    pub focused: Style,
+    /// Patched over the character under the cursor, when the text input is focused.
+    pub cursor: Style,
+    /// Patched over the style, when the value isn't valid, see [`TextInput::validate`](crate::view::TextInput::validate).
+    pub invalid: Style,
+}
+
+impl Default for TextInputTheme {
+    fn default() -> Self {
+        TextInputTheme {
+            style: Style::default().add_modifier(Modifier::UNDERLINED),
+            focused: Style::default().fg(Color::Yellow),
+            cursor: Style::default().add_modifier(Modifier::REVERSED),
+            invalid: Style::default().fg(Color::Red),
+        }
+    }
+}
+
+/// The styles of a [`date_picker`](crate::view::date_picker).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatePickerTheme {
+    /// The style of the month and the navigation buttons.
+    pub header: Style,
+    pub weekdays: Style,
+    pub day: Style,
+    /// Patched over the day style of the selected day.
+    pub selected: Style,
+    /// Patched over the day style of the day under the keyboard cursor, when the date picker is focused.
+    pub cursor: Style,
+}
+
+impl Default for DatePickerTheme {
+    fn default() -> Self {
+        DatePickerTheme {
+            header: Style::default().add_modifier(Modifier::BOLD),
+            weekdays: Style::default().fg(Color::DarkGray),
+            day: Style::default(),
+            selected: Style::default().fg(Color::Black).bg(Color::Green),
+            cursor: Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+/// The styles of a [`tree`](crate::view::tree), the style of the labels is set per node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeTheme {
+    /// The style of the indentation guides.
+    pub guides: Style,
+    /// The style of the expand/collapse indicators.
+    pub indicator: Style,
+    /// Patched over the label style of the node under the cursor, when the tree is focused.
+    pub cursor: Style,
+}
+
+impl Default for TreeTheme {
+    fn default() -> Self {
+        TreeTheme {
+            guides: Style::default().fg(Color::DarkGray),
+            indicator: Style::default().fg(Color::Yellow),
+            cursor: Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+/// The type-based styles of the values in a [`json_view`](crate::view::json_view).
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonTheme {
+    /// The style of objects and arrays.
+    pub container: Style,
+    pub string: Style,
+    pub number: Style,
+    pub bool: Style,
+    pub null: Style,
+}
+
+#[cfg(feature = "json")]
+impl Default for JsonTheme {
+    fn default() -> Self {
+        JsonTheme {
+            container: Style::default().add_modifier(Modifier::BOLD),
+            string: Style::default().fg(Color::Green),
+            number: Style::default().fg(Color::Cyan),
+            bool: Style::default().fg(Color::Yellow),
+            null: Style::default().fg(Color::DarkGray),
+        }
+    }
+}
+
+/// The styles of a [`filterable`](crate::view::filterable).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilterableTheme {
+    /// Patched over the style of text, that matches the query.
+    pub highlight: Style,
+}
+
+impl Default for FilterableTheme {
+    fn default() -> Self {
+        FilterableTheme {
+            highlight: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+/// The styles of a [`collapsible`](crate::view::collapsible).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollapsibleTheme {
+    /// The style of the chevron in front of the header.
+    pub chevron: Style,
+    /// Patched over the `chevron` style, when the collapsible is focused.
+    pub focused: Style,
+    pub expanded_symbol: &'static str,
+    pub collapsed_symbol: &'static str,
+}
+
+impl Default for CollapsibleTheme {
+    fn default() -> Self {
+        CollapsibleTheme {
+            chevron: Style::default(),
+            focused: Style::default().add_modifier(Modifier::BOLD),
+            expanded_symbol: "▾",
+            collapsed_symbol: "▸",
+        }
+    }
+}
+
+/// The styles of text views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextTheme {
+    /// Patched over the style of selected text, see [`ToSelectableText::selectable`](crate::view::ToSelectableText::selectable).
+    pub selection: Style,
+}
+
+impl Default for TextTheme {
+    fn default() -> Self {
+        TextTheme {
+            selection: Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+/// The styles of a [`heatmap`](crate::view::heatmap), the colors of the values are set per heatmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatmapTheme {
+    /// The style of the tooltip, which shows the hovered value.
+    pub tooltip: Style,
+}
+
+impl Default for HeatmapTheme {
+    fn default() -> Self {
+        HeatmapTheme {
+            tooltip: Style::default().fg(Color::Black).bg(Color::White),
+        }
+    }
+}
+
+/// The styles of a [`diff_view`](crate::view::diff_view).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffTheme {
+    /// The style of the line numbers and the separator between the sides.
+    pub gutter: Style,
+    /// The style of the hunk headers like `@@ -1,4 +1,5 @@`.
+    pub hunk: Style,
+    /// The style of unchanged lines.
+    pub context: Style,
+    pub added: Style,
+    pub removed: Style,
+    /// Patched over the `added` style of the changed words within a changed line.
+    pub added_word: Style,
+    /// Patched over the `removed` style of the changed words within a changed line.
+    pub removed_word: Style,
+}
+
+impl Default for DiffTheme {
+    fn default() -> Self {
+        DiffTheme {
+            gutter: Style::default().fg(Color::DarkGray),
+            hunk: Style::default().fg(Color::Cyan),
+            context: Style::default(),
+            added: Style::default().fg(Color::Green),
+            removed: Style::default().fg(Color::Red),
+            added_word: Style::default().add_modifier(Modifier::REVERSED),
+            removed_word: Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+/// The styles of a [`scrollbar`](crate::view::scrollbar).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollbarTheme {
+    pub track: Style,
+    pub thumb: Style,
+    /// Patched over the `thumb` style, while it's dragged.
+    pub dragged: Style,
+}
+
+impl Default for ScrollbarTheme {
+    fn default() -> Self {
+        ScrollbarTheme {
+            track: Style::default().fg(Color::DarkGray),
+            thumb: Style::default(),
+            dragged: Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+/// The styles of a [`reorderable_list`](crate::view::reorderable_list).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorderableListTheme {
+    /// The style of the drag handles in front of the rows.
+    pub handle: Style,
+    /// Patched over the `handle` style of the row, which is moved with the keyboard, when the list is focused.
+    pub focused: Style,
+    /// Patched over the row, which is dragged.
+    pub grabbed: Style,
+    /// The style of the line, which indicates where the dragged row is dropped.
+    pub indicator: Style,
+}
+
+impl Default for ReorderableListTheme {
+    fn default() -> Self {
+        ReorderableListTheme {
+            handle: Style::default().fg(Color::DarkGray),
+            focused: Style::default().fg(Color::Yellow),
+            grabbed: Style::default().add_modifier(Modifier::BOLD),
+            indicator: Style::default().fg(Color::Yellow),
+        }
+    }
+}
+
+/// The styles of a [`selectable_list`](crate::view::selectable_list).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectableListTheme {
+    /// The style of the checkboxes in front of the rows.
+    pub checkbox: Style,
+    /// Patched over the selected rows, including their checkbox.
+    pub selected: Style,
+    /// Patched over the checkbox of the row at the cursor, when the list is focused.
+    pub focused: Style,
+}
+
+impl Default for SelectableListTheme {
+    fn default() -> Self {
+        SelectableListTheme {
+            checkbox: Style::default().fg(Color::DarkGray),
+            selected: Style::default().add_modifier(Modifier::BOLD),
+            focused: Style::default().fg(Color::Yellow),
+        }
+    }
+}
+
+/// The styles of a [`table`](crate::view::table).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableTheme {
+    pub header: Style,
+    /// The style of the column separators.
+    pub separator: Style,
+    /// Patched over the separator of the column, which is resized (or selected for resizing, when the table is focused).
+    pub resizing: Style,
+}
+
+impl Default for TableTheme {
+    fn default() -> Self {
+        TableTheme {
+            header: Style::default().add_modifier(Modifier::BOLD),
+            separator: Style::default().fg(Color::DarkGray),
+            resizing: Style::default().fg(Color::Yellow),
+        }
+    }
+}
+
+/// The styles of the dialog of [`confirm_quit`](crate::view::confirm_quit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmQuitTheme {
+    /// The style of the whole dialog, including its border.
+    pub dialog: Style,
+    /// Patched over the `dialog` style for the buttons.
+    pub button: Style,
+    /// Patched over the `dialog` style for the selected button.
+    pub selected: Style,
+    pub border: BorderKind,
+}
+
+impl Default for ConfirmQuitTheme {
+    fn default() -> Self {
+        ConfirmQuitTheme {
+            dialog: Style::default(),
+            button: Style::default().add_modifier(Modifier::BOLD),
+            selected: Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            border: BorderKind::Rounded,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl Theme {
+    /// Parses a theme from JSON, fields that aren't specified keep their default value.
+    ///
+    /// Styles are objects with an optional `fg` and `bg` color (names like `"light-blue"`, indices or `"#rrggbb"`),
+    /// and a list of `modifiers` (like `"bold"`), or just a string for the foreground color.
+    /// Border kinds are one of `"straight"`, `"rounded"`, `"double-straight"` and `"thick-straight"`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// let theme = Theme::from_json(r##"{
+    ///     "button": { "hover": { "fg": "#ff8800", "modifiers": ["bold"] }, "border": "rounded" },
+    ///     "toggle": { "on": "light-green" }
+    /// }"##).unwrap();
+    /// assert_eq!(theme.button.border, BorderKind::Rounded);
+    /// assert_eq!(theme.toggle.on, Style::default().fg(Color::LightGreen));
+    /// ```
+    pub fn from_json(json: &str) -> anyhow::Result<Theme> {
+        let mut theme = Theme::default();
+        theme.apply_json(&serde_json::from_str(json)?)?;
+        Ok(theme)
+    }
+
+    /// Overrides the fields given in `json`, see [`Theme::from_json`] for the format.
+    pub fn apply_json(&mut self, json: &serde_json::Value) -> anyhow::Result<()> {
+        json_fields!(json, "theme", {
+            button: { style, hover, pressed, focused, disabled, mnemonic, border },
+            toggle: { on, off, focused },
+            slider: { track, thumb, focused },
+            scrollbar: { track, thumb, dragged },
+            stepper: { style, buttons, focused },
+            text_input: { style, focused, cursor, invalid },
+            date_picker: { header, weekdays, day, selected, cursor },
+            tree: { guides, indicator, cursor },
+            json: { container, string, number, bool, null },
+            filterable: { highlight },
+            collapsible: { chevron, focused },
+            text: { selection },
+            heatmap: { tooltip },
+            diff: { gutter, hunk, context, added, removed, added_word, removed_word },
+            reorderable_list: { handle, focused, grabbed, indicator },
+            selectable_list: { checkbox, selected, focused },
+            table: { header, separator, resizing },
+            confirm_quit: { dialog, button, selected, border },
+        } => self);
+        Ok(())
+    }
+}
+
+/// Applies the (nested) fields of a JSON object to the given struct fields, unknown fields are an error.
+#[cfg(feature = "json")]
+macro_rules! json_fields {
+    ($json:expr, $path:expr, { $($section:ident: { $($field:ident),* $(,)? }),* $(,)? } => $target:expr) => {{
+        let Some(sections) = $json.as_object() else {
+            anyhow::bail!("{} has to be an object", $path);
+        };
+        for (section, fields) in sections {
+            match section.as_str() {
+                $(stringify!($section) => {
+                    let Some(fields) = fields.as_object() else {
+                        anyhow::bail!("{}.{section} has to be an object", $path);
+                    };
+                    for (field, value) in fields {
+                        let path = format!("{}.{section}.{field}", $path);
+                        match field.as_str() {
+                            $(stringify!($field) => {
+                                $target.$section.$field = FromJson::from_json(value)
+                                    .map_err(|err| anyhow::anyhow!("{path}: {err}"))?
+                            })*
+                            _ => anyhow::bail!("unknown field {path}"),
+                        }
+                    }
+                })*
+                _ => anyhow::bail!("unknown field {}.{section}", $path),
+            }
+        }
+    }};
+}
+#[cfg(feature = "json")]
+use json_fields;
+
+/// A value in a theme file, see [`Theme::from_json`].
+#[cfg(feature = "json")]
+trait FromJson: Sized {
+    fn from_json(json: &serde_json::Value) -> anyhow::Result<Self>;
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Style {
+    fn from_json(json: &serde_json::Value) -> anyhow::Result<Self> {
+        use std::str::FromStr;
+
+        let color = |value: &serde_json::Value| match value.as_str() {
+            Some(color) => {
+                Color::from_str(color).map_err(|_| anyhow::anyhow!("invalid color {color}"))
+            }
+            None => anyhow::bail!("colors have to be strings"),
+        };
+        let modifier = |value: &serde_json::Value| match value.as_str() {
+            Some("bold") => Ok(Modifier::BOLD),
+            Some("dim") => Ok(Modifier::DIM),
+            Some("italic") => Ok(Modifier::ITALIC),
+            Some("underlined") => Ok(Modifier::UNDERLINED),
+            Some("slow-blink") => Ok(Modifier::SLOW_BLINK),
+            Some("rapid-blink") => Ok(Modifier::RAPID_BLINK),
+            Some("reversed") => Ok(Modifier::REVERSED),
+            Some("hidden") => Ok(Modifier::HIDDEN),
+            Some("crossed-out") => Ok(Modifier::CROSSED_OUT),
+            _ => anyhow::bail!("invalid modifier {value}"),
+        };
+
+        let mut style = Style::default();
+        match json {
+            serde_json::Value::String(_) => style = style.fg(color(json)?),
+            serde_json::Value::Object(fields) => {
+                for (field, value) in fields {
+                    match field.as_str() {
+                        "fg" => style = style.fg(color(value)?),
+                        "bg" => style = style.bg(color(value)?),
+                        "modifiers" => {
+                            let Some(modifiers) = value.as_array() else {
+                                anyhow::bail!("modifiers have to be a list");
+                            };
+                            for value in modifiers {
+                                style = style.add_modifier(modifier(value)?);
+                            }
+                        }
+                        _ => anyhow::bail!("unknown style field {field}"),
+                    }
+                }
+            }
+            _ => anyhow::bail!("styles have to be an object or a color"),
+        }
+        Ok(style)
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for BorderKind {
+    fn from_json(json: &serde_json::Value) -> anyhow::Result<Self> {
+        match json.as_str() {
+            Some("straight") => Ok(BorderKind::Straight),
+            Some("rounded") => Ok(BorderKind::Rounded),
+            Some("double-straight") => Ok(BorderKind::DoubleStraight),
+            Some("thick-straight") => Ok(BorderKind::ThickStraight),
+            _ => anyhow::bail!("invalid border kind {json}"),
+        }
+    }
+}
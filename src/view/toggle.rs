@@ -0,0 +1,107 @@
+use ratatui::style::Style;
+use xilem_core::{Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags};
+
+use super::{Cx, EventHandler, ToggleTheme, View, ViewMarker};
+
+/// A toggle switch, see [`toggle`].
+pub struct Toggle<EH> {
+    pub(crate) value: bool,
+    pub(crate) on_change: EH,
+    pub(crate) on_style: Style,
+    pub(crate) off_style: Style,
+}
+
+/// A toggle switch showing `value`, with a thumb that slides between the off (left) and on (right) position.
+///
+/// `on_change` is called with the new value, when it's clicked, or when `Enter` or `Space` is pressed while it's focused.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(false, move |enabled: &mut bool| {
+/// h_stack((
+///     "Enabled: ",
+///     toggle(*enabled, |enabled: &mut bool, value: bool| *enabled = value),
+/// ))
+/// # });
+/// ```
+pub fn toggle<EH>(value: bool, on_change: EH) -> Toggle<EH> {
+    Toggle {
+        value,
+        on_change,
+        on_style: Style::default(),
+        off_style: Style::default(),
+    }
+}
+
+impl<EH> Toggle<EH> {
+    /// The style patched over the [`ToggleTheme::on`] style.
+    pub fn on_style(mut self, style: Style) -> Self {
+        self.on_style = style;
+        self
+    }
+
+    /// The style patched over the [`ToggleTheme::off`] style.
+    pub fn off_style(mut self, style: Style) -> Self {
+        self.off_style = style;
+        self
+    }
+
+    fn styles(&self, cx: &Cx) -> ToggleTheme {
+        let theme = cx.theme().toggle;
+        ToggleTheme {
+            on: theme.on.patch(self.on_style),
+            off: theme.off.patch(self.off_style),
+            focused: theme.focused,
+        }
+    }
+}
+
+impl<EH> ViewMarker for Toggle<EH> {}
+
+impl<T, A, EH: EventHandler<T, A, bool>> View<T, A> for Toggle<EH> {
+    type State = (Id, EH::State);
+
+    type Element = widget::Toggle;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let element = widget::Toggle::new(cx.id_path(), self.value, self.styles(cx));
+            (self.on_change.build(cx), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        _prev: &Self,
+        id: &mut Id,
+        (eh_id, eh_state): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        cx.with_id(*id, |cx| {
+            element.set_value(self.value)
+                | element.set_styles(self.styles(cx))
+                | self.on_change.rebuild(cx, eh_id, eh_state)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (eh_id, eh_state): &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == eh_id => self
+                .on_change
+                .message(rest_path, eh_state, message, app_state),
+            [] => self.on_change.message(&[], eh_state, message, app_state),
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
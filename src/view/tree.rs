@@ -0,0 +1,237 @@
+use std::{borrow::Cow, future::Future, hash::Hash, sync::Arc};
+
+use futures_task::Waker;
+use ratatui::style::Style;
+use tokio::runtime::Runtime;
+use xilem_core::{AsyncWake, Id, MessageResult};
+
+use crate::widget::{self, ChangeFlags, TreeMessage};
+
+use super::{Cx, PendingTask, View, ViewMarker};
+
+/// The children of a [`TreeNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TreeChildren<K> {
+    Leaf,
+    Loaded(Vec<TreeNode<K>>),
+    /// The children are loaded with [`Tree::load_children`], when the node is expanded for the first time.
+    Lazy,
+}
+
+/// A node of a [`tree`], identified by its `key`, which has to be unique within the tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode<K> {
+    pub key: K,
+    pub label: Cow<'static, str>,
+    pub style: Style,
+    pub children: TreeChildren<K>,
+}
+
+impl<K> TreeNode<K> {
+    pub fn leaf(key: K, label: impl Into<Cow<'static, str>>) -> Self {
+        TreeNode {
+            key,
+            label: label.into(),
+            style: Style::default(),
+            children: TreeChildren::Leaf,
+        }
+    }
+
+    pub fn branch(key: K, label: impl Into<Cow<'static, str>>, children: Vec<TreeNode<K>>) -> Self {
+        TreeNode {
+            key,
+            label: label.into(),
+            style: Style::default(),
+            children: TreeChildren::Loaded(children),
+        }
+    }
+
+    /// A node, whose children are loaded when it's expanded, see [`Tree::load_children`].
+    pub fn lazy(key: K, label: impl Into<Cow<'static, str>>) -> Self {
+        TreeNode {
+            key,
+            label: label.into(),
+            style: Style::default(),
+            children: TreeChildren::Lazy,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+type LoadChildrenFn<K> = fn(K) -> std::future::Ready<Vec<TreeNode<K>>>;
+
+/// A view of hierarchical data, see [`tree`].
+pub struct Tree<K, F, LF = LoadChildrenFn<K>> {
+    pub(crate) nodes: Vec<TreeNode<K>>,
+    pub(crate) on_activate: F,
+    pub(crate) load_children: Option<LF>,
+}
+
+/// Displays hierarchical data with expand/collapse indicators and indentation guides.
+///
+/// `on_activate` is called with the key of a node, when its label is clicked,
+/// or when `Enter` is pressed while it's under the cursor.
+/// The expanded nodes are remembered by their key across rebuilds.
+///
+/// While focused, the following keys can be used:
+///
+/// - `Up`/`Down`: move the cursor
+/// - `Right`: expand the node, or move to its first child, if it's already expanded
+/// - `Left`: collapse the node, or move to its parent, if it's already collapsed
+/// - `Space`: toggle the node
+/// - `Enter`: activate the node
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(String::new(), move |opened: &mut String| {
+/// tree(
+///     vec![TreeNode::branch(
+///         "src",
+///         "src",
+///         vec![TreeNode::leaf("src/lib.rs", "lib.rs")],
+///     )],
+///     |opened: &mut String, key: &'static str| *opened = key.to_string(),
+/// )
+/// # });
+/// ```
+pub fn tree<K, F>(nodes: Vec<TreeNode<K>>, on_activate: F) -> Tree<K, F> {
+    Tree {
+        nodes,
+        on_activate,
+        load_children: None,
+    }
+}
+
+impl<K, F, LF> Tree<K, F, LF> {
+    /// Loads the children of [`TreeNode::lazy`] nodes asynchronously, when they're expanded the first time.
+    ///
+    /// While loading, the node is shown with a loading indicator.
+    pub fn load_children<LF2, Fut>(self, load_children: LF2) -> Tree<K, F, LF2>
+    where
+        LF2: Fn(K) -> Fut + Send + Sync,
+        Fut: Future<Output = Vec<TreeNode<K>>> + Send + 'static,
+    {
+        Tree {
+            nodes: self.nodes,
+            on_activate: self.on_activate,
+            load_children: Some(load_children),
+        }
+    }
+}
+
+pub struct TreeState<K> {
+    waker: Waker,
+    runtime: Arc<Runtime>,
+    loading: Vec<(K, PendingTask<Vec<TreeNode<K>>>)>,
+    /// Children that have been loaded but not yet passed to the widget.
+    loaded: Vec<(K, Vec<TreeNode<K>>)>,
+}
+
+impl<K, F, LF> ViewMarker for Tree<K, F, LF> {}
+
+impl<T, A, K, F, LF, Fut> View<T, A> for Tree<K, F, LF>
+where
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+    F: Fn(&mut T, K) -> A + Send + Sync,
+    LF: Fn(K) -> Fut + Send + Sync,
+    Fut: Future<Output = Vec<TreeNode<K>>> + Send + 'static,
+{
+    type State = TreeState<K>;
+
+    type Element = widget::Tree<K>;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let state = TreeState {
+                waker: cx.waker(),
+                runtime: cx.rt.clone(),
+                loading: Vec::new(),
+                loaded: Vec::new(),
+            };
+            let element = widget::Tree::new(cx.id_path(), self.nodes.clone(), cx.theme().tree);
+            (state, element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        _id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        let mut changeflags = element.set_styles(cx.theme().tree);
+        if self.nodes != prev.nodes {
+            changeflags |= element.set_nodes(self.nodes.clone());
+        }
+        for (key, children) in state.loaded.drain(..) {
+            changeflags |= element.insert_children(key, children);
+        }
+        changeflags
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn std::any::Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        if !id_path.is_empty() {
+            return MessageResult::Stale(message);
+        }
+        if message.downcast_ref::<AsyncWake>().is_some() {
+            let mut finished = false;
+            state.loading.retain_mut(|(key, task)| {
+                if task.poll() {
+                    let children = task.result.take().unwrap_or_default();
+                    state.loaded.push((key.clone(), children));
+                    finished = true;
+                    false
+                } else {
+                    true
+                }
+            });
+            return if finished {
+                MessageResult::RequestRebuild
+            } else {
+                MessageResult::Nop
+            };
+        }
+        match message.downcast::<TreeMessage<K>>() {
+            Ok(message) => match *message {
+                TreeMessage::Activate(key) => {
+                    MessageResult::Action((self.on_activate)(app_state, key))
+                }
+                TreeMessage::LoadChildren(key) => {
+                    let Some(load_children) = &self.load_children else {
+                        // nothing to load, show the node as empty
+                        state.loaded.push((key, Vec::new()));
+                        return MessageResult::RequestRebuild;
+                    };
+                    let join_handle = state.runtime.spawn(load_children(key.clone()));
+                    let mut task = PendingTask::new(
+                        state.waker.clone(),
+                        tokio::task::unconstrained(join_handle),
+                    );
+                    if task.poll() {
+                        let children = task.result.take().unwrap_or_default();
+                        state.loaded.push((key, children));
+                        MessageResult::RequestRebuild
+                    } else {
+                        state.loading.push((key, task));
+                        MessageResult::Nop
+                    }
+                }
+            },
+            Err(message) => MessageResult::Stale(message),
+        }
+    }
+}
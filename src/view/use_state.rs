@@ -227,3 +227,178 @@ impl<
         a
     }
 }
+
+/// Runs `effect` with `key` when this view is built, and again whenever `key` has changed on a rebuild.
+///
+/// The closure returned by `effect` cleans up after it (e.g. cancels a started task), it's called
+/// before the effect runs again with a new key, and when this view is removed.
+/// Together with [`use_state`] this allows self-contained components, that e.g. debounce their input.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// App::new(1u32, |page| {
+///     let view = format!("Page {page}");
+///     use_effect(*page, |page| {
+///         let page = *page;
+///         // e.g. start loading the page here
+///         move || {
+///             // ... and cancel loading it here
+///             let _ = page;
+///         }
+///     }, view)
+/// });
+/// ```
+pub fn use_effect<K, E, C, V>(key: K, effect: E, view: V) -> UseEffect<K, E, V>
+where
+    K: PartialEq,
+    E: Fn(&K) -> C,
+    C: FnOnce(),
+{
+    UseEffect { key, effect, view }
+}
+
+/// See [`use_effect`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UseEffect<K, E, V> {
+    key: K,
+    effect: E,
+    view: V,
+}
+
+pub struct UseEffectState<C: FnOnce(), S> {
+    cleanup: Option<C>,
+    view_state: S,
+}
+
+impl<C: FnOnce(), S> Drop for UseEffectState<C, S> {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+impl<K, E, V> ViewMarker for UseEffect<K, E, V> {}
+
+impl<T, A, K, E, C, V> View<T, A> for UseEffect<K, E, V>
+where
+    K: PartialEq + Send + Sync,
+    E: Fn(&K) -> C + Send + Sync,
+    C: FnOnce() + Send,
+    V: View<T, A>,
+{
+    type State = UseEffectState<C, V::State>;
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let cleanup = Some((self.effect)(&self.key));
+        let (id, view_state, element) = self.view.build(cx);
+        (
+            id,
+            UseEffectState {
+                cleanup,
+                view_state,
+            },
+            element,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        if self.key != prev.key {
+            if let Some(cleanup) = state.cleanup.take() {
+                cleanup();
+            }
+            state.cleanup = Some((self.effect)(&self.key));
+        }
+        self.view
+            .rebuild(cx, &prev.view, id, &mut state.view_state, element)
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.view
+            .message(id_path, &mut state.view_state, message, app_state)
+    }
+}
+
+/// Builds `view` from scratch, whenever `key` has changed on a rebuild,
+/// which resets its local state (e.g. of [`use_state`]), and cleans up its effects (see [`use_effect`]).
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// // the draft is discarded, when another user is selected
+/// App::new(0u32, |user| {
+///     let user = *user;
+///     reset_on(user, use_state(String::new, move |draft| format!("Message to {user}: {draft}")))
+/// });
+/// ```
+pub fn reset_on<K: PartialEq, V>(key: K, view: V) -> ResetOn<K, V> {
+    ResetOn { key, view }
+}
+
+/// See [`reset_on`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResetOn<K, V> {
+    key: K,
+    view: V,
+}
+
+impl<K, V> ViewMarker for ResetOn<K, V> {}
+
+impl<T, A, K, V> View<T, A> for ResetOn<K, V>
+where
+    K: PartialEq + Send + Sync,
+    V: View<T, A>,
+{
+    type State = V::State;
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        self.view.build(cx)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        state: &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        if self.key != prev.key {
+            let (new_id, new_state, new_element) = self.view.build(cx);
+            *id = new_id;
+            *state = new_state;
+            *element = new_element;
+            ChangeFlags::tree_structure()
+        } else {
+            self.view.rebuild(cx, &prev.view, id, state, element)
+        }
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        state: &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        self.view.message(id_path, state, message, app_state)
+    }
+}
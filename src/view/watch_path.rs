@@ -0,0 +1,55 @@
+use std::{path::PathBuf, time::Duration};
+
+use futures_util::Stream;
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult};
+
+pub use notify_debouncer_mini::{notify::RecursiveMode, DebouncedEvent, DebouncedEventKind};
+
+/// The default debounce timeout used by [`ViewExt::watch_path`](crate::view::ViewExt::watch_path).
+pub const WATCH_PATH_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Creates a stream of debounced file system events for the given path.
+///
+/// Events within `timeout` are merged into one event per path.
+/// The watcher is stopped, when the stream is dropped.
+/// Errors are logged, and if the path can't be watched at all, the stream ends immediately.
+///
+/// This can be used together with [`ViewExt::worker`](crate::view::ViewExt::worker),
+/// if [`ViewExt::watch_path`](crate::view::ViewExt::watch_path) isn't flexible enough.
+pub fn watch_path_stream(
+    path: impl Into<PathBuf>,
+    mode: RecursiveMode,
+    timeout: Duration,
+) -> impl Stream<Item = DebouncedEvent> + Send + 'static {
+    let path = path.into();
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+    let debouncer = new_debouncer(timeout, move |result: DebounceEventResult| match result {
+        Ok(events) => {
+            for event in events {
+                let _ = tx.send(event);
+            }
+        }
+        Err(err) => tracing::error!("error while watching files: {err}"),
+    });
+
+    let debouncer = match debouncer {
+        Ok(mut debouncer) => match debouncer.watcher().watch(&path, mode) {
+            Ok(()) => Some(debouncer),
+            Err(err) => {
+                tracing::error!("could not watch {}: {err}", path.display());
+                None
+            }
+        },
+        Err(err) => {
+            tracing::error!("could not create file watcher: {err}");
+            None
+        }
+    };
+
+    // The debouncer is moved into the stream, to keep it alive as long as the stream is polled
+    futures_util::stream::unfold((rx, debouncer), |(mut rx, debouncer)| async move {
+        let event = rx.recv().await?;
+        Some((event, (rx, debouncer)))
+    })
+}
@@ -14,6 +14,13 @@ pub struct WeightedLinearLayout<T, A, VT> {
     phantom: PhantomData<fn() -> (T, A)>,
 }
 
+impl<T, A, VT> WeightedLinearLayout<T, A, VT> {
+    /// Horizontal layouts are mirrored in a right-to-left [`layout_direction`](super::layout_direction).
+    fn is_mirrored(&self, cx: &Cx) -> bool {
+        self.axis == Axis::Horizontal && cx.layout_direction().is_rtl()
+    }
+}
+
 impl<T, A, VT> ViewMarker for WeightedLinearLayout<T, A, VT> {}
 
 impl<T, A, VT: ViewSequence<T, A>> View<T, A> for WeightedLinearLayout<T, A, VT> {
@@ -28,7 +35,8 @@ impl<T, A, VT: ViewSequence<T, A>> View<T, A> for WeightedLinearLayout<T, A, VT>
             self.children
                 .build(cx, &mut VecSplice::new(&mut elements, &mut scratch))
         });
-        let column = widget::WeightedLinearLayout::new(elements, self.axis);
+        let mut column = widget::WeightedLinearLayout::new(elements, self.axis);
+        let _ = column.set_reverse(self.is_mirrored(cx));
         (id, state, column)
     }
 
@@ -40,13 +48,15 @@ impl<T, A, VT: ViewSequence<T, A>> View<T, A> for WeightedLinearLayout<T, A, VT>
         state: &mut Self::State,
         element: &mut Self::Element,
     ) -> ChangeFlags {
+        let changeflags = element.set_reverse(self.is_mirrored(cx));
         let mut scratch = vec![];
         let mut splice = VecSplice::new(&mut element.children, &mut scratch);
 
-        cx.with_id(*id, |cx| {
-            self.children
-                .rebuild(cx, &prev.children, state, &mut splice)
-        })
+        changeflags
+            | cx.with_id(*id, |cx| {
+                self.children
+                    .rebuild(cx, &prev.children, state, &mut splice)
+            })
     }
 
     fn message(
@@ -60,6 +70,10 @@ impl<T, A, VT: ViewSequence<T, A>> View<T, A> for WeightedLinearLayout<T, A, VT>
     }
 }
 
+/// Lays out its children horizontally, distributing the available space by their [`weight`](crate::view::ViewExt::weight).
+///
+/// Children without a weight, that have an intrinsic size (like text), take just the space they need,
+/// the remaining space is distributed to the other children, where children without a weight have a weight of 1.
 pub fn weighted_h_stack<T, A, VT: ViewSequence<T, A>>(
     children: VT,
 ) -> WeightedLinearLayout<T, A, VT> {
@@ -70,6 +84,10 @@ pub fn weighted_h_stack<T, A, VT: ViewSequence<T, A>>(
     }
 }
 
+/// Lays out its children vertically, distributing the available space by their [`weight`](crate::view::ViewExt::weight).
+///
+/// Children without a weight, that have an intrinsic size (like text), take just the space they need,
+/// the remaining space is distributed to the other children, where children without a weight have a weight of 1.
 pub fn weighted_v_stack<T, A, VT: ViewSequence<T, A>>(
     children: VT,
 ) -> WeightedLinearLayout<T, A, VT> {
@@ -83,9 +101,43 @@ pub fn weighted_v_stack<T, A, VT: ViewSequence<T, A>>(
 pub struct WeightedLayoutElement<V, W, T, A> {
     pub(crate) content: V,
     pub(crate) weight: W,
+    pub(crate) min_size: u16,
+    pub(crate) max_size: Option<u16>,
     pub(crate) phantom: PhantomData<fn() -> (T, A)>,
 }
 
+impl<V, W, T, A> WeightedLayoutElement<V, W, T, A> {
+    /// The minimum number of cells along the axis of the layout, that this child gets,
+    /// the space is taken from the other children.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trui::*;
+    /// # App::new((), move |()| {
+    /// weighted_h_stack((
+    ///     "Sidebar".border(BorderKind::Rounded).weight(1.0).min_size(20),
+    ///     "Content".border(BorderKind::Rounded).weight(3.0),
+    /// ))
+    /// # });
+    /// ```
+    pub fn min_size(mut self, cells: u16) -> Self {
+        self.min_size = cells;
+        self
+    }
+
+    /// The maximum number of cells along the axis of the layout, that this child gets,
+    /// the remaining space is distributed to the other children.
+    pub fn max_size(mut self, cells: u16) -> Self {
+        self.max_size = Some(cells);
+        self
+    }
+
+    fn limits(&self) -> (f64, f64) {
+        let max = self.max_size.map_or(f64::INFINITY, f64::from);
+        (self.min_size as f64, max)
+    }
+}
+
 impl<T, A, V, W> ViewMarker for WeightedLayoutElement<V, W, T, A> {}
 
 impl<T, A, V: View<T, A>, W: Animatable<f64>> View<T, A> for WeightedLayoutElement<V, W, T, A> {
@@ -97,7 +149,9 @@ impl<T, A, V: View<T, A>, W: Animatable<f64>> View<T, A> for WeightedLayoutEleme
         let (id, (state, element)) = cx.with_new_id(|cx| {
             let (content_id, content_state, element) = self.content.build(cx);
             let (weight_id, weight_state, weight_element) = self.weight.build(cx);
-            let element = widget::WeightedLayoutElement::new(element, weight_element);
+            let mut element = widget::WeightedLayoutElement::new(element, weight_element);
+            let (min, max) = self.limits();
+            let _ = element.set_limits(min, max);
             (
                 (content_id, content_state, weight_id, weight_state),
                 element,
@@ -114,30 +168,33 @@ impl<T, A, V: View<T, A>, W: Animatable<f64>> View<T, A> for WeightedLayoutEleme
         (content_id, content_state, weight_id, weight_state): &mut Self::State,
         element: &mut Self::Element,
     ) -> crate::widget::ChangeFlags {
-        cx.with_id(*id, |cx| {
-            let changeflags = self.weight.rebuild(
-                cx,
-                &prev.weight,
-                weight_id,
-                weight_state,
-                element
-                    .weight_animatable
-                    .as_any_mut()
+        let (min, max) = self.limits();
+        let limits_changeflags = element.set_limits(min, max);
+        limits_changeflags
+            | cx.with_id(*id, |cx| {
+                let changeflags = self.weight.rebuild(
+                    cx,
+                    &prev.weight,
+                    weight_id,
+                    weight_state,
+                    element
+                        .weight_animatable
+                        .as_any_mut()
+                        .downcast_mut()
+                        .unwrap(),
+                );
+
+                let content_el = element
+                    .content
                     .downcast_mut()
-                    .unwrap(),
-            );
-
-            let content_el = element
-                .content
-                .downcast_mut()
-                .expect("The weighted widget changed its type, this should never happen!");
+                    .expect("The weighted widget changed its type, this should never happen!");
 
-            let content_changeflags =
-                self.content
-                    .rebuild(cx, &prev.content, content_id, content_state, content_el);
+                let content_changeflags =
+                    self.content
+                        .rebuild(cx, &prev.content, content_id, content_state, content_el);
 
-            changeflags | element.content.mark(content_changeflags)
-        })
+                changeflags | element.content.mark(content_changeflags)
+            })
     }
 
     fn message(
@@ -173,6 +230,8 @@ pub fn weighted<T, A, V: View<T, A>, W: Animatable<f64>>(
     WeightedLayoutElement {
         content,
         weight,
+        min_size: 0,
+        max_size: None,
         phantom: PhantomData,
     }
 }
@@ -0,0 +1,92 @@
+use std::{any::Any, marker::PhantomData};
+
+use futures_util::Stream;
+use xilem_core::{AsyncWake, Id, MessageResult};
+
+use crate::widget::ChangeFlags;
+
+use super::{Cx, StreamBuffering, StreamEventHandlerState, StreamMessage, View, ViewMarker};
+
+/// A long-lived background service attached to a view.
+///
+/// The stream is created once when the view is built and kept alive across rebuilds,
+/// every item of it is passed to the update function.
+/// The spawned task is aborted when the view is removed from the tree or the app exits.
+///
+/// See [`ViewExt::worker`](crate::view::ViewExt::worker).
+pub struct Worker<V, SE, SF, UF, T, A> {
+    pub(crate) view: V,
+    pub(crate) stream_fn: SF,
+    pub(crate) update_fn: UF,
+    #[allow(clippy::complexity)]
+    pub(crate) phantom: PhantomData<fn() -> (SE, T, A)>,
+}
+
+impl<V, SE, SF, UF, T, A> ViewMarker for Worker<V, SE, SF, UF, T, A> {}
+
+impl<T, A, V, SE, S, SF, UF> View<T, A> for Worker<V, SE, SF, UF, T, A>
+where
+    V: View<T, A>,
+    SE: Send + 'static,
+    S: Stream<Item = SE> + Send + 'static,
+    SF: Fn() -> S + Send + Sync,
+    UF: Fn(&mut T, SE) + Send + Sync,
+{
+    type State = (V::State, Id, StreamEventHandlerState<SE>);
+
+    type Element = V::Element;
+
+    fn build(&self, cx: &mut Cx) -> (Id, Self::State, Self::Element) {
+        let (id, (state, element)) = cx.with_new_id(|cx| {
+            let mut worker_state = StreamEventHandlerState::new(cx.waker(), cx.rt.clone());
+            worker_state.dispatch((self.stream_fn)(), &StreamBuffering::default());
+            let (child_id, child_state, element) = self.view.build(cx);
+            ((child_state, child_id, worker_state), element)
+        });
+        (id, state, element)
+    }
+
+    fn rebuild(
+        &self,
+        cx: &mut Cx,
+        prev: &Self,
+        id: &mut Id,
+        (state, child_id, _): &mut Self::State,
+        element: &mut Self::Element,
+    ) -> ChangeFlags {
+        // The worker is intentionally not registered as pending async,
+        // as it's usually alive for the whole lifetime of the view, and would otherwise delay every render.
+        cx.with_id(*id, |cx| {
+            self.view.rebuild(cx, &prev.view, child_id, state, element)
+        })
+    }
+
+    fn message(
+        &self,
+        id_path: &[Id],
+        (state, child_id, worker_state): &mut Self::State,
+        message: Box<dyn Any>,
+        app_state: &mut T,
+    ) -> MessageResult<A> {
+        match id_path {
+            [first, rest_path @ ..] if first == child_id => {
+                self.view.message(rest_path, state, message, app_state)
+            }
+            [] if message.downcast_ref::<AsyncWake>().is_some() => {
+                let mut has_updates = false;
+                while let Some(message) = worker_state.poll() {
+                    if let StreamMessage::Begin(item) | StreamMessage::Update(item) = message {
+                        (self.update_fn)(app_state, item);
+                        has_updates = true;
+                    }
+                }
+                if has_updates {
+                    MessageResult::RequestRebuild
+                } else {
+                    MessageResult::Nop
+                }
+            }
+            [..] => MessageResult::Stale(message),
+        }
+    }
+}
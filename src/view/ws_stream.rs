@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+use super::{stream, EventHandler, StreamMessage};
+
+pub use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+/// The delay before the first reconnection attempt, it's doubled after each failed attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Events of a WebSocket connection created with [`ws_stream`] or [`ws_connect`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WsEvent {
+    /// A connection attempt is started.
+    Connecting,
+    Connected,
+    Message(WsMessage),
+    /// The connection was closed or couldn't be established, with a description of the reason.
+    /// It's tried to reconnect after a backoff delay.
+    Disconnected(String),
+}
+
+enum ConnectionState {
+    Idle,
+    Connecting,
+    Open(Box<WebSocketStream<MaybeTlsStream<TcpStream>>>),
+    Retry,
+}
+
+/// Connects to a WebSocket at `url` and streams its messages.
+///
+/// When the connection is closed or fails, it's tried to reconnect with an exponential backoff.
+/// The stream never ends by itself, it's closed when it's dropped.
+///
+/// This can be used directly with [`ViewExt::worker`](crate::view::ViewExt::worker),
+/// for a connection that lives as long as the view.
+pub fn ws_connect(url: impl Into<String>) -> impl Stream<Item = WsEvent> + Send + 'static {
+    let url = url.into();
+    futures_util::stream::unfold(
+        (url, INITIAL_BACKOFF, ConnectionState::Idle),
+        |(url, mut backoff, state)| async move {
+            let (event, state) = match state {
+                ConnectionState::Idle => (WsEvent::Connecting, ConnectionState::Connecting),
+                ConnectionState::Connecting => match connect_async(url.as_str()).await {
+                    Ok((socket, _)) => {
+                        backoff = INITIAL_BACKOFF;
+                        (WsEvent::Connected, ConnectionState::Open(Box::new(socket)))
+                    }
+                    Err(err) => (
+                        WsEvent::Disconnected(err.to_string()),
+                        ConnectionState::Retry,
+                    ),
+                },
+                ConnectionState::Open(mut socket) => match socket.next().await {
+                    Some(Ok(message)) => (WsEvent::Message(message), ConnectionState::Open(socket)),
+                    Some(Err(err)) => (
+                        WsEvent::Disconnected(err.to_string()),
+                        ConnectionState::Retry,
+                    ),
+                    None => (
+                        WsEvent::Disconnected("connection closed".into()),
+                        ConnectionState::Retry,
+                    ),
+                },
+                ConnectionState::Retry => {
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    (WsEvent::Connecting, ConnectionState::Connecting)
+                }
+            };
+            Some((event, (url, backoff, state)))
+        },
+    )
+}
+
+/// An event handler that connects to a WebSocket at `url` when an event is received (e.g. a click)
+/// and passes all events of the connection (including connection state changes) to `update_fn`.
+///
+/// It's built on top of [`stream`], see [`ws_connect`] for more details about the connection.
+///
+/// # Examples
+/// ```
+/// # use trui::*;
+/// # App::new(String::new(), move |log: &mut String| {
+/// format!("Log: {log}").on_click(ws_stream(
+///     "ws://localhost:8080",
+///     |log: &mut String, event: WsEvent| {
+///         if let WsEvent::Message(WsMessage::Text(text)) = event {
+///             *log += &text;
+///         }
+///     },
+/// ))
+/// # });
+/// ```
+pub fn ws_stream<T, A, E, UF>(url: impl Into<String>, update_fn: UF) -> impl EventHandler<T, A, E>
+where
+    E: 'static,
+    UF: Fn(&mut T, WsEvent) + Send + Sync,
+{
+    let url = url.into();
+    stream(
+        move |_: &mut T, _: E| ws_connect(url.clone()),
+        move |app_state: &mut T, message: StreamMessage<WsEvent>| {
+            if let StreamMessage::Begin(event) | StreamMessage::Update(event) = message {
+                update_fn(app_state, event);
+            }
+        },
+    )
+}
@@ -1,5 +1,80 @@
+//! The widget tree, which is built by the views.
+//!
+//! Custom widgets can be implemented in other crates with the items in [`prelude`],
+//! along with a [`View`](crate::View), which creates and updates the widget.
+//!
+//! # Examples
+//! ```
+//! use std::{any::Any, marker::PhantomData};
+//! use trui::{widget::prelude::*, *};
+//!
+//! /// Fills its width proportional to `value`.
+//! pub struct GaugeWidget {
+//!     value: f64,
+//! }
+//!
+//! impl Widget for GaugeWidget {
+//!     fn paint(&mut self, cx: &mut PaintCx) {
+//!         let filled = (cx.size().width * self.value).round() as usize;
+//!         cx.set_string((0.0, 0.0), &"█".repeat(filled), Style::default());
+//!     }
+//!
+//!     fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+//!         bc.constrain((bc.max().width, 1.0))
+//!     }
+//!
+//!     fn lifecycle(&mut self, _cx: &mut LifeCycleCx, _event: &LifeCycle) {}
+//!
+//!     fn event(&mut self, _cx: &mut EventCx, _event: &Event) {}
+//! }
+//!
+//! pub struct Gauge<T> {
+//!     value: f64,
+//!     phantom: PhantomData<fn() -> T>,
+//! }
+//!
+//! impl<T> ViewMarker for Gauge<T> {}
+//!
+//! impl<T> View<T> for Gauge<T> {
+//!     type State = ();
+//!     type Element = GaugeWidget;
+//!
+//!     fn build(&self, cx: &mut Cx) -> (Id, (), GaugeWidget) {
+//!         let (id, element) = cx.with_new_id(|_| GaugeWidget { value: self.value });
+//!         (id, (), element)
+//!     }
+//!
+//!     fn rebuild(
+//!         &self,
+//!         _cx: &mut Cx,
+//!         prev: &Self,
+//!         _id: &mut Id,
+//!         _state: &mut (),
+//!         element: &mut GaugeWidget,
+//!     ) -> ChangeFlags {
+//!         if prev.value != self.value {
+//!             element.value = self.value;
+//!             ChangeFlags::PAINT
+//!         } else {
+//!             ChangeFlags::empty()
+//!         }
+//!     }
+//!
+//!     fn message(&self, _: &[Id], _: &mut (), message: Box<dyn Any>, _: &mut T) -> MessageResult<()> {
+//!         MessageResult::Stale(message)
+//!     }
+//! }
+//!
+//! App::new(0.5, |value: &mut f64| Gauge { value: *value, phantom: PhantomData });
+//! ```
+
+mod bidi;
 mod border;
 mod box_constraints;
+mod button;
+mod collapsible;
+mod confirm_quit;
+mod contrast;
 
 #[cfg(not(any(test, doctest, feature = "doctests")))]
 mod core;
@@ -8,25 +83,91 @@ mod core;
 pub(crate) mod core;
 
 pub(crate) mod animatables;
+mod animation_speed;
+mod date_picker;
+mod effect;
 mod events;
 mod fill_max_size;
+mod focus_scope;
+mod focus_when;
+mod freeze;
+mod gestures;
+mod heatmap;
 mod linear_layout;
 mod margin;
+mod marquee;
+mod modal;
+mod reorderable_list;
+mod responsive;
 mod scroll_view;
+mod scrollbar;
+mod section;
+mod selectable_list;
+mod size_limits;
+mod slider;
+mod stepper;
+mod table;
 mod text;
+mod text_input;
+mod toggle;
+mod tree;
 mod weighted_linear_layout;
 
+pub(crate) use self::core::{fold_mnemonic, CxState, FocusState, PodFlags, WidgetState};
 pub use self::core::{
-    AnyWidget, Canvas, ChangeFlags, CxState, EventCx, LayoutCx, LifeCycleCx, Message, PaintCx, Pod,
-    Widget,
+    AnyWidget, Canvas, ChangeFlags, EventCx, FocusDirection, IntrinsicSize, LayoutCx, LifeCycleCx,
+    Message, PaintCx, Pod, Widget,
 };
-pub(crate) use self::core::{PodFlags, WidgetState};
+pub(crate) use animation_speed::AnimationSpeed;
 pub(crate) use border::Border;
 pub use box_constraints::BoxConstraints;
-pub use events::*;
+pub(crate) use button::Button;
+pub(crate) use collapsible::Collapsible;
+pub(crate) use confirm_quit::ConfirmQuit;
+pub(crate) use contrast::ContrastCheck;
+pub(crate) use date_picker::DatePicker;
+pub(crate) use effect::Effect;
+pub use effect::{effects, CellRegion};
+pub(crate) use events::*;
+pub use events::{
+    CatchMouseButton, Ease, Event, HoverDelay, LifeCycle, MouseEvent, RawMouseEvent, ScrollEvent,
+    StyleTransition, TimerToken, ViewContext,
+};
 pub(crate) use fill_max_size::FillMaxSize;
+pub(crate) use focus_scope::FocusScope;
+pub(crate) use focus_when::FocusWhen;
+pub(crate) use freeze::Freeze;
+pub use gestures::{DragEvent, DragPhase};
+pub(crate) use gestures::{GestureKind, OnGesture, DEFAULT_DRAG_THRESHOLD};
+pub(crate) use heatmap::{Heatmap, TooltipFn};
 pub(crate) use linear_layout::LinearLayout;
 pub(crate) use margin::Margin;
+pub(crate) use marquee::Marquee;
+pub(crate) use modal::Modal;
+pub(crate) use reorderable_list::ReorderableList;
+pub(crate) use responsive::Responsive;
 pub(crate) use scroll_view::ScrollView;
+pub(crate) use scrollbar::Scrollbar;
+pub(crate) use section::Section;
+pub(crate) use selectable_list::SelectableList;
+pub(crate) use size_limits::SizeLimits;
+pub(crate) use slider::Slider;
+pub(crate) use stepper::Stepper;
+pub(crate) use table::Table;
+pub use text::WrapMode;
 pub(crate) use text::*;
+pub(crate) use text_input::TextInput;
+pub(crate) use toggle::Toggle;
+pub(crate) use tree::Tree;
+pub(crate) use tree::TreeMessage;
 pub(crate) use weighted_linear_layout::{WeightedLayoutElement, WeightedLinearLayout};
+
+/// Everything needed to implement a custom [`Widget`] (and a [`View`](crate::View) for it) in another crate.
+pub mod prelude {
+    pub use super::{
+        AnyWidget, BoxConstraints, Canvas, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx,
+        LifeCycle, LifeCycleCx, Message, MouseEvent, PaintCx, Pod, RawMouseEvent, ScrollEvent,
+        TimerToken, ViewContext, Widget,
+    };
+    pub use crate::geometry::{Axis, CellPoint, CellRect, CellSize, Point, Rect, Size};
+}
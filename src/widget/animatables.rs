@@ -1,6 +1,7 @@
 use std::{any::Any, ops::DerefMut, time::Duration};
 
 use super::{ChangeFlags, LifeCycleCx};
+use crate::geometry::Length;
 
 pub trait AnimatableElement<V>: 'static + AnyAnimatableElement<V> {
     fn animate(&mut self, cx: &mut LifeCycleCx) -> &V;
@@ -61,6 +62,30 @@ impl_animatable_for_primitive!(usize);
 impl_animatable_for_primitive!(f32);
 impl_animatable_for_primitive!(f64);
 
+impl_animatable_for_primitive!(Length);
+
+/// Interprets an animated `f64` as [`Length::Percent`].
+pub struct AsPercent<E> {
+    pub(crate) inner: E,
+    value: Length,
+}
+
+impl<E> AsPercent<E> {
+    pub(crate) fn new(inner: E) -> Self {
+        AsPercent {
+            inner,
+            value: Length::Auto,
+        }
+    }
+}
+
+impl<E: AnimatableElement<f64>> AnimatableElement<Length> for AsPercent<E> {
+    fn animate(&mut self, cx: &mut LifeCycleCx) -> &Length {
+        self.value = Length::Percent(*self.inner.animate(cx));
+        &self.value
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct LowPassIIR<AT, V> {
     pub(crate) target: AT,
@@ -85,13 +110,20 @@ impl<AT> LowPassIIR<AT, f64> {
             ChangeFlags::empty()
         }
     }
+
+    /// Jumps to `value`, from which the next animation step continues towards the target.
+    pub(crate) fn set_value(&mut self, value: f64) {
+        self.value = Some(value);
+    }
 }
 
 impl<AT: AnimatableElement<f64>> AnimatableElement<f64> for LowPassIIR<AT, f64> {
     fn animate(&mut self, cx: &mut LifeCycleCx) -> &f64 {
         let target_value = self.target.animate(cx);
         if let Some(value) = &mut self.value {
-            if (*target_value - *value).abs() > 0.0001 {
+            if cx.reduce_motion() {
+                *value = *target_value;
+            } else if (*target_value - *value).abs() > 0.0001 {
                 let delta_time = cx.time_since_last_render_request().as_secs_f64() * 100.0; // could be a different factor, and maybe more precisely a frequency based cutoff or something like that
                 let time_adjusted_decay =
                     1.0 - ((1.0 - self.decay.clamp(0.0, 1.0)).powf(delta_time));
@@ -127,20 +159,68 @@ impl<V, T: TweenableElement<V>, R: AnimatableElement<f64>> AnimatableElement<V>
     }
 }
 
+/// How often a [`PlayTween`] is played, see [`PlayTween::repeat`](crate::PlayTween::repeat).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Repeat {
+    /// Plays the tween this number of times.
+    Times(u32),
+    Forever,
+}
+
+impl Default for Repeat {
+    fn default() -> Self {
+        Repeat::Times(1)
+    }
+}
+
+impl From<u32> for Repeat {
+    fn from(times: u32) -> Self {
+        Repeat::Times(times)
+    }
+}
+
 // TODO Duration could also be animated, but I'm not sure it's worth the complexity (vs benefit)...
 #[derive(Clone, Debug)]
 pub struct PlayTween<PS, TW> {
     pub(crate) play_speed: PS,
     current_time: Duration,
     pub(crate) tweenable: TW,
+    repeat: Repeat,
+    ping_pong: bool,
+    /// The number of times the tween was played completely.
+    plays: u32,
+    /// Whether the current play is backwards, when playing ping-pong.
+    backwards: bool,
 }
 
 impl<PS, TW> PlayTween<PS, TW> {
-    pub(crate) fn new(play_speed: PS, tweenable: TW) -> Self {
+    pub(crate) fn new(play_speed: PS, tweenable: TW, repeat: Repeat, ping_pong: bool) -> Self {
         PlayTween {
             play_speed,
             tweenable,
             current_time: Duration::ZERO,
+            repeat,
+            ping_pong,
+            plays: 0,
+            backwards: false,
+        }
+    }
+
+    pub(crate) fn set_repeat(&mut self, repeat: Repeat, ping_pong: bool) -> ChangeFlags {
+        if self.repeat != repeat || self.ping_pong != ping_pong {
+            self.repeat = repeat;
+            self.ping_pong = ping_pong;
+            self.plays = 0;
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn has_plays_left(&self) -> bool {
+        match self.repeat {
+            Repeat::Times(times) => self.plays + 1 < times,
+            Repeat::Forever => true,
         }
     }
 }
@@ -149,12 +229,10 @@ impl<V: 'static, PS: AnimatableElement<f64>, TW: TweenableElement<V>> Animatable
     for PlayTween<PS, TW>
 {
     fn animate(&mut self, cx: &mut LifeCycleCx) -> &V {
-        let play_speed = self.play_speed.animate(cx);
-        let duration_as_secs = self.tweenable.duration().as_secs_f64();
+        let play_speed = *self.play_speed.animate(cx);
+        let duration = self.tweenable.duration();
+        let duration_as_secs = duration.as_secs_f64();
         let current_time_as_secs = self.current_time.as_secs_f64();
-        let new_time = (current_time_as_secs
-            + *play_speed * cx.time_since_last_render_request().as_secs_f64())
-        .clamp(0.0, duration_as_secs);
         // avoid division by zero
         let ratio = if duration_as_secs == 0.0 {
             0.0
@@ -162,11 +240,47 @@ impl<V: 'static, PS: AnimatableElement<f64>, TW: TweenableElement<V>> Animatable
             current_time_as_secs / duration_as_secs
         };
 
-        if !self.tweenable.duration().is_zero()
-            && ((*play_speed > 0.0 && self.current_time != self.tweenable.duration())
-                || (*play_speed < 0.0 && self.current_time != Duration::ZERO))
-        {
-            self.current_time = Duration::from_secs_f64(new_time);
+        if cx.reduce_motion() {
+            // jump to the end in the play direction
+            if play_speed > 0.0 {
+                self.current_time = duration;
+            } else if play_speed < 0.0 {
+                self.current_time = Duration::ZERO;
+            }
+            let ratio = if duration_as_secs == 0.0 {
+                0.0
+            } else {
+                self.current_time.as_secs_f64() / duration_as_secs
+            };
+            return self.tweenable.interpolate(cx, ratio);
+        }
+
+        let speed = if self.backwards {
+            -play_speed
+        } else {
+            play_speed
+        };
+        let is_at_end = (speed > 0.0 && self.current_time == duration)
+            || (speed < 0.0 && self.current_time == Duration::ZERO);
+        if !duration.is_zero() && speed != 0.0 && (!is_at_end || self.has_plays_left()) {
+            let mut new_time =
+                current_time_as_secs + speed * cx.time_since_last_render_request().as_secs_f64();
+            // start the next play, when the end was passed
+            while (new_time > duration_as_secs || new_time < 0.0) && self.has_plays_left() {
+                let end = if new_time > duration_as_secs {
+                    duration_as_secs
+                } else {
+                    0.0
+                };
+                new_time = if self.ping_pong {
+                    self.backwards = !self.backwards;
+                    2.0 * end - new_time
+                } else {
+                    new_time - end + (duration_as_secs - end)
+                };
+                self.plays += 1;
+            }
+            self.current_time = Duration::from_secs_f64(new_time.clamp(0.0, duration_as_secs));
             cx.request_animation_update();
         }
         self.tweenable.interpolate(cx, ratio)
@@ -247,6 +361,23 @@ impl<A: AnimatableElement<f64>> TweenableElement<f64> for TweenableRange<A, f64>
     }
 }
 
+impl TweenableRange<Length, Length> {
+    pub(crate) fn from_lengths(start: Length, end: Length) -> Self {
+        Self {
+            start,
+            end,
+            value: start,
+        }
+    }
+}
+
+impl TweenableElement<Length> for TweenableRange<Length, Length> {
+    fn interpolate(&mut self, _cx: &mut LifeCycleCx, ratio: f64) -> &Length {
+        self.value = self.start.lerp(self.end, ratio);
+        &self.value
+    }
+}
+
 pub struct Map<I, V, VO> {
     pub(crate) input: I,
     output: Option<VO>,
@@ -339,6 +470,40 @@ impl<V, T: TweenableElement<V>> TweenableElement<V> for WithDuration<T> {
     }
 }
 
+/// Holds the start value of the tweenable it composes for `delay`, before it is played
+pub struct Delay<T> {
+    pub(crate) tweenable: T,
+    pub(crate) delay: Duration,
+}
+
+impl<T> Delay<T> {
+    pub(crate) fn new(tweenable: T, delay: Duration) -> Self {
+        Self { tweenable, delay }
+    }
+}
+
+impl<V, T: TweenableElement<V>> TweenableElement<V> for Delay<T> {
+    fn interpolate(&mut self, cx: &mut LifeCycleCx, ratio: f64) -> &V {
+        let tween_duration = self.tweenable.duration().as_secs_f64();
+        let delay = self.delay.as_secs_f64();
+        let time = ratio * (delay + tween_duration) - delay;
+        let ratio = if tween_duration == 0.0 {
+            if time >= 0.0 {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            (time / tween_duration).clamp(0.0, 1.0)
+        };
+        self.tweenable.interpolate(cx, ratio)
+    }
+
+    fn duration(&mut self) -> Duration {
+        self.delay + self.tweenable.duration()
+    }
+}
+
 pub struct Sequence<V> {
     pub(crate) tweenables: Vec<Box<dyn TweenableElement<V>>>,
 }
@@ -393,6 +558,64 @@ impl<V: 'static> TweenableElement<V> for Sequence<V> {
     }
 }
 
+/// Plays multiple tweenables at the same time, each with its own duration, until the longest has finished.
+pub struct Parallel<E, V> {
+    pub(crate) tweenables: E,
+    value: Option<V>,
+}
+
+impl<E, V> Parallel<E, V> {
+    pub(crate) fn new(tweenables: E) -> Self {
+        Parallel {
+            tweenables,
+            value: None,
+        }
+    }
+}
+
+/// The ratio of a tweenable with `duration` within a parallel group at `time`.
+fn parallel_ratio(time: f64, duration: Duration) -> f64 {
+    let duration = duration.as_secs_f64();
+    if duration == 0.0 {
+        1.0
+    } else {
+        (time / duration).min(1.0)
+    }
+}
+
+macro_rules! impl_tweenable_element_for_parallel {
+    ($( $v:ident, $e:ident, $i:tt );*) => {
+        impl<$($v: Clone + 'static, $e: TweenableElement<$v>),*> TweenableElement<($($v,)*)>
+            for Parallel<($($e,)*), ($($v,)*)>
+        {
+            fn interpolate(&mut self, cx: &mut LifeCycleCx, ratio: f64) -> &($($v,)*) {
+                let time = ratio * self.duration().as_secs_f64();
+                let value = ($({
+                    let ratio = parallel_ratio(time, self.tweenables.$i.duration());
+                    self.tweenables.$i.interpolate(cx, ratio).clone()
+                },)*);
+                self.value.insert(value)
+            }
+
+            fn duration(&mut self) -> Duration {
+                Duration::ZERO$(.max(self.tweenables.$i.duration()))*
+            }
+        }
+    };
+}
+
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4; V5, E5, 5);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4; V5, E5, 5; V6, E6, 6);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4; V5, E5, 5; V6, E6, 6; V7, E7, 7);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4; V5, E5, 5; V6, E6, 6; V7, E7, 7; V8, E8, 8);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4; V5, E5, 5; V6, E6, 6; V7, E7, 7; V8, E8, 8; V9, E9, 9);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4; V5, E5, 5; V6, E6, 6; V7, E7, 7; V8, E8, 8; V9, E9, 9; V10, E10, 10);
+impl_tweenable_element_for_parallel!(V0, E0, 0; V1, E1, 1; V2, E2, 2; V3, E3, 3; V4, E4, 4; V5, E5, 5; V6, E6, 6; V7, E7, 7; V8, E8, 8; V9, E9, 9; V10, E10, 10; V11, E11, 11);
+
 pub mod ease {
     use crate::widget::LifeCycleCx;
     use std::time::Duration;
@@ -0,0 +1,61 @@
+use kurbo::Size;
+
+use crate::geometry::Axis;
+
+use super::{
+    BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx,
+    PaintCx, Pod, Widget,
+};
+
+/// Scales the time that passes for the animations within its content.
+pub struct AnimationSpeed {
+    pub(crate) content: Pod,
+    speed: f64,
+}
+
+impl AnimationSpeed {
+    pub(crate) fn new(content: impl Widget, speed: f64) -> Self {
+        AnimationSpeed {
+            content: Pod::new(content),
+            speed,
+        }
+    }
+
+    pub(crate) fn set_speed(&mut self, speed: f64) -> ChangeFlags {
+        if self.speed != speed {
+            self.speed = speed;
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+}
+
+impl Widget for AnimationSpeed {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.content.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.content.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::Animate = event {
+            let elapsed = cx.cx_state.time_since_last_render_request;
+            cx.cx_state.time_since_last_render_request = elapsed.mul_f64(self.speed.max(0.0));
+            self.content.lifecycle(cx, event);
+            cx.cx_state.time_since_last_render_request = elapsed;
+        } else {
+            self.content.lifecycle(cx, event);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! A simplified version of the Unicode bidirectional algorithm (without explicit embeddings and isolates),
+//! which reorders the graphemes of a line of text from logical to visual order.
+
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    /// Strong left-to-right, e.g. latin letters.
+    Left,
+    /// Strong right-to-left, e.g. hebrew or arabic letters.
+    Right,
+    /// Numbers keep their left-to-right order, even within right-to-left text.
+    Number,
+    /// Whitespace, punctuation, symbols etc. get the direction of the surrounding text.
+    Neutral,
+}
+
+fn class(grapheme: &str) -> Class {
+    let Some(c) = grapheme.chars().next() else {
+        return Class::Neutral;
+    };
+    match c as u32 {
+        0x0660..=0x0669 | 0x06F0..=0x06F9 => Class::Number,
+        0x0590..=0x08FF
+        | 0xFB1D..=0xFDFF
+        | 0xFE70..=0xFEFF
+        | 0x10800..=0x10FFF
+        | 0x1E800..=0x1EFFF => Class::Right,
+        _ if c.is_ascii_digit() => Class::Number,
+        _ if c.is_alphabetic() => Class::Left,
+        _ => Class::Neutral,
+    }
+}
+
+/// Whether `text` contains any right-to-left characters.
+pub(crate) fn has_rtl(text: &str) -> bool {
+    text.graphemes(true).any(|g| class(g) == Class::Right)
+}
+
+fn mirror(grapheme: &str) -> Option<&'static str> {
+    Some(match grapheme {
+        "(" => ")",
+        ")" => "(",
+        "[" => "]",
+        "]" => "[",
+        "{" => "}",
+        "}" => "{",
+        "<" => ">",
+        ">" => "<",
+        "«" => "»",
+        "»" => "«",
+        _ => return None,
+    })
+}
+
+/// Returns the graphemes of `line` with their byte offsets in visual (left to right) order,
+/// where `rtl` is the base direction of the paragraph.
+///
+/// Brackets within right-to-left runs are mirrored.
+pub(crate) fn visual_order(line: &str, rtl: bool) -> Vec<(usize, Cow<'_, str>)> {
+    let graphemes: Vec<_> = line.grapheme_indices(true).collect();
+    if !rtl && !has_rtl(line) {
+        return graphemes
+            .into_iter()
+            .map(|(offset, g)| (offset, g.into()))
+            .collect();
+    }
+    let classes: Vec<_> = graphemes.iter().map(|(_, g)| class(g)).collect();
+    let base: u8 = if rtl { 1 } else { 0 };
+    let left_level = if rtl { 2 } else { 0 };
+    let mut levels = vec![base; graphemes.len()];
+
+    // strong and weak types
+    let mut prev_rtl = rtl;
+    for (level, class) in levels.iter_mut().zip(&classes) {
+        match class {
+            Class::Left => {
+                *level = left_level;
+                prev_rtl = false;
+            }
+            Class::Right => {
+                *level = 1;
+                prev_rtl = true;
+            }
+            Class::Number => *level = if rtl || prev_rtl { 2 } else { 0 },
+            Class::Neutral => (),
+        }
+    }
+
+    // neutrals between text of the same direction get that direction, otherwise the base direction
+    let is_rtl = |class: Class| class != Class::Left;
+    let mut start = 0;
+    while start < classes.len() {
+        if classes[start] != Class::Neutral {
+            start += 1;
+            continue;
+        }
+        let end = (start..classes.len())
+            .find(|&i| classes[i] != Class::Neutral)
+            .unwrap_or(classes.len());
+        let before = start.checked_sub(1).map_or(rtl, |i| is_rtl(classes[i]));
+        let after = classes.get(end).map_or(rtl, |&class| is_rtl(class));
+        let direction = if before == after { before } else { rtl };
+        let level = if direction { 1 } else { left_level };
+        levels[start..end].fill(level);
+        start = end;
+    }
+
+    // trailing whitespace is at the base level
+    for (level, (_, g)) in levels.iter_mut().zip(&graphemes).rev() {
+        if !g.chars().all(char::is_whitespace) {
+            break;
+        }
+        *level = base;
+    }
+
+    let mut visual: Vec<_> = graphemes
+        .into_iter()
+        .zip(&levels)
+        .map(|((offset, g), level)| {
+            let g = match mirror(g) {
+                Some(mirrored) if level % 2 == 1 => mirrored.into(),
+                _ => g.into(),
+            };
+            (offset, g, *level)
+        })
+        .collect();
+
+    // reverse every run at or above each level, from the highest level down to the lowest odd level
+    let max_level = levels.iter().copied().max().unwrap_or(0);
+    for level in (1..=max_level).rev() {
+        let mut i = 0;
+        while i < visual.len() {
+            if visual[i].2 < level {
+                i += 1;
+                continue;
+            }
+            let end = (i..visual.len())
+                .find(|&j| visual[j].2 < level)
+                .unwrap_or(visual.len());
+            visual[i..end].reverse();
+            i = end;
+        }
+    }
+    visual
+        .into_iter()
+        .map(|(offset, g, _)| (offset, g))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visual(line: &str, rtl: bool) -> String {
+        visual_order(line, rtl)
+            .into_iter()
+            .map(|(_, g)| g)
+            .collect()
+    }
+
+    #[test]
+    fn ltr_text_is_unchanged() {
+        assert_eq!(visual("Hello (World) 42", false), "Hello (World) 42");
+    }
+
+    #[test]
+    fn rtl_run_in_ltr_text() {
+        assert_eq!(visual("abc שלום def", false), "abc םולש def");
+    }
+
+    #[test]
+    fn rtl_text_with_numbers_and_brackets() {
+        assert_eq!(visual("שלום (123)", true), "(123) םולש");
+    }
+
+    #[test]
+    fn ltr_run_in_rtl_text() {
+        assert_eq!(visual("שלום abc", true), "abc םולש");
+    }
+}
@@ -1,8 +1,9 @@
 use super::{
-    core::LayoutCx, core::PaintCx, BoxConstraints, ChangeFlags, Event, EventCx, Pod, Widget,
+    animatables::AnimatableElement, core::LayoutCx, core::PaintCx, BoxConstraints, ChangeFlags,
+    Event, EventCx, IntrinsicSize, LifeCycle, Pod, Widget,
 };
 use crate::{
-    geometry::{Point, Size},
+    geometry::{Axis, Point, Size},
     view::Borders,
     BorderKind,
 };
@@ -13,6 +14,9 @@ pub struct Border {
     borders: Borders,
     kind: BorderKind,
     style: Style,
+    /// How much of the outline is drawn (from `0.0` to `1.0`), clockwise from the top left corner.
+    pub(crate) progress_animatable: Option<Box<dyn AnimatableElement<f64>>>,
+    progress: f64,
 }
 
 impl Border {
@@ -27,14 +31,36 @@ impl Border {
             borders,
             kind,
             style,
+            progress_animatable: None,
+            progress: 1.0,
+        }
+    }
+
+    /// A border, that is traced in by `progress`, which is hidden until it's animated the first time.
+    pub(crate) fn animated(
+        content: impl Widget,
+        borders: Borders,
+        style: Style,
+        kind: BorderKind,
+        progress: impl AnimatableElement<f64>,
+    ) -> Self {
+        Border {
+            progress_animatable: Some(Box::new(progress)),
+            progress: 0.0,
+            ..Border::new(content, borders, style, kind)
         }
     }
 
     pub(crate) fn set_borders(&mut self, borders: Borders) -> ChangeFlags {
         if self.borders != borders {
+            let insets = self.insets();
             self.borders = borders;
-            // TODO more sophisticated check for needed ChangeFlags (specifically layout)
-            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+            // e.g. only the corners have changed
+            if self.insets() == insets {
+                ChangeFlags::paint_only()
+            } else {
+                ChangeFlags::relayout()
+            }
         } else {
             ChangeFlags::empty()
         }
@@ -43,8 +69,8 @@ impl Border {
     pub(crate) fn set_kind(&mut self, kind: BorderKind) -> ChangeFlags {
         if self.kind != kind {
             self.kind = kind;
-            // TODO more sophisticated check for needed ChangeFlags (specifically layout)
-            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+            // all kinds take the same space
+            ChangeFlags::paint_only()
         } else {
             ChangeFlags::empty()
         }
@@ -59,32 +85,47 @@ impl Border {
         }
     }
 
+    /// Returns the origin of the content and the space taken by the borders on both axes.
+    fn insets(&self) -> (Point, Size) {
+        let pad = |borders| {
+            if self.borders.intersects(borders) {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        let pad_left = pad(Borders::LEFT_WITH_CORNERS);
+        let pad_right = pad(Borders::RIGHT_WITH_CORNERS);
+        let pad_top = pad(Borders::TOP_WITH_CORNERS);
+        let pad_bottom = pad(Borders::BOTTOM_WITH_CORNERS);
+        (
+            Point::new(pad_left, pad_top),
+            Size::new(pad_left + pad_right, pad_top + pad_bottom),
+        )
+    }
+
     fn render_border(&self, cx: &mut PaintCx) {
         use Borders as B; // unfortunately not possible to wildcard import since it's not an enum...
 
         let style = self.style.patch(cx.override_style);
         cx.override_style = Style::default();
-        let s = cx.size();
-        let width = s.width.round() as usize;
-        let height = s.height.round() as usize;
+        let size = cx.cell_size();
+        let (width, height) = (size.width as usize, size.height as usize);
 
         if width == 0 || height == 0 {
             return;
         }
 
-        let canvas = &mut cx.canvas;
-
+        // the number of cells of the outline, that are drawn
+        let drawn = self.progress.clamp(0.0, 1.0) * (2 * (width + height) - 4).max(1) as f64;
         let mut draw = |x, y, symbol, style| {
-            if x < width && y < height {
-                canvas
-                    .get_mut((x as f64, y as f64))
-                    .set_symbol(symbol)
-                    .set_style(style);
+            if x < width && y < height && (perimeter_index(x, y, width, height) as f64) < drawn {
+                cx.set_cell((x as f64, y as f64), symbol, style);
             }
         };
 
         // Voluntary extra task, find cases where a dot makes sense as well (like `TOP | LEFT`)...
-        if s.width == 1.0 && s.height == 1.0 && self.borders.intersects(B::ALL_CORNERS) {
+        if width == 1 && height == 1 && self.borders.intersects(B::ALL_CORNERS) {
             draw(0, 0, symbols::DOT, self.style);
             return;
         }
@@ -154,6 +195,21 @@ impl Border {
     }
 }
 
+/// The position of the cell at `x`, `y` on the outline of a `width` x `height` rectangle,
+/// going clockwise from the top left corner.
+fn perimeter_index(x: usize, y: usize, width: usize, height: usize) -> usize {
+    let (right, bottom) = (width - 1, height - 1);
+    if y == 0 {
+        x
+    } else if x == right {
+        right + y
+    } else if y == bottom {
+        right + bottom + (right - x)
+    } else {
+        2 * right + bottom + (bottom - y)
+    }
+}
+
 impl Widget for Border {
     fn paint(&mut self, cx: &mut PaintCx) {
         self.render_border(cx);
@@ -165,25 +221,58 @@ impl Widget for Border {
     }
 
     fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
-        let pad = |borders| {
-            if self.borders.intersects(borders) {
-                1.0
-            } else {
-                0.0
-            }
-        };
-        let pad_left = pad(Borders::LEFT_WITH_CORNERS);
-        let pad_right = pad(Borders::RIGHT_WITH_CORNERS);
-        let pad_top = pad(Borders::TOP_WITH_CORNERS);
-        let pad_bottom = pad(Borders::BOTTOM_WITH_CORNERS);
-        let border_padding = Size::new(pad_left + pad_right, pad_top + pad_bottom);
+        let (origin, border_padding) = self.insets();
         let content_size = self.content.layout(cx, &bc.shrink(border_padding));
 
-        self.content.set_origin(cx, Point::new(pad_left, pad_top));
+        self.content.set_origin(cx, origin);
         bc.constrain(content_size + border_padding)
     }
 
-    fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &super::LifeCycle) {
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let (_, border_padding) = self.insets();
+        let cross = (cross - axis.minor(border_padding)).max(0.0);
+        let content = self.content.measure(cx, axis, cross)?;
+        Some(content.expand(axis.major(border_padding)))
+    }
+
+    fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        if let (LifeCycle::Animate, Some(progress)) = (event, &mut self.progress_animatable) {
+            let new_progress = *progress.animate(cx);
+            if new_progress != self.progress {
+                cx.request_paint();
+                self.progress = new_progress;
+            }
+        }
         self.content.lifecycle(cx, event);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_outline_is_traced_clockwise() {
+        // a 4 x 3 rectangle has 10 cells on its outline
+        let outline: Vec<_> = (0..3)
+            .map(|y| {
+                (0..4)
+                    .map(|x| match (x, y) {
+                        (1..=2, 1) => None,
+                        _ => Some(perimeter_index(x, y, 4, 3)),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(
+            outline,
+            [
+                [Some(0), Some(1), Some(2), Some(3)],
+                [Some(9), None, None, Some(4)],
+                [Some(8), Some(7), Some(6), Some(5)],
+            ]
+        );
+        // a single column is traced from top to bottom
+        assert_eq!(perimeter_index(0, 2, 1, 3), 2);
+    }
+}
@@ -56,11 +56,15 @@ impl BoxConstraints {
     /// The given sizes are also [rounded away from zero],
     /// so that the layout is aligned to integers.
     ///
+    /// Negative (e.g. when the space left for a child of a tiny terminal is computed) or inverted sizes are clamped,
+    /// so that the constraints can always be satisfied, with a size of zero at least.
+    ///
     /// [rounded away from zero]: struct.Size.html#method.expand
     pub fn new(min: Size, max: Size) -> BoxConstraints {
+        let max = non_negative(max.expand());
         BoxConstraints {
-            min: min.expand(),
-            max: max.expand(),
+            min: min.expand().clamp(Size::ZERO, max),
+            max,
         }
     }
 
@@ -73,7 +77,7 @@ impl BoxConstraints {
     ///
     /// [rounded away from zero]: struct.Size.html#method.expand
     pub fn tight(size: Size) -> BoxConstraints {
-        let size = size.expand();
+        let size = non_negative(size.expand());
         BoxConstraints {
             min: size,
             max: size,
@@ -358,6 +362,10 @@ impl BoxConstraints {
     }
 }
 
+fn non_negative(size: Size) -> Size {
+    Size::new(size.width.max(0.0), size.height.max(0.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -369,6 +377,15 @@ mod tests {
         )
     }
 
+    #[test]
+    fn invalid_sizes_are_clamped() {
+        assert_eq!(bc(-3.0, 5.0, -1.0, 2.0), bc(0.0, 2.0, 0.0, 2.0));
+        assert_eq!(
+            bc(0.0, 0.0, 0.0, 0.0).constrain_width_to(-4.0).max(),
+            Size::ZERO
+        );
+    }
+
     #[test]
     fn constrain_aspect_ratio() {
         for (bc, aspect_ratio, width, output) in [
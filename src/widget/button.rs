@@ -0,0 +1,208 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+use kurbo::Rect;
+use ratatui::style::Style;
+
+use super::{
+    core::{IdPath, PaintCx},
+    fold_mnemonic, BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle,
+    LifeCycleCx, Message, Pod, RawMouseEvent, Widget,
+};
+use crate::{
+    geometry::{Axis, Size},
+    view::Borders,
+    ButtonTheme,
+};
+
+/// A clickable, focusable widget, that draws its content within a border.
+///
+/// It sends `()` to its view, when it's clicked, activated via `Enter`/`Space` while focused,
+/// or with `Alt` and its mnemonic letter.
+pub struct Button {
+    /// Always a [`Border`](super::Border) wrapping the label.
+    pub(crate) content: Pod,
+    id_path: IdPath,
+    styles: ButtonTheme,
+    disabled: bool,
+    /// The preferred mnemonic letters, the first one, that isn't used by another widget, is registered.
+    mnemonics: Vec<char>,
+    /// The registered mnemonic letter.
+    mnemonic: Option<char>,
+}
+
+impl Button {
+    pub(crate) fn new(
+        label: impl Widget,
+        id_path: &IdPath,
+        styles: ButtonTheme,
+        disabled: bool,
+    ) -> Self {
+        Button {
+            content: Pod::new(super::Border::new(
+                label,
+                Borders::ALL,
+                styles.style,
+                styles.border,
+            )),
+            id_path: id_path.clone(),
+            styles,
+            disabled,
+            mnemonics: Vec::new(),
+            mnemonic: None,
+        }
+    }
+
+    pub(crate) fn set_mnemonics(&mut self, mnemonics: &[char]) -> ChangeFlags {
+        if self.mnemonics != mnemonics {
+            self.mnemonics = mnemonics.to_vec();
+            // The mnemonics are registered while building the focus chain, which currently happens with every layout
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// Underlines the first occurrence of the mnemonic letter within the border.
+    fn paint_mnemonic(&self, cx: &mut PaintCx) {
+        let Some(mnemonic) = self.mnemonic.filter(|_| !self.disabled) else {
+            return;
+        };
+        let size = cx.size();
+        let inner = Rect::new(1.0, 1.0, size.width - 1.0, size.height - 1.0);
+        let style = self.styles.mnemonic;
+        if let Some(mut region) = cx.region(inner) {
+            let cell = region.cells_mut().find(|cell| {
+                cell.symbol()
+                    .chars()
+                    .next()
+                    .is_some_and(|c| fold_mnemonic(c) == mnemonic)
+            });
+            if let Some(cell) = cell {
+                cell.set_style(style);
+            }
+        }
+    }
+
+    pub(crate) fn border_mut(&mut self) -> &mut super::Border {
+        self.content
+            .downcast_mut()
+            .expect("The button content widget changed its type, this should never happen!")
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: ButtonTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            let border = self.border_mut();
+            let changeflags = border.set_style(styles.style) | border.set_kind(styles.border);
+            self.content.mark(changeflags) | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_disabled(&mut self, disabled: bool) -> ChangeFlags {
+        if self.disabled != disabled {
+            self.disabled = disabled;
+            // The focus chain has to be rebuilt, which currently happens with every layout
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn state_style(&self, cx: &PaintCx) -> Style {
+        if self.disabled {
+            return self.styles.disabled;
+        }
+        let mut style = Style::default();
+        if cx.is_hot() {
+            style = style.patch(self.styles.hover);
+        }
+        if cx.is_focused() {
+            style = style.patch(self.styles.focused);
+        }
+        if cx.is_active() {
+            style = style.patch(self.styles.pressed);
+        }
+        style
+    }
+}
+
+impl Widget for Button {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        cx.patch_style(self.state_style(cx));
+        self.content.paint(cx);
+        self.paint_mnemonic(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.content.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.content.event(cx, event);
+
+        if self.disabled {
+            if cx.is_active() {
+                cx.set_active(false);
+                cx.request_paint();
+            }
+            return;
+        }
+
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                ..
+            }) if cx.is_hot() => {
+                cx.set_active(true);
+                cx.request_focus();
+                cx.request_paint();
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            }) if cx.is_active() => {
+                if cx.is_hot() {
+                    cx.add_message(Message::new(self.id_path.clone(), ()));
+                }
+                cx.set_active(false);
+                cx.request_paint();
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter | KeyCode::Char(' '),
+                kind: KeyEventKind::Press,
+                ..
+            }) if cx.is_focused() => {
+                cx.add_message(Message::new(self.id_path.clone(), ()));
+                cx.set_handled(true);
+            }
+            Event::Mnemonic(letter) if Some(*letter) == self.mnemonic => {
+                cx.add_message(Message::new(self.id_path.clone(), ()));
+                cx.request_focus();
+                cx.set_handled(true);
+            }
+            Event::FocusLost => cx.set_active(false),
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        match event {
+            LifeCycle::HotChanged(_) => cx.request_paint(),
+            LifeCycle::BuildFocusChain if !self.disabled => {
+                cx.register_for_focus();
+                let mnemonic = cx.register_mnemonic(self.mnemonics.iter().copied());
+                if mnemonic != self.mnemonic {
+                    self.mnemonic = mnemonic;
+                    cx.request_paint();
+                }
+            }
+            _ => (),
+        }
+        self.content.lifecycle(cx, event);
+    }
+}
@@ -0,0 +1,249 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+
+use super::{
+    animatables::{AnimatableElement, LowPassIIR},
+    core::{IdPath, PaintCx},
+    BoxConstraints, Canvas, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    Pod, RawMouseEvent, Widget,
+};
+use crate::{
+    geometry::{Point, Size},
+    CollapsibleTheme,
+};
+
+/// The width of the chevron in front of the header, including the space after it.
+const CHEVRON_WIDTH: f64 = 2.0;
+
+/// The decay of the low-pass filter that animates the height of the content.
+const EXPAND_DECAY: f64 = 0.3;
+
+/// A header with content below it, which is shown or hidden (with an animated height).
+///
+/// Sends the negated `expanded` value to its view, when the header is clicked,
+/// or when `Enter`/`Space` is pressed while it's focused.
+pub struct Collapsible {
+    id_path: IdPath,
+    pub(crate) header: Pod,
+    pub(crate) content: Pod,
+    expanded: bool,
+    styles: CollapsibleTheme,
+    /// The visible fraction of the content from `0.0` (collapsed) to `1.0` (expanded)
+    visible: LowPassIIR<f64, f64>,
+    visible_fraction: f64,
+    // The content is painted into a separate buffer, so that it can be clipped while it's animated.
+    content_buffer: ratatui::buffer::Buffer,
+}
+
+impl Collapsible {
+    pub(crate) fn new(
+        id_path: &IdPath,
+        header: impl Widget,
+        content: impl Widget,
+        expanded: bool,
+        styles: CollapsibleTheme,
+    ) -> Self {
+        let fraction = if expanded { 1.0 } else { 0.0 };
+        Collapsible {
+            id_path: id_path.clone(),
+            header: Pod::new(header),
+            content: Pod::new(content),
+            expanded,
+            styles,
+            visible: LowPassIIR::new(fraction, EXPAND_DECAY),
+            visible_fraction: fraction,
+            content_buffer: ratatui::buffer::Buffer::default(),
+        }
+    }
+
+    pub(crate) fn set_expanded(&mut self, expanded: bool) -> ChangeFlags {
+        if self.expanded != expanded {
+            self.expanded = expanded;
+            self.visible.target = if expanded { 1.0 } else { 0.0 };
+            // the focus chain changes as well, since the content is only focusable when expanded
+            ChangeFlags::ANIMATION | ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: CollapsibleTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn toggle(&self, cx: &mut EventCx) {
+        cx.add_message(Message::new(self.id_path.clone(), !self.expanded));
+    }
+}
+
+impl Widget for Collapsible {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let mut style = self.styles.chevron;
+        if cx.is_focused() {
+            style = style.patch(self.styles.focused);
+        }
+        if cx.size().width >= 1.0 && cx.size().height >= 1.0 {
+            let symbol = if self.expanded {
+                self.styles.expanded_symbol
+            } else {
+                self.styles.collapsed_symbol
+            };
+            cx.set_cell((0.0, 0.0), symbol, style);
+        }
+        self.header.paint(cx);
+
+        if self.visible_fraction > 0.0 {
+            self.content_buffer.reset();
+            let mut content_canvas = Canvas::new(&mut self.content_buffer);
+            // the part of the content that is visible in the parent canvas, in the coordinates of the content buffer
+            let content_origin = self.content.state.origin;
+            let content_rect =
+                cx.canvas.viewport.with_size(self.content.size()) + content_origin.to_vec2();
+            let visible = cx
+                .canvas
+                .clip
+                .intersect(cx.canvas.viewport)
+                .intersect(content_rect);
+            content_canvas.clip = visible - content_rect.origin().to_vec2();
+            let mut cx_content = PaintCx {
+                cx_state: cx.cx_state,
+                widget_state: cx.widget_state,
+                canvas: &mut content_canvas,
+                override_style: cx.override_style,
+            };
+            // The content is painted at the origin of the buffer, and then blitted below the header (clipped to the size of this widget)
+            self.content.state.origin = Point::ORIGIN;
+            self.content.paint(&mut cx_content);
+            self.content.state.origin = content_origin;
+            cx.canvas
+                .blit_with_offset(&content_canvas, content_origin.to_vec2());
+        }
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let header_bc = bc.loosen().shrink((CHEVRON_WIDTH, 0.0));
+        let header_size = self.header.layout(cx, &header_bc);
+        let header_height = header_size.height.max(1.0);
+        self.header.set_origin(cx, Point::new(CHEVRON_WIDTH, 0.0));
+
+        let content_bc = bc.loosen().shrink((0.0, header_height));
+        let content_size = self.content.layout(cx, &content_bc);
+        self.content.set_origin(cx, Point::new(0.0, header_height));
+        self.content_buffer.resize(ratatui::layout::Rect::new(
+            0,
+            0,
+            content_size.width.round() as u16,
+            content_size.height.round() as u16,
+        ));
+
+        let visible_height = (content_size.height * self.visible_fraction).round();
+        bc.constrain(Size::new(
+            (header_size.width + CHEVRON_WIDTH).max(content_size.width),
+            header_height + visible_height,
+        ))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.header.event(cx, event);
+        if self.expanded {
+            self.content.event(cx, event);
+        }
+        if cx.is_handled() {
+            return;
+        }
+        let header_height = self.content.state.origin.y;
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                row,
+                ..
+            }) if cx.is_hot() && (*row as f64) < header_height => {
+                cx.set_active(true);
+                cx.request_focus();
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                row,
+                ..
+            }) if cx.is_active() => {
+                if cx.is_hot() && (*row as f64) < header_height {
+                    self.toggle(cx);
+                }
+                cx.set_active(false);
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter | KeyCode::Char(' '),
+                kind: KeyEventKind::Press,
+                ..
+            }) if cx.is_focused() => {
+                self.toggle(cx);
+                cx.set_handled(true);
+            }
+            Event::FocusLost => cx.set_active(false),
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        match event {
+            LifeCycle::BuildFocusChain => {
+                cx.register_for_focus();
+                self.header.lifecycle(cx, event);
+                // collapsed content can't be focused
+                if self.expanded {
+                    self.content.lifecycle(cx, event);
+                }
+                return;
+            }
+            LifeCycle::Animate => {
+                let fraction = *self.visible.animate(cx);
+                if fraction != self.visible_fraction {
+                    self.visible_fraction = fraction;
+                    cx.request_layout();
+                    cx.request_paint();
+                }
+            }
+            _ => (),
+        }
+        self.header.lifecycle(cx, event);
+        self.content.lifecycle(cx, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use super::*;
+    use crate::{test_helper::TestCx, widget::Text};
+
+    fn section(content: impl Widget, expanded: bool) -> Collapsible {
+        let header = Text::new("Header".into(), Style::default());
+        Collapsible::new(
+            &IdPath::new(),
+            header,
+            content,
+            expanded,
+            CollapsibleTheme::default(),
+        )
+    }
+
+    fn focus_chain_len(root: &mut Pod) -> usize {
+        let mut cx = TestCx::new();
+        cx.lifecycle_cx(|cx| root.lifecycle(cx, &LifeCycle::BuildFocusChain));
+        cx.focus.focus_chain.len()
+    }
+
+    #[test]
+    fn collapsed_content_cant_be_focused() {
+        let details = || section(Text::new("Details".into(), Style::default()), false);
+        let mut collapsed = Pod::new(section(details(), false));
+        assert_eq!(focus_chain_len(&mut collapsed), 1);
+        let mut expanded = Pod::new(section(details(), true));
+        assert_eq!(focus_chain_len(&mut expanded), 2);
+    }
+}
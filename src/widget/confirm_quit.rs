@@ -0,0 +1,263 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+use unicode_width::UnicodeWidthStr;
+
+use super::{
+    core::PaintCx, BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle,
+    LifeCycleCx, Pod, RawMouseEvent, Widget,
+};
+use crate::{
+    geometry::{Axis, Point, Size, Vec2},
+    ConfirmQuitTheme,
+};
+
+const QUIT_LABEL: &str = " Quit ";
+const CANCEL_LABEL: &str = " Cancel ";
+/// The space between the buttons.
+const BUTTON_GAP: f64 = 2.0;
+/// The space taken by the border and the padding of the dialog on both axes (including the empty row above the buttons).
+const DIALOG_INSETS: Size = Size::new(4.0, 4.0);
+
+/// Intercepts quitting the app, while its content is `dirty`, and asks for confirmation in a dialog on top of the content first.
+///
+/// While the dialog is shown, the focus is trapped in it, and the content doesn't receive mouse events.
+pub struct ConfirmQuit {
+    pub(crate) content: Pod,
+    pub(crate) dialog: Pod,
+    dirty: bool,
+    confirming: bool,
+    /// Whether the quit button is selected, otherwise the cancel button.
+    quit_selected: bool,
+    styles: ConfirmQuitTheme,
+    /// The origin and size of the dialog (including its border) from the last layout.
+    dialog_origin: Point,
+    dialog_size: Size,
+}
+
+impl ConfirmQuit {
+    pub(crate) fn new(
+        content: impl Widget,
+        dialog: impl Widget,
+        dirty: bool,
+        styles: ConfirmQuitTheme,
+    ) -> Self {
+        ConfirmQuit {
+            content: Pod::new(content),
+            dialog: Pod::new(dialog),
+            dirty,
+            confirming: false,
+            quit_selected: false,
+            styles,
+            dialog_origin: Point::ZERO,
+            dialog_size: Size::ZERO,
+        }
+    }
+
+    pub(crate) fn set_dirty(&mut self, dirty: bool) -> ChangeFlags {
+        self.dirty = dirty;
+        // e.g. the changes were saved in the meantime
+        if !dirty && self.confirming {
+            self.confirming = false;
+            ChangeFlags::relayout()
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: ConfirmQuitTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn buttons_width() -> f64 {
+        (QUIT_LABEL.width() + CANCEL_LABEL.width()) as f64 + BUTTON_GAP
+    }
+
+    /// The positions of the quit and cancel buttons.
+    fn button_positions(&self) -> [Point; 2] {
+        let y = self.dialog_origin.y + self.dialog_size.height - 2.0;
+        let x =
+            self.dialog_origin.x + ((self.dialog_size.width - Self::buttons_width()) / 2.0).floor();
+        [
+            Point::new(x, y),
+            Point::new(x + QUIT_LABEL.width() as f64 + BUTTON_GAP, y),
+        ]
+    }
+
+    /// The button at `(column, row)`, `true` for the quit button.
+    fn button_at(&self, column: f64, row: f64) -> Option<bool> {
+        let [quit, cancel] = self.button_positions();
+        let hit = |origin: Point, label: &str| {
+            row == origin.y && column >= origin.x && column < origin.x + label.width() as f64
+        };
+        if hit(quit, QUIT_LABEL) {
+            Some(true)
+        } else if hit(cancel, CANCEL_LABEL) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    fn show(&mut self, cx: &mut EventCx) {
+        self.confirming = true;
+        self.quit_selected = false;
+        cx.request_focus();
+        cx.request_layout();
+        cx.request_paint();
+    }
+
+    fn close(&mut self, cx: &mut EventCx, quit: bool) {
+        self.confirming = false;
+        if quit {
+            cx.request_quit();
+        }
+        // the focus is restored, when the dialog is removed from the focus chain
+        cx.request_layout();
+        cx.request_paint();
+        cx.set_handled(true);
+    }
+
+    fn paint_dialog(&mut self, cx: &mut PaintCx) {
+        let style = self.styles.dialog;
+        let Point { x, y } = self.dialog_origin;
+        let (width, height) = (self.dialog_size.width, self.dialog_size.height);
+        if width < 2.0 || height < 2.0 {
+            return;
+        }
+        let symbols = self.styles.border.symbols();
+        let (right, bottom) = (x + width - 1.0, y + height - 1.0);
+        for row in 0..height as usize {
+            let row = y + row as f64;
+            cx.set_string((x, row), &" ".repeat(width as usize), style);
+            cx.set_cell((x, row), symbols.vertical, style);
+            cx.set_cell((right, row), symbols.vertical, style);
+        }
+        for column in 1..width as usize - 1 {
+            let column = x + column as f64;
+            cx.set_cell((column, y), symbols.horizontal, style);
+            cx.set_cell((column, bottom), symbols.horizontal, style);
+        }
+        cx.set_cell((x, y), symbols.top_left, style);
+        cx.set_cell((right, y), symbols.top_right, style);
+        cx.set_cell((x, bottom), symbols.bottom_left, style);
+        cx.set_cell((right, bottom), symbols.bottom_right, style);
+
+        let override_style = cx.override_style;
+        cx.patch_style(style);
+        self.dialog.paint(cx);
+        cx.override_style = override_style;
+
+        let [quit, cancel] = self.button_positions();
+        let button_style = |selected| {
+            if selected {
+                style.patch(self.styles.selected)
+            } else {
+                style.patch(self.styles.button)
+            }
+        };
+        cx.set_string(quit, QUIT_LABEL, button_style(self.quit_selected));
+        cx.set_string(cancel, CANCEL_LABEL, button_style(!self.quit_selected));
+    }
+}
+
+impl Widget for ConfirmQuit {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+        if self.confirming {
+            self.paint_dialog(cx);
+        }
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let size = self.content.layout(cx, bc);
+
+        let dialog_bc = BoxConstraints::new(Size::ZERO, size).shrink(DIALOG_INSETS);
+        let dialog_size = self.dialog.layout(cx, &dialog_bc);
+        self.dialog_size = Size::new(
+            (dialog_size.width.max(Self::buttons_width()) + DIALOG_INSETS.width).min(size.width),
+            (dialog_size.height + DIALOG_INSETS.height).min(size.height),
+        );
+        self.dialog_origin = Point::new(
+            ((size.width - self.dialog_size.width) / 2.0).floor(),
+            ((size.height - self.dialog_size.height) / 2.0).floor(),
+        );
+        let content_x = ((self.dialog_size.width - dialog_size.width) / 2.0).floor();
+        self.dialog
+            .set_origin(cx, self.dialog_origin + Vec2::new(content_x, 1.0));
+        size
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        if !self.confirming {
+            self.content.event(cx, event);
+            if matches!(event, Event::Quit) && self.dirty && !cx.is_handled() {
+                self.show(cx);
+                cx.set_handled(true);
+            }
+            return;
+        }
+        // pressing `Esc` again quits, as the quit event isn't handled anymore
+        if matches!(event, Event::Quit) {
+            return;
+        }
+        if matches!(event, Event::Resize { .. }) {
+            self.content.event(cx, event);
+        }
+        self.dialog.event(cx, event);
+        if cx.is_handled() {
+            return;
+        }
+        match event {
+            Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press,
+                ..
+            }) if cx.is_focused() => match code {
+                KeyCode::Char('y' | 'Y') => self.close(cx, true),
+                KeyCode::Char('n' | 'N') => self.close(cx, false),
+                KeyCode::Enter | KeyCode::Char(' ') => self.close(cx, self.quit_selected),
+                KeyCode::Left | KeyCode::Right | KeyCode::Tab | KeyCode::BackTab => {
+                    self.quit_selected = !self.quit_selected;
+                    cx.request_paint();
+                    cx.set_handled(true);
+                }
+                _ => (),
+            },
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) => {
+                if let Some(quit) = self.button_at(*column as f64, *row as f64) {
+                    self.close(cx, quit);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        self.content.lifecycle(cx, event);
+        if !matches!(event, LifeCycle::BuildFocusChain) {
+            self.dialog.lifecycle(cx, event);
+            return;
+        }
+        if self.confirming {
+            // the focus is trapped in the dialog, until it's closed
+            let start = cx.cx_state.focus.focus_chain.len();
+            cx.register_for_focus();
+            self.dialog.lifecycle(cx, event);
+            let range = start..cx.cx_state.focus.focus_chain.len();
+            cx.cx_state.focus.add_scope(cx.widget_state.id, range);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+};
+use xilem_core::Id;
+
+use crate::{geometry::CellRect, TerminalColors};
+
+/// Checks the painted cells for a low contrast between the foreground and background color,
+/// see [`App::with_contrast_check`](crate::App::with_contrast_check).
+///
+/// Each widget checks its cells after it was painted, so cells are reported for the innermost widget,
+/// which painted them. Every combination of widget and colors is only reported once.
+pub(crate) struct ContrastCheck {
+    min_ratio: f64,
+    /// Used for [`Color::Reset`], which is the default color of the terminal.
+    terminal_colors: TerminalColors,
+    /// The ids of the widgets from the root to the widget, which is currently painted.
+    id_path: Vec<Id>,
+    /// The cells (x, y) checked in the current frame.
+    checked: HashSet<(u16, u16)>,
+    reported: HashSet<(Id, Color, Color)>,
+}
+
+impl ContrastCheck {
+    pub(crate) fn new(min_ratio: f64, terminal_colors: TerminalColors) -> Self {
+        ContrastCheck {
+            min_ratio,
+            terminal_colors,
+            id_path: Vec::new(),
+            checked: HashSet::new(),
+            reported: HashSet::new(),
+        }
+    }
+
+    /// Starts a new frame, i.e. all cells are checked again.
+    pub(crate) fn begin_frame(&mut self) {
+        self.checked.clear();
+    }
+
+    pub(crate) fn enter(&mut self, id: Id) {
+        self.id_path.push(id);
+    }
+
+    /// Checks the cells within `area` (in buffer coordinates) after the widget was painted, which were not checked yet.
+    pub(crate) fn leave(&mut self, buffer: &Buffer, area: CellRect, widget_name: &str) {
+        let id = self.id_path.last().copied();
+        if let (Some(id), Some(area)) = (id, area.to_buffer_area()) {
+            let area = area.intersection(buffer.area);
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if !self.checked.insert((x, y)) {
+                        continue;
+                    }
+                    let cell = buffer.get(x, y);
+                    if cell.symbol().trim().is_empty() {
+                        continue;
+                    }
+                    let Some(ratio) = self.contrast_ratio(cell) else {
+                        continue;
+                    };
+                    if ratio < self.min_ratio && self.reported.insert((id, cell.fg, cell.bg)) {
+                        let id_path: Vec<String> = self
+                            .id_path
+                            .iter()
+                            .map(|id| id.to_raw().to_string())
+                            .collect();
+                        tracing::warn!(
+                            "low contrast ({ratio:.2}:1) of {:?} on {:?} at ({x}, {y}) painted by {widget_name} (id path {})",
+                            cell.fg,
+                            cell.bg,
+                            id_path.join("/"),
+                        );
+                    }
+                }
+            }
+        }
+        self.id_path.pop();
+    }
+
+    /// The contrast ratio (between 1 and 21) of the colors of `cell`, as defined by WCAG.
+    fn contrast_ratio(&self, cell: &Cell) -> Option<f64> {
+        let default_fg = self.terminal_colors.foreground.unwrap_or(Color::Gray);
+        let default_bg = self.terminal_colors.background.unwrap_or(Color::Black);
+        let fg = if cell.fg == Color::Reset {
+            default_fg
+        } else {
+            cell.fg
+        };
+        let bg = if cell.bg == Color::Reset {
+            default_bg
+        } else {
+            cell.bg
+        };
+        let (fg, bg) = if cell.modifier.contains(Modifier::REVERSED) {
+            (bg, fg)
+        } else {
+            (fg, bg)
+        };
+        let (l0, l1) = (relative_luminance(fg)?, relative_luminance(bg)?);
+        Some((l0.max(l1) + 0.05) / (l0.min(l1) + 0.05))
+    }
+}
+
+/// The relative luminance (between 0 and 1) as defined by WCAG, named colors are approximated with the xterm palette.
+fn relative_luminance(color: Color) -> Option<f64> {
+    let (r, g, b) = rgb(color)?;
+    let linear = |channel: u8| {
+        let c = channel as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+}
+
+/// The RGB value of `color`, named and indexed colors are approximated with the xterm palette, `Reset` has none.
+pub(crate) fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    const ANSI: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Indexed(index) => index,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+    };
+    Some(match index {
+        0..=15 => ANSI[index as usize],
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let index = index - 16;
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contrast_ratio_of_black_and_white_is_21() {
+        let check = ContrastCheck::new(4.5, TerminalColors::default());
+        let mut cell = Cell::default();
+        cell.set_symbol("x")
+            .set_fg(Color::White)
+            .set_bg(Color::Black);
+        assert!((check.contrast_ratio(&cell).unwrap() - 21.0).abs() < 0.01);
+        cell.set_fg(Color::Indexed(16));
+        assert!((check.contrast_ratio(&cell).unwrap() - 1.0).abs() < 0.01);
+    }
+}
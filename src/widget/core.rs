@@ -1,31 +1,45 @@
-use super::{BoxConstraints, Event, LifeCycle};
-use crate::geometry::{Point, Rect, Size};
+use super::{BoxConstraints, CellRegion, ContrastCheck, Event, LifeCycle, TimerToken};
+use crate::geometry::{Axis, CellPoint, CellRect, CellSize, Point, Rect, Size};
 use bitflags::bitflags;
 use crossterm::event::MouseEventKind;
-use std::{any::Any, ops::DerefMut, time::Duration};
+use ratatui::style::Style;
+use std::{
+    any::Any,
+    ops::{DerefMut, Range},
+    time::Duration,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use xilem_core::{message, Id};
 
 message!(Send);
 
 // TODO this should slowly be extended and possibly replace ratatui::buffer::Buffer at some time entirely for more control
+/// The cells a widget paints into, usually accessed via the methods of [`PaintCx`] (like [`PaintCx::set_string`]),
+/// which are relative to the origin of the widget.
 pub struct Canvas<'a> {
     /// This assumes an area of the contained buffer starting with x: 0, y: 0
     /// This *could* be extended to include the area as well, but I think abstraction and possibly moving away completely from ratatui::buffer::Buffer is more future-proof
     pub(crate) buffer: &'a mut ratatui::buffer::Buffer,
     /// x0 and y0 should never be negative currently!
     pub viewport: kurbo::Rect,
+    /// The area of the buffer that is actually visible, e.g. the viewport of a [`ScrollView`](super::ScrollView),
+    /// used for pinned painting (like sticky headers).
+    pub(crate) clip: kurbo::Rect,
 }
 
 impl<'a> Canvas<'a> {
     pub fn new(buffer: &'a mut ratatui::buffer::Buffer) -> Self {
         assert!((buffer.area.x, buffer.area.y) == (0, 0));
+        let area = kurbo::Rect::new(
+            0.0,
+            0.0,
+            buffer.area.width as f64,
+            buffer.area.height as f64,
+        );
         Self {
-            viewport: kurbo::Rect::new(
-                0.0,
-                0.0,
-                buffer.area.width as f64,
-                buffer.area.height as f64,
-            ),
+            viewport: area,
+            clip: area,
             buffer,
         }
     }
@@ -36,12 +50,11 @@ impl<'a> Canvas<'a> {
         self.buffer.resize(rect);
     }
 
+    /// The cell at `position` relative to the viewport.
+    ///
     /// This currently panics, when position is outside the containing buffer
     /// TODO return Option instead?
-    pub(crate) fn get_mut(
-        &mut self,
-        position: impl Into<kurbo::Point>,
-    ) -> &mut ratatui::buffer::Cell {
+    pub fn get_mut(&mut self, position: impl Into<kurbo::Point>) -> &mut ratatui::buffer::Cell {
         let mut position = position.into();
         position.x += self.viewport.x0;
         position.y += self.viewport.y0;
@@ -50,9 +63,11 @@ impl<'a> Canvas<'a> {
             .get_mut(position.x.round() as u16, position.y.round() as u16)
     }
 
+    /// The cell at `position` relative to the viewport.
+    ///
     /// This currently panics, when position is outside the containing buffer
     /// TODO return Option instead?
-    pub(crate) fn get(&self, position: impl Into<kurbo::Point>) -> &ratatui::buffer::Cell {
+    pub fn get(&self, position: impl Into<kurbo::Point>) -> &ratatui::buffer::Cell {
         let mut position = position.into();
         position.x += self.viewport.x0;
         position.y += self.viewport.y0;
@@ -80,18 +95,210 @@ impl<'a> Canvas<'a> {
 /// Static state that is shared between most contexts.
 pub struct CxState<'a> {
     messages: &'a mut Vec<Message>,
+    pub(crate) focus: &'a mut FocusState,
+    /// Whether a widget has captured the mouse, see [`EventCx::capture_mouse`].
+    pub(crate) mouse_captured: &'a mut bool,
     pub(crate) time_since_last_render_request: Duration, // in seconds TODO Duration instead of f64?
+    /// Whether transitions should jump to their end, see [`AnimationControl::set_reduce_motion`](crate::AnimationControl::set_reduce_motion).
+    pub(crate) reduce_motion: bool,
+    /// Checks the contrast of the painted cells, if enabled with [`App::with_contrast_check`](crate::App::with_contrast_check).
+    pub(crate) contrast_check: Option<&'a mut ContrastCheck>,
+    /// Whether a widget has requested to quit the app, see [`EventCx::request_quit`].
+    pub(crate) quit_requested: bool,
+    /// The timers requested by the widgets with their delay, they're scheduled by the app afterwards,
+    /// see [`EventCx::request_timer`].
+    pub(crate) timers: Vec<(Duration, TimerToken)>,
 }
 
 impl<'a> CxState<'a> {
-    pub fn new(messages: &'a mut Vec<Message>, time_since_last_render_request: Duration) -> Self {
+    pub fn new(
+        messages: &'a mut Vec<Message>,
+        focus: &'a mut FocusState,
+        mouse_captured: &'a mut bool,
+        time_since_last_render_request: Duration,
+        reduce_motion: bool,
+    ) -> Self {
         Self {
             messages,
+            focus,
+            mouse_captured,
             time_since_last_render_request,
+            reduce_motion,
+            contrast_check: None,
+            quit_requested: false,
+            timers: Vec::new(),
         }
     }
 }
 
+/// A request to change the keyboard focus, it's applied by the [`App`](crate::App) after the current events are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FocusRequest {
+    Focus(Id),
+    Resign(Id),
+    Next,
+    Previous,
+    Towards(FocusDirection),
+}
+
+/// A direction for spatial focus navigation, see [`EventCx::focus_towards`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FocusDirection {
+    /// The distance from `from` to `to` in this direction, weighted by how far `to` is off-axis,
+    /// or `None`, if `to` isn't (completely) in this direction.
+    fn distance(self, from: Rect, to: Rect) -> Option<f64> {
+        let (main, from_cross, to_cross) = match self {
+            FocusDirection::Up => (from.y0 - to.y1, from.x0..from.x1, to.x0..to.x1),
+            FocusDirection::Down => (to.y0 - from.y1, from.x0..from.x1, to.x0..to.x1),
+            FocusDirection::Left => (from.x0 - to.x1, from.y0..from.y1, to.y0..to.y1),
+            FocusDirection::Right => (to.x0 - from.x1, from.y0..from.y1, to.y0..to.y1),
+        };
+        if main < 0.0 {
+            return None;
+        }
+        // zero, if the ranges overlap on the cross axis
+        let cross = (to_cross.start - from_cross.end)
+            .max(from_cross.start - to_cross.end)
+            .max(0.0);
+        // being aligned is preferred over being close, e.g. the next field of a form over the button next to it
+        Some(main + 2.0 * cross)
+    }
+}
+
+/// Keeps track of the widget that currently receives keyboard events.
+#[derive(Debug, Default)]
+pub struct FocusState {
+    pub(crate) focused: Option<Id>,
+    /// All widgets that can be focused in tree order, collected with [`LifeCycle::BuildFocusChain`]
+    pub(crate) focus_chain: Vec<Id>,
+    /// The rects of the widgets in the focus chain in the window coordinate space, for spatial navigation.
+    pub(crate) focus_rects: Vec<Rect>,
+    pub(crate) request: Option<FocusRequest>,
+    /// The range of the focus chain, that `Tab` navigation is confined to, set by a [`FocusScope`](super::FocusScope).
+    pub(crate) trap: Option<Range<usize>>,
+    /// The focus scopes (by id) with the widget that was focused before they were mounted.
+    pub(crate) scopes: Vec<(Id, Option<Id>)>,
+    /// The focus scopes that were visited while building the focus chain.
+    pub(crate) visited_scopes: Vec<Id>,
+    /// The mnemonic letters of the widgets in the focus chain, see [`LifeCycleCx::register_mnemonic`].
+    pub(crate) mnemonics: Vec<(char, Id)>,
+}
+
+impl FocusState {
+    /// Clears the focus chain, before it's rebuilt with [`LifeCycle::BuildFocusChain`].
+    pub(crate) fn clear_focus_chain(&mut self) {
+        self.focus_chain.clear();
+        self.focus_rects.clear();
+        self.trap = None;
+        self.visited_scopes.clear();
+        self.mnemonics.clear();
+    }
+
+    /// The widget with the mnemonic `letter`, if it's within the scope, that currently traps the focus.
+    pub(crate) fn mnemonic_target(&self, letter: char) -> Option<Id> {
+        let letter = fold_mnemonic(letter);
+        let (_, id) = self.mnemonics.iter().find(|(c, _)| *c == letter)?;
+        match &self.trap {
+            Some(trap) if !self.focus_chain[trap.clone()].contains(id) => None,
+            _ => Some(*id),
+        }
+    }
+
+    /// Registers the focus scope `id` spanning `range` of the focus chain, while building the focus chain.
+    ///
+    /// The innermost (or if they aren't nested, the last) scope traps the focus.
+    pub(crate) fn add_scope(&mut self, id: Id, range: Range<usize>) {
+        self.visited_scopes.push(id);
+        if !self.scopes.iter().any(|(scope, _)| *scope == id) {
+            self.scopes.push((id, self.focused));
+        }
+        let is_nested_trap = self
+            .trap
+            .as_ref()
+            .is_some_and(|trap| range.start <= trap.start && trap.end <= range.end);
+        if !range.is_empty() && !is_nested_trap {
+            self.trap = Some(range);
+        }
+    }
+
+    /// Applies a pending focus request, returns whether the focused widget has changed.
+    pub(crate) fn apply_request(&mut self) -> bool {
+        let Some(request) = self.request.take() else {
+            return false;
+        };
+        let trap = self.trap.clone().unwrap_or(0..self.focus_chain.len());
+        let chain = &self.focus_chain[trap.clone()];
+        let rects = &self.focus_rects[trap];
+        let position = self
+            .focused
+            .and_then(|focused| chain.iter().position(|id| *id == focused));
+        let len = chain.len();
+        let focused = match request {
+            FocusRequest::Focus(id) => Some(id),
+            FocusRequest::Resign(id) if self.focused == Some(id) => None,
+            FocusRequest::Resign(_) => self.focused,
+            FocusRequest::Next if len > 0 => {
+                Some(chain[position.map(|p| (p + 1) % len).unwrap_or(0)])
+            }
+            FocusRequest::Previous if len > 0 => {
+                Some(chain[position.map(|p| (p + len - 1) % len).unwrap_or(len - 1)])
+            }
+            FocusRequest::Next | FocusRequest::Previous => None,
+            FocusRequest::Towards(direction) => match position {
+                // the closest widget in that direction, on ties the first one in the focus chain
+                Some(position) => rects
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, rect)| {
+                        Some((index, direction.distance(rects[position], *rect)?))
+                    })
+                    .filter(|(index, _)| *index != position)
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map_or(self.focused, |(index, _)| Some(chain[index])),
+                None => match direction {
+                    FocusDirection::Down | FocusDirection::Right => chain.first().copied(),
+                    FocusDirection::Up | FocusDirection::Left => chain.last().copied(),
+                },
+            },
+        };
+        let changed = focused != self.focused;
+        self.focused = focused;
+        changed
+    }
+
+    /// Removes the focus, if the focused widget isn't part of the focus chain anymore (e.g. because it was removed),
+    /// returns whether the focused widget has changed.
+    ///
+    /// When a focus scope was removed, the focus is restored to the widget that was focused before the scope was mounted.
+    pub(crate) fn validate(&mut self) -> bool {
+        let previous = self.focused;
+        if self
+            .focused
+            .is_some_and(|focused| !self.focus_chain.contains(&focused))
+        {
+            self.focused = None;
+        }
+        while let Some(index) = self
+            .scopes
+            .iter()
+            .rposition(|(scope, _)| !self.visited_scopes.contains(scope))
+        {
+            let (_, restore) = self.scopes.remove(index);
+            if self.focused.is_none() {
+                self.focused = restore.filter(|restore| self.focus_chain.contains(restore));
+            }
+        }
+        self.focused != previous
+    }
+}
+
 pub struct EventCx<'a, 'b> {
     pub(crate) cx_state: &'a mut CxState<'b>,
     pub(crate) widget_state: &'a mut WidgetState,
@@ -127,12 +334,97 @@ pub struct PaintCx<'a, 'b, 'c> {
 }
 
 impl PaintCx<'_, '_, '_> {
-    pub fn buffer_at_mut(
-        &mut self,
-        position: impl Into<kurbo::Point>,
-    ) -> &mut ratatui::buffer::Cell {
-        let position = self.widget_state.origin + position.into().to_vec2();
-        self.canvas.get_mut(position)
+    /// The visible cells within `rect` (relative to the origin of this widget) as they are painted so far,
+    /// e.g. to transform them after the children of this widget have painted, or `None`, if none of them are visible.
+    pub fn region(&mut self, rect: Rect) -> Option<CellRegion<'_>> {
+        CellRegion::visible_within(self.canvas, rect)
+    }
+
+    /// Swaps the foreground and background colors of the cells within `rect` (relative to the origin of this widget),
+    /// e.g. to show a selection over the already painted children, without them knowing about it.
+    pub fn invert_region(&mut self, rect: Rect) {
+        if let Some(mut region) = self.region(rect) {
+            region.invert();
+        }
+    }
+
+    /// Patches the style of the already painted cells within `rect` (relative to the origin of this widget),
+    /// e.g. to highlight search matches in the children.
+    pub fn restyle_region(&mut self, rect: Rect, style: Style) {
+        if let Some(mut region) = self.region(rect) {
+            region.patch_style(style);
+        }
+    }
+
+    /// Returns whether any part of this widget is within the visible area of the canvas,
+    /// e.g. the terminal, or the viewport of a [`ScrollView`](super::ScrollView).
+    pub fn is_visible(&self) -> bool {
+        let visible = self.canvas.clip.intersect(self.canvas.viewport);
+        visible.width() > 0.0 && visible.height() > 0.0
+    }
+
+    /// The cell at `position` relative to the origin of this widget.
+    ///
+    /// Returns `None`, when the cell is not visible, i.e. it's outside of the terminal,
+    /// or the viewport of a [`ScrollView`](super::ScrollView).
+    pub fn cell_mut(&mut self, position: impl Into<Point>) -> Option<&mut ratatui::buffer::Cell> {
+        let position = CellPoint::from_point(position.into())
+            + CellPoint::from_point(self.canvas.viewport.origin());
+        let visible =
+            CellRect::from_rect(self.canvas.clip).intersect(self.canvas.buffer.area.into());
+        if !visible.contains(position) {
+            return None;
+        }
+        Some(
+            self.canvas
+                .buffer
+                .get_mut(position.x as u16, position.y as u16),
+        )
+    }
+
+    /// Applies the style of the parent (e.g. the hover style of a [`Button`](super::Button)) to `style`.
+    pub fn style(&self, style: Style) -> Style {
+        style.patch(self.override_style)
+    }
+
+    /// Applies `style` to everything painted after this, e.g. by the children of this widget,
+    /// the style of the parent still takes precedence.
+    pub fn patch_style(&mut self, style: Style) {
+        self.override_style = style.patch(self.override_style);
+    }
+
+    /// Sets the symbol of the cell at `position` (relative to the origin of this widget),
+    /// with `style` patched by [`PaintCx::style`], if it's visible.
+    pub fn set_cell(&mut self, position: impl Into<Point>, symbol: &str, style: Style) {
+        let style = self.style(style);
+        if let Some(cell) = self.cell_mut(position) {
+            cell.set_symbol(symbol).set_style(style);
+        }
+    }
+
+    /// Writes `text` starting at `position` (relative to the origin of this widget) into a single row,
+    /// with `style` patched by [`PaintCx::style`].
+    ///
+    /// The text is cut off at the width of the widget, and the cells covered by wide graphemes are reset.
+    /// Returns the x position after the written text.
+    pub fn set_string(&mut self, position: impl Into<Point>, text: &str, style: Style) -> f64 {
+        let Point { mut x, y } = CellPoint::from_point(position.into()).to_point();
+        let width = self.cell_size().width as f64;
+        for g in text.graphemes(true) {
+            let w = g.width() as f64;
+            if x + w > width {
+                break;
+            }
+            self.set_cell((x, y), g, style);
+            // clear all following cells, when the width of `g` is > 1
+            for x in (x as usize + 1)..(x + w) as usize {
+                if let Some(cell) = self.cell_mut((x as f64, y)) {
+                    cell.reset();
+                }
+            }
+            x += w;
+        }
+        x
     }
 }
 
@@ -174,6 +466,11 @@ impl_context_method!(
             self.widget_state.size
         }
 
+        /// Size of this widget in (painted) terminal cells.
+        pub fn cell_size(&self) -> CellSize {
+            CellSize::from_size(self.widget_state.size)
+        }
+
         /// TODO possibly different name, since we're in a terminal context not in a window
         /// origin relative to the top left position of the terminal
         pub fn window_origin(&self) -> Point {
@@ -220,6 +517,11 @@ impl_context_method!(
             self.cx_state.time_since_last_render_request
         }
 
+        /// Whether transitions should jump directly to their end, instead of being animated.
+        pub fn reduce_motion(&self) -> bool {
+            self.cx_state.reduce_motion
+        }
+
         /// Notify Trui that this widgets view context changed.
         ///
         /// A [`LifeCycle::ViewContextChanged`] event will be scheduled.
@@ -230,26 +532,132 @@ impl_context_method!(
         pub fn view_context_changed(&mut self) {
             self.widget_state.flags |= PodFlags::VIEW_CONTEXT_CHANGED;
         }
+
+        /// Returns whether this widget currently receives keyboard events.
+        pub fn is_focused(&self) -> bool {
+            self.widget_state.flags.contains(PodFlags::IS_FOCUSED)
+        }
+
+        /// Returns `true` if this widget or any descendant is focused.
+        pub fn has_focus(&self) -> bool {
+            self.widget_state.flags.contains(PodFlags::HAS_FOCUS)
+        }
     }
 );
 
-// TODO add the other contexts
-// Methods on EventCx, UpdateCx, and LifeCycleCx
-impl_context_method!(EventCx<'_, '_>, {
-    /// Sends a message to the view tree.
+impl LifeCycleCx<'_, '_> {
+    /// Register this widget to be part of the focus chain (e.g. used for navigating with `Tab`).
     ///
-    /// Sending messages is the main way of interacting with views.
-    /// Generally a Widget will send messages to its View after an interaction with the user. The
-    /// view will schedule a rebuild if necessary and update the widget accordingly.
-    /// Since widget can send messages to all views control widgets store the IdPath of their view
-    /// to target them.
-    //TODO: Decide whether it should be possible to send messages from Layout?
-    pub fn add_message(&mut self, message: Message) {
-        self.cx_state.messages.push(message);
+    /// This should be called when [`LifeCycle::BuildFocusChain`] is received.
+    pub fn register_for_focus(&mut self) {
+        let focus = &mut self.cx_state.focus;
+        focus.focus_chain.push(self.widget_state.id);
+        focus.focus_rects.push(Rect::from_origin_size(
+            self.widget_state.window_origin(),
+            self.widget_state.size,
+        ));
+    }
+
+    /// Registers the first of the `candidates`, that isn't used by another widget yet, as the mnemonic of this widget,
+    /// i.e. pressing `Alt` and the letter sends [`Event::Mnemonic`] to the widgets, returns the registered letter.
+    ///
+    /// Letters are case-insensitive. This should be called when [`LifeCycle::BuildFocusChain`] is received,
+    /// widgets earlier in the focus chain take precedence.
+    pub fn register_mnemonic(
+        &mut self,
+        candidates: impl IntoIterator<Item = char>,
+    ) -> Option<char> {
+        let focus = &mut self.cx_state.focus;
+        let letter = candidates
+            .into_iter()
+            .map(fold_mnemonic)
+            .find(|c| !focus.mnemonics.iter().any(|(taken, _)| taken == c))?;
+        focus.mnemonics.push((letter, self.widget_state.id));
+        Some(letter)
+    }
+}
+
+// Methods on EventCx and LifeCycleCx
+impl_context_method!(EventCx<'_, '_>, LifeCycleCx<'_, '_>, {
+    /// Requests an [`Event::Timer`] with the returned token after `delay`,
+    /// e.g. to show a tooltip, to time out a key chord, or to detect a double click.
+    ///
+    /// The event is sent to all widgets, so the widget has to keep the token to recognize its timer.
+    /// Timers can't be cancelled, a widget can just forget the token (e.g. when it requests a new timer) and ignore the event.
+    pub fn request_timer(&mut self, delay: Duration) -> TimerToken {
+        let token = TimerToken::next();
+        self.cx_state.timers.push((delay, token));
+        token
     }
 });
 
+/// Mnemonics are compared case-insensitively.
+pub(crate) fn fold_mnemonic(letter: char) -> char {
+    letter.to_lowercase().next().unwrap_or(letter)
+}
+
+// Methods on all contexts
+impl_context_method!(
+    EventCx<'_, '_>,
+    PaintCx<'_, '_, '_>,
+    LifeCycleCx<'_, '_>,
+    LayoutCx<'_, '_>,
+    {
+        /// Sends a message to the view tree.
+        ///
+        /// Sending messages is the main way of interacting with views.
+        /// Generally a Widget will send messages to its View after an interaction with the user. The
+        /// view will schedule a rebuild if necessary and update the widget accordingly.
+        /// Since widget can send messages to all views control widgets store the IdPath of their view
+        /// to target them.
+        ///
+        /// Messages sent during layout are meant for reacting to the new size,
+        /// e.g. loading more content, when it doesn't fill the viewport.
+        pub fn add_message(&mut self, message: Message) {
+            self.cx_state.messages.push(message);
+        }
+    }
+);
+
 impl<'a, 'b> EventCx<'a, 'b> {
+    /// Request keyboard focus for this widget.
+    ///
+    /// The widget has to be registered for focus via [`LifeCycleCx::register_for_focus`].
+    /// The focus is changed after the current events are handled.
+    pub fn request_focus(&mut self) {
+        self.cx_state.focus.request = Some(FocusRequest::Focus(self.widget_state.id));
+    }
+
+    /// Give up the keyboard focus, if this widget is focused.
+    pub fn resign_focus(&mut self) {
+        self.cx_state.focus.request = Some(FocusRequest::Resign(self.widget_state.id));
+    }
+
+    /// Move the keyboard focus to the next widget in the focus chain.
+    pub fn focus_next(&mut self) {
+        self.cx_state.focus.request = Some(FocusRequest::Next);
+    }
+
+    /// Move the keyboard focus to the previous widget in the focus chain.
+    pub fn focus_previous(&mut self) {
+        self.cx_state.focus.request = Some(FocusRequest::Previous);
+    }
+
+    /// Move the keyboard focus to the closest focusable widget in `direction` (based on their position on screen),
+    /// or to the first (or last) widget, if nothing is focused yet.
+    ///
+    /// The app does this with the arrow keys, if the focused widget doesn't use them itself.
+    pub fn focus_towards(&mut self, direction: FocusDirection) {
+        self.cx_state.focus.request = Some(FocusRequest::Towards(direction));
+    }
+
+    /// Requests the app to quit after the current events are handled, as if `Esc` was pressed.
+    ///
+    /// The quit handler set with [`App::on_quit_request`](crate::App::on_quit_request) is still asked.
+    pub fn request_quit(&mut self) {
+        self.cx_state.quit_requested = true;
+    }
+
     /// Set the [`active`] state of the widget.
     ///
     /// [`active`]: Pod::is_active.
@@ -257,6 +665,34 @@ impl<'a, 'b> EventCx<'a, 'b> {
         self.widget_state.flags.set(PodFlags::IS_ACTIVE, is_active);
     }
 
+    /// Routes all following mouse events to this widget (and only this widget), until [`EventCx::release_mouse`] is called,
+    /// even when the mouse is outside of this widget, e.g. while dragging.
+    ///
+    /// Only one widget can capture the mouse at a time.
+    pub fn capture_mouse(&mut self) {
+        self.widget_state
+            .flags
+            .insert(PodFlags::IS_CAPTURING_MOUSE | PodFlags::HAS_MOUSE_CAPTURE);
+        *self.cx_state.mouse_captured = true;
+    }
+
+    /// Releases the mouse capture of this widget, see [`EventCx::capture_mouse`].
+    pub fn release_mouse(&mut self) {
+        if self.has_mouse_capture() {
+            self.widget_state
+                .flags
+                .remove(PodFlags::IS_CAPTURING_MOUSE | PodFlags::HAS_MOUSE_CAPTURE);
+            *self.cx_state.mouse_captured = false;
+        }
+    }
+
+    /// Returns whether this widget has captured the mouse, see [`EventCx::capture_mouse`].
+    pub fn has_mouse_capture(&self) -> bool {
+        self.widget_state
+            .flags
+            .contains(PodFlags::IS_CAPTURING_MOUSE)
+    }
+
     /// Set the event as "handled", which stops its propagation to other
     /// widgets.
     pub fn set_handled(&mut self, is_handled: bool) {
@@ -270,13 +706,25 @@ impl<'a, 'b> EventCx<'a, 'b> {
 }
 
 bitflags! {
+    /// What has changed in a widget, returned by its setters (and by [`View::rebuild`](crate::view::View::rebuild)),
+    /// and marked on its [`Pod`] (see [`Pod::mark`]), so that only the necessary work is done for the next frame.
+    ///
+    /// A setter should return the least flags, which are needed for its change,
+    /// e.g. a new color only needs a paint ([`ChangeFlags::paint_only`]),
+    /// while a new text needs a layout as well, if its size has changed ([`ChangeFlags::relayout`]).
     #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
     #[must_use]
     pub struct ChangeFlags: u8 {
+        /// Currently unused, the widgets are updated directly by the views while rebuilding.
         const UPDATE = 1;
+        /// The size of the widget (or the position of its children) may have changed, so the layout is recomputed.
+        /// This also rebuilds the focus chain. It's usually combined with [`ChangeFlags::PAINT`].
         const LAYOUT = 2;
+        /// The appearance of the widget has changed, so it's painted again.
         const PAINT = 8;
+        /// Children were added, removed or replaced.
         const TREE = 0x10;
+        /// The widget is animating, and receives [`LifeCycle::Animate`] in the next frame.
         const ANIMATION = 0x20;
     }
 }
@@ -301,11 +749,23 @@ bitflags! {
 
         const NEEDS_SET_ORIGIN = 0x1000;
 
+        const IS_FOCUSED = 0x2000;
+        const HAS_FOCUS = 0x4000;
+
+        const IS_CAPTURING_MOUSE = 0x8000;
+        const HAS_MOUSE_CAPTURE = 0x10000;
+
+        /// Set when the widget wasn't visible in the last paint pass (it has no size, or it was outside the clip),
+        /// it then doesn't receive mouse events, and if it was outside the clip, no animation updates.
+        const IS_CULLED = 0x20000;
+
         const UPWARD_FLAGS = Self::REQUEST_UPDATE.bits()
             | Self::REQUEST_LAYOUT.bits()
             | Self::REQUEST_PAINT.bits()
             | Self::REQUEST_ANIMATION.bits()
             | Self::HAS_ACTIVE.bits()
+            | Self::HAS_FOCUS.bits()
+            | Self::HAS_MOUSE_CAPTURE.bits()
             | Self::TREE_CHANGED.bits()
             | Self::VIEW_CONTEXT_CHANGED.bits();
         const INIT_FLAGS = Self::REQUEST_UPDATE.bits()
@@ -330,6 +790,16 @@ impl ChangeFlags {
         ChangeFlags::TREE
     }
 
+    /// Only the appearance of the widget has changed (e.g. a color), its size stays the same.
+    pub fn paint_only() -> Self {
+        ChangeFlags::PAINT
+    }
+
+    /// The size of the widget may have changed (e.g. a different text), which needs a new layout and a paint.
+    pub fn relayout() -> Self {
+        ChangeFlags::LAYOUT | ChangeFlags::PAINT
+    }
+
     pub(crate) fn upwards(self) -> Self {
         // Note: this assumes PodFlags are a superset of ChangeFlags. This might
         // not always be the case, for example on "structure changed."
@@ -341,8 +811,7 @@ pub type IdPath = Vec<Id>;
 
 #[derive(Debug)]
 pub(crate) struct WidgetState {
-    // TODO could be useful in the future, but not needed currently...
-    // pub(crate) id: Id,
+    pub(crate) id: Id,
     pub(crate) flags: PodFlags,
     pub(crate) size: Size,
     /// The origin of the child in the parent's coordinate space.
@@ -353,9 +822,8 @@ pub(crate) struct WidgetState {
 
 impl WidgetState {
     pub(crate) fn new() -> Self {
-        // let id = Id::next();
         WidgetState {
-            // id,
+            id: Id::next(),
             flags: PodFlags::INIT_FLAGS,
             size: Default::default(),
             origin: Default::default(),
@@ -374,6 +842,14 @@ impl WidgetState {
     pub fn window_origin(&self) -> Point {
         self.parent_window_origin + self.origin.to_vec2()
     }
+
+    /// The cells covered by the widget in the parent's coordinate space, as it is painted.
+    pub(crate) fn cell_rect(&self) -> CellRect {
+        CellRect::from_origin_size(
+            CellPoint::from_point(self.origin),
+            CellSize::from_size(self.size),
+        )
+    }
 }
 
 pub struct Pod {
@@ -395,12 +871,27 @@ impl Pod {
     /// In a widget hierarchy, each widget is wrapped in a `Pod`
     /// so it can participate in layout and event flow.
     pub fn new_from_box(widget: Box<dyn AnyWidget>) -> Self {
+        #[cfg(feature = "bench")]
+        crate::bench::increment(&crate::bench::WIDGETS_BUILT);
         Pod {
             state: WidgetState::new(),
             widget,
         }
     }
 
+    /// Takes the widget (with its state) out of this pod, e.g. to move it to another position in the parent,
+    /// and leaves an invisible placeholder without a size in its place, see [`Pod::is_placeholder`].
+    pub fn take(&mut self) -> Pod {
+        std::mem::replace(self, Pod::new(Placeholder))
+    }
+
+    /// Returns whether the widget was taken out of this pod with [`Pod::take`].
+    ///
+    /// Containers should skip placeholders when arranging their children, e.g. to not add spacing around them.
+    pub fn is_placeholder(&self) -> bool {
+        self.downcast_ref::<Placeholder>().is_some()
+    }
+
     /// Returns the wrapped widget.
     pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
         (*self.widget).as_any().downcast_ref()
@@ -413,6 +904,10 @@ impl Pod {
 
     /// Sets the requested flags on this pod and returns the ChangeFlags the owner of this Pod should set.
     pub fn mark(&mut self, flags: ChangeFlags) -> ChangeFlags {
+        #[cfg(feature = "bench")]
+        if !flags.is_empty() {
+            crate::bench::increment(&crate::bench::WIDGETS_CHANGED);
+        }
         self.state
             .request(PodFlags::from_bits_truncate(flags.bits() as _));
         flags.upwards()
@@ -422,6 +917,31 @@ impl Pod {
         self.state.size
     }
 
+    /// The origin of the widget in the parent's coordinate space, see [`Pod::set_origin`].
+    pub fn origin(&self) -> Point {
+        self.state.origin
+    }
+
+    /// The id of the widget, e.g. to compare it with the focused widget.
+    pub fn id(&self) -> Id {
+        self.state.id
+    }
+
+    /// Returns whether the mouse is over the widget.
+    pub fn is_hot(&self) -> bool {
+        self.state.flags.contains(PodFlags::IS_HOT)
+    }
+
+    /// Returns whether the widget is active, e.g. a button while it is pressed, see [`EventCx::set_active`].
+    pub fn is_active(&self) -> bool {
+        self.state.flags.contains(PodFlags::IS_ACTIVE)
+    }
+
+    /// Returns whether [`Pod::layout`] has to be called, because the widget or one of its descendants requested it.
+    pub fn layout_requested(&self) -> bool {
+        self.state.flags.contains(PodFlags::REQUEST_LAYOUT)
+    }
+
     /// Set the origin of this widget, in the parent's coordinate space.
     ///
     /// A container widget should call the [`Widget::layout`] method on its children in
@@ -456,6 +976,8 @@ impl Pod {
             cx_state: cx.cx_state,
             widget_state: &mut self.state,
         };
+        #[cfg(feature = "bench")]
+        crate::bench::increment(&crate::bench::LAYOUTS);
         let new_size = self.widget.layout(&mut child_cx, bc);
         if new_size != self.state.size {
             self.state.flags.insert(PodFlags::VIEW_CONTEXT_CHANGED);
@@ -470,9 +992,22 @@ impl Pod {
         self.state.size
     }
 
+    /// Measure the intrinsic size of the wrapped widget, see [`Widget::measure`].
+    pub fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let mut child_cx = LayoutCx {
+            cx_state: cx.cx_state,
+            widget_state: &mut self.state,
+        };
+        self.widget.measure(&mut child_cx, axis, cross)
+    }
+
     pub fn paint(&mut self, cx: &mut PaintCx) {
         let parent_viewport = cx.canvas.viewport;
-        let origin = cx.canvas.viewport.origin() + self.state.origin.to_vec2();
+        // the origin is snapped to the cells, so that the painted cells are the ones, which are hit by the mouse
+        let origin = cx.canvas.viewport.origin()
+            + CellPoint::from_point(self.state.origin)
+                .to_point()
+                .to_vec2();
         cx.canvas.viewport = kurbo::Rect::from_origin_size(origin, self.size());
         let inner_cx = &mut PaintCx {
             cx_state: cx.cx_state,
@@ -480,10 +1015,34 @@ impl Pod {
             canvas: cx.canvas,
             override_style: cx.override_style,
         };
+        let is_culled = !inner_cx.is_visible();
+        #[cfg(feature = "bench")]
+        crate::bench::increment(&crate::bench::PAINTS);
+        if let Some(check) = inner_cx.cx_state.contrast_check.as_deref_mut() {
+            check.enter(inner_cx.widget_state.id);
+        }
+        // The widget is still painted, so that it (and its descendants) can track their visibility
         self.widget.paint(inner_cx);
+        if let Some(check) = cx.cx_state.contrast_check.as_deref_mut() {
+            let area = CellRect::from_rect(cx.canvas.viewport.intersect(cx.canvas.clip));
+            check.leave(cx.canvas.buffer, area, self.widget.type_name());
+        }
         cx.canvas.viewport = parent_viewport;
 
+        self.state.flags.set(PodFlags::IS_CULLED, is_culled);
         self.state.flags.remove(PodFlags::REQUEST_PAINT);
+        // Animations of culled widgets are paused, resume them when they become visible again
+        if !self.is_animation_paused() && self.state.flags.contains(PodFlags::REQUEST_ANIMATION) {
+            cx.widget_state.flags.insert(PodFlags::REQUEST_ANIMATION);
+        }
+    }
+
+    /// Whether animations are paused, because the widget is outside the visible area.
+    ///
+    /// Widgets without size are excluded, as they may be animating their size.
+    fn is_animation_paused(&self) -> bool {
+        let size = self.state.size;
+        self.state.flags.contains(PodFlags::IS_CULLED) && size.width > 0.0 && size.height > 0.0
     }
 
     // Return true if hot state has changed
@@ -491,9 +1050,9 @@ impl Pod {
         widget: &mut dyn AnyWidget,
         widget_state: &mut WidgetState,
         cx_state: &mut CxState,
-        mouse_pos: Option<Point>,
+        mouse_pos: Option<CellPoint>,
     ) -> bool {
-        let rect = Rect::from_origin_size(widget_state.origin, widget_state.size);
+        let rect = widget_state.cell_rect();
         let had_hot = widget_state.flags.contains(PodFlags::IS_HOT);
 
         let is_hot = match mouse_pos {
@@ -526,30 +1085,29 @@ impl Pod {
         let had_active = self.state.flags.contains(PodFlags::HAS_ACTIVE);
         let recurse = match event {
             Event::Mouse(mouse_event) => {
-                let hot_changed = Pod::set_hot_state(
-                    &mut self.widget,
-                    &mut self.state,
-                    cx.cx_state,
-                    Some(Point {
-                        x: mouse_event.column as f64,
-                        y: mouse_event.row as f64,
-                    }),
-                );
-                if had_active
-                    || self.state.flags.contains(PodFlags::IS_HOT)
-                    || (hot_changed
-                        && matches!(
-                            mouse_event.kind,
-                            MouseEventKind::Moved | MouseEventKind::Drag(_)
-                        ))
-                {
+                let is_culled = self.state.flags.contains(PodFlags::IS_CULLED);
+                // invisible widgets can't be hovered or clicked
+                let mouse_pos = (!is_culled).then_some(mouse_event.position());
+                let hot_changed =
+                    Pod::set_hot_state(&mut self.widget, &mut self.state, cx.cx_state, mouse_pos);
+                let recurse = if *cx.cx_state.mouse_captured {
+                    // only the path to the capturing widget receives mouse events
+                    self.state.flags.contains(PodFlags::HAS_MOUSE_CAPTURE)
+                } else {
+                    had_active
+                        || self.state.flags.contains(PodFlags::IS_HOT)
+                        || (hot_changed
+                            && !is_culled
+                            && matches!(
+                                mouse_event.kind,
+                                MouseEventKind::Moved | MouseEventKind::Drag(_)
+                            ))
+                };
+                if recurse {
                     let mut mouse_event = *mouse_event;
-                    let (x, y) = (
-                        self.state.origin.x.round() as i16,
-                        self.state.origin.y.round() as i16,
-                    );
-                    mouse_event.column = mouse_event.column.saturating_sub(x);
-                    mouse_event.row = mouse_event.row.saturating_sub(y);
+                    let origin = CellPoint::from_point(self.state.origin);
+                    mouse_event.column = mouse_event.column.saturating_sub(origin.x as i16);
+                    mouse_event.row = mouse_event.row.saturating_sub(origin.y as i16);
                     modified_event = Some(Event::Mouse(mouse_event));
                     true
                 } else {
@@ -563,14 +1121,20 @@ impl Pod {
                     .request(PodFlags::REQUEST_PAINT | PodFlags::REQUEST_LAYOUT);
                 true
             }
+            Event::Key(_) => self.state.flags.contains(PodFlags::HAS_FOCUS),
+            // every widget may intercept quitting the app by handling this event, e.g. to ask for confirmation
+            Event::Quit => true,
+            // the widget with the mnemonic (or the one that requested the timer) may be anywhere in the tree
+            Event::Mnemonic(_) | Event::Timer(_) => true,
             Event::FocusLost => {
                 // right now a FocusLost event will disable any ongoing pointer events,
                 // since we can't really track if the state has changed in the meantime.
                 // There may be workarounds/hacks to remember the previous mouse state (mostly),
                 // but I think it's safer for now to just tell every widget, that there isn't a mouse anymore in focus...
-                self.state
-                    .flags
-                    .set(PodFlags::IS_HOT | PodFlags::IS_ACTIVE, false);
+                self.state.flags.set(
+                    PodFlags::IS_HOT | PodFlags::IS_ACTIVE | PodFlags::IS_CAPTURING_MOUSE,
+                    false,
+                );
                 true
             }
             _ => return,
@@ -584,6 +1148,10 @@ impl Pod {
                 PodFlags::HAS_ACTIVE,
                 self.state.flags.contains(PodFlags::IS_ACTIVE),
             );
+            self.state.flags.set(
+                PodFlags::HAS_MOUSE_CAPTURE,
+                self.state.flags.contains(PodFlags::IS_CAPTURING_MOUSE),
+            );
             let mut inner_cx = EventCx {
                 cx_state: cx.cx_state,
                 widget_state: &mut self.state,
@@ -631,8 +1199,22 @@ impl Pod {
                     false
                 }
             }
+            LifeCycle::BuildFocusChain => true,
+            LifeCycle::FocusChanged => {
+                let is_focused = cx.cx_state.focus.focused == Some(self.state.id);
+                if is_focused != self.state.flags.contains(PodFlags::IS_FOCUSED) {
+                    self.state.flags.insert(PodFlags::REQUEST_PAINT);
+                }
+                self.state.flags.set(PodFlags::IS_FOCUSED, is_focused);
+                // Like `HAS_ACTIVE`, this is set again by the children via `merge_up`
+                self.state.flags.set(PodFlags::HAS_FOCUS, is_focused);
+                true
+            }
             LifeCycle::Animate => {
-                if self.state.flags.contains(PodFlags::REQUEST_ANIMATION) {
+                // Animations of culled widgets are resumed when they are painted again, see `Pod::paint`
+                if self.state.flags.contains(PodFlags::REQUEST_ANIMATION)
+                    && !self.is_animation_paused()
+                {
                     self.state.flags.remove(PodFlags::REQUEST_ANIMATION);
                     true
                 } else {
@@ -653,7 +1235,15 @@ impl Pod {
     }
 }
 
+/// A widget of the widget tree, which is created and updated by a [`View`](crate::View).
+///
+/// Widgets can be implemented outside of trui with the items in [`prelude`](super::prelude),
+/// their children are wrapped in a [`Pod`].
+/// The signature of this trait is part of the public API, and only changes with a breaking release,
+/// new methods are added with a default implementation (like [`Widget::measure`]).
 pub trait Widget: 'static {
+    /// Paint the widget into the canvas of `cx`, e.g. with [`PaintCx::set_string`],
+    /// children are painted by calling [`Pod::paint`].
     fn paint(&mut self, cx: &mut PaintCx);
 
     /// Compute layout.
@@ -690,6 +1280,76 @@ pub trait Widget: 'static {
     fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle);
 
     fn event(&mut self, cx: &mut EventCx, event: &Event);
+
+    /// Measure the intrinsic size of the widget along `axis`.
+    ///
+    /// `cross` is the space available on the other axis (it may be infinite), e.g. the width
+    /// that text should be wrapped to, when its height is measured.
+    ///
+    /// Containers can use this to size non-flexible children, before distributing the remaining space
+    /// to the flexible ones. This should not change the layout of the widget, i.e. [`Widget::layout`]
+    /// will still be called afterwards.
+    ///
+    /// Returns `None` (the default), if the widget has no meaningful intrinsic size,
+    /// e.g. because it just fills the space it is given.
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let _ = (cx, axis, cross);
+        None
+    }
+}
+
+/// Left in a [`Pod`] in place of the widget that was taken out of it, see [`Pod::take`].
+struct Placeholder;
+
+impl Widget for Placeholder {
+    fn paint(&mut self, _cx: &mut PaintCx) {}
+
+    fn layout(&mut self, _cx: &mut LayoutCx, _bc: &BoxConstraints) -> Size {
+        Size::ZERO
+    }
+
+    fn lifecycle(&mut self, _cx: &mut LifeCycleCx, _event: &LifeCycle) {}
+
+    fn event(&mut self, _cx: &mut EventCx, _event: &Event) {}
+
+    fn measure(&mut self, _cx: &mut LayoutCx, _axis: Axis, _cross: f64) -> Option<IntrinsicSize> {
+        Some(IntrinsicSize::default())
+    }
+}
+
+/// The intrinsic size of a widget along an axis, see [`Widget::measure`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IntrinsicSize {
+    /// The smallest size the widget can be laid out in without clipping its content,
+    /// e.g. the longest word of wrapped text.
+    pub min: f64,
+    /// The size the widget would take, if there's no limit,
+    /// e.g. the longest line of text.
+    pub max: f64,
+}
+
+impl IntrinsicSize {
+    pub fn new(min: f64, max: f64) -> Self {
+        IntrinsicSize { min, max }
+    }
+
+    /// An intrinsic size where min and max are the same.
+    pub fn fixed(size: f64) -> Self {
+        IntrinsicSize::new(size, size)
+    }
+
+    /// Adds `amount` to both min and max, e.g. for padding.
+    pub fn expand(self, amount: f64) -> Self {
+        IntrinsicSize::new(self.min + amount, self.max + amount)
+    }
+
+    /// Clamps the size to the constraints `range`.
+    pub fn clamp(self, range: std::ops::Range<f64>) -> Self {
+        IntrinsicSize::new(
+            self.min.max(range.start).min(range.end),
+            self.max.max(range.start).min(range.end),
+        )
+    }
 }
 
 pub trait AnyWidget: Widget {
@@ -730,4 +1390,336 @@ impl Widget for Box<dyn AnyWidget> {
     fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
         self.deref_mut().lifecycle(cx, event)
     }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.deref_mut().measure(cx, axis, cross)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent};
+    use ratatui::style::{Modifier, Style};
+
+    use super::*;
+    use crate::{
+        test_helper::TestCx,
+        view::Borders,
+        widget::{Border, Margin, RawMouseEvent, ScrollView, ViewContext},
+        BorderKind, Length, Position, ScrollAxes,
+    };
+
+    /// Records the last mouse event and the window origin, when it was received.
+    #[derive(Clone, Default)]
+    struct Probe(Arc<Mutex<Option<(RawMouseEvent, Point)>>>);
+
+    impl Widget for Probe {
+        fn paint(&mut self, _cx: &mut PaintCx) {}
+
+        fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+            bc.constrain((20.0, 10.0))
+        }
+
+        fn lifecycle(&mut self, _cx: &mut LifeCycleCx, _event: &LifeCycle) {}
+
+        fn event(&mut self, cx: &mut EventCx, event: &Event) {
+            if let Event::Mouse(
+                mouse @ RawMouseEvent {
+                    kind: MouseEventKind::Down(_),
+                    ..
+                },
+            ) = event
+            {
+                *self.0.lock().unwrap() = Some((*mouse, cx.window_origin()));
+            }
+        }
+    }
+
+    /// Lays out `root` in a 20x10 terminal and sends the mouse `events` to it.
+    fn send_mouse_events(root: impl Widget, events: &[(MouseEventKind, u16, u16)]) {
+        let mut root = Pod::new(root);
+        let mut cx = TestCx::new();
+        cx.layout(&mut root, Size::new(20.0, 10.0));
+        let view = ViewContext {
+            window_origin: Point::ORIGIN,
+            mouse_position: None,
+        };
+        cx.lifecycle_cx(|cx| root.lifecycle(cx, &LifeCycle::ViewContextChanged(view)));
+        for &(kind, column, row) in events {
+            let event = MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            };
+            cx.event_cx(|cx| root.event(cx, &Event::Mouse(event.into())));
+            let view = ViewContext {
+                window_origin: Point::ORIGIN,
+                mouse_position: Some(CellPoint::new(column as i32, row as i32)),
+            };
+            cx.lifecycle_cx(|cx| root.lifecycle(cx, &LifeCycle::ViewContextChanged(view)));
+        }
+    }
+
+    const CLICK: MouseEventKind = MouseEventKind::Down(MouseButton::Left);
+
+    #[test]
+    fn mouse_events_are_local_to_nested_widgets() {
+        let probe = Probe::default();
+        let inner = Margin::new(probe.clone(), Position::LEFT, Length::Cells(3));
+        let border = Border::new(inner, Borders::ALL, Style::default(), BorderKind::Straight);
+        send_mouse_events(
+            Margin::new(border, Position::TOP, Length::Cells(2)),
+            &[(CLICK, 6, 4)],
+        );
+
+        let (event, window_origin) = probe.0.lock().unwrap().unwrap();
+        assert_eq!(window_origin, Point::new(4.0, 3.0));
+        assert_eq!(event.position(), CellPoint::new(2, 1));
+        assert_eq!(event.window_position(), CellPoint::new(6, 4));
+    }
+
+    #[test]
+    fn mouse_events_are_local_to_scrolled_widgets() {
+        let probe = Probe::default();
+        let content = Margin::new(probe.clone(), Position::TOP, Length::Cells(1));
+        let scroll_view = ScrollView::new(content);
+        let border = Border::new(
+            scroll_view,
+            Borders::ALL,
+            Style::default(),
+            BorderKind::Straight,
+        );
+        let scroll = (MouseEventKind::ScrollDown, 3, 2);
+        send_mouse_events(border, &[scroll, scroll, (CLICK, 3, 2)]);
+
+        let (event, window_origin) = probe.0.lock().unwrap().unwrap();
+        assert_eq!(window_origin, Point::new(1.0, 0.0));
+        assert_eq!(event.position(), CellPoint::new(2, 2));
+        assert_eq!(event.window_position(), CellPoint::new(3, 2));
+    }
+
+    #[test]
+    fn mouse_events_are_local_to_horizontally_scrolled_widgets() {
+        let probe = Probe::default();
+        let content = Margin::new(probe.clone(), Position::LEFT, Length::Cells(10));
+        let mut scroll_view = ScrollView::new(content);
+        let _ = scroll_view.set_axes(ScrollAxes::Both);
+        let scroll = (MouseEventKind::ScrollRight, 3, 2);
+        send_mouse_events(scroll_view, &[scroll, scroll, scroll, (CLICK, 9, 2)]);
+
+        let (event, window_origin) = probe.0.lock().unwrap().unwrap();
+        assert_eq!(window_origin, Point::new(7.0, 0.0));
+        assert_eq!(event.position(), CellPoint::new(2, 2));
+    }
+
+    #[test]
+    fn arrow_keys_focus_the_closest_widget_in_that_direction() {
+        // a form with two fields below each other and a button right of the first field
+        let (first, second, button) = (Id::next(), Id::next(), Id::next());
+        let mut focus = FocusState {
+            focused: Some(first),
+            focus_chain: vec![first, button, second],
+            focus_rects: vec![
+                Rect::new(0.0, 0.0, 10.0, 1.0),
+                Rect::new(12.0, 0.0, 20.0, 1.0),
+                Rect::new(0.0, 2.0, 10.0, 3.0),
+            ],
+            ..FocusState::default()
+        };
+        focus.request = Some(FocusRequest::Towards(FocusDirection::Down));
+        assert!(focus.apply_request());
+        assert_eq!(focus.focused, Some(second));
+        focus.request = Some(FocusRequest::Towards(FocusDirection::Right));
+        assert!(focus.apply_request());
+        assert_eq!(focus.focused, Some(button));
+        // there's nothing further right
+        focus.request = Some(FocusRequest::Towards(FocusDirection::Right));
+        assert!(!focus.apply_request());
+        assert_eq!(focus.focused, Some(button));
+    }
+
+    #[test]
+    fn mnemonics_fall_back_to_the_next_free_letter() {
+        let mut cx = TestCx::new();
+        let mut register = |candidates: &str| {
+            // every call registers another widget
+            cx.widget_state = WidgetState::new();
+            let letter = cx.lifecycle_cx(|cx| cx.register_mnemonic(candidates.chars()));
+            (letter, cx.widget_state.id)
+        };
+        let (letter, save) = register("Sav");
+        assert_eq!(letter, Some('s'));
+        let (letter, select) = register("Sel");
+        assert_eq!(letter, Some('e'));
+        assert_eq!(register("SE").0, None);
+
+        let focus = &mut cx.focus;
+        focus.focus_chain = vec![save, select];
+        assert_eq!(focus.mnemonic_target('S'), Some(save));
+        // only the widgets within the trapping scope can be activated
+        focus.trap = Some(1..2);
+        assert_eq!(focus.mnemonic_target('s'), None);
+        assert_eq!(focus.mnemonic_target('e'), Some(select));
+    }
+
+    /// Rebuilds the focus chain like [`LifeCycle::BuildFocusChain`], with the focus scopes registered innermost first,
+    /// returns whether the focused widget has changed.
+    fn rebuild_focus_chain(
+        focus: &mut FocusState,
+        chain: &[Id],
+        scopes: &[(Id, Range<usize>)],
+    ) -> bool {
+        focus.clear_focus_chain();
+        focus.focus_chain = chain.to_vec();
+        focus.focus_rects = vec![Rect::ZERO; chain.len()];
+        for (scope, range) in scopes {
+            focus.add_scope(*scope, range.clone());
+        }
+        focus.validate()
+    }
+
+    #[test]
+    fn nested_focus_scopes_trap_the_focus_in_the_innermost_scope() {
+        let [a, b, c, d, inner, outer] = std::array::from_fn(|_| Id::next());
+        let mut focus = FocusState {
+            focused: Some(c),
+            ..FocusState::default()
+        };
+        rebuild_focus_chain(&mut focus, &[a, b, c, d], &[(inner, 1..3), (outer, 0..4)]);
+        assert_eq!(focus.trap, Some(1..3));
+        focus.request = Some(FocusRequest::Next);
+        assert!(focus.apply_request());
+        assert_eq!(focus.focused, Some(b));
+
+        // the outer scope traps the focus, after the inner scope was removed
+        rebuild_focus_chain(&mut focus, &[a, d], &[(outer, 0..2)]);
+        assert_eq!(focus.trap, Some(0..2));
+        focus.request = Some(FocusRequest::Previous);
+        assert!(focus.apply_request());
+        assert_eq!(focus.focused, Some(d));
+    }
+
+    #[test]
+    fn removed_focus_scopes_restore_the_previous_focus() {
+        let [outside, a, b, outer, inner] = std::array::from_fn(|_| Id::next());
+        let mut focus = FocusState {
+            focused: Some(outside),
+            ..FocusState::default()
+        };
+        rebuild_focus_chain(&mut focus, &[outside, a], &[(outer, 1..2)]);
+        focus.request = Some(FocusRequest::Focus(a));
+        assert!(focus.apply_request());
+        rebuild_focus_chain(
+            &mut focus,
+            &[outside, a, b],
+            &[(inner, 2..3), (outer, 1..3)],
+        );
+        focus.request = Some(FocusRequest::Focus(b));
+        assert!(focus.apply_request());
+
+        // both scopes are removed at once, the focus from before the outer one was mounted is restored
+        assert!(rebuild_focus_chain(&mut focus, &[outside], &[]));
+        assert_eq!(focus.focused, Some(outside));
+        assert!(focus.scopes.is_empty());
+    }
+
+    /// Paints "hello" and inverts the cells of "el" afterwards.
+    struct Selection;
+
+    impl Widget for Selection {
+        fn paint(&mut self, cx: &mut PaintCx) {
+            cx.set_string((0.0, 0.0), "hello", Style::default());
+            cx.invert_region(Rect::new(1.0, 0.0, 3.0, 1.0));
+        }
+
+        fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+            bc.constrain((5.0, 1.0))
+        }
+
+        fn lifecycle(&mut self, _cx: &mut LifeCycleCx, _event: &LifeCycle) {}
+
+        fn event(&mut self, _cx: &mut EventCx, _event: &Event) {}
+    }
+
+    #[test]
+    fn regions_are_relative_to_the_widget() {
+        let mut root = Pod::new(Margin::new(Selection, Position::LEFT, Length::Cells(2)));
+        let mut cx = TestCx::new();
+        cx.layout(&mut root, Size::new(8.0, 1.0));
+        let mut buffer = ratatui::buffer::Buffer::empty(ratatui::layout::Rect::new(0, 0, 8, 1));
+        cx.paint_cx(&mut buffer, |cx| root.paint(cx));
+
+        let inverted: String = buffer
+            .content
+            .iter()
+            .filter(|cell| cell.modifier.contains(Modifier::REVERSED))
+            .map(|cell| cell.symbol())
+            .collect();
+        assert_eq!(inverted, "el");
+    }
+
+    /// Requests a timer, when the focus chain is built, and counts the timer events with its token.
+    #[derive(Default)]
+    struct Timed {
+        token: Option<TimerToken>,
+        elapsed: Arc<Mutex<usize>>,
+    }
+
+    impl Widget for Timed {
+        fn paint(&mut self, _cx: &mut PaintCx) {}
+
+        fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+            bc.constrain((1.0, 1.0))
+        }
+
+        fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+            if let LifeCycle::BuildFocusChain = event {
+                self.token = Some(cx.request_timer(Duration::from_millis(500)));
+            }
+        }
+
+        fn event(&mut self, _cx: &mut EventCx, event: &Event) {
+            if matches!(event, Event::Timer(token) if Some(*token) == self.token) {
+                *self.elapsed.lock().unwrap() += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn timers_are_sent_back_to_the_widget_that_requested_them() {
+        let (first, second) = (Timed::default(), Timed::default());
+        let (first_elapsed, second_elapsed) = (first.elapsed.clone(), second.elapsed.clone());
+        let mut root = Pod::new(Border::new(
+            Margin::new(first, Position::LEFT, Length::Cells(1)),
+            Borders::ALL,
+            Style::default(),
+            BorderKind::Straight,
+        ));
+        let mut other = Pod::new(second);
+        let mut cx = TestCx::new();
+        for pod in [&mut root, &mut other] {
+            cx.lifecycle_cx(|cx| pod.lifecycle(cx, &LifeCycle::BuildFocusChain));
+        }
+        let timers = std::mem::take(&mut cx.timers);
+        assert_eq!(timers.len(), 2);
+        assert!(timers
+            .iter()
+            .all(|(delay, _)| *delay == Duration::from_millis(500)));
+        assert_ne!(timers[0].1, timers[1].1);
+
+        // the app sends the event to the whole tree, after the delay has elapsed
+        let mut send = |event: &Event| {
+            for pod in [&mut root, &mut other] {
+                cx.event_cx(|cx| pod.event(cx, event));
+            }
+        };
+        send(&Event::Timer(timers[0].1));
+        assert_eq!(*first_elapsed.lock().unwrap(), 1);
+        assert_eq!(*second_elapsed.lock().unwrap(), 0);
+        send(&Event::Timer(timers[1].1));
+        assert_eq!(*second_elapsed.lock().unwrap(), 1);
+    }
 }
@@ -0,0 +1,258 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::style::Style;
+
+use super::{
+    core::{IdPath, PaintCx},
+    BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    RawMouseEvent, Widget,
+};
+use crate::{days_in_month, geometry::Size, Date, DatePickerTheme, DateTime};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Each day takes 2 cells and is separated by one cell.
+const DAY_WIDTH: usize = 3;
+const WIDTH: usize = 7 * DAY_WIDTH - 1;
+/// The header (month) and weekday rows above the days
+const DAYS_ROW: usize = 2;
+const WEEKS: usize = 6;
+
+/// A calendar grid, which sends the newly selected [`DateTime`] to its view.
+///
+/// The displayed month is always the month of the (keyboard) cursor.
+pub struct DatePicker {
+    id_path: IdPath,
+    selected: DateTime,
+    cursor: Date,
+    minute_step: u8,
+    styles: DatePickerTheme,
+}
+
+impl DatePicker {
+    pub(crate) fn new(
+        id_path: &IdPath,
+        selected: DateTime,
+        minute_step: u8,
+        styles: DatePickerTheme,
+    ) -> Self {
+        DatePicker {
+            id_path: id_path.clone(),
+            selected,
+            cursor: selected.date,
+            minute_step,
+            styles,
+        }
+    }
+
+    pub(crate) fn set_selected(&mut self, selected: DateTime) -> ChangeFlags {
+        if self.selected != selected {
+            let mut changeflags = ChangeFlags::PAINT;
+            if self.selected.time.is_some() != selected.time.is_some() {
+                changeflags |= ChangeFlags::LAYOUT;
+            }
+            self.selected = selected;
+            self.cursor = selected.date;
+            changeflags
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_minute_step(&mut self, minute_step: u8) -> ChangeFlags {
+        self.minute_step = minute_step;
+        ChangeFlags::empty()
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: DatePickerTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The column of the first day of the displayed month in the grid.
+    fn first_weekday(&self) -> usize {
+        self.cursor.first_of_month().weekday() as usize
+    }
+
+    /// The date at the given cell, if it's a day of the displayed month.
+    fn date_at(&self, x: usize, y: usize) -> Option<Date> {
+        if !(DAYS_ROW..DAYS_ROW + WEEKS).contains(&y) || x >= WIDTH || x % DAY_WIDTH == 2 {
+            return None;
+        }
+        let index = (y - DAYS_ROW) * 7 + x / DAY_WIDTH;
+        let day = (index + 1).checked_sub(self.first_weekday())?;
+        let month = self.cursor.first_of_month();
+        (1..=days_in_month(month.year, month.month) as usize)
+            .contains(&day)
+            .then(|| Date::new(month.year, month.month, day as u8))
+    }
+
+    /// The time row is shown below the days as `◀ HH:MM ▶`, returns the column of `◀`.
+    fn time_row_start() -> usize {
+        (WIDTH - 9) / 2
+    }
+
+    fn select(&mut self, cx: &mut EventCx, date: Date) {
+        self.cursor = date;
+        let selected = DateTime {
+            date,
+            ..self.selected
+        };
+        if selected != self.selected {
+            cx.add_message(Message::new(self.id_path.clone(), selected));
+        }
+        cx.request_paint();
+    }
+
+    fn change_time(&self, cx: &mut EventCx, steps: i32) {
+        if let Some(time) = self.selected.time {
+            let time = time.add_minutes(steps * self.minute_step as i32);
+            let selected = DateTime {
+                time: Some(time),
+                ..self.selected
+            };
+            cx.add_message(Message::new(self.id_path.clone(), selected));
+        }
+    }
+
+    fn move_cursor(&mut self, cx: &mut EventCx, cursor: Date) {
+        self.cursor = cursor;
+        cx.request_paint();
+    }
+}
+
+impl Widget for DatePicker {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let size = cx.cell_size();
+        let (width, height) = (size.width as usize, size.height as usize);
+        let is_focused = cx.is_focused();
+        let mut print = |x: usize, y: usize, text: &str, style: Style| {
+            if x < width && y < height {
+                cx.set_string((x as f64, y as f64), text, style);
+            }
+        };
+
+        let month = self.cursor.first_of_month();
+        let title = format!("{} {}", MONTHS[month.month as usize - 1], month.year);
+        print(0, 0, &" ".repeat(WIDTH), self.styles.header);
+        print(0, 0, "◀", self.styles.header);
+        print((WIDTH - title.len()) / 2, 0, &title, self.styles.header);
+        print(WIDTH - 1, 0, "▶", self.styles.header);
+
+        for (i, weekday) in WEEKDAYS.iter().enumerate() {
+            print(i * DAY_WIDTH, 1, weekday, self.styles.weekdays);
+        }
+
+        for week in 0..WEEKS {
+            print(0, DAYS_ROW + week, &" ".repeat(WIDTH), self.styles.day);
+        }
+        for day in 1..=days_in_month(month.year, month.month) {
+            let date = Date::new(month.year, month.month, day);
+            let index = self.first_weekday() + day as usize - 1;
+            let mut style = self.styles.day;
+            if date == self.selected.date {
+                style = style.patch(self.styles.selected);
+            }
+            if is_focused && date == self.cursor {
+                style = style.patch(self.styles.cursor);
+            }
+            print(
+                (index % 7) * DAY_WIDTH,
+                DAYS_ROW + index / 7,
+                &format!("{day:>2}"),
+                style,
+            );
+        }
+
+        if let Some(time) = self.selected.time {
+            let text = format!("◀ {time} ▶");
+            print(
+                Self::time_row_start(),
+                DAYS_ROW + WEEKS,
+                &text,
+                self.styles.day,
+            );
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let time_row = self.selected.time.is_some() as usize;
+        bc.constrain(Size::new(
+            WIDTH as f64,
+            (DAYS_ROW + WEEKS + time_row) as f64,
+        ))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if cx.is_hot() => {
+                cx.request_focus();
+                let (x, y) = (*column as usize, *row as usize);
+                let time_row_start = Self::time_row_start();
+                match (x, y) {
+                    (0, 0) => self.move_cursor(cx, self.cursor.add_months(-1)),
+                    (x, 0) if x == WIDTH - 1 => self.move_cursor(cx, self.cursor.add_months(1)),
+                    (x, y) if y == DAYS_ROW + WEEKS && x == time_row_start => {
+                        self.change_time(cx, -1)
+                    }
+                    (x, y) if y == DAYS_ROW + WEEKS && x == time_row_start + 8 => {
+                        self.change_time(cx, 1)
+                    }
+                    (x, y) => {
+                        if let Some(date) = self.date_at(x, y) {
+                            self.select(cx, date);
+                        }
+                    }
+                }
+            }
+            Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) if cx.is_focused() => {
+                match code {
+                    KeyCode::Left => self.move_cursor(cx, self.cursor.add_days(-1)),
+                    KeyCode::Right => self.move_cursor(cx, self.cursor.add_days(1)),
+                    KeyCode::Up => self.move_cursor(cx, self.cursor.add_days(-7)),
+                    KeyCode::Down => self.move_cursor(cx, self.cursor.add_days(7)),
+                    KeyCode::PageUp => self.move_cursor(cx, self.cursor.add_months(-1)),
+                    KeyCode::PageDown => self.move_cursor(cx, self.cursor.add_months(1)),
+                    KeyCode::Enter | KeyCode::Char(' ') => self.select(cx, self.cursor),
+                    KeyCode::Char('-') if self.selected.time.is_some() => self.change_time(cx, -1),
+                    KeyCode::Char('+') if self.selected.time.is_some() => self.change_time(cx, 1),
+                    _ => return,
+                }
+                cx.set_handled(true);
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::BuildFocusChain = event {
+            cx.register_for_focus();
+        }
+    }
+}
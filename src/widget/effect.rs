@@ -0,0 +1,177 @@
+use kurbo::{Point, Rect, Size};
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Modifier, Style},
+};
+
+use crate::geometry::{Axis, CellRect};
+
+use super::{
+    BoxConstraints, Canvas, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle,
+    LifeCycleCx, PaintCx, Pod, Widget,
+};
+
+/// The painted cells of a widget, which can be transformed by an effect, see [`ViewExt::effect`](crate::ViewExt::effect).
+pub struct CellRegion<'a> {
+    buffer: &'a mut Buffer,
+    /// The visible area of the widget within the buffer.
+    area: ratatui::layout::Rect,
+}
+
+impl<'a> CellRegion<'a> {
+    /// The visible cells of the widget, which is painted on `canvas`, or `None`, if it isn't visible.
+    pub(crate) fn visible(canvas: &'a mut Canvas) -> Option<Self> {
+        let size = canvas.viewport.size();
+        Self::visible_within(canvas, Rect::from_origin_size(Point::ORIGIN, size))
+    }
+
+    /// The visible cells within `rect` (relative to the origin of the widget, which is painted on `canvas`),
+    /// or `None`, if none of them are visible.
+    pub(crate) fn visible_within(canvas: &'a mut Canvas, rect: Rect) -> Option<Self> {
+        let rect = rect + canvas.viewport.origin().to_vec2();
+        let visible = CellRect::from_rect(rect.intersect(canvas.viewport).intersect(canvas.clip))
+            .intersect(canvas.buffer.area.into());
+        let area = visible.to_buffer_area().filter(|area| !area.is_empty())?;
+        Some(CellRegion {
+            buffer: canvas.buffer,
+            area,
+        })
+    }
+
+    pub fn width(&self) -> u16 {
+        self.area.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.area.height
+    }
+
+    /// The cell at `x`, `y` relative to the top left corner of the region.
+    pub fn cell_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
+        (x < self.area.width && y < self.area.height)
+            .then(|| self.buffer.get_mut(self.area.x + x, self.area.y + y))
+    }
+
+    /// All cells of the region, row by row.
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        let width = self.buffer.area.width as usize;
+        let columns = self.area.left() as usize..self.area.right() as usize;
+        self.buffer
+            .content
+            .chunks_mut(width.max(1))
+            .skip(self.area.y as usize)
+            .take(self.area.height as usize)
+            .flat_map(move |row| row[columns.clone()].iter_mut())
+    }
+
+    /// Patches the style of all cells, e.g. to highlight a search match.
+    pub fn patch_style(&mut self, style: Style) {
+        for cell in self.cells_mut() {
+            cell.set_style(style);
+        }
+    }
+
+    /// Swaps the foreground and background colors of all cells, e.g. for a selection.
+    pub fn invert(&mut self) {
+        for cell in self.cells_mut() {
+            cell.modifier.toggle(Modifier::REVERSED);
+        }
+    }
+}
+
+/// Applies an effect to the painted cells of its content.
+pub struct Effect {
+    pub(crate) content: Pod,
+    effect: fn(&mut CellRegion),
+}
+
+impl Effect {
+    pub(crate) fn new(content: impl Widget, effect: fn(&mut CellRegion)) -> Self {
+        Effect {
+            content: Pod::new(content),
+            effect,
+        }
+    }
+
+    pub(crate) fn set_effect(&mut self, effect: fn(&mut CellRegion)) -> ChangeFlags {
+        if self.effect != effect {
+            self.effect = effect;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+}
+
+impl Widget for Effect {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+
+        if let Some(mut region) = CellRegion::visible(cx.canvas) {
+            (self.effect)(&mut region);
+        }
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.content.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.content.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        self.content.lifecycle(cx, event);
+    }
+}
+
+/// Common effects for [`ViewExt::effect`](crate::ViewExt::effect).
+pub mod effects {
+    use ratatui::style::{Color, Modifier};
+
+    use super::CellRegion;
+
+    /// Leaves the cells as they are, e.g. to disable an effect conditionally.
+    pub fn none(_region: &mut CellRegion) {}
+
+    /// Dims the text, e.g. for disabled parts of the UI.
+    pub fn dim(region: &mut CellRegion) {
+        for cell in region.cells_mut() {
+            cell.modifier.insert(Modifier::DIM);
+        }
+    }
+
+    /// Swaps the foreground and background colors, e.g. to flash a selection.
+    pub fn invert(region: &mut CellRegion) {
+        region.invert();
+    }
+
+    /// Replaces all colors with shades of gray.
+    pub fn grayscale(region: &mut CellRegion) {
+        for cell in region.cells_mut() {
+            cell.fg = gray(cell.fg);
+            cell.bg = gray(cell.bg);
+        }
+    }
+
+    fn gray(color: Color) -> Color {
+        match color {
+            Color::Reset | Color::Black | Color::White | Color::Gray | Color::DarkGray => color,
+            Color::Rgb(r, g, b) => {
+                let l = (0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64).round() as u8;
+                Color::Rgb(l, l, l)
+            }
+            Color::LightRed
+            | Color::LightGreen
+            | Color::LightYellow
+            | Color::LightBlue
+            | Color::LightMagenta
+            | Color::LightCyan => Color::Gray,
+            _ => Color::DarkGray,
+        }
+    }
+}
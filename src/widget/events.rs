@@ -1,19 +1,28 @@
 use bitflags::bitflags;
-use std::marker::PhantomData;
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
 
-use crate::geometry::{Point, Size};
+use crate::geometry::{Axis, CellPoint, Point, Size};
 use crossterm::event::{MouseButton, MouseEventKind};
-use ratatui::style::Style;
+use ratatui::{
+    buffer::Cell,
+    style::{Color, Style},
+};
 
 use super::{
+    contrast::rgb,
     core::{IdPath, PaintCx},
-    EventCx, LayoutCx, Message, Pod, Widget,
+    CellRegion, ChangeFlags, EventCx, IntrinsicSize, LayoutCx, LifeCycleCx, Message, Pod, Widget,
 };
 
 #[derive(Debug, Clone)]
 pub enum Event {
     /// Only sent once at the start of the application
     Start,
+    /// Sent when the user wants to quit the app (e.g. with `Esc`), the app quits, unless a widget has handled it.
     Quit,
     /// Sent e.g. when a future requests waking up the application
     Wake,
@@ -24,7 +33,28 @@ pub enum Event {
         height: u16,
     },
     Mouse(RawMouseEvent),
+    // TODO IME composition (preedit) events, see Philipp-M/trui#synth-2915
+    // crossterm doesn't expose them (yet), the terminal composes the text itself and only sends the committed characters as key events
     Key(crossterm::event::KeyEvent),
+    /// Sent to all widgets, when `Alt` and a letter registered as mnemonic was pressed (in lowercase),
+    /// see [`LifeCycleCx::register_mnemonic`](super::LifeCycleCx::register_mnemonic).
+    Mnemonic(char),
+    /// Sent to all widgets, when a timer requested with [`EventCx::request_timer`](super::EventCx::request_timer)
+    /// (or [`LifeCycleCx::request_timer`](super::LifeCycleCx::request_timer)) has elapsed,
+    /// widgets should only react to the tokens they have requested themselves.
+    Timer(TimerToken),
+}
+
+/// Identifies a timer requested by a widget, it's sent back with [`Event::Timer`], when the timer has elapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimerToken(u64);
+
+impl TimerToken {
+    /// A token, which is unique for the whole app.
+    pub(crate) fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+        TimerToken(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
 }
 
 #[derive(Debug)]
@@ -33,20 +63,30 @@ pub enum LifeCycle {
     ViewContextChanged(ViewContext),
     TreeUpdate,
     Animate,
+    /// Sent to all widgets, when the focus chain is rebuilt,
+    /// widgets that want to receive keyboard events should call [`LifeCycleCx::register_for_focus`](super::LifeCycleCx::register_for_focus).
+    BuildFocusChain,
+    /// Sent to all widgets, when the focused widget has changed, see [`LifeCycleCx::is_focused`](super::LifeCycleCx::is_focused).
+    FocusChanged,
 }
 
 #[derive(Debug)]
 pub struct ViewContext {
     pub window_origin: Point,
     // pub clip: Rect,
-    pub mouse_position: Option<Point>,
+    pub mouse_position: Option<CellPoint>,
 }
 
+/// A mouse event, `column` and `row` are relative to the origin of the widget, which receives it.
 #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct RawMouseEvent {
     pub kind: MouseEventKind,
     pub column: i16,
     pub row: i16,
+    /// The column in the terminal, which isn't translated to the origin of the widget.
+    pub window_column: u16,
+    /// The row in the terminal, which isn't translated to the origin of the widget.
+    pub window_row: u16,
     pub modifiers: crossterm::event::KeyModifiers,
 }
 
@@ -56,11 +96,25 @@ impl From<crossterm::event::MouseEvent> for RawMouseEvent {
             kind: event.kind,
             column: event.column as i16,
             row: event.row as i16,
+            window_column: event.column,
+            window_row: event.row,
             modifiers: event.modifiers,
         }
     }
 }
 
+impl RawMouseEvent {
+    /// The cell of the mouse, relative to the origin of the widget, which receives the event.
+    pub fn position(&self) -> CellPoint {
+        CellPoint::new(self.column as i32, self.row as i32)
+    }
+
+    /// The cell of the mouse in the terminal.
+    pub fn window_position(&self) -> CellPoint {
+        CellPoint::new(self.window_column as i32, self.window_row as i32)
+    }
+}
+
 impl ViewContext {
     pub fn translate_to(&self, new_origin: Point) -> ViewContext {
         // TODO I think the clip calculation is buggy in xilem (width/height?)
@@ -71,10 +125,11 @@ impl ViewContext {
         //     height: self.clip.height,
         // };
         let translate = new_origin.to_vec2();
+        let cell_origin = CellPoint::from_point(new_origin);
         ViewContext {
             window_origin: self.window_origin + translate,
             // clip,
-            mouse_position: self.mouse_position.map(|p| p - translate),
+            mouse_position: self.mouse_position.map(|p| p - cell_origin),
         }
     }
 }
@@ -82,13 +137,17 @@ impl ViewContext {
 // TODO separate the widgets etc. into its own module?
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-/// A message representing a mouse event.
+/// A message representing a mouse event, in the local coordinates of the element.
 pub struct MouseEvent {
     pub over_element: bool,
     pub is_active: bool,
     pub kind: MouseEventKind,
     pub column: i16,
     pub row: i16,
+    /// The column in the terminal, e.g. to position a popup.
+    pub window_column: u16,
+    /// The row in the terminal.
+    pub window_row: u16,
     pub modifiers: crossterm::event::KeyModifiers,
 }
 
@@ -100,11 +159,50 @@ impl MouseEvent {
             kind: event.kind,
             column: event.column,
             row: event.row,
+            window_column: event.window_column,
+            window_row: event.window_row,
             modifiers: event.modifiers,
         }
     }
 }
 
+/// A message representing a scroll wheel event, in the local coordinates of the element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollEvent {
+    /// `-1` when scrolled left, `1` when scrolled right
+    pub delta_x: i16,
+    /// `-1` when scrolled up, `1` when scrolled down
+    pub delta_y: i16,
+    pub column: i16,
+    pub row: i16,
+    /// The column in the terminal.
+    pub window_column: u16,
+    /// The row in the terminal.
+    pub window_row: u16,
+    pub modifiers: crossterm::event::KeyModifiers,
+}
+
+impl ScrollEvent {
+    fn from_mouse_event(event: &RawMouseEvent) -> Option<Self> {
+        let (delta_x, delta_y) = match event.kind {
+            MouseEventKind::ScrollUp => (0, -1),
+            MouseEventKind::ScrollDown => (0, 1),
+            MouseEventKind::ScrollLeft => (-1, 0),
+            MouseEventKind::ScrollRight => (1, 0),
+            _ => return None,
+        };
+        Some(ScrollEvent {
+            delta_x,
+            delta_y,
+            column: event.column,
+            row: event.row,
+            window_column: event.window_column,
+            window_row: event.window_row,
+            modifiers: event.modifiers,
+        })
+    }
+}
+
 bitflags! {
     #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
     #[must_use]
@@ -142,6 +240,10 @@ impl<E: Widget> Widget for OnMouse<E> {
         self.element.layout(cx, bc)
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
     fn event(&mut self, cx: &mut EventCx, event: &Event) {
         self.element.event(cx, event);
 
@@ -199,6 +301,96 @@ impl<E: Widget> Widget for OnMouse<E> {
     }
 }
 
+/// Sends scroll wheel events over the element to its view, see [`ScrollEvent`].
+///
+/// The events are handled, so that e.g. a surrounding [`ScrollView`](super::ScrollView) doesn't scroll as well.
+pub struct OnScroll {
+    pub(crate) element: Pod,
+    id_path: IdPath,
+}
+
+impl OnScroll {
+    pub fn new<E: Widget>(element: E, id_path: &IdPath) -> Self {
+        OnScroll {
+            element: Pod::new(element),
+            id_path: id_path.clone(),
+        }
+    }
+}
+
+impl Widget for OnScroll {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.element.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
+        self.element.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.element.event(cx, event);
+
+        if cx.is_handled() || !cx.is_hot() {
+            return;
+        }
+        if let Some(scroll_event) = match event {
+            Event::Mouse(mouse_event) => ScrollEvent::from_mouse_event(mouse_event),
+            _ => None,
+        } {
+            cx.add_message(Message::new(self.id_path.clone(), scroll_event));
+            cx.set_handled(true);
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        self.element.lifecycle(cx, event);
+    }
+}
+
+/// Sends the new terminal size as `(width, height)` to its view, when the terminal is resized.
+pub struct OnResize {
+    pub(crate) element: Pod,
+    id_path: IdPath,
+}
+
+impl OnResize {
+    pub fn new<E: Widget>(element: E, id_path: &IdPath) -> Self {
+        OnResize {
+            element: Pod::new(element),
+            id_path: id_path.clone(),
+        }
+    }
+}
+
+impl Widget for OnResize {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.element.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
+        self.element.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        if let Event::Resize { width, height } = event {
+            cx.add_message(Message::new(self.id_path.clone(), (*width, *height)));
+        }
+        self.element.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        self.element.lifecycle(cx, event);
+    }
+}
+
 pub struct OnClick<E> {
     pub(crate) element: Pod,
     id_path: IdPath,
@@ -224,6 +416,10 @@ impl<E: Widget> Widget for OnClick<E> {
         self.element.layout(cx, bc)
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
     fn event(&mut self, cx: &mut EventCx, event: &Event) {
         self.element.event(cx, event);
 
@@ -253,20 +449,104 @@ impl<E: Widget> Widget for OnClick<E> {
     }
 }
 
+/// Delays for the hover state of a widget ("hover intent"),
+/// so that the mouse just passing over a widget doesn't trigger hover handlers or styles.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HoverDelay {
+    /// How long the mouse has to stay over the widget, until it's hovered.
+    pub enter: Duration,
+    /// How long the mouse has to stay outside of the widget, until it's not hovered anymore.
+    pub leave: Duration,
+}
+
+impl From<Duration> for HoverDelay {
+    fn from(delay: Duration) -> Self {
+        HoverDelay {
+            enter: delay,
+            leave: delay,
+        }
+    }
+}
+
+impl From<(Duration, Duration)> for HoverDelay {
+    fn from((enter, leave): (Duration, Duration)) -> Self {
+        HoverDelay { enter, leave }
+    }
+}
+
+/// Tracks the (delayed) hover state of a widget, the delay is timed with animation updates.
+#[derive(Default)]
+pub(crate) struct HoverIntent {
+    pub(crate) delay: HoverDelay,
+    pub(crate) is_hovering: bool,
+    /// The time since the hot state differs from `is_hovering`.
+    pending: Option<Duration>,
+}
+
+impl HoverIntent {
+    /// Returns the new hover state, if it has changed.
+    fn update(&mut self, cx: &mut EventCx) -> Option<bool> {
+        let is_hot = cx.is_hot();
+        if is_hot == self.is_hovering {
+            self.pending = None;
+            return None;
+        }
+        let delay = if is_hot {
+            self.delay.enter
+        } else {
+            self.delay.leave
+        };
+        if delay.is_zero() {
+            self.is_hovering = is_hot;
+            return Some(is_hot);
+        }
+        if self.pending.is_none() {
+            self.pending = Some(Duration::ZERO);
+            cx.request_animation_update();
+        }
+        None
+    }
+
+    /// Returns the new hover state, if the delay has passed.
+    fn animate(&mut self, cx: &mut LifeCycleCx) -> Option<bool> {
+        let elapsed = self.pending.as_mut()?;
+        *elapsed += cx.time_since_last_render_request();
+        let is_hot = !self.is_hovering;
+        let delay = if is_hot {
+            self.delay.enter
+        } else {
+            self.delay.leave
+        };
+        if *elapsed >= delay {
+            self.pending = None;
+            self.is_hovering = is_hot;
+            Some(is_hot)
+        } else {
+            cx.request_animation_update();
+            None
+        }
+    }
+}
+
 pub struct OnHover {
     pub(crate) element: Pod,
     id_path: IdPath,
-    is_hovering: bool,
+    pub(crate) hover: HoverIntent,
 }
 
 impl OnHover {
     pub fn new<E: Widget>(element: E, id_path: &IdPath) -> Self {
         OnHover {
             element: Pod::new(element),
-            is_hovering: false,
+            hover: HoverIntent::default(),
             id_path: id_path.clone(),
         }
     }
+
+    pub(crate) fn set_hover_delay(&mut self, delay: HoverDelay) -> ChangeFlags {
+        self.hover.delay = delay;
+        ChangeFlags::empty()
+    }
 }
 
 impl Widget for OnHover {
@@ -278,20 +558,24 @@ impl Widget for OnHover {
         self.element.layout(cx, bc)
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
     fn event(&mut self, cx: &mut EventCx, event: &Event) {
         self.element.event(cx, event);
 
-        if matches!(event, Event::Mouse(_) | Event::FocusLost) {
-            if cx.is_hot() && !self.is_hovering {
-                self.is_hovering = true;
-                cx.add_message(Message::new(self.id_path.clone(), ()));
-            } else if !cx.is_hot() && self.is_hovering {
-                self.is_hovering = false;
-            }
+        if matches!(event, Event::Mouse(_) | Event::FocusLost)
+            && self.hover.update(cx) == Some(true)
+        {
+            cx.add_message(Message::new(self.id_path.clone(), ()));
         }
     }
 
     fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        if matches!(event, LifeCycle::Animate) && self.hover.animate(cx) == Some(true) {
+            cx.add_message(Message::new(self.id_path.clone(), ()));
+        }
         self.element.lifecycle(cx, event);
     }
 }
@@ -299,17 +583,22 @@ impl Widget for OnHover {
 pub struct OnHoverLost {
     pub element: Pod,
     id_path: IdPath,
-    is_hovering: bool,
+    pub(crate) hover: HoverIntent,
 }
 
 impl OnHoverLost {
     pub fn new<E: Widget>(element: E, id_path: &IdPath) -> Self {
         OnHoverLost {
             element: Pod::new(element),
-            is_hovering: false,
+            hover: HoverIntent::default(),
             id_path: id_path.clone(),
         }
     }
+
+    pub(crate) fn set_hover_delay(&mut self, delay: HoverDelay) -> ChangeFlags {
+        self.hover.delay = delay;
+        ChangeFlags::empty()
+    }
 }
 
 impl Widget for OnHoverLost {
@@ -321,28 +610,350 @@ impl Widget for OnHoverLost {
         self.element.layout(cx, bc)
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
     fn event(&mut self, cx: &mut EventCx, event: &Event) {
         self.element.event(cx, event);
 
-        if matches!(event, Event::Mouse(_) | Event::FocusLost) {
-            if cx.is_hot() && !self.is_hovering {
-                self.is_hovering = true;
-            } else if !cx.is_hot() && self.is_hovering {
-                self.is_hovering = false;
-                cx.add_message(Message::new(self.id_path.clone(), ()));
-            }
+        if matches!(event, Event::Mouse(_) | Event::FocusLost)
+            && self.hover.update(cx) == Some(false)
+        {
+            cx.add_message(Message::new(self.id_path.clone(), ()));
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        if matches!(event, LifeCycle::Animate) && self.hover.animate(cx) == Some(false) {
+            cx.add_message(Message::new(self.id_path.clone(), ()));
+        }
+        self.element.lifecycle(cx, event);
+    }
+}
+
+/// Notifies its view, when the element is painted (at least partially) within the visible area,
+/// i.e. the terminal, or the viewport of a [`ScrollView`](super::ScrollView), after it wasn't.
+pub struct OnAppear {
+    pub(crate) element: Pod,
+    id_path: IdPath,
+    is_visible: bool,
+}
+
+impl OnAppear {
+    pub fn new<E: Widget>(element: E, id_path: &IdPath) -> Self {
+        OnAppear {
+            element: Pod::new(element),
+            is_visible: false,
+            id_path: id_path.clone(),
+        }
+    }
+}
+
+impl Widget for OnAppear {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.element.paint(cx);
+
+        let is_visible = cx.is_visible();
+        if is_visible && !self.is_visible {
+            cx.add_message(Message::new(self.id_path.clone(), ()));
+        }
+        self.is_visible = is_visible;
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
+        self.element.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.element.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        self.element.lifecycle(cx, event);
+    }
+}
+
+/// Notifies its view, when the element is painted outside of the visible area, after it was visible, see [`OnAppear`].
+pub struct OnDisappear {
+    pub(crate) element: Pod,
+    id_path: IdPath,
+    is_visible: bool,
+}
+
+impl OnDisappear {
+    pub fn new<E: Widget>(element: E, id_path: &IdPath) -> Self {
+        OnDisappear {
+            element: Pod::new(element),
+            is_visible: false,
+            id_path: id_path.clone(),
         }
     }
+}
+
+impl Widget for OnDisappear {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.element.paint(cx);
+
+        let is_visible = cx.is_visible();
+        if !is_visible && self.is_visible {
+            cx.add_message(Message::new(self.id_path.clone(), ()));
+        }
+        self.is_visible = is_visible;
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
+        self.element.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.element.event(cx, event);
+    }
 
     fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
         self.element.lifecycle(cx, event);
     }
 }
 
+/// Notifies its view once, after it was added to the widget tree (i.e. on the first lifecycle event it receives).
+pub struct OnMount {
+    pub(crate) element: Pod,
+    id_path: IdPath,
+    is_mounted: bool,
+}
+
+impl OnMount {
+    pub fn new<E: Widget>(element: E, id_path: &IdPath) -> Self {
+        OnMount {
+            element: Pod::new(element),
+            is_mounted: false,
+            id_path: id_path.clone(),
+        }
+    }
+}
+
+impl Widget for OnMount {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.element.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
+        self.element.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.element.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        if !self.is_mounted {
+            self.is_mounted = true;
+            cx.add_message(Message::new(self.id_path.clone(), ()));
+        }
+        self.element.lifecycle(cx, event);
+    }
+}
+
+/// The event filter of an [`OnEvent`] widget, which returns the message (if any) for an event.
+pub(crate) type BoxedEventFilter =
+    Box<dyn FnMut(&mut EventCx, &Event) -> Option<Box<dyn std::any::Any + Send>> + Send>;
+
+/// Sends the output of an event filter as message, see [`ViewExt::on_event`](crate::ViewExt::on_event).
+pub struct OnEvent {
+    pub(crate) element: Pod,
+    id_path: IdPath,
+    pub(crate) filter: BoxedEventFilter,
+}
+
+impl OnEvent {
+    pub fn new(element: impl Widget, id_path: &IdPath, filter: BoxedEventFilter) -> Self {
+        OnEvent {
+            element: Pod::new(element),
+            id_path: id_path.clone(),
+            filter,
+        }
+    }
+}
+
+impl Widget for OnEvent {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.element.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
+        self.element.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.element.event(cx, event);
+        if let Some(message) = (self.filter)(cx, event) {
+            cx.add_message(Message {
+                id_path: self.id_path.clone(),
+                body: message,
+            });
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        self.element.lifecycle(cx, event);
+    }
+}
+
+/// An easing curve, which maps the linear progress of a transition (between 0 and 1) to the progress of the animated value.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Ease {
+    #[default]
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+}
+
+impl Ease {
+    pub fn apply(self, ratio: f64) -> f64 {
+        match self {
+            Ease::Linear => ratio,
+            Ease::QuadraticIn => ratio * ratio,
+            Ease::QuadraticOut => -(ratio * (ratio - 2.0)),
+            Ease::QuadraticInOut if ratio < 0.5 => 2.0 * ratio * ratio,
+            Ease::QuadraticInOut => (-2.0 * ratio * ratio) + (4.0 * ratio) - 1.0,
+        }
+    }
+}
+
+/// How a style override (like [`ViewExt::on_hover_style`](crate::ViewExt::on_hover_style)) fades in and out,
+/// by default it's switched instantly.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StyleTransition {
+    pub duration: Duration,
+    pub ease: Ease,
+}
+
+impl From<Duration> for StyleTransition {
+    fn from(duration: Duration) -> Self {
+        StyleTransition {
+            duration,
+            ease: Ease::default(),
+        }
+    }
+}
+
+impl From<(Duration, Ease)> for StyleTransition {
+    fn from((duration, ease): (Duration, Ease)) -> Self {
+        StyleTransition { duration, ease }
+    }
+}
+
+/// Fades a style override in and out, the transition is timed with animation updates.
+#[derive(Default)]
+pub(crate) struct StyleFade {
+    pub(crate) transition: StyleTransition,
+    /// Whether the style is faded in.
+    applied: bool,
+    /// From 0 (not applied) to 1 (fully applied), before easing.
+    progress: f64,
+}
+
+impl StyleFade {
+    /// Returns whether the style has to be repainted, the transition continues with animation updates.
+    fn set_applied(&mut self, applied: bool) -> bool {
+        if self.applied == applied {
+            return false;
+        }
+        self.applied = applied;
+        if self.transition.duration.is_zero() {
+            self.progress = applied as u8 as f64;
+        }
+        true
+    }
+
+    fn animate(&mut self, cx: &mut LifeCycleCx) {
+        let target = self.applied as u8 as f64;
+        if self.progress == target {
+            return;
+        }
+        let step = if cx.reduce_motion() || self.transition.duration.is_zero() {
+            1.0
+        } else {
+            cx.time_since_last_render_request().as_secs_f64()
+                / self.transition.duration.as_secs_f64()
+        };
+        self.progress = if self.applied {
+            (self.progress + step).min(1.0)
+        } else {
+            (self.progress - step).max(0.0)
+        };
+        if self.progress != target {
+            cx.request_animation_update();
+        }
+        cx.request_paint();
+    }
+
+    /// Paints `element` with `style` faded in by the current progress.
+    fn paint(&self, cx: &mut PaintCx, style: Style, element: &mut Pod) {
+        let ratio = self.transition.ease.apply(self.progress);
+        if ratio >= 1.0 {
+            cx.patch_style(style);
+        }
+        element.paint(cx);
+        if ratio <= 0.0 || ratio >= 1.0 {
+            return;
+        }
+        if let Some(mut region) = CellRegion::visible(cx.canvas) {
+            for cell in region.cells_mut() {
+                blend_style(cell, style, ratio);
+            }
+        }
+    }
+}
+
+/// Blends the colors of `cell` towards the ones of `style` by `ratio`, the modifiers are switched halfway.
+fn blend_style(cell: &mut Cell, style: Style, ratio: f64) {
+    if let Some(fg) = style.fg {
+        cell.fg = blend(cell.fg, fg, ratio);
+    }
+    if let Some(bg) = style.bg {
+        cell.bg = blend(cell.bg, bg, ratio);
+    }
+    if ratio >= 0.5 {
+        cell.modifier.insert(style.add_modifier);
+        cell.modifier.remove(style.sub_modifier);
+    }
+}
+
+/// Interpolates between two colors in RGB, colors without an RGB value (i.e. `Reset`) are switched halfway.
+fn blend(from: Color, to: Color, ratio: f64) -> Color {
+    match (rgb(from), rgb(to)) {
+        (Some((r0, g0, b0)), Some((r1, g1, b1))) => {
+            let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * ratio).round() as u8;
+            Color::Rgb(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1))
+        }
+        _ if ratio < 0.5 => from,
+        _ => to,
+    }
+}
+
 pub struct StyleOnHover {
     pub element: Pod,
-    is_hovering: bool,
+    pub(crate) hover: HoverIntent,
     pub(crate) style: Style,
+    pub(crate) fade: StyleFade,
 }
 
 impl StyleOnHover {
@@ -350,35 +961,48 @@ impl StyleOnHover {
         StyleOnHover {
             element: Pod::new(element),
             style,
-            is_hovering: false,
+            hover: HoverIntent::default(),
+            fade: StyleFade::default(),
         }
     }
 }
 
 impl Widget for StyleOnHover {
     fn paint(&mut self, cx: &mut PaintCx) {
-        if cx.is_hot() {
-            cx.override_style = self.style.patch(cx.override_style);
-        };
-        self.element.paint(cx);
+        self.fade.paint(cx, self.style, &mut self.element);
     }
 
     fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
         self.element.layout(cx, bc)
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
     fn event(&mut self, cx: &mut EventCx, event: &Event) {
         self.element.event(cx, event);
-        if cx.is_hot() && !self.is_hovering {
-            cx.request_paint();
-            self.is_hovering = true;
-        } else if !cx.is_hot() && self.is_hovering {
+        if self
+            .hover
+            .update(cx)
+            .is_some_and(|is_hovering| self.fade.set_applied(is_hovering))
+        {
+            cx.request_animation_update();
             cx.request_paint();
-            self.is_hovering = false;
         }
     }
 
     fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        if matches!(event, LifeCycle::Animate) {
+            if self
+                .hover
+                .animate(cx)
+                .is_some_and(|is_hovering| self.fade.set_applied(is_hovering))
+            {
+                cx.request_paint();
+            }
+            self.fade.animate(cx);
+        }
         self.element.lifecycle(cx, event);
     }
 }
@@ -386,6 +1010,7 @@ impl Widget for StyleOnHover {
 pub struct StyleOnPressed {
     pub(crate) element: Pod,
     pub(crate) style: Style,
+    pub(crate) fade: StyleFade,
 }
 
 impl StyleOnPressed {
@@ -393,22 +1018,24 @@ impl StyleOnPressed {
         StyleOnPressed {
             element: Pod::new(element),
             style,
+            fade: StyleFade::default(),
         }
     }
 }
 
 impl Widget for StyleOnPressed {
     fn paint(&mut self, cx: &mut PaintCx) {
-        if cx.is_active() {
-            cx.override_style = self.style.patch(cx.override_style);
-        };
-        self.element.paint(cx);
+        self.fade.paint(cx, self.style, &mut self.element);
     }
 
     fn layout(&mut self, cx: &mut LayoutCx, bc: &super::BoxConstraints) -> Size {
         self.element.layout(cx, bc)
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
     fn event(&mut self, cx: &mut EventCx, event: &Event) {
         self.element.event(cx, event);
 
@@ -418,7 +1045,11 @@ impl Widget for StyleOnPressed {
                 ..
             }) => {
                 cx.request_paint();
-                cx.set_active(cx.is_hot());
+                let is_hot = cx.is_hot();
+                cx.set_active(is_hot);
+                if self.fade.set_applied(is_hot) {
+                    cx.request_animation_update();
+                }
             }
             Event::Mouse(RawMouseEvent {
                 kind: MouseEventKind::Up(MouseButton::Left) | MouseEventKind::Moved,
@@ -427,12 +1058,18 @@ impl Widget for StyleOnPressed {
             | Event::FocusLost => {
                 cx.request_paint();
                 cx.set_active(false);
+                if self.fade.set_applied(false) {
+                    cx.request_animation_update();
+                }
             }
             _ => (),
         }
     }
 
     fn lifecycle(&mut self, cx: &mut super::core::LifeCycleCx, event: &LifeCycle) {
+        if matches!(event, LifeCycle::Animate) {
+            self.fade.animate(cx);
+        }
         self.element.lifecycle(cx, event);
     }
 }
@@ -1,25 +1,28 @@
-use crate::{geometry::Size, Fill};
+use crate::{
+    geometry::{Axis, Length, Size},
+    Fill,
+};
 
 use super::{
     animatables::AnimatableElement,
     core::{EventCx, LifeCycleCx, PaintCx},
-    BoxConstraints, ChangeFlags, Event, LayoutCx, LifeCycle, Pod, Widget,
+    BoxConstraints, ChangeFlags, Event, IntrinsicSize, LayoutCx, LifeCycle, Pod, Widget,
 };
 
-pub struct FillMaxSize<P> {
+pub struct FillMaxSize<L> {
     pub(crate) content: Pod,
     fill: Fill,
-    pub(crate) percent: P,
-    percent_value: f64,
+    pub(crate) length: L,
+    length_value: Length,
 }
 
-impl<P> FillMaxSize<P> {
-    pub(crate) fn new(content: impl Widget, fill: Fill, percent: P) -> Self {
+impl<L> FillMaxSize<L> {
+    pub(crate) fn new(content: impl Widget, fill: Fill, length: L) -> Self {
         FillMaxSize {
             content: Pod::new(content),
             fill,
-            percent,
-            percent_value: 1.0,
+            length,
+            length_value: Length::Percent(1.0),
         }
     }
 
@@ -33,22 +36,22 @@ impl<P> FillMaxSize<P> {
     }
 }
 
-impl<P: AnimatableElement<f64> + 'static> Widget for FillMaxSize<P> {
+impl<L: AnimatableElement<Length> + 'static> Widget for FillMaxSize<L> {
     fn paint(&mut self, cx: &mut PaintCx) {
         self.content.paint(cx)
     }
 
     fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
         let mut bc = *bc;
-        if self.fill.contains(Fill::WIDTH) && bc.is_width_bounded() {
-            bc = bc
-                .constrain_width_to(bc.max().width * self.percent_value)
-                .tighten_max_width();
+        if self.fill.contains(Fill::WIDTH) {
+            if let Some(width) = self.length_value.resolve(bc.max().width) {
+                bc = bc.constrain_width_to(width).tighten_max_width();
+            }
         }
-        if self.fill.contains(Fill::HEIGHT) && bc.is_height_bounded() {
-            bc = bc
-                .constrain_height_to(bc.max().height * self.percent_value)
-                .tighten_max_height();
+        if self.fill.contains(Fill::HEIGHT) {
+            if let Some(height) = self.length_value.resolve(bc.max().height) {
+                bc = bc.constrain_height_to(height).tighten_max_height();
+            }
         }
         self.content.layout(cx, &bc)
     }
@@ -57,12 +60,33 @@ impl<P: AnimatableElement<f64> + 'static> Widget for FillMaxSize<P> {
         self.content.event(cx, event)
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let fill_axis = |axis| match axis {
+            Axis::Horizontal => Fill::WIDTH,
+            Axis::Vertical => Fill::HEIGHT,
+        };
+        if self.fill.contains(fill_axis(axis)) {
+            match self.length_value {
+                Length::Cells(cells) => return Some(IntrinsicSize::fixed(cells as f64)),
+                // The size along a filled axis depends only on the available space
+                Length::Percent(_) => return None,
+                Length::Auto => {}
+            }
+        }
+        let cross = if self.fill.contains(fill_axis(axis.cross())) {
+            self.length_value.resolve(cross).unwrap_or(cross)
+        } else {
+            cross
+        };
+        self.content.measure(cx, axis, cross)
+    }
+
     fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
         if let LifeCycle::Animate = event {
-            let new_percent_value = *self.percent.animate(cx);
-            if new_percent_value != self.percent_value {
+            let new_length_value = *self.length.animate(cx);
+            if new_length_value != self.length_value {
                 cx.request_layout();
-                self.percent_value = new_percent_value;
+                self.length_value = new_length_value;
             }
         }
         self.content.lifecycle(cx, event);
@@ -0,0 +1,67 @@
+use kurbo::Size;
+
+use crate::geometry::Axis;
+
+use super::{
+    core::FocusRequest, BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx,
+    LifeCycle, LifeCycleCx, PaintCx, Pod, Widget,
+};
+
+/// Confines `Tab` navigation to the focusable widgets in its content.
+pub struct FocusScope {
+    pub(crate) content: Pod,
+    autofocus: bool,
+    mounted: bool,
+}
+
+impl FocusScope {
+    pub(crate) fn new(content: impl Widget, autofocus: bool) -> Self {
+        FocusScope {
+            content: Pod::new(content),
+            autofocus,
+            mounted: false,
+        }
+    }
+
+    pub(crate) fn set_autofocus(&mut self, autofocus: bool) -> ChangeFlags {
+        self.autofocus = autofocus;
+        ChangeFlags::empty()
+    }
+}
+
+impl Widget for FocusScope {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.content.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.content.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if !matches!(event, LifeCycle::BuildFocusChain) {
+            self.content.lifecycle(cx, event);
+            return;
+        }
+        let start = cx.cx_state.focus.focus_chain.len();
+        self.content.lifecycle(cx, event);
+        let focus = &mut *cx.cx_state.focus;
+        let range = start..focus.focus_chain.len();
+        focus.add_scope(cx.widget_state.id, range.clone());
+
+        if !self.mounted {
+            self.mounted = true;
+            if self.autofocus && !range.is_empty() {
+                focus.request = Some(FocusRequest::Focus(focus.focus_chain[range.start]));
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use kurbo::Size;
+
+use crate::geometry::Axis;
+
+use super::{
+    core::FocusRequest, BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx,
+    LifeCycle, LifeCycleCx, PaintCx, Pod, Widget,
+};
+
+/// Focuses the first focusable widget in its content, when requested by its view.
+pub struct FocusWhen {
+    pub(crate) content: Pod,
+    request_focus: bool,
+}
+
+impl FocusWhen {
+    pub(crate) fn new(content: impl Widget, request_focus: bool) -> Self {
+        FocusWhen {
+            content: Pod::new(content),
+            request_focus,
+        }
+    }
+
+    /// Focuses the content, when the focus chain is built the next time (which is triggered via [`ChangeFlags::LAYOUT`]).
+    pub(crate) fn request_focus(&mut self) -> ChangeFlags {
+        self.request_focus = true;
+        ChangeFlags::LAYOUT
+    }
+}
+
+impl Widget for FocusWhen {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.content.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.content.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        let start = cx.cx_state.focus.focus_chain.len();
+        self.content.lifecycle(cx, event);
+        if matches!(event, LifeCycle::BuildFocusChain) && self.request_focus {
+            let focus = &mut *cx.cx_state.focus;
+            if let Some(id) = focus.focus_chain.get(start) {
+                self.request_focus = false;
+                focus.request = Some(FocusRequest::Focus(*id));
+            }
+        }
+    }
+}
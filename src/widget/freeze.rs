@@ -0,0 +1,106 @@
+use kurbo::{Size, Vec2};
+
+use crate::geometry::Axis;
+
+use super::{
+    BoxConstraints, Canvas, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle,
+    LifeCycleCx, PaintCx, Pod, Widget,
+};
+
+/// Paints the cached cells of its content instead of laying it out and painting it, while it's frozen.
+///
+/// The content doesn't receive any events, isn't animated and can't be focused while it's frozen.
+pub struct Freeze {
+    pub(crate) content: Pod,
+    frozen: bool,
+    /// The size of the content from the last layout.
+    size: Option<Size>,
+    /// The cells of the content, painted once after it was frozen.
+    cache: Option<ratatui::buffer::Buffer>,
+}
+
+impl Freeze {
+    pub(crate) fn new(content: impl Widget, frozen: bool) -> Self {
+        Freeze {
+            content: Pod::new(content),
+            frozen,
+            size: None,
+            cache: None,
+        }
+    }
+
+    pub(crate) fn set_frozen(&mut self, frozen: bool) -> ChangeFlags {
+        if self.frozen == frozen {
+            return ChangeFlags::empty();
+        }
+        self.frozen = frozen;
+        if frozen {
+            ChangeFlags::PAINT
+        } else {
+            self.cache = None;
+            // the focus chain has to be rebuilt as well, which currently happens with every layout
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT | ChangeFlags::ANIMATION
+        }
+    }
+}
+
+impl Widget for Freeze {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        if !self.frozen {
+            self.content.paint(cx);
+            return;
+        }
+        let cache = self.cache.get_or_insert_with(|| {
+            let size = self.content.size();
+            let mut buffer = ratatui::buffer::Buffer::empty(ratatui::layout::Rect::new(
+                0,
+                0,
+                size.width.round() as u16,
+                size.height.round() as u16,
+            ));
+            let mut canvas = Canvas::new(&mut buffer);
+            let mut cx_content = PaintCx {
+                cx_state: cx.cx_state,
+                widget_state: cx.widget_state,
+                canvas: &mut canvas,
+                override_style: cx.override_style,
+            };
+            self.content.paint(&mut cx_content);
+            buffer
+        });
+        cx.canvas.blit_with_offset(&Canvas::new(cache), Vec2::ZERO);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        match self.size {
+            Some(size) if self.frozen => bc.constrain(size),
+            _ => {
+                let size = self.content.layout(cx, bc);
+                self.size = Some(size);
+                size
+            }
+        }
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        match self.size {
+            Some(size) if self.frozen => Some(IntrinsicSize::fixed(match axis {
+                Axis::Horizontal => size.width,
+                Axis::Vertical => size.height,
+            })),
+            _ => self.content.measure(cx, axis, cross),
+        }
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        if !self.frozen {
+            self.content.event(cx, event);
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if !(self.frozen && matches!(event, LifeCycle::Animate | LifeCycle::BuildFocusChain)) {
+            self.content.lifecycle(cx, event);
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use std::time::Duration;
+
+use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+use super::{
+    core::IdPath, BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle,
+    LifeCycleCx, Message, PaintCx, Pod, RawMouseEvent, Widget,
+};
+use crate::geometry::{Axis, Size};
+
+/// The default distance in cells, which the mouse has to move while pressed to start a drag.
+pub(crate) const DEFAULT_DRAG_THRESHOLD: u16 = 1;
+
+/// The phase of a drag gesture, see [`DragEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DragPhase {
+    /// The mouse moved beyond the drag threshold while the left button was pressed.
+    Start,
+    Move,
+    /// The mouse button was released.
+    End,
+    /// The drag was interrupted, e.g. because the terminal lost focus, changes made while dragging should be reverted.
+    Cancel,
+}
+
+/// A message representing a drag gesture, see [`ViewExt::on_drag`](crate::ViewExt::on_drag).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DragEvent {
+    pub phase: DragPhase,
+    /// The column of the mouse, relative to the origin of the element.
+    pub column: i16,
+    /// The row of the mouse, relative to the origin of the element.
+    pub row: i16,
+    /// The horizontal distance in cells from where the mouse button was pressed.
+    pub offset_x: i16,
+    /// The vertical distance in cells from where the mouse button was pressed.
+    pub offset_y: i16,
+    pub modifiers: KeyModifiers,
+}
+
+/// A gesture recognized by a [`GestureRecognizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Gesture {
+    Tap,
+    LongPress,
+    Drag(DragEvent),
+}
+
+#[derive(Debug, Default)]
+enum PressState {
+    #[default]
+    Idle,
+    Pressed {
+        start: RawMouseEvent,
+        held: Duration,
+        long_pressed: bool,
+    },
+    Dragging {
+        start: RawMouseEvent,
+        last: RawMouseEvent,
+    },
+}
+
+/// Recognizes taps, long presses and drags with the left mouse button from the raw mouse events of a widget.
+///
+/// A press is either a tap (when released over the widget), a long press (when held for `long_press` without moving),
+/// or a drag (when moved at least `drag_threshold` cells), but never more than one of them.
+/// While pressed, the widget is active and captures the mouse, so that drags continue outside of it.
+#[derive(Debug)]
+pub(crate) struct GestureRecognizer {
+    pub(crate) long_press: Option<Duration>,
+    pub(crate) drag_threshold: u16,
+    state: PressState,
+}
+
+impl Default for GestureRecognizer {
+    fn default() -> Self {
+        GestureRecognizer {
+            long_press: None,
+            drag_threshold: DEFAULT_DRAG_THRESHOLD,
+            state: PressState::Idle,
+        }
+    }
+}
+
+fn drag_event(phase: DragPhase, start: &RawMouseEvent, current: &RawMouseEvent) -> DragEvent {
+    DragEvent {
+        phase,
+        column: current.column,
+        row: current.row,
+        offset_x: current.window_column as i16 - start.window_column as i16,
+        offset_y: current.window_row as i16 - start.window_row as i16,
+        modifiers: current.modifiers,
+    }
+}
+
+impl GestureRecognizer {
+    fn end_press(&mut self, cx: &mut EventCx) {
+        self.state = PressState::Idle;
+        cx.set_active(false);
+        cx.release_mouse();
+    }
+
+    /// Returns the gesture, which was recognized with `event`, if any.
+    pub(crate) fn event(&mut self, cx: &mut EventCx, event: &Event) -> Option<Gesture> {
+        let mouse = match event {
+            Event::Mouse(mouse) => mouse,
+            Event::FocusLost => {
+                let state = std::mem::take(&mut self.state);
+                self.end_press(cx);
+                return match state {
+                    PressState::Dragging { start, last } => {
+                        Some(Gesture::Drag(drag_event(DragPhase::Cancel, &start, &last)))
+                    }
+                    _ => None,
+                };
+            }
+            _ => return None,
+        };
+        match (&mut self.state, mouse.kind) {
+            (PressState::Idle, MouseEventKind::Down(MouseButton::Left)) if cx.is_hot() => {
+                self.state = PressState::Pressed {
+                    start: *mouse,
+                    held: Duration::ZERO,
+                    long_pressed: false,
+                };
+                cx.set_active(true);
+                cx.capture_mouse();
+                if self.long_press.is_some() {
+                    cx.request_animation_update();
+                }
+                None
+            }
+            (PressState::Pressed { start, .. }, MouseEventKind::Drag(MouseButton::Left)) => {
+                let distance = (mouse.window_column.abs_diff(start.window_column))
+                    .max(mouse.window_row.abs_diff(start.window_row));
+                if distance < self.drag_threshold {
+                    return None;
+                }
+                let start = *start;
+                self.state = PressState::Dragging {
+                    start,
+                    last: *mouse,
+                };
+                Some(Gesture::Drag(drag_event(DragPhase::Start, &start, mouse)))
+            }
+            (PressState::Dragging { start, last }, MouseEventKind::Drag(MouseButton::Left)) => {
+                *last = *mouse;
+                Some(Gesture::Drag(drag_event(DragPhase::Move, start, mouse)))
+            }
+            (PressState::Pressed { long_pressed, .. }, MouseEventKind::Up(MouseButton::Left)) => {
+                let is_tap = !*long_pressed && cx.is_hot();
+                self.end_press(cx);
+                is_tap.then_some(Gesture::Tap)
+            }
+            (PressState::Dragging { start, .. }, MouseEventKind::Up(MouseButton::Left)) => {
+                let start = *start;
+                self.end_press(cx);
+                Some(Gesture::Drag(drag_event(DragPhase::End, &start, mouse)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns [`Gesture::LongPress`], when the mouse button was held long enough, this has to be called on [`LifeCycle::Animate`].
+    pub(crate) fn animate(&mut self, cx: &mut LifeCycleCx) -> Option<Gesture> {
+        let (
+            Some(long_press),
+            PressState::Pressed {
+                held,
+                long_pressed: long_pressed @ false,
+                ..
+            },
+        ) = (self.long_press, &mut self.state)
+        else {
+            return None;
+        };
+        *held += cx.time_since_last_render_request();
+        if *held >= long_press {
+            *long_pressed = true;
+            Some(Gesture::LongPress)
+        } else {
+            cx.request_animation_update();
+            None
+        }
+    }
+}
+
+/// The gesture, which an [`OnGesture`] widget sends to its view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum GestureKind {
+    Tap,
+    LongPress(Duration),
+    Drag,
+}
+
+/// Sends one kind of gesture to its view, `()` for taps and long presses, and [`DragEvent`]s for drags.
+pub struct OnGesture {
+    pub(crate) element: Pod,
+    id_path: IdPath,
+    kind: GestureKind,
+    recognizer: GestureRecognizer,
+}
+
+impl OnGesture {
+    pub(crate) fn new(element: impl Widget, id_path: &IdPath, kind: GestureKind) -> Self {
+        let mut on_gesture = OnGesture {
+            element: Pod::new(element),
+            id_path: id_path.clone(),
+            kind,
+            recognizer: GestureRecognizer::default(),
+        };
+        let _ = on_gesture.set_kind(kind);
+        on_gesture
+    }
+
+    pub(crate) fn set_kind(&mut self, kind: GestureKind) -> ChangeFlags {
+        self.kind = kind;
+        self.recognizer.long_press = match kind {
+            GestureKind::LongPress(duration) => Some(duration),
+            _ => None,
+        };
+        ChangeFlags::empty()
+    }
+
+    pub(crate) fn set_drag_threshold(&mut self, threshold: u16) -> ChangeFlags {
+        self.recognizer.drag_threshold = threshold;
+        ChangeFlags::empty()
+    }
+
+    /// The message for the view, if `gesture` is of the kind of this widget.
+    fn message(&self, gesture: Gesture) -> Option<Message> {
+        match (self.kind, gesture) {
+            (GestureKind::Tap, Gesture::Tap) | (GestureKind::LongPress(_), Gesture::LongPress) => {
+                Some(Message::new(self.id_path.clone(), ()))
+            }
+            (GestureKind::Drag, Gesture::Drag(event)) => {
+                Some(Message::new(self.id_path.clone(), event))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Widget for OnGesture {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.element.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.element.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.element.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.element.event(cx, event);
+        if let Some(message) = self
+            .recognizer
+            .event(cx, event)
+            .and_then(|gesture| self.message(gesture))
+        {
+            cx.add_message(message);
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if matches!(event, LifeCycle::Animate) {
+            if let Some(message) = self
+                .recognizer
+                .animate(cx)
+                .and_then(|gesture| self.message(gesture))
+            {
+                cx.add_message(message);
+            }
+        }
+        self.element.lifecycle(cx, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::TestCx;
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+        Event::Mouse(RawMouseEvent {
+            kind,
+            column: column as i16,
+            row: row as i16,
+            window_column: column,
+            window_row: row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    /// Feeds `events` (while the mouse is over the widget) to a recognizer and returns the recognized gestures.
+    fn recognize(recognizer: &mut GestureRecognizer, events: &[Event]) -> Vec<Gesture> {
+        let mut cx = TestCx::hot();
+        events
+            .iter()
+            .filter_map(|event| cx.event_cx(|cx| recognizer.event(cx, event)))
+            .collect()
+    }
+
+    #[test]
+    fn press_without_moving_is_a_tap() {
+        let gestures = recognize(
+            &mut GestureRecognizer::default(),
+            &[
+                mouse(MouseEventKind::Down(MouseButton::Left), 3, 1),
+                mouse(MouseEventKind::Up(MouseButton::Left), 3, 1),
+            ],
+        );
+        assert_eq!(gestures, [Gesture::Tap]);
+    }
+
+    #[test]
+    fn moving_beyond_the_threshold_starts_a_drag_instead_of_a_tap() {
+        let mut recognizer = GestureRecognizer {
+            drag_threshold: 2,
+            ..Default::default()
+        };
+        let gestures = recognize(
+            &mut recognizer,
+            &[
+                mouse(MouseEventKind::Down(MouseButton::Left), 3, 1),
+                mouse(MouseEventKind::Drag(MouseButton::Left), 4, 1),
+                mouse(MouseEventKind::Drag(MouseButton::Left), 5, 1),
+                mouse(MouseEventKind::Drag(MouseButton::Left), 5, 3),
+                mouse(MouseEventKind::Up(MouseButton::Left), 5, 3),
+            ],
+        );
+        let phases: Vec<_> = gestures
+            .iter()
+            .map(|gesture| match gesture {
+                Gesture::Drag(drag) => (drag.phase, drag.offset_x, drag.offset_y),
+                _ => panic!("unexpected gesture {gesture:?}"),
+            })
+            .collect();
+        assert_eq!(
+            phases,
+            [
+                (DragPhase::Start, 2, 0),
+                (DragPhase::Move, 2, 2),
+                (DragPhase::End, 2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn losing_focus_cancels_a_drag() {
+        let gestures = recognize(
+            &mut GestureRecognizer::default(),
+            &[
+                mouse(MouseEventKind::Down(MouseButton::Left), 0, 0),
+                mouse(MouseEventKind::Drag(MouseButton::Left), 1, 0),
+                Event::FocusLost,
+                mouse(MouseEventKind::Up(MouseButton::Left), 1, 0),
+            ],
+        );
+        assert!(matches!(
+            gestures.as_slice(),
+            [
+                Gesture::Drag(DragEvent {
+                    phase: DragPhase::Start,
+                    ..
+                }),
+                Gesture::Drag(DragEvent {
+                    phase: DragPhase::Cancel,
+                    ..
+                })
+            ]
+        ));
+    }
+}
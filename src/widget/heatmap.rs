@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use crossterm::event::MouseEventKind;
+use ratatui::style::{Color, Style};
+use unicode_width::UnicodeWidthStr;
+
+use super::{
+    core::PaintCx, BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx,
+    RawMouseEvent, Widget,
+};
+use crate::{geometry::Size, HeatmapTheme};
+
+/// Each value takes one cell and is separated by one cell.
+const CELL_WIDTH: usize = 2;
+const CELL_SYMBOL: &str = "■";
+
+pub(crate) type TooltipFn = Arc<dyn Fn(usize, usize, f64) -> String + Send + Sync>;
+
+/// A grid of colored cells, one for each value, with a tooltip showing the value under the mouse.
+pub struct Heatmap {
+    values: Vec<Vec<f64>>,
+    colors: Vec<Color>,
+    range: Option<(f64, f64)>,
+    tooltip: TooltipFn,
+    styles: HeatmapTheme,
+    /// The (row, column) of the value under the mouse.
+    hovered: Option<(usize, usize)>,
+}
+
+impl Heatmap {
+    pub(crate) fn new(
+        values: Vec<Vec<f64>>,
+        colors: Vec<Color>,
+        range: Option<(f64, f64)>,
+        tooltip: TooltipFn,
+        styles: HeatmapTheme,
+    ) -> Self {
+        Heatmap {
+            values,
+            colors,
+            range,
+            tooltip,
+            styles,
+            hovered: None,
+        }
+    }
+
+    pub(crate) fn set_values(&mut self, values: &[Vec<f64>]) -> ChangeFlags {
+        if self.values != values {
+            let mut changeflags = ChangeFlags::PAINT;
+            if self.values.len() != values.len() || self.columns() != columns(values) {
+                changeflags |= ChangeFlags::LAYOUT;
+            }
+            self.values = values.to_vec();
+            changeflags
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_colors(&mut self, colors: &[Color]) -> ChangeFlags {
+        if self.colors != colors {
+            self.colors = colors.to_vec();
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_range(&mut self, range: Option<(f64, f64)>) -> ChangeFlags {
+        if self.range != range {
+            self.range = range;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The tooltip function can't be compared, so the tooltip is only painted again, when it's shown.
+    pub(crate) fn set_tooltip(&mut self, tooltip: TooltipFn) -> ChangeFlags {
+        self.tooltip = tooltip;
+        if self.hovered.is_some() {
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: HeatmapTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn columns(&self) -> usize {
+        columns(&self.values)
+    }
+
+    fn value(&self, row: usize, column: usize) -> Option<f64> {
+        let value = *self.values.get(row)?.get(column)?;
+        (!value.is_nan()).then_some(value)
+    }
+
+    /// The range of the color scale, from the smallest to the largest value, if it isn't set.
+    fn range(&self) -> (f64, f64) {
+        self.range.unwrap_or_else(|| {
+            self.values
+                .iter()
+                .flatten()
+                .filter(|value| !value.is_nan())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                    (min.min(value), max.max(value))
+                })
+        })
+    }
+
+    /// The color of `value` on the color scale.
+    fn color(&self, value: f64, (min, max): (f64, f64)) -> Option<Color> {
+        let last = self.colors.len().checked_sub(1)?;
+        let level = if max > min {
+            ((value - min) / (max - min) * last as f64).round()
+        } else {
+            last as f64
+        };
+        Some(self.colors[level.clamp(0.0, last as f64) as usize])
+    }
+
+    fn paint_tooltip(&self, cx: &mut PaintCx, row: usize, column: usize, value: f64) {
+        let text = format!(" {} ", (self.tooltip)(row, column, value));
+        let size = cx.cell_size();
+        let (width, height) = (size.width as usize, size.height as usize);
+        // above the hovered value, or below it in the first row
+        let y = if row > 0 || height < 2 {
+            row.saturating_sub(1)
+        } else {
+            row + 1
+        };
+        let x = (column * CELL_WIDTH).min(width.saturating_sub(text.width()));
+        cx.set_string((x as f64, y as f64), &text, self.styles.tooltip);
+    }
+}
+
+fn columns(values: &[Vec<f64>]) -> usize {
+    values.iter().map(Vec::len).max().unwrap_or(0)
+}
+
+impl Widget for Heatmap {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let range = self.range();
+        for (row, values) in self.values.iter().enumerate() {
+            for (column, &value) in values.iter().enumerate() {
+                if value.is_nan() {
+                    continue;
+                }
+                if let Some(color) = self.color(value, range) {
+                    let position = ((column * CELL_WIDTH) as f64, row as f64);
+                    cx.set_string(position, CELL_SYMBOL, Style::default().fg(color));
+                }
+            }
+        }
+        if let Some((row, column)) = self.hovered {
+            if let Some(value) = self.value(row, column) {
+                self.paint_tooltip(cx, row, column, value);
+            }
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let width = (self.columns() * CELL_WIDTH).saturating_sub(1);
+        bc.constrain(Size::new(width as f64, self.values.len() as f64))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        if let Event::Mouse(RawMouseEvent {
+            kind: MouseEventKind::Moved,
+            column,
+            row,
+            ..
+        }) = event
+        {
+            let (x, y) = (*column as usize, *row as usize);
+            let hovered = (cx.is_hot() && x % CELL_WIDTH == 0)
+                .then_some((y, x / CELL_WIDTH))
+                .filter(|&(row, column)| self.value(row, column).is_some());
+            if hovered != self.hovered {
+                self.hovered = hovered;
+                cx.request_paint();
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::HotChanged(false) = event {
+            if self.hovered.take().is_some() {
+                cx.request_paint();
+            }
+        }
+    }
+}
@@ -1,14 +1,21 @@
-use crate::geometry::{Axis, Size};
+use crate::{
+    geometry::{Axis, Size},
+    CrossAxisAlignment, MainAxisAlignment,
+};
 
 use super::{
     core::{EventCx, PaintCx},
-    BoxConstraints, LayoutCx, Pod, Widget,
+    BoxConstraints, ChangeFlags, IntrinsicSize, LayoutCx, Pod, Widget,
 };
 
 pub struct LinearLayout {
     pub children: Vec<Pod>,
     pub spacing: f64,
     pub axis: Axis,
+    pub(crate) main_axis_alignment: MainAxisAlignment,
+    pub(crate) cross_axis_alignment: CrossAxisAlignment,
+    /// Whether the children are arranged from the end to the start of the main axis.
+    pub(crate) reverse: bool,
 }
 
 impl LinearLayout {
@@ -17,7 +24,144 @@ impl LinearLayout {
             children,
             axis,
             spacing,
+            main_axis_alignment: MainAxisAlignment::default(),
+            cross_axis_alignment: CrossAxisAlignment::default(),
+            reverse: false,
+        }
+    }
+
+    pub(crate) fn set_spacing(&mut self, spacing: f64) -> ChangeFlags {
+        if self.spacing != spacing {
+            self.spacing = spacing;
+            ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_main_axis_alignment(&mut self, alignment: MainAxisAlignment) -> ChangeFlags {
+        if self.main_axis_alignment != alignment {
+            self.main_axis_alignment = alignment;
+            ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_cross_axis_alignment(
+        &mut self,
+        alignment: CrossAxisAlignment,
+    ) -> ChangeFlags {
+        if self.cross_axis_alignment != alignment {
+            self.cross_axis_alignment = alignment;
+            ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_reverse(&mut self, reverse: bool) -> ChangeFlags {
+        if self.reverse != reverse {
+            self.reverse = reverse;
+            ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The indices of the children in the order they are arranged along the main axis, without placeholders.
+    fn arranged_indices(&self) -> Vec<usize> {
+        let indices =
+            (0..self.children.len()).filter(|&index| !self.children[index].is_placeholder());
+        if self.reverse {
+            indices.rev().collect()
+        } else {
+            indices.collect()
+        }
+    }
+
+    /// Shares the space on the main axis between the children by their intrinsic sizes, when they don't all fit,
+    /// so that e.g. wrapped text in a `h_stack` is wrapped to its share of the width (and gets the height of its lines),
+    /// instead of taking all the space from the children after it.
+    ///
+    /// Each child gets at least its minimum size, the remaining space is distributed in proportion to how much
+    /// each child can grow beyond its minimum size.
+    /// Returns the maximum main axis size for each child (by index), or `None` if they fit,
+    /// or if a child has no intrinsic size.
+    fn distribute(
+        &mut self,
+        cx: &mut LayoutCx,
+        major_max: f64,
+        minor_max: f64,
+    ) -> Option<Vec<f64>> {
+        let arranged = self.arranged_indices();
+        let mut sizes = vec![IntrinsicSize::default(); self.children.len()];
+        for &index in &arranged {
+            sizes[index] = self.children[index].measure(cx, self.axis, minor_max)?;
+        }
+        let available = major_max - self.spacing * (arranged.len() - 1) as f64;
+        let (min, max) = sizes.iter().fold((0.0, 0.0), |(min, max), size| {
+            (min + size.min, max + size.max)
+        });
+        if max <= available || min >= available {
+            return None;
+        }
+        let extra = available - min;
+        let mut remainder = extra;
+        let mut limits: Vec<f64> = sizes
+            .iter()
+            .map(|size| {
+                let share = (extra * (size.max - size.min) / (max - min)).floor();
+                remainder -= share;
+                size.min + share
+            })
+            .collect();
+        // the cells lost by rounding go to the first children, that can still grow
+        for &index in &arranged {
+            if remainder < 1.0 {
+                break;
+            }
+            if limits[index] < sizes[index].max {
+                limits[index] += 1.0;
+                remainder -= 1.0;
+            }
+        }
+        Some(limits)
+    }
+
+    /// Lays out the children one after another, each child gets the remaining space on the main axis,
+    /// or less if it's limited by `limits`.
+    ///
+    /// Returns the sizes of the children (by index) and the used space on the main axis.
+    fn layout_children(
+        &mut self,
+        cx: &mut LayoutCx,
+        mut child_bc: BoxConstraints,
+        major_max: f64,
+        limits: Option<&[f64]>,
+    ) -> (Vec<Size>, f64) {
+        let arranged = self.arranged_indices();
+        let child_count = arranged.len();
+        let mut sizes = vec![Size::ZERO; self.children.len()];
+        let mut major_used: f64 = 0.0;
+
+        for (position, index) in arranged.into_iter().enumerate() {
+            let bc = match limits {
+                Some(limits) => child_bc.shrink_max_to(
+                    self.axis,
+                    limits[index].min(self.axis.major(child_bc.max())),
+                ),
+                None => child_bc,
+            };
+            let size = self.children[index].layout(cx, &bc);
+            major_used += self.axis.major(size);
+            if position < child_count - 1 {
+                major_used += self.spacing;
+            }
+            child_bc = child_bc.shrink_max_to(self.axis, major_max - major_used);
+            sizes[index] = size;
         }
+        (sizes, major_used)
     }
 }
 
@@ -29,25 +173,74 @@ impl Widget for LinearLayout {
     }
 
     fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let child_count = self.arranged_indices().len();
+        if child_count == 0 {
+            return bc.constrain(Size::ZERO);
+        }
         let major_max = self.axis.major(*bc).end;
+        let minor_max = self.axis.minor(*bc).end;
+        let stretch = self.cross_axis_alignment == CrossAxisAlignment::Stretch;
+
         let mut child_bc = self.axis.with_major(bc.loosen(), 0.0..major_max);
-        let child_count = self.children.len();
+        if stretch && minor_max.is_finite() {
+            child_bc = self.axis.with_minor(child_bc, minor_max..minor_max);
+        }
+        let limits = if major_max.is_finite() {
+            self.distribute(cx, major_max, minor_max)
+        } else {
+            None
+        };
+        let (mut sizes, mut major_used) =
+            self.layout_children(cx, child_bc, major_max, limits.as_deref());
+        let mut max_minor = sizes
+            .iter()
+            .map(|size| self.axis.minor(*size))
+            .fold(0.0, f64::max);
 
-        let mut major_used: f64 = 0.0;
-        let mut max_minor: f64 = 0.0;
+        // Without a bounded cross axis, the children are stretched to the largest child
+        if stretch && !minor_max.is_finite() {
+            child_bc = self.axis.with_minor(child_bc, max_minor..max_minor);
+            (sizes, major_used) = self.layout_children(cx, child_bc, major_max, limits.as_deref());
+            max_minor = sizes
+                .iter()
+                .map(|size| self.axis.minor(*size))
+                .fold(0.0, f64::max);
+        }
 
-        for (index, child) in self.children.iter_mut().enumerate() {
-            let size = child.layout(cx, &child_bc);
-            child.set_origin(cx, self.axis.pack(major_used, 0.0));
-            major_used += self.axis.major(size);
-            if index < child_count - 1 {
-                major_used += self.spacing;
+        let fills_major = self.main_axis_alignment != MainAxisAlignment::Start;
+        let major_size = if fills_major && major_max.is_finite() {
+            major_max.max(major_used)
+        } else {
+            major_used
+        };
+        let size = bc.constrain(self.axis.pack::<Size>(major_size, max_minor));
+        let minor_size = self.axis.minor(size);
+
+        let extra = (self.axis.major(size) - major_used).max(0.0);
+        let (mut major_position, spacing) = match self.main_axis_alignment {
+            MainAxisAlignment::Start => (0.0, self.spacing),
+            MainAxisAlignment::Center => ((extra / 2.0).floor(), self.spacing),
+            MainAxisAlignment::End => (extra, self.spacing),
+            MainAxisAlignment::SpaceBetween if child_count > 1 => {
+                (0.0, self.spacing + extra / (child_count - 1) as f64)
             }
-            child_bc = child_bc.shrink_max_to(self.axis, major_max - major_used);
-            max_minor = max_minor.max(self.axis.minor(size));
+            MainAxisAlignment::SpaceBetween => (0.0, self.spacing),
+        };
+
+        for index in self.arranged_indices() {
+            let child_size = sizes[index];
+            let free_minor = (minor_size - self.axis.minor(child_size)).max(0.0);
+            let minor_position = match self.cross_axis_alignment {
+                CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
+                CrossAxisAlignment::Center => (free_minor / 2.0).floor(),
+                CrossAxisAlignment::End => free_minor,
+            };
+            self.children[index]
+                .set_origin(cx, self.axis.pack(major_position.round(), minor_position));
+            major_position += self.axis.major(child_size) + spacing;
         }
 
-        bc.constrain(self.axis.pack::<Size>(major_used, max_minor))
+        size
     }
 
     fn event(&mut self, cx: &mut EventCx, event: &super::Event) {
@@ -61,4 +254,130 @@ impl Widget for LinearLayout {
             child.lifecycle(cx, event);
         }
     }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        measure_stack(&mut self.children, cx, self.axis, self.spacing, axis, cross)
+    }
+}
+
+/// Measures children that are stacked along `stack_axis` with `spacing` in between.
+///
+/// The intrinsic sizes are summed up along the stack axis, and the largest is taken on the other axis.
+/// Returns `None`, if any of the children has no intrinsic size.
+pub(crate) fn measure_stack(
+    children: &mut [Pod],
+    cx: &mut LayoutCx,
+    stack_axis: Axis,
+    spacing: f64,
+    axis: Axis,
+    cross: f64,
+) -> Option<IntrinsicSize> {
+    let mut total = IntrinsicSize::default();
+    let mut count = 0;
+    for child in children.iter_mut().filter(|child| !child.is_placeholder()) {
+        count += 1;
+        let size = child.measure(cx, axis, cross)?;
+        if axis == stack_axis {
+            total = IntrinsicSize::new(total.min + size.min, total.max + size.max);
+        } else {
+            total = IntrinsicSize::new(total.min.max(size.min), total.max.max(size.max));
+        }
+    }
+    if axis == stack_axis && count > 1 {
+        total = total.expand(spacing * (count - 1) as f64);
+    }
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use super::*;
+    use crate::{geometry::Point, test_helper::TestCx, widget::Text};
+
+    fn layout(
+        axis: Axis,
+        texts: &[&'static str],
+        configure: impl FnOnce(&mut LinearLayout),
+        bc: BoxConstraints,
+    ) -> (Size, LinearLayout) {
+        let children = texts
+            .iter()
+            .map(|text| Pod::new(Text::new((*text).into(), Style::default())))
+            .collect();
+        let mut layout = LinearLayout::new(children, 0.0, axis);
+        configure(&mut layout);
+        let size = TestCx::new().layout_cx(|cx| layout.layout(cx, &bc));
+        (size, layout)
+    }
+
+    fn x_positions(layout: &LinearLayout) -> Vec<f64> {
+        layout
+            .children
+            .iter()
+            .map(|child| child.origin().x)
+            .collect()
+    }
+
+    #[test]
+    fn space_between_distributes_the_remaining_space() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(10.0, 1.0));
+        let space_between = |layout: &mut LinearLayout| {
+            layout.main_axis_alignment = MainAxisAlignment::SpaceBetween;
+        };
+        let (size, row) = layout(Axis::Horizontal, &["a", "bb", "c"], space_between, bc);
+        assert_eq!(size, Size::new(10.0, 1.0));
+        assert_eq!(x_positions(&row), [0.0, 4.0, 9.0]);
+
+        let (_, row) = layout(
+            Axis::Horizontal,
+            &["a", "bb", "c"],
+            |layout| {
+                space_between(layout);
+                layout.reverse = true;
+            },
+            bc,
+        );
+        assert_eq!(x_positions(&row), [9.0, 4.0, 0.0]);
+    }
+
+    #[test]
+    fn center_alignment_centers_the_arranged_children() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(10.0, 1.0));
+        let (_, row) = layout(
+            Axis::Horizontal,
+            &["a", "bb", "c"],
+            |layout| layout.main_axis_alignment = MainAxisAlignment::Center,
+            bc,
+        );
+        assert_eq!(x_positions(&row), [3.0, 4.0, 6.0]);
+
+        let (_, row) = layout(
+            Axis::Horizontal,
+            &["a", "bb", "c"],
+            |layout| {
+                layout.main_axis_alignment = MainAxisAlignment::Center;
+                layout.reverse = true;
+            },
+            bc,
+        );
+        assert_eq!(x_positions(&row), [6.0, 4.0, 3.0]);
+    }
+
+    #[test]
+    fn stretch_uses_the_widest_child_without_a_bounded_cross_axis() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(f64::INFINITY, 10.0));
+        let (size, column) = layout(
+            Axis::Vertical,
+            &["a", "bbb"],
+            |layout| layout.cross_axis_alignment = CrossAxisAlignment::Stretch,
+            bc,
+        );
+        assert_eq!(size, Size::new(3.0, 2.0));
+        let sizes: Vec<_> = column.children.iter().map(Pod::size).collect();
+        assert_eq!(sizes, [Size::new(3.0, 1.0), Size::new(3.0, 1.0)]);
+        let origins: Vec<_> = column.children.iter().map(Pod::origin).collect();
+        assert_eq!(origins, [Point::new(0.0, 0.0), Point::new(0.0, 1.0)]);
+    }
 }
@@ -1,37 +1,38 @@
 use crate::{
-    geometry::{Point, Size},
+    geometry::{Axis, Length, Point, Size},
     Position,
 };
 
 use super::{
+    animatables::AnimatableElement,
     core::{EventCx, LifeCycleCx, PaintCx},
-    BoxConstraints, ChangeFlags, Event, LayoutCx, LifeCycle, Pod, Widget,
+    BoxConstraints, ChangeFlags, Event, IntrinsicSize, LayoutCx, LifeCycle, Pod, Widget,
 };
 
-pub struct Margin {
+pub struct Margin<L = Length> {
     pub(crate) content: Pod,
-    amount: u16,
+    pub(crate) amount: L,
+    /// The current value of the (possibly animated) `amount`.
+    amount_value: Length,
     position: Position,
 }
 
-impl Margin {
-    pub(crate) fn new(content: impl Widget, position: Position, amount: u16) -> Self {
+impl<L: AnimatableElement<Length>> Margin<L> {
+    pub(crate) fn new(content: impl Widget, position: Position, amount: L) -> Self {
+        // a plain length is known right away, an animated one with the first animation frame (before the first layout)
+        let amount_value = amount
+            .as_any()
+            .downcast_ref::<Length>()
+            .copied()
+            .unwrap_or(Length::Cells(0));
         Margin {
             content: Pod::new(content),
             amount,
+            amount_value,
             position,
         }
     }
 
-    pub(crate) fn set_amount(&mut self, amount: u16) -> ChangeFlags {
-        if self.amount != amount {
-            self.amount = amount;
-            ChangeFlags::LAYOUT
-        } else {
-            ChangeFlags::empty()
-        }
-    }
-
     pub(crate) fn set_position(&mut self, position: Position) -> ChangeFlags {
         if self.position != position {
             self.position = position;
@@ -40,17 +41,18 @@ impl Margin {
             ChangeFlags::empty()
         }
     }
-}
 
-impl Widget for Margin {
-    fn paint(&mut self, cx: &mut PaintCx) {
-        self.content.paint(cx)
-    }
-
-    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+    /// Returns the origin of the content and the sum of the margins on both axes,
+    /// where percentages are relative to the `available` space.
+    fn insets(&self, available: Size) -> (Point, Size) {
         let margin = |position| {
+            let available = if Position::HORIZONTAL.contains(position) {
+                available.width
+            } else {
+                available.height
+            };
             if self.position.contains(position) {
-                self.amount as f64
+                self.amount_value.resolve(available).unwrap_or(0.0)
             } else {
                 0.0
             }
@@ -59,19 +61,45 @@ impl Widget for Margin {
         let margin_bottom = margin(Position::BOTTOM);
         let margin_left = margin(Position::LEFT);
         let margin_right = margin(Position::RIGHT);
-        let margin = Size::new(margin_left + margin_right, margin_top + margin_bottom);
+        (
+            Point::new(margin_left, margin_top),
+            Size::new(margin_left + margin_right, margin_top + margin_bottom),
+        )
+    }
+}
+
+impl<L: AnimatableElement<Length>> Widget for Margin<L> {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx)
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let (origin, margin) = self.insets(bc.max());
         let content_size = self.content.layout(cx, &bc.shrink(margin));
 
-        self.content
-            .set_origin(cx, Point::new(margin_left, margin_top));
+        self.content.set_origin(cx, origin);
         content_size + margin
     }
 
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let (_, margin) = self.insets(axis.pack(f64::INFINITY, cross));
+        let cross = (cross - axis.minor(margin)).max(0.0);
+        let content = self.content.measure(cx, axis, cross)?;
+        Some(content.expand(axis.major(margin)))
+    }
+
     fn event(&mut self, cx: &mut EventCx, event: &Event) {
         self.content.event(cx, event)
     }
 
     fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::Animate = event {
+            let amount_value = *self.amount.animate(cx);
+            if amount_value != self.amount_value {
+                cx.request_layout();
+                self.amount_value = amount_value;
+            }
+        }
         self.content.lifecycle(cx, event)
     }
 }
@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+
+use ratatui::style::Style;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::{
+    BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx,
+    PaintCx, Widget,
+};
+use crate::geometry::{Axis, Size};
+
+/// A single line of text, that scrolls horizontally in a loop, when it doesn't fit.
+pub struct Marquee {
+    text: Cow<'static, str>,
+    style: Style,
+    /// In cells per second.
+    speed: f64,
+    /// The space between the end and the (repeated) start of the text.
+    gap: usize,
+    pause_on_hover: bool,
+    /// The current scroll offset in cells.
+    offset: f64,
+}
+
+impl Marquee {
+    pub(crate) fn new(
+        text: Cow<'static, str>,
+        style: Style,
+        speed: f64,
+        gap: usize,
+        pause_on_hover: bool,
+    ) -> Self {
+        Marquee {
+            text,
+            style,
+            speed,
+            gap,
+            pause_on_hover,
+            offset: 0.0,
+        }
+    }
+
+    pub(crate) fn set_text(&mut self, text: Cow<'static, str>) -> ChangeFlags {
+        if self.text != text {
+            self.text = text;
+            self.offset = 0.0;
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_style(&mut self, style: Style) -> ChangeFlags {
+        if self.style != style {
+            self.style = style;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_speed(&mut self, speed: f64) -> ChangeFlags {
+        if self.speed != speed {
+            self.speed = speed;
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_gap(&mut self, gap: usize) -> ChangeFlags {
+        if self.gap != gap {
+            self.gap = gap;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_pause_on_hover(&mut self, pause_on_hover: bool) -> ChangeFlags {
+        if self.pause_on_hover != pause_on_hover {
+            self.pause_on_hover = pause_on_hover;
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The width of the text without line breaks.
+    fn text_width(&self) -> usize {
+        self.text.lines().map(|l| l.width()).sum()
+    }
+}
+
+impl Widget for Marquee {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let width = cx.cell_size().width as usize;
+        if width == 0 {
+            return;
+        }
+        let text_width = self.text_width();
+        let scrolls = text_width > width;
+        let gap = " ".repeat(if scrolls { self.gap } else { 0 });
+        let graphemes = self
+            .text
+            .lines()
+            .flat_map(|l| l.graphemes(true))
+            .chain(gap.graphemes(true));
+
+        // the text is repeated, so that the end is followed by the start again
+        let mut x = -(self.offset.floor() as isize);
+        let repeats = if scrolls { 2 } else { 1 };
+        for g in (0..repeats).flat_map(|_| graphemes.clone()) {
+            let w = g.width();
+            if x + w as isize > width as isize {
+                break;
+            }
+            if x >= 0 {
+                cx.set_string((x as f64, 0.0), g, self.style);
+            }
+            x += w as isize;
+        }
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let size = bc.constrain(Size::new(self.text_width() as f64, 1.0));
+        if self.text_width() as f64 > size.width {
+            cx.request_animation_update();
+        } else {
+            self.offset = 0.0;
+        }
+        size
+    }
+
+    fn measure(&mut self, _cx: &mut LayoutCx, axis: Axis, _cross: f64) -> Option<IntrinsicSize> {
+        Some(match axis {
+            // the text scrolls, so it can be shrunk to any width
+            Axis::Horizontal => IntrinsicSize::new(0.0, self.text_width() as f64),
+            Axis::Vertical => IntrinsicSize::fixed(1.0),
+        })
+    }
+
+    fn event(&mut self, _cx: &mut EventCx, _event: &Event) {}
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        match event {
+            LifeCycle::Animate => {
+                let text_width = self.text_width();
+                if text_width as f64 <= cx.size().width
+                    || (self.pause_on_hover && cx.is_hot())
+                    || self.speed == 0.0
+                    || cx.reduce_motion()
+                {
+                    return;
+                }
+                let period = (text_width + self.gap) as f64;
+                let offset =
+                    self.offset + self.speed * cx.time_since_last_render_request().as_secs_f64();
+                let offset = offset.rem_euclid(period);
+                if offset.floor() != self.offset.floor() {
+                    cx.request_paint();
+                }
+                self.offset = offset;
+                cx.request_animation_update();
+            }
+            // continue scrolling, when the mouse leaves the marquee
+            LifeCycle::HotChanged(false) if self.pause_on_hover => cx.request_animation_update(),
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use kurbo::Size;
+
+use super::{
+    core::{FocusRequest, IdPath},
+    BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx,
+    Message, PaintCx, Pod, Widget,
+};
+use crate::{geometry::Axis, Key, Mode};
+
+/// Routes the key events of its content by the current [`Mode`].
+///
+/// The key bindings of the current mode take precedence over the content,
+/// the index of a matching binding is sent to the view.
+/// Typed characters only reach the content in [`Mode::Insert`].
+pub struct Modal {
+    id_path: IdPath,
+    pub(crate) content: Pod,
+    mode: Mode,
+    bindings: Vec<(Mode, Key)>,
+    mounted: bool,
+}
+
+impl Modal {
+    pub(crate) fn new(
+        id_path: &IdPath,
+        content: impl Widget,
+        mode: Mode,
+        bindings: Vec<(Mode, Key)>,
+    ) -> Self {
+        Modal {
+            id_path: id_path.clone(),
+            content: Pod::new(content),
+            mode,
+            bindings,
+            mounted: false,
+        }
+    }
+
+    pub(crate) fn set_mode(&mut self, mode: Mode) -> ChangeFlags {
+        self.mode = mode;
+        ChangeFlags::empty()
+    }
+
+    pub(crate) fn set_bindings(&mut self, bindings: Vec<(Mode, Key)>) -> ChangeFlags {
+        self.bindings = bindings;
+        ChangeFlags::empty()
+    }
+}
+
+/// Whether `event` would insert a character into a text input, i.e. a printable character without `Ctrl` or `Alt`.
+///
+/// `Space` is excluded, so that e.g. buttons can still be pressed outside of the insert mode.
+fn is_typed_character(event: &KeyEvent) -> bool {
+    matches!(event.code, KeyCode::Char(c) if c != ' ')
+        && !event
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
+
+impl Widget for Modal {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.content.layout(cx, bc)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        if let Event::Key(key_event) = event {
+            if key_event.kind != KeyEventKind::Release {
+                let binding = self
+                    .bindings
+                    .iter()
+                    .position(|(mode, key)| *mode == self.mode && key.matches(key_event));
+                if let Some(index) = binding {
+                    cx.add_message(Message::new(self.id_path.clone(), index));
+                    cx.set_handled(true);
+                    return;
+                }
+            }
+            if self.mode != Mode::Insert && is_typed_character(key_event) {
+                return;
+            }
+        }
+        self.content.event(cx, event);
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if matches!(event, LifeCycle::BuildFocusChain) {
+            // Key events only reach focused widgets, so the modal itself is focused,
+            // when nothing else is, to receive the key bindings right from the start.
+            cx.register_for_focus();
+            if !self.mounted {
+                self.mounted = true;
+                let focus = &mut *cx.cx_state.focus;
+                if focus.focused.is_none() && focus.request.is_none() {
+                    focus.request = Some(FocusRequest::Focus(cx.widget_state.id));
+                }
+            }
+        }
+        self.content.lifecycle(cx, event);
+    }
+}
@@ -0,0 +1,287 @@
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
+
+use super::{
+    animatables::{AnimatableElement, LowPassIIR},
+    core::{IdPath, PaintCx, PodFlags},
+    linear_layout::measure_stack,
+    BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx,
+    Message, Pod, RawMouseEvent, Widget,
+};
+use crate::{
+    geometry::{Axis, Point, Size},
+    ReorderableListTheme,
+};
+
+/// The width of the drag handle in front of each row, including the space after it.
+const HANDLE_WIDTH: f64 = 2.0;
+const HANDLE_SYMBOL: &str = "⠿";
+
+/// The decay of the low-pass filter that animates the gap at the drop position.
+const GAP_DECAY: f64 = 0.3;
+
+/// A row, which is dragged with the mouse.
+#[derive(Debug, Clone, Copy)]
+struct Grab {
+    index: usize,
+    /// The row, before which the grabbed row is inserted, when it's dropped (or the number of rows to insert it at the end).
+    target: usize,
+}
+
+/// Rows with drag handles, which can be reordered by dragging the handles, or with `Alt` + `Up`/`Down`.
+///
+/// Sends the old and new index of a moved row as `(usize, usize)` to its view.
+pub struct ReorderableList {
+    id_path: IdPath,
+    pub(crate) children: Vec<Pod>,
+    styles: ReorderableListTheme,
+    /// The row, which is moved with the keyboard, when the list itself is focused.
+    cursor: usize,
+    grab: Option<Grab>,
+    /// The height of the gap at the drop position, which opens from 0 to 1 row.
+    gap: LowPassIIR<f64, f64>,
+    gap_height: f64,
+    /// The y position of the gap from the last layout.
+    gap_y: Option<f64>,
+}
+
+impl ReorderableList {
+    pub(crate) fn new(id_path: &IdPath, children: Vec<Pod>, styles: ReorderableListTheme) -> Self {
+        ReorderableList {
+            id_path: id_path.clone(),
+            children,
+            styles,
+            cursor: 0,
+            grab: None,
+            gap: LowPassIIR::new(0.0, GAP_DECAY),
+            gap_height: 0.0,
+            gap_y: None,
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: ReorderableListTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The row at `y`, and whether `y` is in its upper half.
+    fn row_at(&self, y: f64) -> Option<(usize, bool)> {
+        self.children.iter().enumerate().find_map(|(index, child)| {
+            let (top, height) = (child.origin().y, child.size().height);
+            (y >= top && y < top + height.max(1.0)).then_some((index, y < top + height / 2.0))
+        })
+    }
+
+    /// Opens the gap at `target` again (animated).
+    fn set_target(&mut self, cx: &mut EventCx, target: usize) {
+        if let Some(grab) = &mut self.grab {
+            if grab.target != target {
+                grab.target = target;
+                self.gap.set_value(0.0);
+                self.gap.target = 1.0;
+                cx.request_animation_update();
+                cx.request_layout();
+                cx.request_paint();
+            }
+        }
+    }
+
+    fn drop_grabbed(&mut self, cx: &mut EventCx) {
+        if let Some(Grab { index, target }) = self.grab.take() {
+            let to = if target > index { target - 1 } else { target };
+            if to != index {
+                self.move_row(cx, index, to);
+            }
+            self.gap.set_value(0.0);
+            self.gap.target = 0.0;
+            self.gap_height = 0.0;
+            cx.request_layout();
+            cx.request_paint();
+        }
+    }
+
+    fn move_row(&mut self, cx: &mut EventCx, from: usize, to: usize) {
+        self.cursor = to;
+        cx.add_message(Message::new(self.id_path.clone(), (from, to)));
+    }
+
+    /// The row, which is moved with the keyboard, i.e. the one containing the focused widget, or the cursor.
+    fn focused_row(&self, cx: &EventCx) -> Option<usize> {
+        if cx.is_focused() {
+            return (self.cursor < self.children.len()).then_some(self.cursor);
+        }
+        self.children
+            .iter()
+            .position(|child| child.state.flags.contains(PodFlags::HAS_FOCUS))
+    }
+}
+
+impl Widget for ReorderableList {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let is_focused = cx.is_focused();
+        let grabbed = self.grab.map(|grab| grab.index);
+        for (index, child) in self.children.iter_mut().enumerate() {
+            let mut style = self.styles.handle;
+            if is_focused && index == self.cursor {
+                style = style.patch(self.styles.focused);
+            }
+            if grabbed == Some(index) {
+                style = style.patch(self.styles.grabbed);
+                let override_style = cx.override_style;
+                cx.patch_style(self.styles.grabbed);
+                child.paint(cx);
+                cx.override_style = override_style;
+            } else {
+                child.paint(cx);
+            }
+            cx.set_cell((0.0, child.origin().y), HANDLE_SYMBOL, style);
+        }
+        if let Some(y) = self.gap_y {
+            let width = cx.size().width as usize;
+            cx.set_string((0.0, y), &"─".repeat(width), self.styles.indicator);
+        }
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let child_bc = bc.loosen().shrink((HANDLE_WIDTH, 0.0));
+        let gap = self.grab.map(|grab| (grab.target, self.gap_height.round()));
+        self.gap_y = None;
+        let (mut y, mut width) = (0.0, 0.0_f64);
+        for (index, child) in self.children.iter_mut().enumerate() {
+            if let Some((_, gap)) = gap.filter(|(target, _)| *target == index) {
+                self.gap_y = Some(y);
+                y += gap;
+            }
+            let size = child.layout(cx, &child_bc);
+            child.set_origin(cx, Point::new(HANDLE_WIDTH, y));
+            y += size.height;
+            width = width.max(size.width);
+        }
+        if let Some((_, gap)) = gap.filter(|(target, _)| *target == self.children.len()) {
+            self.gap_y = Some(y);
+            y += gap;
+        }
+        bc.constrain(Size::new(width + HANDLE_WIDTH, y))
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let size = measure_stack(&mut self.children, cx, Axis::Vertical, 0.0, axis, cross)?;
+        Some(match axis {
+            Axis::Horizontal => size.expand(HANDLE_WIDTH),
+            Axis::Vertical => size,
+        })
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        if self.grab.is_none() {
+            for child in &mut self.children {
+                child.event(cx, event);
+            }
+            if cx.is_handled() {
+                return;
+            }
+        }
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if cx.is_hot() && (*column as f64) < HANDLE_WIDTH => {
+                if let Some((index, _)) = self.row_at(*row as f64) {
+                    self.grab = Some(Grab {
+                        index,
+                        target: index,
+                    });
+                    self.cursor = index;
+                    cx.set_active(true);
+                    cx.capture_mouse();
+                    cx.request_focus();
+                    cx.request_paint();
+                    cx.set_handled(true);
+                }
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                row,
+                ..
+            }) if self.grab.is_some() => {
+                let target = match self.row_at(*row as f64) {
+                    Some((index, upper_half)) => index + !upper_half as usize,
+                    None if *row < 0 => 0,
+                    None => self.children.len(),
+                };
+                self.set_target(cx, target);
+                cx.set_handled(true);
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            }) if self.grab.is_some() => {
+                self.drop_grabbed(cx);
+                cx.set_active(false);
+                cx.release_mouse();
+                cx.set_handled(true);
+            }
+            Event::Key(KeyEvent {
+                code: code @ (KeyCode::Up | KeyCode::Down),
+                modifiers,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) => {
+                let Some(index) = self.focused_row(cx) else {
+                    return;
+                };
+                let to = match code {
+                    KeyCode::Up => index.checked_sub(1),
+                    _ => Some(index + 1).filter(|to| *to < self.children.len()),
+                };
+                if modifiers.contains(KeyModifiers::ALT) {
+                    if let Some(to) = to {
+                        self.move_row(cx, index, to);
+                    }
+                    cx.set_handled(true);
+                } else if cx.is_focused() {
+                    if let Some(to) = to {
+                        self.cursor = to;
+                        cx.request_paint();
+                    }
+                    cx.set_handled(true);
+                }
+            }
+            Event::FocusLost if self.grab.is_some() => {
+                // the drag is cancelled
+                self.grab = None;
+                self.gap_height = 0.0;
+                cx.set_active(false);
+                cx.release_mouse();
+                cx.request_layout();
+                cx.request_paint();
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        match event {
+            LifeCycle::BuildFocusChain => cx.register_for_focus(),
+            LifeCycle::Animate => {
+                let height = *self.gap.animate(cx);
+                if height != self.gap_height && self.grab.is_some() {
+                    self.gap_height = height;
+                    cx.request_layout();
+                    cx.request_paint();
+                }
+            }
+            _ => (),
+        }
+        for child in &mut self.children {
+            child.lifecycle(cx, event);
+        }
+    }
+}
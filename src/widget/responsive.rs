@@ -0,0 +1,72 @@
+use kurbo::Size;
+
+use crate::{geometry::Axis, Breakpoints, SizeClass};
+
+use super::{
+    core::IdPath, BoxConstraints, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx,
+    Message, PaintCx, Pod, Widget,
+};
+
+/// Notifies its view, when the terminal is resized across one of the breakpoints.
+pub struct Responsive {
+    pub(crate) content: Pod,
+    id_path: IdPath,
+    breakpoints: Breakpoints,
+    size_class: SizeClass,
+}
+
+impl Responsive {
+    pub(crate) fn new(
+        content: impl Widget,
+        id_path: &IdPath,
+        breakpoints: Breakpoints,
+        size_class: SizeClass,
+    ) -> Self {
+        Responsive {
+            content: Pod::new(content),
+            id_path: id_path.clone(),
+            breakpoints,
+            size_class,
+        }
+    }
+
+    pub(crate) fn set_size_class(
+        &mut self,
+        id_path: &IdPath,
+        breakpoints: Breakpoints,
+        size_class: SizeClass,
+    ) {
+        self.id_path.clone_from(id_path);
+        self.breakpoints = breakpoints;
+        self.size_class = size_class;
+    }
+}
+
+impl Widget for Responsive {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.content.layout(cx, bc)
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        self.content.lifecycle(cx, event);
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        if let Event::Resize { width, height } = event {
+            let size_class = self.breakpoints.size_class(*width, *height);
+            if size_class != self.size_class {
+                self.size_class = size_class;
+                cx.add_message(Message::new(self.id_path.clone(), ()));
+            }
+        }
+        self.content.event(cx, event);
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
+}
@@ -1,32 +1,244 @@
-use crossterm::event::MouseEventKind;
-use kurbo::Size;
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
+use kurbo::{Size, Vec2};
+
+use super::animatables::{AnimatableElement, LowPassIIR};
+use crate::{geometry::Axis, ScrollAxes, ScrollBehavior};
 
 use super::{
-    BoxConstraints, Canvas, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, PaintCx, Pod,
-    RawMouseEvent, Widget,
+    core::IdPath, BoxConstraints, Canvas, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle,
+    LifeCycleCx, Message, PaintCx, Pod, RawMouseEvent, Widget,
 };
 
+/// Notifies a view when the viewport of a [`ScrollView`] nears the end of its content.
+pub(crate) struct ReachEnd {
+    pub(crate) id_path: IdPath,
+    /// The number of remaining rows below the viewport, at which the view is notified.
+    pub(crate) threshold: f64,
+    /// The view is only notified again, after the remaining rows were above `2 * threshold + 1` in the meantime,
+    /// to avoid notifying multiple times, while e.g. more content is loaded.
+    armed: bool,
+}
+
 pub struct ScrollView {
     child: Pod,
-    offset: f64,
+    axes: ScrollAxes,
+    /// The size of the child, limited to the viewport, when it's unbounded.
+    content_size: Size,
+    /// The offset, which is currently shown.
+    offset: Vec2,
+    /// The offset, which is scrolled to, `offset` follows it with a smooth [`ScrollBehavior`].
+    target: Vec2,
+    behavior: ScrollBehavior,
+    /// Animates the offset on both axes towards the target.
+    animation: [LowPassIIR<f64, f64>; 2],
     scroll_speed: f64,
+    /// The number of rows scrolled with the page keys, `None` for the height of the viewport minus one row.
+    page_scroll: Option<f64>,
+    /// Whether the scroll view itself can be focused, to scroll with the keyboard.
+    focusable: bool,
     // TODO to avoid lifetime issues, this is a raw ratatui buffer, this should be a `Canvas` as some point though
     child_buffer: ratatui::buffer::Buffer,
+    reach_end: Option<ReachEnd>,
 }
 
 impl ScrollView {
     pub fn new(child: impl Widget + 'static) -> Self {
         ScrollView {
             child: Pod::new(child),
-            offset: 0.0,
+            axes: ScrollAxes::Vertical,
+            content_size: Size::ZERO,
+            offset: Vec2::ZERO,
+            target: Vec2::ZERO,
+            behavior: ScrollBehavior::default(),
+            animation: [0.0, 0.0].map(|offset| {
+                let mut animation = LowPassIIR::new(offset, ScrollBehavior::default().decay);
+                animation.set_value(offset);
+                animation
+            }),
             scroll_speed: 1.0,
+            page_scroll: None,
+            focusable: false,
             child_buffer: ratatui::buffer::Buffer::default(),
+            reach_end: None,
         }
     }
 
     pub fn child_mut(&mut self) -> &mut Pod {
         &mut self.child
     }
+
+    /// The size of the scrolled content, as it was laid out.
+    pub fn content_size(&self) -> Size {
+        self.content_size
+    }
+
+    /// The scroll offset on both axes.
+    pub fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    /// Sets the number of rows scrolled with the mouse wheel and the arrow keys, and with the page keys.
+    pub(crate) fn set_scroll_amounts(&mut self, line: f64, page: Option<f64>) -> ChangeFlags {
+        self.scroll_speed = line;
+        self.page_scroll = page;
+        ChangeFlags::empty()
+    }
+
+    pub(crate) fn set_focusable(&mut self, focusable: bool) -> ChangeFlags {
+        if self.focusable != focusable {
+            self.focusable = focusable;
+            ChangeFlags::tree_structure()
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_axes(&mut self, axes: ScrollAxes) -> ChangeFlags {
+        if self.axes != axes {
+            self.axes = axes;
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The largest offset on each axis, at which the viewport is still filled by the content.
+    fn max_offset(&self, viewport: Size) -> Vec2 {
+        let content = self.content_size;
+        Vec2::new(
+            (content.width - viewport.width).max(0.0),
+            (content.height - viewport.height).max(0.0),
+        )
+    }
+
+    pub(crate) fn set_behavior(&mut self, behavior: ScrollBehavior) -> ChangeFlags {
+        if self.behavior != behavior {
+            self.behavior = behavior;
+            for animation in &mut self.animation {
+                let _ = animation.set_decay(behavior.decay);
+            }
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// Scrolls to `offset`, which is clamped to the content, extended by `overscroll` on both ends.
+    ///
+    /// Returns whether the target offset has changed.
+    fn scroll_to(&mut self, cx: &mut EventCx, offset: Vec2, overscroll: f64) -> bool {
+        let max_offset = self.max_offset(cx.size());
+        let clamp = |offset: f64, max: f64| offset.max(-overscroll).min(max + overscroll);
+        let target = Vec2::new(clamp(offset.x, max_offset.x), clamp(offset.y, max_offset.y));
+        if target == self.target {
+            return false;
+        }
+        self.target = target;
+        if self.behavior.is_smooth() {
+            cx.request_animation_update();
+        } else {
+            self.set_offset(cx, target);
+        }
+        true
+    }
+
+    fn set_offset(&mut self, cx: &mut EventCx, offset: Vec2) {
+        if offset.round() != self.offset.round() {
+            cx.request_paint();
+            // the window origin of the child has changed
+            cx.view_context_changed();
+        }
+        self.offset = offset;
+        self.animation[0].set_value(offset.x);
+        self.animation[1].set_value(offset.y);
+    }
+
+    /// Moves the offset towards the target, and bounces back, when it's scrolled beyond the content.
+    fn animate(&mut self, cx: &mut LifeCycleCx) {
+        let [x, y] = &mut self.animation;
+        x.target = self.target.x;
+        y.target = self.target.y;
+        let offset = Vec2::new(*x.animate(cx), *y.animate(cx));
+
+        let max_offset = self.max_offset(cx.size());
+        let bounded = Vec2::new(
+            self.target.x.max(0.0).min(max_offset.x),
+            self.target.y.max(0.0).min(max_offset.y),
+        );
+        if bounded != self.target && (offset - self.target).hypot() < 0.5 {
+            self.target = bounded;
+            cx.request_animation_update();
+        }
+        if offset.round() != self.offset.round() {
+            cx.request_paint();
+            cx.view_context_changed();
+        }
+        self.offset = offset;
+    }
+
+    /// The new offset for a scroll key, `None` if the key doesn't scroll.
+    fn key_offset(&self, code: KeyCode, viewport: Size) -> Option<Vec2> {
+        let (horizontal, vertical) = (self.axes.horizontal(), self.axes.vertical());
+        // the page keys and Home/End scroll vertically, unless the scroll view only scrolls horizontally
+        let page_axis = if vertical {
+            Axis::Vertical
+        } else {
+            Axis::Horizontal
+        };
+        let page = self
+            .page_scroll
+            .unwrap_or((page_axis.major(viewport) - 1.0).max(1.0));
+        let line = self.scroll_speed;
+        let scrolled =
+            |axis: Axis, delta: f64| axis.map_major(self.target, |offset| offset + delta);
+        Some(match code {
+            KeyCode::Up if vertical => scrolled(Axis::Vertical, -line),
+            KeyCode::Down if vertical => scrolled(Axis::Vertical, line),
+            KeyCode::Left if horizontal => scrolled(Axis::Horizontal, -line),
+            KeyCode::Right if horizontal => scrolled(Axis::Horizontal, line),
+            KeyCode::PageUp => scrolled(page_axis, -page),
+            KeyCode::PageDown => scrolled(page_axis, page),
+            KeyCode::Home => page_axis.with_major(self.target, 0.0),
+            KeyCode::End => page_axis.with_major(self.target, f64::INFINITY),
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn set_reach_end(&mut self, id_path: &IdPath, threshold: f64) -> ChangeFlags {
+        match &mut self.reach_end {
+            Some(reach_end) => {
+                reach_end.id_path.clone_from(id_path);
+                reach_end.threshold = threshold;
+            }
+            None => {
+                self.reach_end = Some(ReachEnd {
+                    id_path: id_path.clone(),
+                    threshold,
+                    armed: true,
+                })
+            }
+        }
+        ChangeFlags::empty()
+    }
+
+    /// Checks whether the view has to be notified, that the viewport of `viewport_height` nears the end of the content.
+    ///
+    /// This is checked after scrolling, and after layout, e.g. for content that doesn't fill the viewport yet.
+    fn check_reach_end(&mut self, viewport_height: f64) -> Option<Message> {
+        let reach_end = self.reach_end.as_mut()?;
+        let remaining = self.content_size.height - viewport_height - self.target.y;
+        if remaining <= reach_end.threshold {
+            if reach_end.armed {
+                reach_end.armed = false;
+                return Some(Message::new(reach_end.id_path.clone(), ()));
+            }
+        } else if remaining > 2.0 * reach_end.threshold + 1.0 {
+            reach_end.armed = true;
+        }
+        None
+    }
 }
 
 // TODO: scroll bars
@@ -37,7 +249,8 @@ impl Widget for ScrollView {
         // TODO: scroll wheel + click-drag on scroll bars
         let child_event = if let Event::Mouse(mouse_event) = event {
             let mut mouse_event = *mouse_event;
-            mouse_event.row += self.offset.round() as i16;
+            mouse_event.column += self.offset.x.round() as i16;
+            mouse_event.row += self.offset.y.round() as i16;
             Event::Mouse(mouse_event)
         } else {
             event.clone()
@@ -45,43 +258,105 @@ impl Widget for ScrollView {
 
         self.child.event(cx, &child_event);
 
-        // Handle scroll wheel events
+        // Handle scroll wheel and keyboard events, which weren't handled by the child
         // TODO scroll left/right
         if !cx.is_handled() {
-            if let Event::Mouse(RawMouseEvent {
-                kind: kind @ (MouseEventKind::ScrollDown | MouseEventKind::ScrollUp),
-                ..
-            }) = event
-            {
-                let max_offset = (self.child.size().height - cx.size().height).max(0.0);
-                let y_delta = match kind {
-                    MouseEventKind::ScrollDown => self.scroll_speed,
-                    MouseEventKind::ScrollUp => -self.scroll_speed,
-                    _ => unreachable!(),
-                };
-
-                let new_offset = (self.offset + y_delta).max(0.0).min(max_offset);
-                if new_offset != self.offset {
-                    self.offset = new_offset;
-                    cx.set_handled(true);
-                    cx.request_paint();
+            match event {
+                Event::Mouse(RawMouseEvent {
+                    kind:
+                        kind @ (MouseEventKind::ScrollDown
+                        | MouseEventKind::ScrollUp
+                        | MouseEventKind::ScrollLeft
+                        | MouseEventKind::ScrollRight),
+                    modifiers,
+                    ..
+                }) => {
+                    let delta = match kind {
+                        MouseEventKind::ScrollDown | MouseEventKind::ScrollRight => {
+                            self.scroll_speed
+                        }
+                        _ => -self.scroll_speed,
+                    };
+                    // Shift + wheel scrolls horizontally, as well as the wheel of a scroll view, which only scrolls horizontally
+                    let horizontal = matches!(
+                        kind,
+                        MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight
+                    ) || modifiers.contains(KeyModifiers::SHIFT)
+                        || !self.axes.vertical();
+                    let axis = if horizontal {
+                        Axis::Horizontal
+                    } else {
+                        Axis::Vertical
+                    };
+                    let offset = axis.map_major(self.target, |offset| offset + delta);
+                    if self.scroll_to(cx, offset, self.behavior.overscroll) {
+                        cx.set_handled(true);
+                    }
                 }
+                Event::Mouse(RawMouseEvent {
+                    kind: MouseEventKind::Down(MouseButton::Left),
+                    ..
+                }) if self.focusable && cx.is_hot() && !cx.has_focus() => cx.request_focus(),
+                Event::Key(KeyEvent {
+                    code,
+                    kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                    ..
+                }) if cx.has_focus() => {
+                    if let Some(offset) = self.key_offset(*code, cx.size()) {
+                        // the key is handled even at the end, so that it doesn't scroll a surrounding scroll view
+                        cx.set_handled(true);
+                        self.scroll_to(cx, offset, 0.0);
+                    }
+                }
+                _ => (),
             }
         }
+
+        if let Some(message) = self.check_reach_end(cx.size().height) {
+            cx.add_message(message);
+        }
     }
 
     fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
-        self.child.lifecycle(cx, event);
+        if self.focusable && matches!(event, LifeCycle::BuildFocusChain) {
+            cx.register_for_focus();
+        }
+        if matches!(event, LifeCycle::Animate) && self.behavior.is_smooth() {
+            self.animate(cx);
+        }
+        if let LifeCycle::ViewContextChanged(view) = event {
+            // the child is scrolled up by the offset
+            let view = view.translate_to((-self.offset.round()).to_point());
+            self.child
+                .lifecycle(cx, &LifeCycle::ViewContextChanged(view));
+        } else {
+            self.child.lifecycle(cx, event);
+        }
     }
 
     fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
         cx.request_paint();
 
-        let cbc = BoxConstraints::new(
-            Size::new(0.0, 0.0),
-            Size::new(bc.max().width, f64::INFINITY),
-        );
-        let child_size = self.child.layout(cx, &cbc);
+        // the content is unbounded on the scrollable axes
+        let max_width = if self.axes.horizontal() {
+            f64::INFINITY
+        } else {
+            bc.max().width
+        };
+        let max_height = if self.axes.vertical() {
+            f64::INFINITY
+        } else {
+            bc.max().height
+        };
+        let cbc = BoxConstraints::new(Size::ZERO, Size::new(max_width, max_height));
+        let mut child_size = self.child.layout(cx, &cbc);
+        // content, which fills the space it's given, is limited to the viewport
+        if !child_size.width.is_finite() {
+            child_size.width = bc.max().width;
+        }
+        if !child_size.height.is_finite() {
+            child_size.height = bc.max().height;
+        }
         let child_rect = ratatui::layout::Rect::new(
             0,
             0,
@@ -89,15 +364,23 @@ impl Widget for ScrollView {
             child_size.height.round() as u16,
         );
         self.child_buffer.resize(child_rect);
+        self.content_size = child_size;
         let size = Size::new(
             child_size.width.min(bc.max().width),
             child_size.height.min(bc.max().height),
         );
 
         // Ensure that scroll offset is within bounds
-        let max_offset = (child_size.height - size.height).max(0.0);
-        if max_offset < self.offset {
-            self.offset = max_offset;
+        let max_offset = self.max_offset(size);
+        let clamp =
+            |offset: Vec2| Vec2::new(offset.x.min(max_offset.x), offset.y.min(max_offset.y));
+        self.offset = clamp(self.offset);
+        self.target = clamp(self.target);
+        self.animation[0].set_value(self.offset.x);
+        self.animation[1].set_value(self.offset.y);
+
+        if let Some(message) = self.check_reach_end(size.height) {
+            cx.add_message(message);
         }
 
         size
@@ -107,6 +390,10 @@ impl Widget for ScrollView {
         // TODO only repaint child, if it's really necessary (i.e. anything in child changed)
         self.child_buffer.reset();
         let mut child_canvas = Canvas::new(&mut self.child_buffer);
+        // the part of the viewport that is visible in the parent canvas, in the coordinates of the child buffer
+        let viewport = cx.canvas.viewport;
+        child_canvas.clip =
+            cx.canvas.clip.intersect(viewport) - viewport.origin().to_vec2() + self.offset.round();
         let mut cx_child = PaintCx {
             cx_state: cx.cx_state,
             widget_state: cx.widget_state,
@@ -115,6 +402,26 @@ impl Widget for ScrollView {
         };
         self.child.paint(&mut cx_child);
         cx.canvas
-            .blit_with_offset(&child_canvas, (0.0, -self.offset));
+            .blit_with_offset(&child_canvas, -self.offset.round());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use super::*;
+    use crate::{test_helper::TestCx, widget::Text};
+
+    #[test]
+    fn empty_content_reaches_the_end_once() {
+        let mut scroll_view = ScrollView::new(Text::new("".into(), Style::default()));
+        let _ = scroll_view.set_reach_end(&IdPath::new(), 3.0);
+        let mut root = Pod::new(scroll_view);
+        let mut cx = TestCx::new();
+        // without any mouse or key event
+        cx.layout(&mut root, Size::new(20.0, 10.0));
+        cx.layout(&mut root, Size::new(20.0, 10.0));
+        assert_eq!(cx.take_messages::<()>().len(), 1);
     }
 }
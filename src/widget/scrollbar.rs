@@ -0,0 +1,221 @@
+use crossterm::event::{MouseButton, MouseEventKind};
+
+use super::{
+    core::{IdPath, PaintCx},
+    BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    RawMouseEvent, Widget,
+};
+use crate::{
+    geometry::{Axis, Size},
+    ScrollbarTheme,
+};
+
+/// The length of the scrollbar, when it isn't bounded by its parent.
+const DEFAULT_SCROLLBAR_LENGTH: f64 = 10.0;
+
+/// A scrollbar for a viewport, which is managed elsewhere.
+///
+/// It sends the new position (as `f64` from `0.0` to `1.0`) to its view, when the thumb is dragged,
+/// the track is clicked (which scrolls by a page), or the mouse wheel is used over it.
+pub struct Scrollbar {
+    id_path: IdPath,
+    axis: Axis,
+    position: f64,
+    page_size: f64,
+    styles: ScrollbarTheme,
+    /// The cell within the thumb, where it was grabbed.
+    grab: Option<f64>,
+}
+
+impl Scrollbar {
+    pub(crate) fn new(
+        id_path: &IdPath,
+        axis: Axis,
+        position: f64,
+        page_size: f64,
+        styles: ScrollbarTheme,
+    ) -> Self {
+        Scrollbar {
+            id_path: id_path.clone(),
+            axis,
+            position: position.clamp(0.0, 1.0),
+            page_size: page_size.clamp(0.0, 1.0),
+            styles,
+            grab: None,
+        }
+    }
+
+    pub(crate) fn set_axis(&mut self, axis: Axis) -> ChangeFlags {
+        if self.axis != axis {
+            self.axis = axis;
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_position(&mut self, position: f64, page_size: f64) -> ChangeFlags {
+        let (position, page_size) = (position.clamp(0.0, 1.0), page_size.clamp(0.0, 1.0));
+        if self.position != position || self.page_size != page_size {
+            self.position = position;
+            self.page_size = page_size;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: ScrollbarTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn send_position(&self, cx: &mut EventCx, position: f64) {
+        let position = position.clamp(0.0, 1.0);
+        if position != self.position {
+            cx.add_message(Message::new(self.id_path.clone(), position));
+        }
+    }
+
+    /// The change of the position, when scrolled by a page.
+    fn page_step(&self) -> f64 {
+        if self.page_size < 1.0 {
+            self.page_size / (1.0 - self.page_size)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The start and length (in cells) of the thumb in a track of `length` cells.
+fn thumb(length: f64, position: f64, page_size: f64) -> (f64, f64) {
+    let thumb_length = (page_size * length).round().clamp(1.0, length.max(1.0));
+    ((position * (length - thumb_length)).round(), thumb_length)
+}
+
+/// The position, at which the thumb starts at `start`.
+fn position_at(length: f64, page_size: f64, start: f64) -> f64 {
+    let (_, thumb_length) = thumb(length, 0.0, page_size);
+    let range = length - thumb_length;
+    if range > 0.0 {
+        (start / range).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+impl Widget for Scrollbar {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let length = self.axis.major(cx.size()).round();
+        let (start, thumb_length) = thumb(length, self.position, self.page_size);
+        let (track, thumb_symbol) = match self.axis {
+            Axis::Horizontal => ("─", "▀"),
+            Axis::Vertical => ("│", "█"),
+        };
+        let mut thumb_style = self.styles.thumb;
+        if self.grab.is_some() {
+            thumb_style = thumb_style.patch(self.styles.dragged);
+        }
+        for i in 0..length as usize {
+            let i = i as f64;
+            let position = match self.axis {
+                Axis::Horizontal => (i, 0.0),
+                Axis::Vertical => (0.0, i),
+            };
+            if i >= start && i < start + thumb_length {
+                cx.set_cell(position, thumb_symbol, thumb_style);
+            } else {
+                cx.set_cell(position, track, self.styles.track);
+            }
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let max = self.axis.major(bc.max());
+        let length = if max.is_finite() {
+            max
+        } else {
+            DEFAULT_SCROLLBAR_LENGTH
+        };
+        bc.constrain(match self.axis {
+            Axis::Horizontal => Size::new(length, 1.0),
+            Axis::Vertical => Size::new(1.0, length),
+        })
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        let Event::Mouse(mouse @ RawMouseEvent { kind, .. }) = event else {
+            return;
+        };
+        let length = self.axis.major(cx.size()).round();
+        let at = self.axis.major(mouse.position()) as f64;
+        match kind {
+            MouseEventKind::Down(MouseButton::Left) if cx.is_hot() => {
+                let (start, thumb_length) = thumb(length, self.position, self.page_size);
+                if at < start {
+                    self.send_position(cx, self.position - self.page_step());
+                } else if at >= start + thumb_length {
+                    self.send_position(cx, self.position + self.page_step());
+                } else {
+                    self.grab = Some(at - start);
+                    cx.set_active(true);
+                    // keep dragging, even when the mouse leaves the scrollbar
+                    cx.capture_mouse();
+                    cx.request_paint();
+                }
+                cx.set_handled(true);
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                if let Some(grab) = self.grab {
+                    self.send_position(cx, position_at(length, self.page_size, at - grab));
+                    cx.set_handled(true);
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.grab.is_some() => {
+                self.grab = None;
+                cx.set_active(false);
+                cx.release_mouse();
+                cx.request_paint();
+                cx.set_handled(true);
+            }
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown if cx.is_hot() => {
+                let (_, thumb_length) = thumb(length, self.position, self.page_size);
+                // by one cell of the thumb
+                let step = 1.0 / (length - thumb_length).max(1.0);
+                let step = if *kind == MouseEventKind::ScrollUp {
+                    -step
+                } else {
+                    step
+                };
+                self.send_position(cx, self.position + step);
+                cx.set_handled(true);
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, _cx: &mut LifeCycleCx, _event: &LifeCycle) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_thumb_covers_the_visible_part() {
+        // 20 of 100 rows are visible in a track of 10 cells
+        assert_eq!(thumb(10.0, 0.0, 0.2), (0.0, 2.0));
+        assert_eq!(thumb(10.0, 1.0, 0.2), (8.0, 2.0));
+        assert_eq!(thumb(10.0, 0.5, 0.2), (4.0, 2.0));
+        // the thumb is at least one cell long
+        assert_eq!(thumb(10.0, 1.0, 0.01), (9.0, 1.0));
+        assert_eq!(position_at(10.0, 0.2, 4.0), 0.5);
+        assert_eq!(position_at(10.0, 0.2, 12.0), 1.0);
+        // everything is visible
+        assert_eq!(position_at(10.0, 1.0, 3.0), 0.0);
+    }
+}
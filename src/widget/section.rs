@@ -0,0 +1,77 @@
+use crate::geometry::{Point, Size};
+
+use super::{
+    core::{EventCx, LifeCycleCx, PaintCx},
+    BoxConstraints, Event, LayoutCx, LifeCycle, Pod, Widget,
+};
+
+/// A header above some content, the header sticks to the top of the visible area (e.g. of a [`ScrollView`](super::ScrollView)),
+/// while the content is visible.
+pub struct Section {
+    pub(crate) header: Pod,
+    pub(crate) content: Pod,
+}
+
+impl Section {
+    pub(crate) fn new(header: impl Widget, content: impl Widget) -> Self {
+        Section {
+            header: Pod::new(header),
+            content: Pod::new(content),
+        }
+    }
+}
+
+impl Widget for Section {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx);
+
+        // Pin the header to the top of the visible area, but never beyond the end of the section,
+        // so that the header of the next section pushes it out.
+        let header_height = self.header.size().height;
+        let visible_top = cx.canvas.clip.y0 - cx.canvas.viewport.y0;
+        let max_y = (cx.size().height - header_height).max(0.0);
+        let header_y = visible_top.clamp(0.0, max_y).round();
+        // The origin is updated directly (and not during layout), as it depends on the scroll offset,
+        // this also keeps mouse events on the pinned header consistent with what is drawn.
+        self.header.state.origin = Point::new(0.0, header_y);
+        if header_y > 0.0 {
+            // clear the content below the pinned header
+            let width = cx.cell_size().width as usize;
+            for y in 0..header_height.round() as usize {
+                for x in 0..width {
+                    if let Some(cell) = cx.cell_mut((x as f64, header_y + y as f64)) {
+                        cell.reset();
+                    }
+                }
+            }
+        }
+        self.header.paint(cx);
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let header_size = self.header.layout(cx, &bc.loosen());
+        let content_bc = bc.loosen().shrink((0.0, header_size.height));
+        let content_size = self.content.layout(cx, &content_bc);
+
+        self.header.set_origin(cx, Point::ORIGIN);
+        self.content
+            .set_origin(cx, Point::new(0.0, header_size.height));
+        bc.constrain(Size::new(
+            header_size.width.max(content_size.width),
+            header_size.height + content_size.height,
+        ))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        // The header is drawn above the content, so it gets the events first
+        self.header.event(cx, event);
+        if !cx.is_handled() {
+            self.content.event(cx, event);
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        self.header.lifecycle(cx, event);
+        self.content.lifecycle(cx, event);
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::BTreeSet;
+
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
+
+use super::{
+    core::{IdPath, PaintCx},
+    linear_layout::measure_stack,
+    BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx,
+    Message, Pod, RawMouseEvent, Widget,
+};
+use crate::{
+    geometry::{Axis, Point, Size},
+    SelectableListTheme,
+};
+
+/// The width of the checkbox in front of each row, including the space after it.
+const CHECKBOX_WIDTH: f64 = 4.0;
+const CHECKED: &str = "[x]";
+const UNCHECKED: &str = "[ ]";
+
+/// Rows with a checkbox column, of which several can be selected.
+///
+/// Sends the new selection as `BTreeSet<usize>` of row indices to its view, when it's changed.
+pub struct SelectableList {
+    id_path: IdPath,
+    pub(crate) children: Vec<Pod>,
+    styles: SelectableListTheme,
+    selection: BTreeSet<usize>,
+    /// The row, which is toggled with `Space`, when the list is focused.
+    cursor: usize,
+    /// The row, where the range extended with `Shift` started, and the selection before the range.
+    anchor: Option<(usize, BTreeSet<usize>)>,
+}
+
+impl SelectableList {
+    pub(crate) fn new(
+        id_path: &IdPath,
+        children: Vec<Pod>,
+        selection: BTreeSet<usize>,
+        styles: SelectableListTheme,
+    ) -> Self {
+        SelectableList {
+            id_path: id_path.clone(),
+            children,
+            styles,
+            selection,
+            cursor: 0,
+            anchor: None,
+        }
+    }
+
+    pub(crate) fn set_selection(&mut self, selection: BTreeSet<usize>) -> ChangeFlags {
+        if self.selection != selection {
+            self.selection = selection;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: SelectableListTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The row at `y`.
+    fn row_at(&self, y: f64) -> Option<usize> {
+        self.children.iter().position(|child| {
+            let (top, height) = (child.origin().y, child.size().height);
+            y >= top && y < top + height.max(1.0)
+        })
+    }
+
+    fn change_selection(&mut self, cx: &mut EventCx, selection: BTreeSet<usize>) {
+        if selection != self.selection {
+            cx.add_message(Message::new(self.id_path.clone(), selection));
+        }
+    }
+
+    fn toggle(&mut self, cx: &mut EventCx, index: usize) {
+        let mut selection = self.selection.clone();
+        if !selection.remove(&index) {
+            selection.insert(index);
+        }
+        self.anchor = None;
+        self.change_selection(cx, selection);
+    }
+
+    /// Selects the rows from the anchor (or the cursor) to `index` in addition to the rows selected before the range.
+    fn extend_to(&mut self, cx: &mut EventCx, index: usize) {
+        let (start, base) = self
+            .anchor
+            .get_or_insert_with(|| (self.cursor, self.selection.clone()));
+        let range = (*start).min(index)..=(*start).max(index);
+        let selection = base.iter().copied().chain(range).collect();
+        self.cursor = index;
+        self.change_selection(cx, selection);
+    }
+}
+
+impl Widget for SelectableList {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let is_focused = cx.is_focused();
+        for (index, child) in self.children.iter_mut().enumerate() {
+            let selected = self.selection.contains(&index);
+            let mut style = self.styles.checkbox;
+            if selected {
+                style = style.patch(self.styles.selected);
+                let override_style = cx.override_style;
+                cx.patch_style(self.styles.selected);
+                child.paint(cx);
+                cx.override_style = override_style;
+            } else {
+                child.paint(cx);
+            }
+            if is_focused && index == self.cursor {
+                style = style.patch(self.styles.focused);
+            }
+            let checkbox = if selected { CHECKED } else { UNCHECKED };
+            cx.set_string((0.0, child.origin().y), checkbox, style);
+        }
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let child_bc = bc.loosen().shrink((CHECKBOX_WIDTH, 0.0));
+        let (mut y, mut width) = (0.0, 0.0_f64);
+        for child in &mut self.children {
+            let size = child.layout(cx, &child_bc);
+            child.set_origin(cx, Point::new(CHECKBOX_WIDTH, y));
+            y += size.height;
+            width = width.max(size.width);
+        }
+        bc.constrain(Size::new(width + CHECKBOX_WIDTH, y))
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let size = measure_stack(&mut self.children, cx, Axis::Vertical, 0.0, axis, cross)?;
+        Some(match axis {
+            Axis::Horizontal => size.expand(CHECKBOX_WIDTH),
+            Axis::Vertical => size,
+        })
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        for child in &mut self.children {
+            child.event(cx, event);
+        }
+        if cx.is_handled() {
+            return;
+        }
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                modifiers,
+                ..
+            }) if cx.is_hot() => {
+                let Some(index) = self.row_at(*row as f64) else {
+                    return;
+                };
+                if modifiers.contains(KeyModifiers::SHIFT) {
+                    self.extend_to(cx, index);
+                } else if (*column as f64) < CHECKBOX_WIDTH {
+                    self.toggle(cx, index);
+                }
+                self.cursor = index;
+                cx.request_focus();
+                cx.request_paint();
+                cx.set_handled(true);
+            }
+            Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) if cx.is_focused() && !self.children.is_empty() => {
+                let last = self.children.len() - 1;
+                let to = match code {
+                    KeyCode::Up => self.cursor.saturating_sub(1),
+                    KeyCode::Down => (self.cursor + 1).min(last),
+                    KeyCode::Home => 0,
+                    KeyCode::End => last,
+                    KeyCode::Char(' ') => {
+                        self.toggle(cx, self.cursor);
+                        cx.set_handled(true);
+                        return;
+                    }
+                    KeyCode::Char('a' | 'A') if modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.anchor = None;
+                        self.change_selection(cx, (0..=last).collect());
+                        cx.set_handled(true);
+                        return;
+                    }
+                    _ => return,
+                };
+                if modifiers.contains(KeyModifiers::SHIFT) {
+                    self.extend_to(cx, to);
+                } else {
+                    self.anchor = None;
+                    self.cursor = to;
+                }
+                cx.request_paint();
+                cx.set_handled(true);
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::BuildFocusChain = event {
+            cx.register_for_focus();
+            self.cursor = self.cursor.min(self.children.len().saturating_sub(1));
+        }
+        for child in &mut self.children {
+            child.lifecycle(cx, event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use super::*;
+    use crate::{test_helper::TestCx, widget::Text};
+
+    /// Sends the `keys` to the focused `list`, and returns the selections sent to the view.
+    fn send_keys(
+        list: &mut SelectableList,
+        keys: &[(KeyCode, KeyModifiers)],
+    ) -> Vec<BTreeSet<usize>> {
+        let mut cx = TestCx::focused();
+        let mut selections = Vec::new();
+        for &(code, modifiers) in keys {
+            cx.event_cx(|cx| list.event(cx, &Event::Key(KeyEvent::new(code, modifiers))));
+            // the app state is updated with every change
+            for selection in cx.take_messages::<BTreeSet<usize>>() {
+                list.selection = selection.clone();
+                selections.push(selection);
+            }
+        }
+        selections
+    }
+
+    #[test]
+    fn shift_extends_the_range_from_the_anchor() {
+        let children = (0..5)
+            .map(|_| Pod::new(Text::new("row".into(), Style::default())))
+            .collect();
+        let mut list = SelectableList::new(
+            &IdPath::new(),
+            children,
+            BTreeSet::new(),
+            SelectableListTheme::default(),
+        );
+        let (none, shift) = (KeyModifiers::NONE, KeyModifiers::SHIFT);
+        let selections = send_keys(
+            &mut list,
+            &[
+                (KeyCode::Char(' '), none),
+                (KeyCode::Down, none),
+                (KeyCode::Down, none),
+                (KeyCode::Down, shift),
+                (KeyCode::Down, shift),
+                (KeyCode::Up, shift),
+                (KeyCode::Char('a'), KeyModifiers::CONTROL),
+            ],
+        );
+        assert_eq!(
+            selections,
+            [
+                BTreeSet::from([0]),
+                BTreeSet::from([0, 2, 3]),
+                BTreeSet::from([0, 2, 3, 4]),
+                BTreeSet::from([0, 2, 3]),
+                BTreeSet::from([0, 1, 2, 3, 4]),
+            ]
+        );
+    }
+}
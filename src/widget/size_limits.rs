@@ -0,0 +1,87 @@
+use crate::geometry::{Axis, Length, Size};
+
+use super::{
+    core::{EventCx, LifeCycleCx, PaintCx},
+    BoxConstraints, ChangeFlags, Event, IntrinsicSize, LayoutCx, LifeCycle, Pod, Widget,
+};
+
+/// Limits the size of its content to a min and max (width, height), within the constraints of the parent.
+pub struct SizeLimits {
+    pub(crate) content: Pod,
+    min: (Length, Length),
+    max: (Length, Length),
+}
+
+impl SizeLimits {
+    pub(crate) fn new(content: impl Widget, min: (Length, Length), max: (Length, Length)) -> Self {
+        SizeLimits {
+            content: Pod::new(content),
+            min,
+            max,
+        }
+    }
+
+    pub(crate) fn set_limits(
+        &mut self,
+        min: (Length, Length),
+        max: (Length, Length),
+    ) -> ChangeFlags {
+        if self.min != min || self.max != max {
+            self.min = min;
+            self.max = max;
+            ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The min and max limit on `axis`, resolved with the `available` space.
+    fn limits(&self, axis: Axis, available: f64) -> (Option<f64>, Option<f64>) {
+        let (min, max) = match axis {
+            Axis::Horizontal => (self.min.0, self.max.0),
+            Axis::Vertical => (self.min.1, self.max.1),
+        };
+        (min.resolve(available), max.resolve(available))
+    }
+}
+
+impl Widget for SizeLimits {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.content.paint(cx)
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let limit = |axis: Axis| {
+            let range = axis.major(*bc);
+            let (min, max) = self.limits(axis, range.end);
+            let max = max.map_or(range.end, |max| max.clamp(range.start, range.end));
+            let min = min.map_or(range.start, |min| min.clamp(range.start, range.end));
+            min..max.max(min)
+        };
+        let child_bc = BoxConstraints::new(
+            Size::new(limit(Axis::Horizontal).start, limit(Axis::Vertical).start),
+            Size::new(limit(Axis::Horizontal).end, limit(Axis::Vertical).end),
+        );
+        let size = self.content.layout(cx, &child_bc);
+        bc.constrain(size)
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        self.content.event(cx, event)
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        self.content.lifecycle(cx, event)
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let cross = match self.limits(axis.cross(), cross).1 {
+            Some(max) => cross.min(max),
+            None => cross,
+        };
+        // percentages of the unknown available space can't be resolved here
+        let (min, max) = self.limits(axis, f64::INFINITY);
+        let size = self.content.measure(cx, axis, cross)?;
+        Some(size.clamp(min.unwrap_or(0.0)..max.unwrap_or(f64::INFINITY)))
+    }
+}
@@ -0,0 +1,325 @@
+use std::ops::RangeInclusive;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+
+use super::{
+    animatables::{AnimatableElement, LowPassIIR},
+    core::{IdPath, PaintCx},
+    toggle::THUMB_DECAY,
+    BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    RawMouseEvent, Widget,
+};
+use crate::{geometry::Size, SliderTheme};
+
+/// The width of the slider, when the width isn't bounded by its parent.
+const DEFAULT_SLIDER_WIDTH: f64 = 20.0;
+
+/// A horizontal slider with an animated thumb.
+///
+/// It sends the new value (as `f64`) to its view, when it's clicked or dragged with the mouse,
+/// or when the arrow keys (and `Home`/`End`) are pressed while it's focused.
+pub struct Slider {
+    id_path: IdPath,
+    range: RangeInclusive<f64>,
+    value: f64,
+    step: f64,
+    styles: SliderTheme,
+    /// The thumb position from `0.0` (start of the range) to `1.0` (end of the range)
+    thumb: LowPassIIR<f64, f64>,
+    thumb_position: f64,
+}
+
+impl Slider {
+    pub(crate) fn new(
+        id_path: &IdPath,
+        range: RangeInclusive<f64>,
+        value: f64,
+        step: f64,
+        styles: SliderTheme,
+    ) -> Self {
+        let position = ratio(&range, value);
+        Slider {
+            id_path: id_path.clone(),
+            range,
+            value,
+            step,
+            styles,
+            thumb: LowPassIIR::new(position, THUMB_DECAY),
+            thumb_position: position,
+        }
+    }
+
+    pub(crate) fn set_value(&mut self, range: RangeInclusive<f64>, value: f64) -> ChangeFlags {
+        if self.range != range || self.value != value {
+            self.thumb.target = ratio(&range, value);
+            self.range = range;
+            self.value = value;
+            ChangeFlags::ANIMATION
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_step(&mut self, step: f64) -> ChangeFlags {
+        self.step = step;
+        ChangeFlags::empty()
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: SliderTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn send_value(&self, cx: &mut EventCx, value: f64) {
+        let value = value.clamp(*self.range.start(), *self.range.end());
+        if value != self.value {
+            cx.add_message(Message::new(self.id_path.clone(), value));
+        }
+    }
+
+    /// Sends the value at the given column (relative to the slider).
+    fn send_value_at(&self, cx: &mut EventCx, column: i16) {
+        let width = cx.size().width.round() - 1.0;
+        let ratio = if width > 0.0 {
+            (column as f64 / width).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (start, end) = (*self.range.start(), *self.range.end());
+        self.send_value(cx, start + ratio * (end - start));
+    }
+}
+
+/// The position of `value` in `range` from `0.0` to `1.0`.
+fn ratio(range: &RangeInclusive<f64>, value: f64) -> f64 {
+    let span = range.end() - range.start();
+    if span > 0.0 {
+        ((value - range.start()) / span).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+impl Widget for Slider {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let width = cx.cell_size().width as usize;
+        if width == 0 || cx.size().height < 1.0 {
+            return;
+        }
+        let mut thumb_style = self.styles.thumb;
+        if cx.is_focused() {
+            thumb_style = thumb_style.patch(self.styles.focused);
+        }
+
+        let thumb = (self.thumb_position * (width - 1) as f64).round() as usize;
+        for x in 0..width {
+            if x == thumb {
+                cx.set_cell((x as f64, 0.0), "█", thumb_style);
+            } else {
+                cx.set_cell((x as f64, 0.0), "─", self.styles.track);
+            }
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let width = if bc.is_width_bounded() {
+            bc.max().width
+        } else {
+            DEFAULT_SLIDER_WIDTH
+        };
+        bc.constrain(Size::new(width, 1.0))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                ..
+            }) if cx.is_hot() => {
+                cx.set_active(true);
+                // keep dragging, even when the mouse leaves the slider
+                cx.capture_mouse();
+                cx.request_focus();
+                self.send_value_at(cx, *column);
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                column,
+                ..
+            }) if cx.is_active() => self.send_value_at(cx, *column),
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            }) => {
+                cx.set_active(false);
+                cx.release_mouse();
+            }
+            Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) if cx.is_focused() => {
+                let value = match code {
+                    KeyCode::Left | KeyCode::Down => self.value - self.step,
+                    KeyCode::Right | KeyCode::Up => self.value + self.step,
+                    KeyCode::Home => *self.range.start(),
+                    KeyCode::End => *self.range.end(),
+                    _ => return,
+                };
+                self.send_value(cx, value);
+                cx.set_handled(true);
+            }
+            Event::FocusLost => cx.set_active(false),
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        match event {
+            LifeCycle::BuildFocusChain => cx.register_for_focus(),
+            LifeCycle::Animate => {
+                let position = *self.thumb.animate(cx);
+                if position != self.thumb_position {
+                    self.thumb_position = position;
+                    cx.request_paint();
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+    use crate::{test_helper::TestCx, widget::PodFlags};
+
+    /// A slider from 0 to 10 with a step of 3, which is 11 cells wide, so that every column is one unit.
+    fn slider(value: f64) -> (Slider, TestCx) {
+        let slider = Slider::new(
+            &IdPath::new(),
+            0.0..=10.0,
+            value,
+            3.0,
+            SliderTheme::default(),
+        );
+        let mut cx = TestCx::new();
+        cx.widget_state.size = Size::new(11.0, 1.0);
+        (slider, cx)
+    }
+
+    /// Sends the `events` to the `slider`, and returns the values sent to the view.
+    fn send_events(slider: &mut Slider, cx: &mut TestCx, events: &[Event]) -> Vec<f64> {
+        let mut values = Vec::new();
+        for event in events {
+            cx.event_cx(|cx| slider.event(cx, event));
+            // the app state is updated with every change
+            for value in cx.take_messages::<f64>() {
+                let _ = slider.set_value(0.0..=10.0, value);
+                values.push(value);
+            }
+        }
+        values
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(code.into())
+    }
+
+    fn mouse(kind: MouseEventKind, column: i16) -> Event {
+        Event::Mouse(RawMouseEvent {
+            kind,
+            column,
+            row: 0,
+            window_column: column.max(0) as u16,
+            window_row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn keys_change_the_value_by_steps_within_the_range() {
+        let (mut slider, mut cx) = slider(5.0);
+        assert!(send_events(&mut slider, &mut cx, &[key(KeyCode::Right)]).is_empty());
+
+        cx.widget_state.flags |= PodFlags::IS_FOCUSED;
+        let values = send_events(
+            &mut slider,
+            &mut cx,
+            &[
+                key(KeyCode::Right),
+                key(KeyCode::Up),
+                key(KeyCode::Right),
+                key(KeyCode::Left),
+                key(KeyCode::Home),
+                key(KeyCode::Down),
+                key(KeyCode::End),
+            ],
+        );
+        // the value is clamped to the range, and nothing is sent at its ends
+        assert_eq!(values, [8.0, 10.0, 7.0, 0.0, 10.0]);
+    }
+
+    #[test]
+    fn clicking_and_dragging_sets_the_value_at_the_mouse() {
+        let (mut slider, mut cx) = slider(5.0);
+        cx.widget_state.flags |= PodFlags::IS_HOT;
+        let values = send_events(
+            &mut slider,
+            &mut cx,
+            &[
+                mouse(MouseEventKind::Down(MouseButton::Left), 2),
+                mouse(MouseEventKind::Drag(MouseButton::Left), 7),
+                // the mouse is captured, so dragging continues beyond the slider
+                mouse(MouseEventKind::Drag(MouseButton::Left), 20),
+                mouse(MouseEventKind::Drag(MouseButton::Left), -3),
+                mouse(MouseEventKind::Up(MouseButton::Left), -3),
+                mouse(MouseEventKind::Drag(MouseButton::Left), 4),
+            ],
+        );
+        assert_eq!(values, [2.0, 7.0, 10.0, 0.0]);
+        assert!(!cx.mouse_captured);
+    }
+
+    #[test]
+    fn the_thumb_slides_to_the_new_value() {
+        let (mut slider, mut cx) = slider(0.0);
+        let mut animate = |slider: &mut Slider| {
+            cx.with_cx_state(|cx_state, widget_state| {
+                cx_state.time_since_last_render_request = Duration::from_millis(16);
+                let cx = &mut LifeCycleCx {
+                    cx_state,
+                    widget_state,
+                };
+                slider.lifecycle(cx, &LifeCycle::Animate);
+            });
+            slider.thumb_position
+        };
+        assert_eq!(animate(&mut slider), 0.0);
+
+        // values outside of the range move the thumb only to its end
+        assert!(slider
+            .set_value(0.0..=10.0, 20.0)
+            .contains(ChangeFlags::ANIMATION));
+        let first = animate(&mut slider);
+        // the thumb doesn't jump to the new value
+        assert!(first > 0.0);
+        assert!(first < 1.0);
+        let mut previous = first;
+        for _ in 0..100 {
+            let position = animate(&mut slider);
+            assert!(position >= previous);
+            previous = position;
+        }
+        assert_eq!(previous, 1.0);
+    }
+}
@@ -0,0 +1,214 @@
+use std::ops::RangeInclusive;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::style::Modifier;
+
+use super::{
+    core::{IdPath, PaintCx},
+    BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    RawMouseEvent, Widget,
+};
+use crate::{geometry::Size, StepperTheme};
+
+const DECREMENT: &str = "[-]";
+const INCREMENT: &str = "[+]";
+
+/// A number input with decrement/increment buttons, which sends the new (clamped) value as `i64` to its view.
+///
+/// While focused, the value can be changed with the arrow keys, or typed directly and committed with `Enter`.
+pub struct Stepper {
+    id_path: IdPath,
+    value: i64,
+    range: RangeInclusive<i64>,
+    step: i64,
+    styles: StepperTheme,
+    /// The text that is currently typed, it's discarded when the stepper loses focus.
+    input: Option<String>,
+}
+
+impl Stepper {
+    pub(crate) fn new(
+        id_path: &IdPath,
+        value: i64,
+        range: RangeInclusive<i64>,
+        step: i64,
+        styles: StepperTheme,
+    ) -> Self {
+        Stepper {
+            id_path: id_path.clone(),
+            value,
+            range,
+            step,
+            styles,
+            input: None,
+        }
+    }
+
+    pub(crate) fn set_value(&mut self, value: i64) -> ChangeFlags {
+        if self.value != value {
+            self.value = value;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_range(&mut self, range: RangeInclusive<i64>, step: i64) -> ChangeFlags {
+        self.step = step;
+        if self.range != range {
+            self.range = range;
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: StepperTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The width of the number field, enough for the widest value within the range.
+    fn field_width(&self) -> usize {
+        let start = self.range.start().to_string().len();
+        let end = self.range.end().to_string().len();
+        start.max(end)
+    }
+
+    fn send_value(&self, cx: &mut EventCx, value: i64) {
+        let value = value.clamp(*self.range.start(), *self.range.end());
+        if value != self.value {
+            cx.add_message(Message::new(self.id_path.clone(), value));
+        }
+    }
+
+    fn commit_input(&mut self, cx: &mut EventCx) {
+        if let Some(value) = self.input.take().and_then(|input| input.parse().ok()) {
+            self.send_value(cx, value);
+        }
+        cx.request_paint();
+    }
+}
+
+impl Widget for Stepper {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let width = cx.cell_size().width as usize;
+        if cx.size().height < 1.0 {
+            return;
+        }
+
+        let mut style = self.styles.style;
+        if cx.is_focused() {
+            style = style.patch(self.styles.focused);
+        }
+
+        let text = match &self.input {
+            Some(input) => input.clone(),
+            None => self.value.to_string(),
+        };
+        let field_width = width.saturating_sub(DECREMENT.len() + INCREMENT.len());
+        let text_offset = DECREMENT.len() + field_width.saturating_sub(text.len()) / 2;
+
+        let mut line = String::with_capacity(width);
+        line += DECREMENT;
+        line += &" ".repeat(field_width);
+        line += INCREMENT;
+
+        for (x, symbol) in line.chars().enumerate().take(width) {
+            let is_button = x < DECREMENT.len() || x >= width.saturating_sub(INCREMENT.len());
+            let symbol_style = if is_button {
+                self.styles.buttons
+            } else {
+                style
+            };
+            cx.set_cell(
+                (x as f64, 0.0),
+                symbol.encode_utf8(&mut [0; 4]),
+                symbol_style,
+            );
+        }
+        let text_style = if self.input.is_some() {
+            style.add_modifier(Modifier::UNDERLINED)
+        } else {
+            style
+        };
+        for (x, symbol) in text.chars().enumerate().take(field_width) {
+            let position = ((text_offset + x) as f64, 0.0);
+            cx.set_cell(position, symbol.encode_utf8(&mut [0; 4]), text_style);
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        // one additional space on each side of the number
+        let width = DECREMENT.len() + self.field_width() + 2 + INCREMENT.len();
+        bc.constrain(Size::new(width as f64, 1.0))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                ..
+            }) if cx.is_hot() => {
+                cx.request_focus();
+                let column = *column as usize;
+                if column < DECREMENT.len() {
+                    self.send_value(cx, self.value.saturating_sub(self.step));
+                } else if column >= (cx.size().width as usize).saturating_sub(INCREMENT.len()) {
+                    self.send_value(cx, self.value.saturating_add(self.step));
+                }
+            }
+            Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) if cx.is_focused() => {
+                match code {
+                    KeyCode::Up | KeyCode::Right | KeyCode::Char('+') => {
+                        self.input = None;
+                        self.send_value(cx, self.value.saturating_add(self.step));
+                    }
+                    KeyCode::Down | KeyCode::Left => {
+                        self.input = None;
+                        self.send_value(cx, self.value.saturating_sub(self.step));
+                    }
+                    KeyCode::Char(c @ ('0'..='9' | '-')) => {
+                        let field_width = self.field_width();
+                        let input = self.input.get_or_insert_with(String::new);
+                        // a minus is only allowed at the start, and the input can't get wider than the field
+                        if (*c != '-' || input.is_empty()) && input.len() < field_width {
+                            input.push(*c);
+                        }
+                        cx.request_paint();
+                    }
+                    KeyCode::Backspace if self.input.is_some() => {
+                        if let Some(input) = &mut self.input {
+                            input.pop();
+                        }
+                        cx.request_paint();
+                    }
+                    KeyCode::Enter if self.input.is_some() => self.commit_input(cx),
+                    _ => return,
+                }
+                cx.set_handled(true);
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        match event {
+            LifeCycle::BuildFocusChain => cx.register_for_focus(),
+            LifeCycle::FocusChanged if !cx.is_focused() && self.input.is_some() => {
+                self.input = None;
+                cx.request_paint();
+            }
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,377 @@
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
+use ratatui::style::Style;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::{
+    core::{IdPath, PaintCx},
+    BoxConstraints, ChangeFlags, Event, EventCx, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx,
+    Message, Pod, RawMouseEvent, Widget, WrapMode, WrappedText,
+};
+use crate::{
+    geometry::{Axis, Point, Size},
+    TableColumn, TableTheme,
+};
+
+const SEPARATOR: &str = "│";
+
+/// Rows of text in columns with a header, the columns can be resized by dragging the separator after them in the header,
+/// or with `Shift` + `Left`/`Right` (and `-`/`+`) for the selected column, while the table is focused.
+///
+/// Sends the index and the new width of a resized column as `(usize, u16)` to the view at `on_resize`, if it's set.
+pub struct Table {
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<String>>,
+    wrap: Option<WrapMode>,
+    /// The [`WrappedText`] of each cell, when the cells are wrapped.
+    cells: Vec<Vec<Pod>>,
+    /// The y position of each row (after the header) and the end of the last row from the last layout.
+    row_tops: Vec<f64>,
+    /// The current widths of the columns, which may differ from the widths of `columns` after they were resized.
+    widths: Vec<u16>,
+    styles: TableTheme,
+    on_resize: Option<IdPath>,
+    /// The column, which is resized with the keyboard.
+    cursor: usize,
+    /// The column, whose separator is dragged with the mouse.
+    dragged: Option<usize>,
+}
+
+impl Table {
+    pub(crate) fn new(
+        columns: Vec<TableColumn>,
+        rows: Vec<Vec<String>>,
+        styles: TableTheme,
+    ) -> Self {
+        Table {
+            widths: columns.iter().map(TableColumn::clamped_width).collect(),
+            columns,
+            rows,
+            wrap: None,
+            cells: Vec::new(),
+            row_tops: Vec::new(),
+            styles,
+            on_resize: None,
+            cursor: 0,
+            dragged: None,
+        }
+    }
+
+    /// Keeps the width of resized columns, unless the width of the column itself was changed.
+    pub(crate) fn set_columns(&mut self, columns: Vec<TableColumn>) -> ChangeFlags {
+        if self.columns == columns {
+            return ChangeFlags::empty();
+        }
+        let mut widths = Vec::with_capacity(columns.len());
+        for (index, column) in columns.iter().enumerate() {
+            let width = match (self.columns.get(index), self.widths.get(index)) {
+                (Some(prev), Some(width)) if prev.width == column.width => *width,
+                _ => column.width,
+            };
+            widths.push(width.clamp(column.min_width, column.max_width.max(column.min_width)));
+        }
+        self.columns = columns;
+        self.widths = widths;
+        self.cursor = self.cursor.min(self.columns.len().saturating_sub(1));
+        ChangeFlags::LAYOUT | ChangeFlags::PAINT
+    }
+
+    pub(crate) fn set_rows(&mut self, rows: Vec<Vec<String>>) -> ChangeFlags {
+        if self.rows != rows {
+            self.rows = rows;
+            self.update_cells();
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_wrap(&mut self, wrap: Option<WrapMode>) -> ChangeFlags {
+        if self.wrap != wrap {
+            self.wrap = wrap;
+            self.update_cells();
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn update_cells(&mut self) {
+        self.cells = match self.wrap {
+            Some(mode) => self
+                .rows
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|text| {
+                            let words = text
+                                .split_word_bounds()
+                                .map(|word| (word.to_string(), Style::default()))
+                                .collect();
+                            Pod::new(WrappedText::new(words, mode, None))
+                        })
+                        .collect()
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+    }
+
+    /// The height of the (wrapped) cells of `row`, when its columns have their current widths.
+    fn row_height(&mut self, cx: &mut LayoutCx, row: usize) -> f64 {
+        let Some(cells) = self.cells.get_mut(row) else {
+            return 1.0;
+        };
+        cells
+            .iter_mut()
+            .zip(&self.widths)
+            .filter_map(|(cell, width)| cell.measure(cx, Axis::Vertical, *width as f64))
+            .fold(1.0, |height, size| height.max(size.max))
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: TableTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_on_resize(&mut self, id_path: &IdPath) -> ChangeFlags {
+        self.on_resize = Some(id_path.clone());
+        ChangeFlags::empty()
+    }
+
+    /// The x position of the separator after each column.
+    fn separators(&self) -> impl Iterator<Item = f64> + '_ {
+        self.widths.iter().scan(0.0, |x, width| {
+            let separator = *x + *width as f64;
+            *x = separator + 1.0;
+            Some(separator)
+        })
+    }
+
+    fn total_width(&self) -> f64 {
+        self.separators().last().map_or(0.0, |x| x + 1.0)
+    }
+
+    fn resize(&mut self, cx: &mut EventCx, index: usize, width: i64) {
+        let column = &self.columns[index];
+        let width = width.clamp(
+            column.min_width as i64,
+            column.max_width.max(column.min_width) as i64,
+        ) as u16;
+        if self.widths[index] == width {
+            return;
+        }
+        self.widths[index] = width;
+        if let Some(id_path) = &self.on_resize {
+            cx.add_message(Message::new(id_path.clone(), (index, width)));
+        }
+        cx.request_layout();
+        cx.request_paint();
+    }
+}
+
+/// Writes `text` at `x` in `row` cut off after `width` cells.
+fn set_cell_text(cx: &mut PaintCx, (x, row): (f64, f64), width: u16, text: &str, style: Style) {
+    let mut column = x;
+    for g in text.graphemes(true) {
+        let w = g.width() as f64;
+        if column + w > x + width as f64 {
+            break;
+        }
+        cx.set_cell((column, row), g, style);
+        column += w;
+    }
+}
+
+impl Widget for Table {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let is_focused = cx.is_focused();
+        let separators: Vec<f64> = self.separators().collect();
+        let starts = std::iter::once(0.0).chain(separators.iter().map(|x| x + 1.0));
+        for ((index, column), (start, separator)) in self
+            .columns
+            .iter()
+            .enumerate()
+            .zip(starts.zip(separators.iter().copied()))
+        {
+            let width = self.widths[index];
+            set_cell_text(cx, (start, 0.0), width, &column.title, self.styles.header);
+            if self.wrap.is_some() {
+                for cell in self
+                    .cells
+                    .iter_mut()
+                    .filter_map(|cells| cells.get_mut(index))
+                {
+                    cell.paint(cx);
+                }
+            } else {
+                for (cells, y) in self.rows.iter().zip(&self.row_tops) {
+                    if let Some(text) = cells.get(index) {
+                        set_cell_text(cx, (start, *y), width, text, Style::default());
+                    }
+                }
+            }
+            let resizing = self.dragged == Some(index) || (is_focused && self.cursor == index);
+            let style = if resizing {
+                self.styles.separator.patch(self.styles.resizing)
+            } else {
+                self.styles.separator
+            };
+            let height = self.row_tops.last().copied().unwrap_or(1.0);
+            for row in 0..height as usize {
+                cx.set_cell((separator, row as f64), SEPARATOR, style);
+            }
+        }
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        self.row_tops.clear();
+        // below the header
+        let mut y = 1.0;
+        for row in 0..self.rows.len() {
+            self.row_tops.push(y);
+            let mut x = 0.0;
+            if let Some(cells) = self.cells.get_mut(row) {
+                for (cell, width) in cells.iter_mut().zip(&self.widths) {
+                    let width = *width as f64;
+                    let cell_bc = BoxConstraints::new(Size::ZERO, Size::new(width, f64::INFINITY));
+                    cell.layout(cx, &cell_bc);
+                    cell.set_origin(cx, Point::new(x, y));
+                    x += width + 1.0;
+                }
+            }
+            y += self.row_height(cx, row);
+        }
+        self.row_tops.push(y);
+        bc.constrain(Size::new(self.total_width(), y))
+    }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, _cross: f64) -> Option<IntrinsicSize> {
+        let size = match axis {
+            Axis::Horizontal => self.total_width(),
+            Axis::Vertical => {
+                1.0 + (0..self.rows.len())
+                    .map(|row| self.row_height(cx, row))
+                    .sum::<f64>()
+            }
+        };
+        Some(IntrinsicSize::fixed(size))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row: 0,
+                ..
+            }) if cx.is_hot() => {
+                let column = *column as f64;
+                let separator = self.separators().position(|x| x == column);
+                if let Some(index) = separator {
+                    self.dragged = Some(index);
+                    self.cursor = index;
+                    cx.set_active(true);
+                    cx.capture_mouse();
+                    cx.request_focus();
+                    cx.request_paint();
+                    cx.set_handled(true);
+                }
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                column,
+                ..
+            }) => {
+                let Some(index) = self.dragged else {
+                    return;
+                };
+                // the mouse position is relative to the table, also when it's scrolled horizontally
+                let start = self.separators().nth(index).unwrap() - self.widths[index] as f64;
+                self.resize(cx, index, *column as i64 - start as i64);
+                cx.set_handled(true);
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            }) if self.dragged.is_some() => {
+                self.dragged = None;
+                cx.set_active(false);
+                cx.release_mouse();
+                cx.request_paint();
+                cx.set_handled(true);
+            }
+            Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) if cx.is_focused() && !self.columns.is_empty() => {
+                let shift = modifiers.contains(KeyModifiers::SHIFT);
+                let width = self.widths[self.cursor] as i64;
+                match code {
+                    KeyCode::Left if shift => self.resize(cx, self.cursor, width - 1),
+                    KeyCode::Right if shift => self.resize(cx, self.cursor, width + 1),
+                    KeyCode::Char('-') => self.resize(cx, self.cursor, width - 1),
+                    KeyCode::Char('+' | '=') => self.resize(cx, self.cursor, width + 1),
+                    KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+                    KeyCode::Right => self.cursor = (self.cursor + 1).min(self.columns.len() - 1),
+                    _ => return,
+                }
+                cx.request_paint();
+                cx.set_handled(true);
+            }
+            Event::FocusLost if self.dragged.is_some() => {
+                self.dragged = None;
+                cx.set_active(false);
+                cx.release_mouse();
+                cx.request_paint();
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::BuildFocusChain = event {
+            cx.register_for_focus();
+        }
+        for cell in self.cells.iter_mut().flatten() {
+            cell.lifecycle(cx, event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resized_widths_are_kept_until_the_column_width_changes() {
+        let columns = vec![
+            TableColumn::new("Name", 10).min_width(4),
+            TableColumn::new("Size", 6),
+        ];
+        let mut table = Table::new(columns.clone(), Vec::new(), TableTheme::default());
+        table.widths[0] = 14;
+        assert_eq!(table.separators().collect::<Vec<_>>(), [14.0, 21.0]);
+
+        // e.g. a row was added, but the columns are the same
+        let _ = table.set_columns(columns.clone());
+        assert_eq!(table.widths, [14, 6]);
+        // the persisted width is restored
+        let mut restored = columns.clone();
+        restored[1].width = 8;
+        let _ = table.set_columns(restored);
+        assert_eq!(table.widths, [14, 8]);
+        let mut restored = columns;
+        restored[0].width = 2;
+        let _ = table.set_columns(restored);
+        assert_eq!(table.widths, [4, 6]);
+    }
+}
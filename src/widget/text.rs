@@ -1,19 +1,45 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, ops::Range};
 
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
 use kurbo::Size;
 use ratatui::style::Style;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use super::{core::EventCx, BoxConstraints, ChangeFlags, Event, LayoutCx, PaintCx, Widget};
+use super::{
+    bidi,
+    core::{EventCx, IdPath},
+    BoxConstraints, ChangeFlags, Event, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    PaintCx, RawMouseEvent, Widget,
+};
+use crate::geometry::Axis;
 
 pub struct Text {
     pub(crate) text: Cow<'static, str>,
     pub(crate) style: Style,
+    /// Byte ranges of `text`, which are drawn with `highlight_style` patched over `style`.
+    highlights: Vec<Range<usize>>,
+    highlight_style: Style,
+    /// Whether the base direction of the text is right-to-left, which also aligns the lines to the right.
+    rtl: bool,
+    selection: Option<TextSelection>,
 }
 
 // TODO maybe a generic macro for stuff like below?
 impl Text {
+    pub(crate) fn new(text: Cow<'static, str>, style: Style) -> Self {
+        Text {
+            text,
+            style,
+            highlights: Vec::new(),
+            highlight_style: Style::default(),
+            rtl: false,
+            selection: None,
+        }
+    }
+
     pub fn set_text(&mut self, text: Cow<'static, str>) -> ChangeFlags {
         let mut changeflags = ChangeFlags::empty();
         if self.text != text {
@@ -31,33 +57,100 @@ impl Text {
             ChangeFlags::empty()
         }
     }
+
+    pub(crate) fn set_rtl(&mut self, rtl: bool) -> ChangeFlags {
+        if rtl != self.rtl {
+            self.rtl = rtl;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_highlights(
+        &mut self,
+        highlights: Vec<Range<usize>>,
+        style: Style,
+    ) -> ChangeFlags {
+        if highlights != self.highlights
+            || (!highlights.is_empty() && style != self.highlight_style)
+        {
+            self.highlights = highlights;
+            self.highlight_style = style;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The graphemes of each line in logical order, with the column they're drawn at.
+    fn cells(&self, width: usize) -> Vec<(bool, Vec<(usize, &str)>)> {
+        self.text
+            .lines()
+            .map(|l| {
+                let mut x = if self.rtl {
+                    width.saturating_sub(l.width())
+                } else {
+                    0
+                };
+                let mut cells: Vec<_> = bidi::visual_order(l, self.rtl)
+                    .into_iter()
+                    .map(|(offset, g)| {
+                        let column = x;
+                        x += g.width();
+                        // the visual grapheme may be mirrored, the selected text should contain the original one
+                        let g = l[offset..].graphemes(true).next().unwrap_or_default();
+                        (offset, column, g)
+                    })
+                    .collect();
+                cells.sort_by_key(|(offset, ..)| *offset);
+                let cells = cells.into_iter().map(|(_, x, g)| (x, g)).collect();
+                (false, cells)
+            })
+            .collect()
+    }
+}
+
+impl SelectableElement for Text {
+    fn set_selectable(&mut self, id_path: &IdPath, style: Style) -> ChangeFlags {
+        set_selectable(&mut self.selection, id_path, style)
+    }
 }
 
 impl Widget for Text {
     fn paint(&mut self, cx: &mut PaintCx) {
-        let style = self.style.patch(cx.override_style);
+        let highlight_style = self.style.patch(self.highlight_style);
 
-        let size = cx.size();
-        let width = size.width.round() as usize;
-        let height = size.height.round() as usize;
+        let size = cx.cell_size();
+        let (width, height) = (size.width as usize, size.height as usize);
 
         for (line_num, l) in self.text.lines().enumerate() {
             if line_num >= height {
                 break;
             }
-            let mut line_width = 0;
-            for g in l.graphemes(true) {
+            let mut line_width = if self.rtl {
+                width.saturating_sub(l.width())
+            } else {
+                0
+            };
+            let line_offset = l.as_ptr() as usize - self.text.as_ptr() as usize;
+            for (i, g) in bidi::visual_order(l, self.rtl) {
                 let w = g.width();
                 if line_width + w > width {
                     break;
                 }
-                let cell = cx.canvas.get_mut((line_width as f64, line_num as f64));
-                cell.set_symbol(g);
-                cell.set_style(style);
-                // clear all following graphemes, when the width of `g` is > 1
-                for x in (line_width + 1)..(line_width + w) {
-                    cx.canvas.get_mut((x as f64, line_num as f64)).reset();
+                let offset = line_offset + i;
+                let mut style = if self.highlights.iter().any(|r| r.contains(&offset)) {
+                    highlight_style
+                } else {
+                    self.style
+                };
+                if let Some(selection) = &self.selection {
+                    if selection.contains(line_num, line_width) {
+                        style = style.patch(selection.style);
+                    }
                 }
+                cx.set_string((line_width as f64, line_num as f64), &g, style);
                 line_width += w;
             }
         }
@@ -78,66 +171,599 @@ impl Widget for Text {
         })
     }
 
-    fn event(&mut self, _cx: &mut EventCx, _event: &Event) {}
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        let Some(mut selection) = self.selection.take() else {
+            return;
+        };
+        let cells = self.cells(cx.size().width as usize);
+        selection.event(cx, event, &cells);
+        self.selection = Some(selection);
+    }
 
-    fn lifecycle(&mut self, _cx: &mut super::core::LifeCycleCx, _event: &super::LifeCycle) {}
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if self.selection.is_some() && matches!(event, LifeCycle::BuildFocusChain) {
+            cx.register_for_focus();
+        }
+    }
+
+    fn measure(&mut self, _cx: &mut LayoutCx, axis: Axis, _cross: f64) -> Option<IntrinsicSize> {
+        // The text isn't wrapped (yet), so it can't get any smaller without clipping
+        let size = match axis {
+            Axis::Horizontal => self.text.lines().map(|l| l.width()).max().unwrap_or(0),
+            Axis::Vertical => self.text.lines().count(),
+        };
+        Some(IntrinsicSize::fixed(size as f64))
+    }
+}
+
+/// How [`WrappedText`](crate::WrappedText) breaks text that doesn't fit into a line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WrapMode {
+    /// Breaks lines on the whitespace between words, words longer than a line are clipped.
+    #[default]
+    Word,
+    /// Like [`WrapMode::Word`], but words longer than a line (e.g. URLs) are broken anywhere.
+    Anywhere,
+    /// Like [`WrapMode::Word`], but lines are never broken on no-break spaces (e.g. `U+00A0`).
+    KeepNoBreakSpaces,
+}
+
+impl WrapMode {
+    fn is_break(self, grapheme: &str) -> bool {
+        grapheme.chars().all(|c| {
+            c.is_whitespace()
+                && !(self == WrapMode::KeepNoBreakSpaces
+                    && matches!(c, '\u{a0}' | '\u{2007}' | '\u{202f}'))
+        })
+    }
 }
 
-// TODO relatively hacky naive implementation of wrapping text via flexbox
+/// A wrapped line of styled text.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Line {
+    pieces: Vec<(String, Style)>,
+    width: usize,
+    /// Whether this line was started by wrapping the previous line (in contrast to a new line in the text).
+    wrapped: bool,
+}
+
+impl Line {
+    fn push(&mut self, grapheme: &str, style: Style) {
+        match self.pieces.last_mut() {
+            Some((text, s)) if *s == style => text.push_str(grapheme),
+            _ => self.pieces.push((grapheme.to_string(), style)),
+        }
+        self.width += grapheme.width();
+    }
+
+    fn pop(&mut self) -> Option<Style> {
+        let (text, style) = self.pieces.last_mut()?;
+        let style = *style;
+        let (offset, grapheme) = text.grapheme_indices(true).next_back()?;
+        self.width -= grapheme.width();
+        text.truncate(offset);
+        if text.is_empty() {
+            self.pieces.pop();
+        }
+        Some(style)
+    }
+
+    fn last_is_whitespace(&self) -> bool {
+        self.pieces
+            .last()
+            .and_then(|(text, _)| text.chars().next_back())
+            .is_some_and(char::is_whitespace)
+    }
+
+    /// Replaces the end of the line with an ellipsis, so that it fits into `max_width`.
+    fn ellipsize(&mut self, max_width: usize) {
+        let mut style = self.pieces.last().map(|(_, style)| *style);
+        while self.width + 1 > max_width || self.last_is_whitespace() {
+            match self.pop() {
+                Some(s) => style = Some(s),
+                None => break,
+            }
+        }
+        if max_width > 0 {
+            self.push("…", style.unwrap_or_default());
+        }
+    }
+}
+
+/// Text, that is wrapped to the available width, see [`WrapMode`] for how lines are broken.
 pub struct WrappedText {
     pub(crate) words: Vec<(String, Style)>,
-    // pub(crate) words_layout: Vec<NodeId>,
-    words_need_layout: bool, // TODO necessary?
+    mode: WrapMode,
+    /// Lines beyond this are cut off and an ellipsis is shown at the end of the last line.
+    max_lines: Option<usize>,
+    /// The lines of the last layout.
+    lines: Vec<Line>,
+    selection: Option<TextSelection>,
 }
 
 impl WrappedText {
-    pub(crate) fn new(words: Vec<(String, Style)>) -> Self {
+    pub(crate) fn new(
+        words: Vec<(String, Style)>,
+        mode: WrapMode,
+        max_lines: Option<usize>,
+    ) -> Self {
         WrappedText {
             words,
-            words_need_layout: true,
-            // words_layout: Vec::new(),
+            mode,
+            max_lines,
+            lines: Vec::new(),
+            selection: None,
         }
     }
 
     pub fn set_words(&mut self, words: &Vec<(String, Style)>) -> ChangeFlags {
         if &self.words != words {
             self.words = words.clone();
-            self.words_need_layout = true;
+            // the styles are part of the wrapped lines as well, so they're also computed in the layout
+            ChangeFlags::relayout()
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_mode(&mut self, mode: WrapMode) -> ChangeFlags {
+        if self.mode != mode {
+            self.mode = mode;
+            ChangeFlags::PAINT | ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_max_lines(&mut self, max_lines: Option<usize>) -> ChangeFlags {
+        if self.max_lines != max_lines {
+            self.max_lines = max_lines;
             ChangeFlags::PAINT | ChangeFlags::LAYOUT
         } else {
             ChangeFlags::empty()
         }
     }
+
+    /// Breaks the words into lines that fit into `max_width` (if possible with the wrap mode).
+    fn wrap(&self, max_width: f64) -> Vec<Line> {
+        let max_width = if max_width.is_finite() {
+            max_width.max(0.0) as usize
+        } else {
+            usize::MAX
+        };
+        let mut lines = vec![Line::default()];
+        let mut spaces: Vec<(&str, Style)> = Vec::new();
+        let mut word: Vec<(&str, Style)> = Vec::new();
+
+        let place_word = |lines: &mut Vec<Line>,
+                          spaces: &mut Vec<(&str, Style)>,
+                          word: &mut Vec<(&str, Style)>| {
+            let width_of = |graphemes: &[(&str, Style)]| {
+                graphemes.iter().map(|(g, _)| g.width()).sum::<usize>()
+            };
+            let (spaces_width, word_width) = (width_of(spaces), width_of(word));
+            let line = lines.last_mut().unwrap();
+            if line.width > 0 && line.width.saturating_add(spaces_width + word_width) > max_width {
+                lines.push(Line {
+                    wrapped: true,
+                    ..Line::default()
+                });
+            }
+            let line = lines.last_mut().unwrap();
+            // whitespace at the start of wrapped lines is dropped
+            if !(line.wrapped && line.width == 0) {
+                for (g, style) in spaces.drain(..) {
+                    line.push(g, style);
+                }
+            }
+            spaces.clear();
+            for (g, style) in word.drain(..) {
+                let line = lines.last_mut().unwrap();
+                if self.mode == WrapMode::Anywhere
+                    && line.width > 0
+                    && line.width + g.width() > max_width
+                {
+                    lines.push(Line {
+                        wrapped: true,
+                        ..Line::default()
+                    });
+                }
+                lines.last_mut().unwrap().push(g, style);
+            }
+        };
+
+        for (text, style) in &self.words {
+            for g in text.graphemes(true) {
+                if g == "\n" || g == "\r\n" {
+                    if !word.is_empty() {
+                        place_word(&mut lines, &mut spaces, &mut word);
+                    }
+                    spaces.clear();
+                    lines.push(Line::default());
+                } else if self.mode.is_break(g) {
+                    if !word.is_empty() {
+                        place_word(&mut lines, &mut spaces, &mut word);
+                    }
+                    spaces.push((g, *style));
+                } else {
+                    word.push((g, *style));
+                }
+            }
+        }
+        if !word.is_empty() {
+            place_word(&mut lines, &mut spaces, &mut word);
+        }
+        lines
+    }
+}
+
+impl SelectableElement for WrappedText {
+    fn set_selectable(&mut self, id_path: &IdPath, style: Style) -> ChangeFlags {
+        set_selectable(&mut self.selection, id_path, style)
+    }
 }
 
 impl Widget for WrappedText {
-    fn paint(&mut self, _cx: &mut PaintCx) {
-        // let rect = cx.rect();
-        // for ((word, style), node) in self.words.iter().zip(self.words_layout.iter()) {
-        //     let layout = cx.taffy.layout(*node).unwrap();
-        //     let x = rect.x + (layout.location.x as u16);
-        //     let y = rect.y + (layout.location.y as u16);
-        //     let term_size = cx.terminal.size().unwrap();
-
-        //     let max_width = rect
-        //         .width
-        //         .saturating_sub(layout.location.x as u16)
-        //         .min(term_size.width.saturating_sub(x)) as usize;
-        //     if max_width > 0 && y < term_size.height {
-        //         let style = style.patch(cx.override_style);
-        //         cx.terminal
-        //             .current_buffer_mut()
-        //             .set_stringn(x, y, word, max_width, style);
-        //     }
-        // }
-        todo!()
-    }
-
-    fn layout(&mut self, _cx: &mut LayoutCx, _bc: &BoxConstraints) -> Size {
-        todo!()
-    }
-
-    fn event(&mut self, _cx: &mut EventCx, _event: &Event) {}
-
-    fn lifecycle(&mut self, _cx: &mut super::core::LifeCycleCx, _event: &super::LifeCycle) {}
+    fn paint(&mut self, cx: &mut PaintCx) {
+        for (y, line) in self.lines.iter().enumerate() {
+            let mut x = 0.0;
+            for (text, style) in &line.pieces {
+                match &self.selection {
+                    Some(selection) if selection.intersects_row(y) => {
+                        for g in text.graphemes(true) {
+                            let style = if selection.contains(y, x as usize) {
+                                style.patch(selection.style)
+                            } else {
+                                *style
+                            };
+                            x = cx.set_string((x, y as f64), g, style);
+                        }
+                    }
+                    _ => x = cx.set_string((x, y as f64), text, *style),
+                }
+            }
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let max_width = bc.max().width;
+        self.lines = self.wrap(max_width);
+        if let Some(max_lines) = self.max_lines {
+            if self.lines.len() > max_lines {
+                self.lines.truncate(max_lines);
+                if let Some(last) = self.lines.last_mut() {
+                    last.ellipsize(if max_width.is_finite() {
+                        max_width.max(0.0) as usize
+                    } else {
+                        usize::MAX
+                    });
+                }
+            }
+        }
+        let width = self.lines.iter().map(|l| l.width).max().unwrap_or(0);
+        bc.constrain(Size {
+            width: width as f64,
+            height: self.lines.len() as f64,
+        })
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        let Some(selection) = &mut self.selection else {
+            return;
+        };
+        let cells: Vec<_> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let mut x = 0;
+                let cells = line
+                    .pieces
+                    .iter()
+                    .flat_map(|(text, _)| text.graphemes(true))
+                    .map(|g| {
+                        let column = x;
+                        x += g.width();
+                        (column, g)
+                    })
+                    .collect();
+                (line.wrapped, cells)
+            })
+            .collect();
+        selection.event(cx, event, &cells);
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if self.selection.is_some() && matches!(event, LifeCycle::BuildFocusChain) {
+            cx.register_for_focus();
+        }
+    }
+
+    fn measure(&mut self, _cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        let max_line_width =
+            |lines: Vec<Line>| lines.iter().map(|l| l.width).max().unwrap_or(0) as f64;
+        Some(match axis {
+            // the longest word (or grapheme when breaking anywhere), and the longest line without wrapping
+            Axis::Horizontal => IntrinsicSize::new(
+                max_line_width(self.wrap(0.0)),
+                max_line_width(self.wrap(f64::INFINITY)),
+            ),
+            Axis::Vertical => {
+                let lines = self.wrap(cross).len();
+                IntrinsicSize::fixed(self.max_lines.map_or(lines, |max| lines.min(max)) as f64)
+            }
+        })
+    }
+}
+
+/// Text widgets, whose text can be selected, see [`SelectableText`](crate::view::SelectableText).
+pub trait SelectableElement: Widget {
+    /// Makes the text selectable, the selected text is sent to `id_path`, whenever the selection changes.
+    fn set_selectable(&mut self, id_path: &IdPath, style: Style) -> ChangeFlags;
+}
+
+fn set_selectable(
+    selection: &mut Option<TextSelection>,
+    id_path: &IdPath,
+    style: Style,
+) -> ChangeFlags {
+    match selection {
+        Some(selection) if selection.style == style => {
+            selection.id_path.clone_from(id_path);
+            ChangeFlags::empty()
+        }
+        Some(selection) => {
+            selection.id_path.clone_from(id_path);
+            selection.style = style;
+            ChangeFlags::PAINT
+        }
+        None => {
+            *selection = Some(TextSelection::new(id_path, style));
+            // The focus chain has to be rebuilt, which currently happens with every layout
+            ChangeFlags::LAYOUT | ChangeFlags::PAINT
+        }
+    }
+}
+
+/// A caret between two cells of a text as `(row, column)`.
+type Caret = (usize, usize);
+
+/// The selection of a text widget, which is made by dragging the mouse or with `Shift` + arrow keys.
+///
+/// It works on the cells of the drawn lines, which are given as the graphemes of each line (in logical order)
+/// with the column they're drawn at, and whether the line was wrapped.
+struct TextSelection {
+    id_path: IdPath,
+    style: Style,
+    /// Where the selection started.
+    anchor: Caret,
+    /// The end of the selection, which is moved by dragging or the arrow keys.
+    cursor: Caret,
+}
+
+impl TextSelection {
+    fn new(id_path: &IdPath, style: Style) -> Self {
+        TextSelection {
+            id_path: id_path.clone(),
+            style,
+            anchor: (0, 0),
+            cursor: (0, 0),
+        }
+    }
+
+    fn range(&self) -> (Caret, Caret) {
+        (self.anchor.min(self.cursor), self.anchor.max(self.cursor))
+    }
+
+    fn contains(&self, row: usize, column: usize) -> bool {
+        let (start, end) = self.range();
+        start <= (row, column) && (row, column) < end
+    }
+
+    fn intersects_row(&self, row: usize) -> bool {
+        let (start, end) = self.range();
+        start != end && (start.0..=end.0).contains(&row)
+    }
+
+    /// The selected text, wrapped lines are joined with a space, other lines with a newline.
+    fn text(&self, lines: &[(bool, Vec<(usize, &str)>)]) -> String {
+        let (start, end) = self.range();
+        let mut text = String::new();
+        if start == end {
+            return text;
+        }
+        for (row, (wrapped, cells)) in lines.iter().enumerate() {
+            if row > end.0 {
+                break;
+            }
+            if row > start.0 {
+                text.push(if *wrapped { ' ' } else { '\n' });
+            }
+            for (column, g) in cells {
+                if self.contains(row, *column) {
+                    text.push_str(g);
+                }
+            }
+        }
+        text
+    }
+
+    /// The caret at the mouse position, dragging forward includes the cell below the mouse.
+    fn caret_at(
+        lines: &[(bool, Vec<(usize, &str)>)],
+        row: i16,
+        column: i16,
+        forward: bool,
+    ) -> Caret {
+        if row < 0 || lines.is_empty() {
+            return (0, 0);
+        }
+        let row = row as usize;
+        if row >= lines.len() {
+            return (lines.len() - 1, line_width(&lines[lines.len() - 1].1));
+        }
+        let column = column.max(0) as usize + forward as usize;
+        (row, column.min(line_width(&lines[row].1)))
+    }
+
+    /// Moves the cursor by one cell or line (or to the start or end of the line) with the arrow keys.
+    fn move_cursor(&mut self, lines: &[(bool, Vec<(usize, &str)>)], code: KeyCode) -> bool {
+        let Some(last) = lines.len().checked_sub(1) else {
+            return false;
+        };
+        let (row, column) = self.cursor;
+        let width = |row: usize| line_width(&lines[row].1);
+        self.cursor = match code {
+            KeyCode::Left if column > 0 => (row, column - 1),
+            KeyCode::Left if row > 0 => (row - 1, width(row - 1)),
+            KeyCode::Right if column < width(row) => (row, column + 1),
+            KeyCode::Right if row < last => (row + 1, 0),
+            KeyCode::Up if row > 0 => (row - 1, column.min(width(row - 1))),
+            KeyCode::Down if row < last => (row + 1, column.min(width(row + 1))),
+            KeyCode::Home => (row, 0),
+            KeyCode::End => (row, width(row)),
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down => self.cursor,
+            _ => return false,
+        };
+        true
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event, lines: &[(bool, Vec<(usize, &str)>)]) {
+        let prev = self.range();
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if cx.is_hot() => {
+                cx.set_active(true);
+                cx.request_focus();
+                self.anchor = Self::caret_at(lines, *row, *column, false);
+                self.cursor = self.anchor;
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if cx.is_active() => {
+                let forward = (*row, *column) >= (self.anchor.0 as i16, self.anchor.1 as i16);
+                self.cursor = Self::caret_at(lines, *row, *column, forward);
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            }) if cx.is_active() => cx.set_active(false),
+            Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) if cx.is_focused()
+                && modifiers.contains(KeyModifiers::SHIFT)
+                && self.move_cursor(lines, *code) =>
+            {
+                cx.set_handled(true);
+            }
+            Event::FocusLost => cx.set_active(false),
+            _ => (),
+        }
+        // empty selections are all the same
+        let empty = |(start, end): (Caret, Caret)| start == end;
+        if self.range() != prev && !(empty(self.range()) && empty(prev)) {
+            cx.add_message(Message::new(self.id_path.clone(), self.text(lines)));
+            cx.request_paint();
+        }
+    }
+}
+
+fn line_width(cells: &[(usize, &str)]) -> usize {
+    cells
+        .iter()
+        .map(|(column, g)| column + g.width())
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrap(text: &str, mode: WrapMode, width: f64) -> Vec<String> {
+        let widget = WrappedText::new(vec![(text.to_string(), Style::default())], mode, None);
+        widget
+            .wrap(width)
+            .into_iter()
+            .map(|line| line.pieces.into_iter().map(|(text, _)| text).collect())
+            .collect()
+    }
+
+    #[test]
+    fn wraps_on_words() {
+        assert_eq!(
+            wrap(
+                "Lorem ipsum dolor sit amet\n indented",
+                WrapMode::Word,
+                11.0
+            ),
+            ["Lorem ipsum", "dolor sit", "amet", " indented"]
+        );
+        assert_eq!(
+            wrap("see https://example.com/path", WrapMode::Word, 8.0),
+            ["see", "https://example.com/path"]
+        );
+    }
+
+    #[test]
+    fn breaks_long_words_anywhere() {
+        assert_eq!(
+            wrap("see https://example.com/path", WrapMode::Anywhere, 8.0),
+            ["see", "https://", "example.", "com/path"]
+        );
+    }
+
+    #[test]
+    fn keeps_no_break_spaces() {
+        let text = "costs 100\u{a0}€";
+        assert_eq!(wrap(text, WrapMode::Word, 9.0), ["costs 100", "€"]);
+        assert_eq!(
+            wrap(text, WrapMode::KeepNoBreakSpaces, 9.0),
+            ["costs", "100\u{a0}€"]
+        );
+    }
+
+    #[test]
+    fn ellipsizes_last_line() {
+        let mut line = Line::default();
+        line.push("Lorem ipsum", Style::default());
+        line.ellipsize(7);
+        assert_eq!(line.pieces[0].0, "Lorem…");
+    }
+
+    #[test]
+    fn selects_text_across_lines() {
+        let cells = |text: &'static str| text.graphemes(true).enumerate().collect::<Vec<_>>();
+        let lines = [
+            (false, cells("Lorem ipsum")),
+            (true, cells("dolor sit")),
+            (false, cells("amet")),
+        ];
+        let mut selection = TextSelection::new(&IdPath::new(), Style::default());
+        selection.anchor = (0, 6);
+        selection.cursor = (2, 2);
+        assert_eq!(selection.text(&lines), "ipsum dolor sit\nam");
+
+        // the selection is the same in both directions
+        (selection.anchor, selection.cursor) = (selection.cursor, selection.anchor);
+        assert_eq!(selection.text(&lines), "ipsum dolor sit\nam");
+
+        selection.anchor = (1, 0);
+        selection.cursor = (1, 0);
+        assert!(selection.move_cursor(&lines, KeyCode::End));
+        for _ in 0..4 {
+            assert!(selection.move_cursor(&lines, KeyCode::Left));
+        }
+        assert_eq!(selection.text(&lines), "dolor");
+        assert!(!selection.move_cursor(&lines, KeyCode::Char('a')));
+    }
 }
@@ -0,0 +1,369 @@
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
+use unicode_width::UnicodeWidthChar;
+
+use super::{
+    core::{IdPath, PaintCx},
+    BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    RawMouseEvent, Widget,
+};
+use crate::{geometry::Size, EditHistory, TextInputTheme};
+
+/// The width of the text input, when it isn't bounded by its parent.
+const DEFAULT_TEXT_INPUT_WIDTH: f64 = 20.0;
+
+/// A single line text field, which sends the edited value as `String` to its view.
+///
+/// The value is only transformed for painting (e.g. masked), the sent value is always the raw value.
+/// Edits can be undone with `Ctrl-Z` and redone with `Ctrl-Shift-Z` (or `Ctrl-Y`), see [`EditHistory::handle_key`].
+pub struct TextInput {
+    id_path: IdPath,
+    value: String,
+    styles: TextInputTheme,
+    /// Every character is painted as this character, e.g. for passwords.
+    mask: Option<char>,
+    /// Only characters, for which this returns `true`, can be typed.
+    filter: Option<fn(char) -> bool>,
+    /// The maximum number of characters, that can be typed.
+    max_length: Option<usize>,
+    is_valid: bool,
+    /// The position of the cursor in characters.
+    cursor: usize,
+    /// The first visible character, so that the cursor stays visible in long values.
+    offset: usize,
+    /// The edits of the value with the cursor after each edit, it's cleared when the app changes the value itself.
+    history: EditHistory<(String, usize)>,
+}
+
+impl TextInput {
+    pub(crate) fn new(id_path: &IdPath, value: String, styles: TextInputTheme) -> Self {
+        let cursor = value.chars().count();
+        TextInput {
+            id_path: id_path.clone(),
+            history: EditHistory::new((value.clone(), cursor)),
+            cursor,
+            value,
+            styles,
+            mask: None,
+            filter: None,
+            max_length: None,
+            is_valid: true,
+            offset: 0,
+        }
+    }
+
+    pub(crate) fn set_value(&mut self, value: String) -> ChangeFlags {
+        if self.value != value {
+            self.value = value;
+            self.cursor = self.cursor.min(self.len());
+            // the edits don't apply to a value set by the app
+            if self.history.current().0 != self.value {
+                self.history = EditHistory::new((self.value.clone(), self.cursor));
+            }
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: TextInputTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_mask(&mut self, mask: Option<char>) -> ChangeFlags {
+        if self.mask != mask {
+            self.mask = mask;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The filter and the maximum length only apply to the characters typed afterwards.
+    pub(crate) fn set_filter(
+        &mut self,
+        filter: Option<fn(char) -> bool>,
+        max_length: Option<usize>,
+    ) {
+        self.filter = filter;
+        self.max_length = max_length;
+    }
+
+    pub(crate) fn set_valid(&mut self, is_valid: bool) -> ChangeFlags {
+        if self.is_valid != is_valid {
+            self.is_valid = is_valid;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// The number of characters of the value.
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    /// The byte index of the character at `index`.
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(index)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+
+    /// Whether `c` can be typed, with the filter and the maximum length of this input.
+    fn accepts(&self, c: char) -> bool {
+        !c.is_control()
+            && self.filter.is_none_or(|filter| filter(c))
+            && self.max_length.is_none_or(|max| self.len() < max)
+    }
+
+    /// Changes the value locally (until the view is rebuilt), so that following keys in the same batch see the change.
+    fn change_value(&mut self, cx: &mut EventCx, value: String) {
+        self.value = value;
+        self.history.record((self.value.clone(), self.cursor));
+        cx.add_message(Message::new(self.id_path.clone(), self.value.clone()));
+    }
+
+    /// Moves the cursor, a following edit is a new undo step.
+    fn move_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor;
+        self.history.break_coalescing();
+    }
+
+    /// The characters as they are painted.
+    fn display(&self) -> Vec<char> {
+        match self.mask {
+            Some(mask) => vec![mask; self.len()],
+            None => self.value.chars().collect(),
+        }
+    }
+}
+
+impl Widget for TextInput {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let width = cx.cell_size().width as usize;
+        if width == 0 || cx.size().height < 1.0 {
+            return;
+        }
+        let is_focused = cx.is_focused();
+        let mut style = self.styles.style;
+        if is_focused {
+            style = style.patch(self.styles.focused);
+        }
+        if !self.is_valid {
+            style = style.patch(self.styles.invalid);
+        }
+
+        let chars = self.display();
+        // the cursor takes a cell after the last character
+        let cell_width = |c: &char| c.width().unwrap_or(0).max(1);
+        self.offset = self.offset.min(self.cursor);
+        while chars[self.offset..self.cursor]
+            .iter()
+            .map(cell_width)
+            .sum::<usize>()
+            >= width
+        {
+            self.offset += 1;
+        }
+
+        for x in 0..width {
+            cx.set_cell((x as f64, 0.0), " ", style);
+        }
+        let mut x = 0;
+        for (index, c) in chars.iter().enumerate().skip(self.offset) {
+            let w = cell_width(c);
+            if x + w > width {
+                break;
+            }
+            let symbol_style = if is_focused && index == self.cursor {
+                style.patch(self.styles.cursor)
+            } else {
+                style
+            };
+            cx.set_cell((x as f64, 0.0), c.encode_utf8(&mut [0; 4]), symbol_style);
+            x += w;
+        }
+        if is_focused && self.cursor == chars.len() && x < width {
+            cx.set_cell((x as f64, 0.0), " ", style.patch(self.styles.cursor));
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let max = bc.max().width;
+        let width = if max.is_finite() {
+            max
+        } else {
+            DEFAULT_TEXT_INPUT_WIDTH
+        };
+        bc.constrain(Size::new(width, 1.0))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                ..
+            }) if cx.is_hot() => {
+                cx.request_focus();
+                // the cursor is placed at the clicked character
+                let mut x = 0;
+                let chars = self.display();
+                let cursor = self.offset
+                    + chars[self.offset.min(chars.len())..]
+                        .iter()
+                        .take_while(|c| {
+                            x += c.width().unwrap_or(0).max(1);
+                            x <= *column as usize
+                        })
+                        .count();
+                self.move_cursor(cursor);
+                cx.request_paint();
+                cx.set_handled(true);
+            }
+            Event::Key(
+                key @ KeyEvent {
+                    code,
+                    modifiers,
+                    kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                    ..
+                },
+            ) if cx.is_focused() => {
+                if let Some((value, cursor)) = self.history.handle_key(key).cloned() {
+                    self.value = value;
+                    self.cursor = cursor;
+                    cx.add_message(Message::new(self.id_path.clone(), self.value.clone()));
+                    cx.request_paint();
+                    cx.set_handled(true);
+                    return;
+                }
+                let len = self.len();
+                match code {
+                    KeyCode::Char(c)
+                        if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                    {
+                        if !self.accepts(*c) {
+                            return;
+                        }
+                        let mut value = self.value.clone();
+                        value.insert(self.byte_index(self.cursor), *c);
+                        self.cursor += 1;
+                        self.change_value(cx, value);
+                    }
+                    KeyCode::Backspace if self.cursor > 0 => {
+                        self.cursor -= 1;
+                        let mut value = self.value.clone();
+                        value.remove(self.byte_index(self.cursor));
+                        self.change_value(cx, value);
+                    }
+                    KeyCode::Delete if self.cursor < len => {
+                        let mut value = self.value.clone();
+                        value.remove(self.byte_index(self.cursor));
+                        self.change_value(cx, value);
+                    }
+                    KeyCode::Left if self.cursor > 0 => self.move_cursor(self.cursor - 1),
+                    KeyCode::Right if self.cursor < len => self.move_cursor(self.cursor + 1),
+                    KeyCode::Home => self.move_cursor(0),
+                    KeyCode::End => self.move_cursor(len),
+                    _ => return,
+                }
+                cx.request_paint();
+                cx.set_handled(true);
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::BuildFocusChain = event {
+            cx.register_for_focus();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::TestCx;
+
+    /// Sends the `keys` to the focused `input`, and returns the values sent to the view.
+    fn press_keys(input: &mut TextInput, keys: &[KeyEvent]) -> Vec<String> {
+        let mut cx = TestCx::focused();
+        for key in keys {
+            cx.event_cx(|cx| input.event(cx, &Event::Key(*key)));
+        }
+        cx.take_messages()
+    }
+
+    /// Types the `keys` without modifiers, see [`press_keys`].
+    fn type_keys(input: &mut TextInput, keys: &[KeyCode]) -> Vec<String> {
+        let keys: Vec<_> = keys.iter().map(|&code| KeyEvent::from(code)).collect();
+        press_keys(input, &keys)
+    }
+
+    #[test]
+    fn typed_characters_are_filtered() {
+        let mut input = TextInput::new(&IdPath::new(), "12".into(), TextInputTheme::default());
+        input.set_filter(Some(|c| c.is_ascii_digit()), Some(4));
+        let values = type_keys(
+            &mut input,
+            &[
+                KeyCode::Char('3'),
+                KeyCode::Char('x'),
+                KeyCode::Home,
+                KeyCode::Char('0'),
+                KeyCode::Char('9'),
+                KeyCode::End,
+                KeyCode::Backspace,
+            ],
+        );
+        assert_eq!(values, ["123", "0123", "012"]);
+    }
+
+    #[test]
+    fn masked_values_are_sent_raw() {
+        let mut input = TextInput::new(&IdPath::new(), "pw".into(), TextInputTheme::default());
+        let _ = input.set_mask(Some('•'));
+        let values = type_keys(&mut input, &[KeyCode::Left, KeyCode::Char('ä')]);
+        assert_eq!(values, ["päw"]);
+        assert_eq!(input.display(), ['•', '•', '•']);
+    }
+
+    #[test]
+    fn edits_can_be_undone_and_redone() {
+        let mut input = TextInput::new(&IdPath::new(), String::new(), TextInputTheme::default());
+        // typing is coalesced into one step, until the cursor is moved
+        let mut values = type_keys(
+            &mut input,
+            &[
+                KeyCode::Char('a'),
+                KeyCode::Char('b'),
+                KeyCode::Home,
+                KeyCode::Char('c'),
+            ],
+        );
+        let ctrl = KeyModifiers::CONTROL;
+        values.extend(press_keys(
+            &mut input,
+            &[
+                KeyEvent::new(KeyCode::Char('z'), ctrl),
+                KeyEvent::new(KeyCode::Char('z'), ctrl),
+                KeyEvent::new(KeyCode::Char('Z'), ctrl | KeyModifiers::SHIFT),
+            ],
+        ));
+        assert_eq!(values, ["a", "ab", "cab", "ab", "", "ab"]);
+        assert_eq!(input.cursor, 2);
+
+        // the app resetting the value starts a new history
+        let _ = input.set_value("reset".into());
+        assert!(press_keys(&mut input, &[KeyEvent::new(KeyCode::Char('z'), ctrl)]).is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+
+use super::{
+    animatables::{AnimatableElement, LowPassIIR},
+    core::{IdPath, PaintCx},
+    BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    RawMouseEvent, Widget,
+};
+use crate::{geometry::Size, ToggleTheme};
+
+/// The width of the toggle in cells, including the brackets.
+const TOGGLE_WIDTH: usize = 6;
+
+/// The decay of the low-pass filter that animates the thumb.
+pub(crate) const THUMB_DECAY: f64 = 0.3;
+
+/// A switch with an animated thumb, that sends the negated value to its view when it's clicked,
+/// or when `Enter`/`Space` is pressed while it's focused.
+pub struct Toggle {
+    id_path: IdPath,
+    value: bool,
+    styles: ToggleTheme,
+    /// The thumb position from `0.0` (off) to `1.0` (on)
+    thumb: LowPassIIR<f64, f64>,
+    thumb_position: f64,
+}
+
+impl Toggle {
+    pub(crate) fn new(id_path: &IdPath, value: bool, styles: ToggleTheme) -> Self {
+        let position = if value { 1.0 } else { 0.0 };
+        Toggle {
+            id_path: id_path.clone(),
+            value,
+            styles,
+            thumb: LowPassIIR::new(position, THUMB_DECAY),
+            thumb_position: position,
+        }
+    }
+
+    pub(crate) fn set_value(&mut self, value: bool) -> ChangeFlags {
+        if self.value != value {
+            self.value = value;
+            self.thumb.target = if value { 1.0 } else { 0.0 };
+            ChangeFlags::ANIMATION | ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: ToggleTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    fn toggle(&self, cx: &mut EventCx) {
+        cx.add_message(Message::new(self.id_path.clone(), !self.value));
+    }
+}
+
+impl Widget for Toggle {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let mut style = if self.value {
+            self.styles.on
+        } else {
+            self.styles.off
+        };
+        if cx.is_focused() {
+            style = style.patch(self.styles.focused);
+        }
+
+        let width = (cx.cell_size().width as usize).min(TOGGLE_WIDTH);
+        if width < 3 || cx.size().height < 1.0 {
+            return;
+        }
+        let track_width = width - 2;
+        let thumb = (self.thumb_position * (track_width - 1) as f64).round() as usize;
+        for x in 0..width {
+            let symbol = match x {
+                0 => "[",
+                x if x == width - 1 => "]",
+                x if x - 1 == thumb => "●",
+                _ => "─",
+            };
+            cx.set_cell((x as f64, 0.0), symbol, style);
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        bc.constrain(Size::new(TOGGLE_WIDTH as f64, 1.0))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                ..
+            }) if cx.is_hot() => {
+                cx.set_active(true);
+                cx.request_focus();
+            }
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            }) if cx.is_active() => {
+                if cx.is_hot() {
+                    self.toggle(cx);
+                }
+                cx.set_active(false);
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter | KeyCode::Char(' '),
+                kind: KeyEventKind::Press,
+                ..
+            }) if cx.is_focused() => {
+                self.toggle(cx);
+                cx.set_handled(true);
+            }
+            Event::FocusLost => cx.set_active(false),
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        match event {
+            LifeCycle::BuildFocusChain => cx.register_for_focus(),
+            LifeCycle::Animate => {
+                let position = *self.thumb.animate(cx);
+                if position != self.thumb_position {
+                    self.thumb_position = position;
+                    cx.request_paint();
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+    use crate::{
+        test_helper::TestCx,
+        widget::{core::FocusRequest, PodFlags},
+    };
+
+    fn mouse(kind: MouseEventKind) -> Event {
+        Event::Mouse(RawMouseEvent {
+            kind,
+            column: 1,
+            row: 0,
+            window_column: 1,
+            window_row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn enter_and_space_toggle_the_focused_toggle() {
+        let mut toggle = Toggle::new(&IdPath::new(), false, ToggleTheme::default());
+        let mut cx = TestCx::new();
+        cx.event_cx(|cx| toggle.event(cx, &Event::Key(KeyCode::Enter.into())));
+        assert!(cx.take_messages::<bool>().is_empty());
+
+        cx.widget_state.flags |= PodFlags::IS_FOCUSED;
+        for code in [KeyCode::Enter, KeyCode::Char(' '), KeyCode::Char('x')] {
+            cx.event_cx(|cx| toggle.event(cx, &Event::Key(code.into())));
+        }
+        assert_eq!(cx.take_messages::<bool>(), [true, true]);
+    }
+
+    #[test]
+    fn clicks_toggle_and_focus_the_toggle() {
+        let mut toggle = Toggle::new(&IdPath::new(), true, ToggleTheme::default());
+        let mut cx = TestCx::hot();
+        cx.event_cx(|cx| toggle.event(cx, &mouse(MouseEventKind::Down(MouseButton::Left))));
+        assert_eq!(
+            cx.focus.request,
+            Some(FocusRequest::Focus(cx.widget_state.id))
+        );
+        assert!(cx.take_messages::<bool>().is_empty());
+        cx.event_cx(|cx| toggle.event(cx, &mouse(MouseEventKind::Up(MouseButton::Left))));
+        assert_eq!(cx.take_messages::<bool>(), [false]);
+
+        // releasing the mouse outside of the toggle cancels the click
+        cx.event_cx(|cx| toggle.event(cx, &mouse(MouseEventKind::Down(MouseButton::Left))));
+        cx.widget_state.flags.remove(PodFlags::IS_HOT);
+        cx.event_cx(|cx| toggle.event(cx, &mouse(MouseEventKind::Up(MouseButton::Left))));
+        assert!(cx.take_messages::<bool>().is_empty());
+    }
+
+    #[test]
+    fn the_thumb_slides_to_the_new_value() {
+        let mut toggle = Toggle::new(&IdPath::new(), false, ToggleTheme::default());
+        let mut cx = TestCx::new();
+        let mut animate = |toggle: &mut Toggle| {
+            cx.with_cx_state(|cx_state, widget_state| {
+                cx_state.time_since_last_render_request = Duration::from_millis(16);
+                let cx = &mut LifeCycleCx {
+                    cx_state,
+                    widget_state,
+                };
+                toggle.lifecycle(cx, &LifeCycle::Animate);
+            });
+            toggle.thumb_position
+        };
+        assert_eq!(animate(&mut toggle), 0.0);
+
+        assert!(toggle.set_value(true).contains(ChangeFlags::ANIMATION));
+        let first = animate(&mut toggle);
+        // the thumb doesn't jump to the new value
+        assert!(first > 0.0);
+        assert!(first < 1.0);
+        let mut previous = first;
+        for _ in 0..100 {
+            let position = animate(&mut toggle);
+            assert!(position >= previous);
+            previous = position;
+        }
+        assert_eq!(previous, 1.0);
+    }
+}
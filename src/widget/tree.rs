@@ -0,0 +1,324 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::style::Style;
+use unicode_width::UnicodeWidthStr;
+
+use super::{
+    core::{IdPath, PaintCx},
+    BoxConstraints, ChangeFlags, Event, EventCx, LayoutCx, LifeCycle, LifeCycleCx, Message,
+    RawMouseEvent, Widget,
+};
+use crate::{geometry::Size, TreeChildren, TreeNode, TreeTheme};
+
+/// The width of one indentation level and of the expand/collapse indicator.
+const INDENT: usize = 2;
+
+/// Sent by the [`Tree`] widget to its view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TreeMessage<K> {
+    Activate(K),
+    /// A lazy node was expanded the first time.
+    LoadChildren(K),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RowKind {
+    Leaf,
+    Collapsed,
+    Expanded,
+    Loading,
+}
+
+/// A visible row of the flattened tree.
+struct Row<K> {
+    key: K,
+    parent: Option<usize>,
+    depth: usize,
+    label: Cow<'static, str>,
+    style: Style,
+    kind: RowKind,
+}
+
+pub struct Tree<K> {
+    id_path: IdPath,
+    nodes: Vec<TreeNode<K>>,
+    /// The children of lazy nodes, that have been loaded.
+    loaded: HashMap<K, Vec<TreeNode<K>>>,
+    expanded: HashSet<K>,
+    rows: Vec<Row<K>>,
+    cursor: Option<K>,
+    styles: TreeTheme,
+}
+
+impl<K: Clone + Eq + Hash + Send + 'static> Tree<K> {
+    pub(crate) fn new(id_path: &IdPath, nodes: Vec<TreeNode<K>>, styles: TreeTheme) -> Self {
+        let mut tree = Tree {
+            id_path: id_path.clone(),
+            nodes,
+            loaded: HashMap::new(),
+            expanded: HashSet::new(),
+            rows: Vec::new(),
+            cursor: None,
+            styles,
+        };
+        tree.update_rows();
+        tree
+    }
+
+    pub(crate) fn set_nodes(&mut self, nodes: Vec<TreeNode<K>>) -> ChangeFlags {
+        self.nodes = nodes;
+        self.update_rows();
+        ChangeFlags::LAYOUT | ChangeFlags::PAINT
+    }
+
+    pub(crate) fn insert_children(&mut self, key: K, children: Vec<TreeNode<K>>) -> ChangeFlags {
+        self.loaded.insert(key, children);
+        self.update_rows();
+        ChangeFlags::LAYOUT | ChangeFlags::PAINT
+    }
+
+    pub(crate) fn set_styles(&mut self, styles: TreeTheme) -> ChangeFlags {
+        if self.styles != styles {
+            self.styles = styles;
+            ChangeFlags::PAINT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// Flattens the expanded parts of the tree into rows.
+    fn update_rows(&mut self) {
+        fn flatten<K: Clone + Eq + Hash>(
+            rows: &mut Vec<Row<K>>,
+            nodes: &[TreeNode<K>],
+            loaded: &HashMap<K, Vec<TreeNode<K>>>,
+            expanded: &HashSet<K>,
+            parent: Option<usize>,
+            depth: usize,
+        ) {
+            for node in nodes {
+                let children = match &node.children {
+                    TreeChildren::Leaf => None,
+                    TreeChildren::Loaded(children) => Some(Some(children)),
+                    TreeChildren::Lazy => Some(loaded.get(&node.key)),
+                };
+                let is_expanded = expanded.contains(&node.key);
+                let kind = match children {
+                    None => RowKind::Leaf,
+                    Some(_) if !is_expanded => RowKind::Collapsed,
+                    Some(None) => RowKind::Loading,
+                    Some(Some(_)) => RowKind::Expanded,
+                };
+                rows.push(Row {
+                    key: node.key.clone(),
+                    parent,
+                    depth,
+                    label: node.label.clone(),
+                    style: node.style,
+                    kind,
+                });
+                if let (RowKind::Expanded, Some(Some(children))) = (kind, children) {
+                    let index = rows.len() - 1;
+                    flatten(rows, children, loaded, expanded, Some(index), depth + 1);
+                }
+            }
+        }
+
+        self.rows.clear();
+        flatten(
+            &mut self.rows,
+            &self.nodes,
+            &self.loaded,
+            &self.expanded,
+            None,
+            0,
+        );
+        if !self
+            .cursor
+            .as_ref()
+            .is_some_and(|cursor| self.rows.iter().any(|row| row.key == *cursor))
+        {
+            self.cursor = self.rows.first().map(|row| row.key.clone());
+        }
+    }
+
+    fn cursor_index(&self) -> Option<usize> {
+        let cursor = self.cursor.as_ref()?;
+        self.rows.iter().position(|row| row.key == *cursor)
+    }
+
+    fn set_expanded(&mut self, cx: &mut EventCx, index: usize, expand: bool) {
+        let row = &self.rows[index];
+        if row.kind == RowKind::Leaf || expand == self.expanded.contains(&row.key) {
+            return;
+        }
+        let key = row.key.clone();
+        if expand {
+            let is_unloaded_lazy = row.kind == RowKind::Collapsed
+                && !self.loaded.contains_key(&key)
+                && self.lazy_node(&key);
+            if is_unloaded_lazy {
+                cx.add_message(Message::new(
+                    self.id_path.clone(),
+                    TreeMessage::LoadChildren(key.clone()),
+                ));
+            }
+            self.expanded.insert(key);
+        } else {
+            self.expanded.remove(&key);
+        }
+        self.update_rows();
+        cx.request_layout();
+        cx.request_paint();
+    }
+
+    /// Whether the node with `key` has lazily loaded children.
+    fn lazy_node(&self, key: &K) -> bool {
+        fn find<K: Eq + Hash>(
+            nodes: &[TreeNode<K>],
+            loaded: &HashMap<K, Vec<TreeNode<K>>>,
+            key: &K,
+        ) -> Option<bool> {
+            nodes.iter().find_map(|node| {
+                if node.key == *key {
+                    return Some(matches!(node.children, TreeChildren::Lazy));
+                }
+                match &node.children {
+                    TreeChildren::Leaf => None,
+                    TreeChildren::Loaded(children) => find(children, loaded, key),
+                    TreeChildren::Lazy => find(loaded.get(&node.key)?, loaded, key),
+                }
+            })
+        }
+        find(&self.nodes, &self.loaded, key).unwrap_or(false)
+    }
+
+    fn activate(&self, cx: &mut EventCx, index: usize) {
+        let key = self.rows[index].key.clone();
+        cx.add_message(Message::new(
+            self.id_path.clone(),
+            TreeMessage::Activate(key),
+        ));
+    }
+
+    fn move_cursor(&mut self, cx: &mut EventCx, index: usize) {
+        if let Some(row) = self.rows.get(index) {
+            self.cursor = Some(row.key.clone());
+            cx.request_paint();
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash + Send + 'static> Widget for Tree<K> {
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let height = cx.cell_size().height as usize;
+        let cursor = if cx.is_focused() {
+            self.cursor_index()
+        } else {
+            None
+        };
+
+        for (y, row) in self.rows.iter().enumerate().take(height) {
+            let mut print = |x: usize, text: &str, style: Style| {
+                cx.set_string((x as f64, y as f64), text, style);
+            };
+            for level in 0..row.depth {
+                print(level * INDENT, "│ ", self.styles.guides);
+            }
+            let indicator = match row.kind {
+                RowKind::Leaf => "  ",
+                RowKind::Collapsed => "▸ ",
+                RowKind::Expanded => "▾ ",
+                RowKind::Loading => "⋯ ",
+            };
+            let x = row.depth * INDENT;
+            print(x, indicator, self.styles.indicator);
+            let mut style = row.style;
+            if cursor == Some(y) {
+                style = style.patch(self.styles.cursor);
+            }
+            print(x + INDENT, &row.label, style);
+        }
+    }
+
+    fn layout(&mut self, _cx: &mut LayoutCx, bc: &BoxConstraints) -> Size {
+        let width = self
+            .rows
+            .iter()
+            .map(|row| (row.depth + 1) * INDENT + row.label.width())
+            .max()
+            .unwrap_or(0);
+        bc.constrain(Size::new(width as f64, self.rows.len() as f64))
+    }
+
+    fn event(&mut self, cx: &mut EventCx, event: &Event) {
+        match event {
+            Event::Mouse(RawMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if cx.is_hot() => {
+                cx.request_focus();
+                let (x, index) = (*column as usize, *row as usize);
+                let Some(row) = self.rows.get(index) else {
+                    return;
+                };
+                let indicator_x = row.depth * INDENT;
+                if (indicator_x..indicator_x + INDENT).contains(&x) {
+                    let expand = !self.expanded.contains(&row.key);
+                    self.move_cursor(cx, index);
+                    self.set_expanded(cx, index, expand);
+                } else if x >= indicator_x + INDENT {
+                    self.move_cursor(cx, index);
+                    self.activate(cx, index);
+                }
+            }
+            Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                ..
+            }) if cx.is_focused() => {
+                let Some(index) = self.cursor_index() else {
+                    return;
+                };
+                let row = &self.rows[index];
+                match code {
+                    KeyCode::Up => self.move_cursor(cx, index.saturating_sub(1)),
+                    KeyCode::Down => self.move_cursor(cx, index + 1),
+                    KeyCode::Right if row.kind == RowKind::Expanded => {
+                        self.move_cursor(cx, index + 1)
+                    }
+                    KeyCode::Right => self.set_expanded(cx, index, true),
+                    KeyCode::Left if matches!(row.kind, RowKind::Expanded | RowKind::Loading) => {
+                        self.set_expanded(cx, index, false)
+                    }
+                    KeyCode::Left => {
+                        if let Some(parent) = row.parent {
+                            self.move_cursor(cx, parent);
+                        }
+                    }
+                    KeyCode::Char(' ') => {
+                        let expand = !self.expanded.contains(&row.key);
+                        self.set_expanded(cx, index, expand);
+                    }
+                    KeyCode::Enter => self.activate(cx, index),
+                    _ => return,
+                }
+                cx.set_handled(true);
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(&mut self, cx: &mut LifeCycleCx, event: &LifeCycle) {
+        if let LifeCycle::BuildFocusChain = event {
+            cx.register_for_focus();
+        }
+    }
+}
@@ -3,19 +3,37 @@ use crate::geometry::{Axis, Size};
 use super::{
     animatables::AnimatableElement,
     core::{EventCx, PaintCx},
-    BoxConstraints, LayoutCx, LifeCycle, LifeCycleCx, Pod, Widget,
+    linear_layout::measure_stack,
+    BoxConstraints, ChangeFlags, IntrinsicSize, LayoutCx, LifeCycle, LifeCycleCx, Pod, Widget,
 };
 
+/// Distributes the available space along `axis` to its children by their weight.
+///
+/// Children without an explicit weight (i.e. not wrapped in a [`WeightedLayoutElement`]),
+/// that have an intrinsic size (see [`Widget::measure`]), are not flexible:
+/// they are sized first, and only the remaining space is distributed to the other children.
+/// Children without an intrinsic size get a weight of 1.
+///
+/// The sizes of the flexible children are clamped to their limits (see [`WeightedLayoutElement`]),
+/// and rounded to whole cells, so that they always fill the available space exactly.
 pub struct WeightedLinearLayout {
     pub children: Vec<Pod>,
     pub weights: Vec<f64>,
     pub axis: Axis,
+    /// The min and max size on the major axis of the children (by index), computed in each layout pass.
+    limits: Vec<(f64, f64)>,
+    /// The size on the major axis of the non-flexible children (by index), computed in each layout pass.
+    fixed_sizes: Vec<Option<f64>>,
+    /// Whether the children are arranged from the end to the start of the main axis (e.g. for right-to-left layouts).
+    reverse: bool,
 }
 
 pub struct WeightedLayoutElement {
     pub(crate) content: Pod,
     pub(crate) weight_animatable: Box<dyn AnimatableElement<f64>>,
     weight: f64,
+    /// The min and max size on the major axis of the layout.
+    limits: (f64, f64),
 }
 
 impl WeightedLayoutElement {
@@ -24,6 +42,16 @@ impl WeightedLayoutElement {
             content: Pod::new(content),
             weight_animatable: Box::new(weight_element),
             weight: 1.0,
+            limits: (0.0, f64::INFINITY),
+        }
+    }
+
+    pub(crate) fn set_limits(&mut self, min: f64, max: f64) -> ChangeFlags {
+        if self.limits != (min, max) {
+            self.limits = (min, max);
+            ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
         }
     }
 }
@@ -48,30 +76,157 @@ impl Widget for WeightedLayoutElement {
     fn event(&mut self, cx: &mut EventCx, event: &super::Event) {
         self.content.event(cx, event)
     }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        self.content.measure(cx, axis, cross)
+    }
 }
 
-fn get_weights(children: &[Pod], weights: &mut Vec<f64>) -> f64 {
+fn get_weights(children: &[Pod], fixed_sizes: &[Option<f64>], weights: &mut Vec<f64>) {
     weights.clear();
-    let mut sum = 0.0;
-    for child in children {
+    for (child, fixed_size) in children.iter().zip(fixed_sizes) {
         let weight = if let Some(weighted_el) = child.downcast_ref::<WeightedLayoutElement>() {
             weighted_el.weight
+        } else if fixed_size.is_some() {
+            0.0
         } else {
             1.0
         };
-        sum += weight;
         weights.push(weight);
     }
-    sum
+}
+
+fn get_limits(children: &[Pod], limits: &mut Vec<(f64, f64)>) {
+    limits.clear();
+    limits.extend(children.iter().map(|child| {
+        child
+            .downcast_ref::<WeightedLayoutElement>()
+            .map_or((0.0, f64::INFINITY), |weighted_el| weighted_el.limits)
+    }));
+}
+
+/// Distributes `space` by `weights`, while keeping each size within its `limits`.
+///
+/// The sizes of children that would violate their limits are fixed at the limit,
+/// and the space is distributed again to the remaining children (like flexbox does).
+/// If the min sizes don't fit, the sum of the sizes exceeds the space.
+fn distribute(space: f64, weights: &[f64], limits: &[(f64, f64)]) -> Vec<f64> {
+    let mut sizes = vec![0.0; weights.len()];
+    let mut frozen = vec![false; weights.len()];
+    loop {
+        let frozen_space: f64 = (0..sizes.len())
+            .filter(|&i| frozen[i])
+            .map(|i| sizes[i])
+            .sum();
+        let total_weight: f64 = (0..weights.len())
+            .filter(|&i| !frozen[i])
+            .map(|i| weights[i])
+            .sum();
+        let remaining = (space - frozen_space).max(0.0);
+        let mut violation = 0.0;
+        for i in (0..sizes.len()).filter(|&i| !frozen[i]) {
+            let (min, max) = limits[i];
+            let size = if total_weight > 0.0 {
+                remaining * weights[i] / total_weight
+            } else {
+                0.0
+            };
+            sizes[i] = size.min(max).max(min);
+            violation += sizes[i] - size;
+        }
+        if violation == 0.0 {
+            return sizes;
+        }
+        // the children that are clamped in the direction of the total violation are fixed at their limit
+        let mut changed = false;
+        for i in 0..sizes.len() {
+            let (min, max) = limits[i];
+            let clamped = if violation > 0.0 {
+                sizes[i] == min
+            } else {
+                sizes[i] == max
+            };
+            if !frozen[i] && clamped {
+                frozen[i] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            return sizes;
+        }
+    }
+}
+
+/// Rounds `sizes` to whole cells, so that their sum is the rounded sum of the exact sizes,
+/// the remaining cells go to the sizes with the largest fractional part (largest remainder method).
+fn round_sizes(sizes: &mut [f64]) {
+    let total = sizes.iter().sum::<f64>().round();
+    let mut remainders = Vec::with_capacity(sizes.len());
+    for (index, size) in sizes.iter_mut().enumerate() {
+        let floor = size.floor();
+        remainders.push((index, *size - floor));
+        *size = floor;
+    }
+    let missing = (total - sizes.iter().sum::<f64>()).max(0.0) as usize;
+    // stable, so that on ties the first children get the cells
+    remainders.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    for (index, _) in remainders.into_iter().take(missing) {
+        sizes[index] += 1.0;
+    }
 }
 
 impl WeightedLinearLayout {
     pub(crate) fn new(children: Vec<Pod>, axis: Axis) -> Self {
         let weights = Vec::with_capacity(children.len());
+        let fixed_sizes = Vec::with_capacity(children.len());
         WeightedLinearLayout {
             children,
             axis,
             weights,
+            limits: Vec::new(),
+            fixed_sizes,
+            reverse: false,
+        }
+    }
+
+    pub(crate) fn set_reverse(&mut self, reverse: bool) -> ChangeFlags {
+        if self.reverse != reverse {
+            self.reverse = reverse;
+            ChangeFlags::LAYOUT
+        } else {
+            ChangeFlags::empty()
+        }
+    }
+
+    /// Measures the children without an explicit weight, and stores their size on the major axis in `fixed_sizes`.
+    ///
+    /// They get their max intrinsic size, or their min intrinsic size, if the max sizes don't fit into `major_max`.
+    /// Returns the total space used by them.
+    fn measure_fixed_children(&mut self, cx: &mut LayoutCx, major_max: f64, minor_max: f64) -> f64 {
+        self.fixed_sizes.clear();
+        let mut intrinsic_sizes = Vec::with_capacity(self.children.len());
+        let mut total = IntrinsicSize::default();
+        for child in &mut self.children {
+            let size = if child.downcast_ref::<WeightedLayoutElement>().is_none() {
+                child.measure(cx, self.axis, minor_max)
+            } else {
+                None
+            };
+            if let Some(size) = size {
+                total = IntrinsicSize::new(total.min + size.min, total.max + size.max);
+            }
+            intrinsic_sizes.push(size);
+        }
+        let fits = total.max <= major_max;
+        self.fixed_sizes.extend(
+            intrinsic_sizes
+                .into_iter()
+                .map(|size| size.map(|size| if fits { size.max } else { size.min })),
+        );
+        if fits {
+            total.max
+        } else {
+            total.min
         }
     }
 }
@@ -87,17 +242,29 @@ impl Widget for WeightedLinearLayout {
         let mut major_used: f64 = 0.0;
         let mut max_minor: f64 = 0.0;
         let major_max = self.axis.major(*bc).end;
+        let minor_max = self.axis.minor(*bc).end;
 
-        let total_weight_inv = 1.0 / get_weights(&self.children, &mut self.weights);
-        let space_available = self.axis.major(*bc).end;
+        let fixed_space = self.measure_fixed_children(cx, major_max, minor_max);
+        get_weights(&self.children, &self.fixed_sizes, &mut self.weights);
+        get_limits(&self.children, &mut self.limits);
+        let space_available = (major_max - fixed_space).max(0.0);
+        let flexible_sizes = if space_available != f64::INFINITY {
+            let mut sizes = distribute(space_available, &self.weights, &self.limits);
+            round_sizes(&mut sizes);
+            Some(sizes)
+        } else {
+            None
+        };
 
         for (index, child) in self.children.iter_mut().enumerate() {
-            let constraint = if space_available != f64::INFINITY {
-                let size = (space_available * (self.weights[index] * total_weight_inv))
-                    .min(major_max - major_used);
+            let constraint = if let Some(size) = self.fixed_sizes[index] {
+                0.0..size.min(major_max - major_used).max(0.0)
+            } else if let Some(sizes) = &flexible_sizes {
+                let size = sizes[index].min(major_max - major_used).max(0.0);
                 size..size // TODO loosen the minimum size (to 0)?
             } else {
-                0.0..f64::INFINITY
+                let (min, max) = self.limits[index];
+                min..max
             };
             let child_bc = self.axis.with_major(bc.loosen(), constraint);
             let size = child.layout(cx, &child_bc);
@@ -106,6 +273,13 @@ impl Widget for WeightedLinearLayout {
             max_minor = max_minor.max(self.axis.minor(size));
         }
 
+        if self.reverse {
+            for child in &mut self.children {
+                let major = self.axis.major(child.state.origin) + self.axis.major(child.state.size);
+                child.set_origin(cx, self.axis.pack(major_used - major, 0.0));
+            }
+        }
+
         bc.constrain(self.axis.pack::<Size>(major_used, max_minor))
     }
 
@@ -120,4 +294,72 @@ impl Widget for WeightedLinearLayout {
             child.lifecycle(cx, event);
         }
     }
+
+    fn measure(&mut self, cx: &mut LayoutCx, axis: Axis, cross: f64) -> Option<IntrinsicSize> {
+        measure_stack(&mut self.children, cx, self.axis, 0.0, axis, cross)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use super::*;
+    use crate::{test_helper::TestCx, widget::Text};
+
+    fn text(text: &'static str) -> Pod {
+        Pod::new(Text::new(text.into(), Style::default()))
+    }
+
+    fn weighted(text: &'static str) -> Pod {
+        Pod::new(WeightedLayoutElement::new(
+            Text::new(text.into(), Style::default()),
+            1.0,
+        ))
+    }
+
+    #[test]
+    fn rounded_sizes_fill_the_space_exactly() {
+        let mut sizes = distribute(10.0, &[1.0, 1.0, 1.0], &[(0.0, f64::INFINITY); 3]);
+        round_sizes(&mut sizes);
+        assert_eq!(sizes, [4.0, 3.0, 3.0]);
+        assert_eq!(sizes.iter().sum::<f64>(), 10.0);
+    }
+
+    #[test]
+    fn limited_sizes_are_redistributed() {
+        let limits = [(0.0, 2.0), (0.0, f64::INFINITY), (5.0, f64::INFINITY)];
+        let sizes = distribute(12.0, &[1.0, 1.0, 0.0], &limits);
+        assert_eq!(sizes, [2.0, 5.0, 5.0]);
+    }
+
+    #[test]
+    fn measure_sums_up_the_children_along_the_axis() {
+        let mut row = WeightedLinearLayout::new(vec![text("ab"), text("cde\nf")], Axis::Horizontal);
+        let (width, height) = TestCx::new().layout_cx(|cx| {
+            (
+                row.measure(cx, Axis::Horizontal, 1.0),
+                row.measure(cx, Axis::Vertical, f64::INFINITY),
+            )
+        });
+        assert_eq!(width, Some(IntrinsicSize::fixed(5.0)));
+        assert_eq!(height, Some(IntrinsicSize::fixed(2.0)));
+    }
+
+    #[test]
+    fn weighted_children_have_no_intrinsic_size() {
+        let mut row = WeightedLinearLayout::new(vec![text("ab"), weighted("x")], Axis::Horizontal);
+        let width = TestCx::new().layout_cx(|cx| row.measure(cx, Axis::Horizontal, 1.0));
+        assert_eq!(width, None);
+    }
+
+    #[test]
+    fn measured_children_keep_their_size() {
+        let mut row = WeightedLinearLayout::new(vec![text("abc"), weighted("x")], Axis::Horizontal);
+        let bc = BoxConstraints::tight(Size::new(10.0, 1.0));
+        let size = TestCx::new().layout_cx(|cx| row.layout(cx, &bc));
+        assert_eq!(size, Size::new(10.0, 1.0));
+        let sizes: Vec<_> = row.children.iter().map(Pod::size).collect();
+        assert_eq!(sizes, [Size::new(3.0, 1.0), Size::new(7.0, 1.0)]);
+    }
 }
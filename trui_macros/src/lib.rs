@@ -0,0 +1,192 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, GenericArgument,
+    GenericParam, Ident, Member, PathArguments, Type,
+};
+
+/// Implements `Styleable` for a wrapper view by delegating to the field marked with `#[styleable]`.
+///
+/// The type of that field has to be a type parameter of the struct (e.g. `V`),
+/// which is replaced by `V::Output` in the `Output` of the implementation.
+/// All other fields are moved to the output as they are.
+///
+/// ```ignore
+/// #[derive(Styleable)]
+/// pub struct OnClick<V, EH> {
+///     #[styleable]
+///     view: V,
+///     event_handler: EH,
+/// }
+/// ```
+#[proc_macro_derive(Styleable, attributes(styleable))]
+pub fn derive_styleable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Styleable can only be derived for structs",
+        ));
+    };
+    let members: Vec<Member> = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| Member::Named(field.ident.clone().unwrap()))
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len()).map(Member::from).collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let mut delegates = data.fields.iter().zip(&members).filter(|(field, _)| {
+        field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("styleable"))
+    });
+    let Some((delegate, delegate_member)) = delegates.next() else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "one field has to be marked with #[styleable]",
+        ));
+    };
+    if let Some((field, _)) = delegates.next() {
+        return Err(Error::new_spanned(
+            field,
+            "only one field can be marked with #[styleable]",
+        ));
+    }
+    let param = match &delegate.ty {
+        Type::Path(path) if path.qself.is_none() => path.path.get_ident().filter(|ident| {
+            input
+                .generics
+                .type_params()
+                .any(|param| param.ident == **ident)
+        }),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        Error::new_spanned(
+            &delegate.ty,
+            "the #[styleable] field has to be of a generic type of the struct",
+        )
+    })?;
+
+    let trui = quote!(::trui);
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#param: #trui::Styleable));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // the type arguments of the output, with the delegate's parameter replaced by its output
+    let output_args = input
+        .generics
+        .params
+        .iter()
+        .map(|param_def| match param_def {
+            GenericParam::Type(ty) if ty.ident == *param => {
+                quote!(<#param as #trui::Styleable>::Output)
+            }
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                quote!(#ident)
+            }
+            GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                quote!(#lifetime)
+            }
+            GenericParam::Const(constant) => {
+                let ident = &constant.ident;
+                quote!(#ident)
+            }
+        });
+    let output = quote!(#name<#(#output_args),*>);
+    if uses_param_elsewhere(data, delegate_member, &members, param) {
+        return Err(Error::new_spanned(
+            &delegate.ty,
+            "the type of the #[styleable] field can't be used by other fields",
+        ));
+    }
+
+    let method = |method: Ident, arg: proc_macro2::TokenStream| {
+        let fields = members.iter().map(|member| {
+            if member == delegate_member {
+                quote!(#member: self.#member.#method(#arg))
+            } else {
+                quote!(#member: self.#member)
+            }
+        });
+        quote!(#name { #(#fields),* })
+    };
+    let fg = method(Ident::new("fg", Span::call_site()), quote!(color));
+    let bg = method(Ident::new("bg", Span::call_site()), quote!(color));
+    let style = method(Ident::new("style", Span::call_site()), quote!(style));
+    let modifier = method(Ident::new("modifier", Span::call_site()), quote!(modifier));
+
+    Ok(quote! {
+        impl #impl_generics #trui::Styleable for #name #ty_generics #where_clause {
+            type Output = #output;
+
+            fn fg(self, color: #trui::Color) -> Self::Output {
+                #fg
+            }
+
+            fn bg(self, color: #trui::Color) -> Self::Output {
+                #bg
+            }
+
+            fn style(self, style: #trui::Style) -> Self::Output {
+                #style
+            }
+
+            fn modifier(self, modifier: #trui::Modifier) -> Self::Output {
+                #modifier
+            }
+
+            fn current_style(&self) -> #trui::Style {
+                #trui::Styleable::current_style(&self.#delegate_member)
+            }
+        }
+    })
+}
+
+/// Whether a field other than the delegate mentions the type parameter `param`,
+/// which would lead to a type mismatch, as only the delegate is converted to the output type.
+fn uses_param_elsewhere(
+    data: &syn::DataStruct,
+    delegate: &Member,
+    members: &[Member],
+    param: &Ident,
+) -> bool {
+    fn mentions(ty: &Type, param: &Ident) -> bool {
+        match ty {
+            Type::Path(path) => path.path.segments.iter().any(|segment| {
+                segment.ident == *param
+                    || match &segment.arguments {
+                        PathArguments::AngleBracketed(args) => args.args.iter().any(
+                            |arg| matches!(arg, GenericArgument::Type(ty) if mentions(ty, param)),
+                        ),
+                        _ => false,
+                    }
+            }),
+            Type::Reference(reference) => mentions(&reference.elem, param),
+            Type::Tuple(tuple) => tuple.elems.iter().any(|ty| mentions(ty, param)),
+            Type::Array(array) => mentions(&array.elem, param),
+            Type::Slice(slice) => mentions(&slice.elem, param),
+            _ => false,
+        }
+    }
+    data.fields
+        .iter()
+        .zip(members)
+        .any(|(field, member)| member != delegate && mentions(&field.ty, param))
+}